@@ -0,0 +1,131 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use clap::Parser;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_log::set_log_level;
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::event::Event;
+
+fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn core::error::Error>>
+where
+    <T::Event as Event>::Listener: SynchronousMultiplexing,
+{
+    let service_name = ServiceName::new("waitset_burst")?;
+    let node = NodeBuilder::new().create::<T>()?;
+
+    let service = node
+        .service_builder(&service_name)
+        .event()
+        .max_listeners(args.number_of_attachments)
+        .create()?;
+
+    let notifier = service.notifier_builder().create()?;
+    let mut listeners = Vec::with_capacity(args.number_of_attachments);
+    for _ in 0..args.number_of_attachments {
+        listeners.push(service.listener_builder().create()?);
+    }
+
+    let waitset = WaitSetBuilder::new().create::<T>()?;
+    let mut guards = Vec::with_capacity(args.number_of_attachments);
+    for listener in &listeners {
+        // the deadline is never hit, it is only reset by every burst
+        guards.push(waitset.attach_deadline(listener, Duration::from_secs(3600))?);
+    }
+
+    let mut total_time = Duration::ZERO;
+    for _ in 0..args.iterations {
+        // every notification wakes up all listeners at once
+        notifier.notify()?;
+
+        let start = Time::now().expect("failed to acquire time");
+        waitset.wait_and_process_once(|_| CallbackProgression::Continue)?;
+        total_time += start.elapsed().expect("failed to measure time");
+
+        for listener in &listeners {
+            listener.try_wait_all(|_| {})?;
+        }
+    }
+
+    println!(
+        "{} ::: Attachments: {}, Iterations: {}, Time: {} s, Per burst: {} ns, Per attachment: {} ns",
+        core::any::type_name::<T>(),
+        args.number_of_attachments,
+        args.iterations,
+        total_time.as_secs_f64(),
+        total_time.as_nanos() / args.iterations as u128,
+        total_time.as_nanos() / (args.iterations * args.number_of_attachments) as u128
+    );
+
+    Ok(())
+}
+
+const ITERATIONS: usize = 10000;
+const NUMBER_OF_ATTACHMENTS: usize = 128;
+
+#[derive(Parser, Debug)]
+#[clap(version, about, long_about = None)]
+struct Args {
+    /// Number of bursts that are handled by the WaitSet
+    #[clap(short, long, default_value_t = ITERATIONS)]
+    iterations: usize,
+    /// Run benchmark for every service setup
+    #[clap(short, long)]
+    bench_all: bool,
+    /// Run benchmark for the IPC zero copy setup
+    #[clap(long)]
+    bench_ipc: bool,
+    /// Run benchmark for the process local setup
+    #[clap(long)]
+    bench_local: bool,
+    /// The number of deadline attachments that are triggered with every burst
+    #[clap(short, long, default_value_t = NUMBER_OF_ATTACHMENTS)]
+    number_of_attachments: usize,
+    /// Activate full log output
+    #[clap(short, long)]
+    debug_mode: bool,
+}
+
+fn main() -> Result<(), Box<dyn core::error::Error>> {
+    let args = Args::parse();
+
+    if args.debug_mode {
+        set_log_level(iceoryx2_bb_log::LogLevel::Trace);
+    } else {
+        set_log_level(iceoryx2_bb_log::LogLevel::Error);
+    }
+
+    let mut at_least_one_benchmark_did_run = false;
+
+    if args.bench_ipc || args.bench_all {
+        perform_benchmark::<ipc::Service>(&args)?;
+        perform_benchmark::<ipc_threadsafe::Service>(&args)?;
+        at_least_one_benchmark_did_run = true;
+    }
+
+    if args.bench_local || args.bench_all {
+        perform_benchmark::<local::Service>(&args)?;
+        perform_benchmark::<local_threadsafe::Service>(&args)?;
+        at_least_one_benchmark_did_run = true;
+    }
+
+    if !at_least_one_benchmark_did_run {
+        println!(
+            "Please use either '--bench-all' or select a specific benchmark. See `--help` for details."
+        );
+    }
+
+    Ok(())
+}
@@ -10,12 +10,37 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use iceoryx2::node::NodeView;
 use iceoryx2::prelude::*;
 
 fn main() -> Result<(), Box<dyn core::error::Error>> {
     set_log_level_from_env_or(LogLevel::Info);
+
+    println!("Nodes:");
+    Node::<ipc::Service>::list(Config::global_config(), |node_state| {
+        if let NodeState::Alive(node) = node_state {
+            let name = node
+                .details()
+                .as_ref()
+                .map(|details| details.name().to_string())
+                .unwrap_or_default();
+            println!(
+                "  name: {:?}, id: {}, pid: {}, created: {}s",
+                name,
+                node.id().value(),
+                node.id().pid(),
+                node.id().creation_time().seconds()
+            );
+        }
+        CallbackProgression::Continue
+    })?;
+
+    println!("\nServices:");
     ipc::Service::list(Config::global_config(), |service| {
-        println!("\n{:#?}", &service);
+        match service {
+            Ok(service) => println!("\n{:#?}", &service),
+            Err(e) => println!("\nunable to acquire service details ({e})"),
+        }
         CallbackProgression::Continue
     })?;
 
@@ -29,7 +29,9 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
 
     // use the custom config when listing the services
     ipc::Service::list(&config, |service| {
-        println!("  {}", &service.static_details.name());
+        if let Ok(service) = service {
+            println!("  {}", &service.static_details.name());
+        }
         CallbackProgression::Continue
     })?;
 
@@ -107,7 +107,7 @@ impl CustomSubscriber {
     }
 
     fn handle_event(&self) -> Result<(), Box<dyn core::error::Error>> {
-        while let Some(event) = self.listener.try_wait_one()? {
+        while let Some(event) = self.listener.try_wait_one()?.event_id() {
             let event: PubSubEvent = event.into();
             match event {
                 PubSubEvent::SentHistory => {
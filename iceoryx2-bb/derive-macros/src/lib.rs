@@ -330,5 +330,67 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Implements `From<T> for iceoryx2::port::event_id::EventId` and
+/// `TryFrom<iceoryx2::port::event_id::EventId> for T` for an enum without fields so that its
+/// variants can be used as event ids. Every variant is mapped to its discriminant, therefore
+/// all discriminants must be non-negative. An event id that does not correspond to any
+/// variant is rejected with `iceoryx2::port::event_id::EventIdConversionError::UnknownEventId`.
+///
+/// The derive does not know the `event_id_max_value` of the service, event ids that exceed it
+/// are rejected by the notifier when the event is sent.
+///
+/// See `iceoryx2::port::event_id` for an example.
+#[proc_macro_derive(IntoEventId)]
+pub fn into_event_id_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let enum_name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let data_enum = match ast.data {
+        Data::Enum(ref data_enum) => data_enum,
+        _ => {
+            return quote! {compile_error!("IntoEventId can only be implemented for enums");}
+                .into();
+        }
+    };
+
+    if data_enum
+        .variants
+        .iter()
+        .any(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return quote! {compile_error!("IntoEventId can only be implemented for enums without fields");}
+            .into();
+    }
+
+    let variant_matches = data_enum.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        quote! {
+            v if v == Self::#variant_name as usize => Ok(Self::#variant_name),
+        }
+    });
+
+    let expanded = quote! {
+        impl #impl_generics From<#enum_name #ty_generics> for ::iceoryx2::port::event_id::EventId #where_clause {
+            fn from(value: #enum_name #ty_generics) -> Self {
+                ::iceoryx2::port::event_id::EventId::new(value as usize)
+            }
+        }
+
+        impl #impl_generics TryFrom<::iceoryx2::port::event_id::EventId> for #enum_name #ty_generics #where_clause {
+            type Error = ::iceoryx2::port::event_id::EventIdConversionError;
+
+            fn try_from(value: ::iceoryx2::port::event_id::EventId) -> Result<Self, Self::Error> {
+                match value.as_value() {
+                    #(#variant_matches)*
+                    _ => Err(::iceoryx2::port::event_id::EventIdConversionError::UnknownEventId),
+                }
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
 #[cfg(doctest)]
 mod zero_copy_send_compile_tests;
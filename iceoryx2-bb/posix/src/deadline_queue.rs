@@ -38,8 +38,13 @@
 
 pub use iceoryx2_bb_elementary::CallbackProgression;
 
-use core::{cell::RefCell, fmt::Debug, sync::atomic::Ordering, time::Duration};
-use iceoryx2_bb_log::fail;
+use core::{
+    cell::RefCell,
+    fmt::Debug,
+    sync::atomic::Ordering,
+    time::Duration,
+};
+use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use crate::{
@@ -51,6 +56,13 @@ use crate::{
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct DeadlineQueueIndex(u64);
 
+impl DeadlineQueueIndex {
+    /// Returns the underlying raw value of the index
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
 pub trait DeadlineQueueGuardable: Debug {}
 
 /// Represents the RAII guard of [`DeadlineQueue`] and is returned by [`DeadlineQueue::add_deadline_interval()`].
@@ -73,6 +85,19 @@ impl DeadlineQueueGuard<'_> {
     pub fn reset(&self) -> Result<(), TimeError> {
         self.deadline_queue.reset(self.index)
     }
+
+    /// Returns the waiting duration until the deadline of the attachment is reached. See
+    /// [`DeadlineQueue::duration_until_deadline()`].
+    pub fn duration_until_deadline(&self) -> Result<Option<Duration>, TimeError> {
+        self.deadline_queue.duration_until_deadline(self.index)
+    }
+
+    /// Replaces the deadline of the attachment with the provided deadline and waits
+    /// again the full time. See [`DeadlineQueue::update_deadline_interval()`].
+    pub fn update_deadline_interval(&self, deadline: Duration) -> Result<(), TimeError> {
+        self.deadline_queue
+            .update_deadline_interval(self.index, deadline)
+    }
 }
 
 impl Drop for DeadlineQueueGuard<'_> {
@@ -118,6 +143,8 @@ impl DeadlineQueueBuilder {
             id_count: IoxAtomicU64::new(0),
             clock_type: self.clock_type,
             previous_iteration: RefCell::new(start_time),
+            #[cfg(feature = "testing")]
+            injected_time_error: core::cell::Cell::new(None),
         })
     }
 }
@@ -126,7 +153,9 @@ impl DeadlineQueueBuilder {
 struct Attachment {
     index: u64,
     period: u128,
-    start_time: u128,
+    // the deadlines are hit at first_deadline + n * period with n >= 0
+    first_deadline: u128,
+    realign_on_reset: bool,
 }
 
 impl Attachment {
@@ -138,15 +167,69 @@ impl Attachment {
         Ok(Self {
             index,
             period,
-            start_time,
+            first_deadline: start_time + period,
+            realign_on_reset: false,
         })
     }
 
-    fn reset(&mut self, clock_type: ClockType) -> Result<(), TimeError> {
-        let start_time = fail!(from "Attachment::new()", when Time::now_with_clock(clock_type),
-                                "Failed to reset DeadlineQueue attachment since the current time could not be acquired.");
-        self.start_time = start_time.as_duration().as_nanos();
-        Ok(())
+    fn new_with_delay(
+        index: u64,
+        period: u128,
+        delay: u128,
+        realign_on_reset: bool,
+        clock_type: ClockType,
+    ) -> Result<Self, TimeError> {
+        let now = fail!(from "Attachment::new_with_delay()", when Time::now_with_clock(clock_type),
+                                "Failed to create DeadlineQueue attachment since the current time could not be acquired.");
+        let now = now.as_duration().as_nanos();
+
+        Ok(Self {
+            index,
+            period,
+            first_deadline: now + delay,
+            realign_on_reset,
+        })
+    }
+
+    fn reset(&mut self, now: u128) {
+        if self.realign_on_reset && self.period != 0 {
+            if self.first_deadline <= now {
+                self.first_deadline +=
+                    ((now - self.first_deadline) / self.period + 1) * self.period;
+            }
+        } else {
+            self.first_deadline = now + self.period;
+        }
+    }
+
+    fn has_missed_deadline(&self, last: u128, now: u128) -> bool {
+        self.number_of_missed_deadlines(last, now) > 0
+    }
+
+    // number of deadlines that were hit until and including the provided point in time
+    fn number_of_deadlines_until(&self, time: u128) -> u128 {
+        if time < self.first_deadline {
+            0
+        } else {
+            (time - self.first_deadline) / self.period + 1
+        }
+    }
+
+    fn number_of_missed_deadlines(&self, last: u128, now: u128) -> u64 {
+        match self.period {
+            0 => 1,
+            _ => self
+                .number_of_deadlines_until(now)
+                .saturating_sub(self.number_of_deadlines_until(last)) as u64,
+        }
+    }
+
+    fn duration_until_deadline(&self, now: u128) -> u128 {
+        if now < self.first_deadline {
+            self.first_deadline - now
+        } else {
+            self.period - (now - self.first_deadline) % self.period
+        }
     }
 }
 
@@ -161,9 +244,28 @@ pub struct DeadlineQueue {
     previous_iteration: RefCell<u128>,
 
     clock_type: ClockType,
+    #[cfg(feature = "testing")]
+    injected_time_error: core::cell::Cell<Option<TimeError>>,
 }
 
 impl DeadlineQueue {
+    /// Every subsequent time query fails with the provided [`TimeError`] until it is called
+    /// with [`None`]. Used to verify the error handling of a failing clock.
+    #[doc(hidden)]
+    #[cfg(feature = "testing")]
+    pub fn __internal_inject_time_error(&self, value: Option<TimeError>) {
+        self.injected_time_error.set(value);
+    }
+
+    fn now(&self) -> Result<Time, TimeError> {
+        #[cfg(feature = "testing")]
+        if let Some(e) = self.injected_time_error.get() {
+            return Err(e);
+        }
+
+        Time::now_with_clock(self.clock_type)
+    }
+
     /// Returns the number of attachments.
     pub fn len(&self) -> usize {
         self.attachments.borrow().len()
@@ -196,6 +298,35 @@ impl DeadlineQueue {
         })
     }
 
+    /// Adds a cyclic deadline to the [`DeadlineQueue`] whose first deadline is hit after
+    /// `first_deadline` and every `deadline` afterwards. A `first_deadline` of zero is reported
+    /// as missed with the next call to [`DeadlineQueue::missed_deadlines()`].
+    /// If `realign_on_reset` is true, [`DeadlineQueue::reset()`] does not restart the full
+    /// deadline but keeps the attachment aligned to its original schedule.
+    pub fn add_deadline_interval_at(
+        &self,
+        first_deadline: Duration,
+        deadline: Duration,
+        realign_on_reset: bool,
+    ) -> Result<DeadlineQueueGuard<'_>, TimeError> {
+        let current_idx = self.id_count.load(Ordering::Relaxed);
+        self.attachments
+            .borrow_mut()
+            .push(Attachment::new_with_delay(
+                current_idx,
+                deadline.as_nanos(),
+                first_deadline.as_nanos(),
+                realign_on_reset,
+                self.clock_type,
+            )?);
+        self.id_count.fetch_add(1, Ordering::Relaxed);
+
+        Ok(DeadlineQueueGuard {
+            deadline_queue: self,
+            index: DeadlineQueueIndex(current_idx),
+        })
+    }
+
     fn remove(&self, index: u64) {
         let mut index_to_remove = None;
         for (n, attachment) in self.attachments.borrow().iter().enumerate() {
@@ -212,16 +343,62 @@ impl DeadlineQueue {
 
     /// Resets the attached deadline_queue and wait again the full time.
     pub fn reset(&self, index: DeadlineQueueIndex) -> Result<(), TimeError> {
-        for attachment in &mut *self.attachments.borrow_mut() {
-            if attachment.index == index.0 {
-                attachment.reset(self.clock_type)?;
-                break;
+        self.reset_multiple(core::iter::once(index))
+    }
+
+    /// Resets all provided attachments and waits again the full time. In contrast to calling
+    /// [`DeadlineQueue::reset()`] for every attachment, the current time is acquired only once.
+    pub fn reset_multiple<I: IntoIterator<Item = DeadlineQueueIndex>>(
+        &self,
+        indices: I,
+    ) -> Result<(), TimeError> {
+        let mut indices = indices.into_iter().peekable();
+        if indices.peek().is_none() {
+            return Ok(());
+        }
+
+        let now = fail!(from self, when self.now(),
+                        "Failed to reset deadlines since the current time could not be acquired.");
+        let now = now.as_duration().as_nanos();
+
+        let mut attachments = self.attachments.borrow_mut();
+        for index in indices {
+            // attachments are added with an increasing index and the order is never changed
+            if let Ok(n) = attachments.binary_search_by_key(&index.0, |a| a.index) {
+                attachments[n].reset(now);
             }
         }
 
         Ok(())
     }
 
+    /// Replaces the deadline of an attachment in-place with the provided deadline and waits
+    /// again the full time. The [`DeadlineQueueIndex`] of the attachment stays the same.
+    pub fn update_deadline_interval(
+        &self,
+        index: DeadlineQueueIndex,
+        deadline: Duration,
+    ) -> Result<(), TimeError> {
+        let now = fail!(from self, when self.now(),
+                "Failed to update deadline interval since the current time could not be acquired.");
+
+        let mut attachments = self.attachments.borrow_mut();
+        // attachments are added with an increasing index and the order is never changed
+        match attachments.binary_search_by_key(&index.0, |a| a.index) {
+            Ok(n) => {
+                let attachment = &mut attachments[n];
+                attachment.period = deadline.as_nanos();
+                attachment.realign_on_reset = false;
+                attachment.reset(now.as_duration().as_nanos());
+                Ok(())
+            }
+            Err(_) => {
+                fatal_panic!(from self,
+                    "This should never happen! Unable to update the deadline interval of {:?} since it is not attached.", index);
+            }
+        }
+    }
+
     /// Returns the waiting duration until the next deadline is reached. If there have been
     /// already deadlines missed it returns a duration of zero.
     pub fn duration_until_next_deadline(&self) -> Result<Duration, TimeError> {
@@ -229,11 +406,11 @@ impl DeadlineQueue {
             return Ok(Duration::MAX);
         }
 
-        let now = fail!(from self, when Time::now_with_clock(self.clock_type),
+        let now = fail!(from self, when self.now(),
                         "Unable to return next duration since the current time could not be acquired.");
         let now = now.as_duration().as_nanos();
         let mut has_missed_deadline = false;
-        self.handle_missed_deadlines(now, |_| {
+        self.handle_missed_deadlines(now, |_, _| {
             has_missed_deadline = true;
             CallbackProgression::Stop
         });
@@ -248,14 +425,39 @@ impl DeadlineQueue {
 
         let mut min_time = u128::MAX;
         for attachment in &*self.attachments.borrow() {
-            min_time =
-                min_time.min(attachment.period - (now - attachment.start_time) % attachment.period);
+            min_time = min_time.min(attachment.duration_until_deadline(now));
         }
 
         Ok(Duration::from_nanos(min_time as _))
     }
 
-    fn handle_missed_deadlines<F: FnMut(DeadlineQueueIndex) -> CallbackProgression>(
+    /// Returns the waiting duration until the deadline of the attachment with the provided
+    /// [`DeadlineQueueIndex`] is reached. If the deadline was already missed it returns a
+    /// duration of zero. Returns [`None`] when no such attachment exists.
+    pub fn duration_until_deadline(
+        &self,
+        index: DeadlineQueueIndex,
+    ) -> Result<Option<Duration>, TimeError> {
+        let now = fail!(from self, when self.now(),
+                        "Unable to return duration until deadline since the current time could not be acquired.");
+        let now = now.as_duration().as_nanos();
+        let last = *self.previous_iteration.borrow();
+
+        Ok(self
+            .attachments
+            .borrow()
+            .iter()
+            .find(|attachment| attachment.index == index.0)
+            .map(|attachment| {
+                if attachment.has_missed_deadline(last, now) {
+                    Duration::ZERO
+                } else {
+                    Duration::from_nanos(attachment.duration_until_deadline(now) as _)
+                }
+            }))
+    }
+
+    fn handle_missed_deadlines<F: FnMut(DeadlineQueueIndex, u64) -> CallbackProgression>(
         &self,
         now: u128,
         mut call: F,
@@ -263,30 +465,17 @@ impl DeadlineQueue {
         let last = *self.previous_iteration.borrow();
 
         for attachment in &*self.attachments.borrow() {
-            let duration_until_last = last.max(attachment.start_time) - attachment.start_time;
-            let duration_until_now = now - attachment.start_time;
-            match attachment.period {
-                0 => {
-                    if matches!(
-                        call(DeadlineQueueIndex(attachment.index)),
-                        CallbackProgression::Stop
-                    ) {
-                        return;
-                    }
-                }
-                _ => {
-                    let last = duration_until_last / attachment.period;
-                    let current = duration_until_now / attachment.period;
-
-                    if last < current
-                        && matches!(
-                            call(DeadlineQueueIndex(attachment.index)),
-                            CallbackProgression::Stop
-                        )
-                    {
-                        return;
-                    }
-                }
+            let number_of_missed_deadlines = attachment.number_of_missed_deadlines(last, now);
+            if number_of_missed_deadlines > 0
+                && matches!(
+                    call(
+                        DeadlineQueueIndex(attachment.index),
+                        number_of_missed_deadlines
+                    ),
+                    CallbackProgression::Stop
+                )
+            {
+                return;
             }
         }
     }
@@ -297,11 +486,22 @@ impl DeadlineQueue {
         &self,
         mut call: F,
     ) -> Result<(), TimeError> {
-        let now = fail!(from self, when Time::now_with_clock(self.clock_type),
+        self.missed_deadlines_with_count(|idx, _| -> CallbackProgression { call(idx) })
+    }
+
+    /// Iterates over all missed deadlines like [`DeadlineQueue::missed_deadlines()`] but
+    /// provides additionally how many deadlines of the attachment were missed since the last
+    /// call. A value greater than 1 means that the deadline was missed multiple times, for
+    /// instance because the previous iteration took longer than the deadline interval.
+    pub fn missed_deadlines_with_count<F: FnMut(DeadlineQueueIndex, u64) -> CallbackProgression>(
+        &self,
+        call: F,
+    ) -> Result<(), TimeError> {
+        let now = fail!(from self, when self.now(),
                         "Unable to return next duration since the current time could not be acquired.");
 
         let now = now.as_duration().as_nanos();
-        self.handle_missed_deadlines(now, |idx| -> CallbackProgression { call(idx) });
+        self.handle_missed_deadlines(now, call);
         *self.previous_iteration.borrow_mut() = now;
 
         Ok(())
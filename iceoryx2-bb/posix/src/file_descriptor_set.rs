@@ -162,10 +162,29 @@ impl FileDescriptorSet {
         self.add_impl(fd.file_descriptor())
     }
 
+    /// Adds a file descriptor without returning a [`FileDescriptorSetGuard`]. It stays in the
+    /// [`FileDescriptorSet`] until it is removed with [`FileDescriptorSet::remove_unguarded()`].
+    /// The owner of the file descriptor must remove it before it is closed, otherwise the next
+    /// wait fails.
+    pub fn add_unguarded(&self, fd: &FileDescriptor) -> Result<(), FileDescriptorSetAddError> {
+        self.insert(fd)
+    }
+
+    /// Removes a file descriptor that was added with [`FileDescriptorSet::add_unguarded()`].
+    /// Since the file descriptor may be already closed, it is identified by its native handle.
+    pub fn remove_unguarded(&self, native_handle: i32) {
+        self.remove(native_handle)
+    }
+
     fn add_impl<'set, 'fd>(
         &'set self,
         fd: &'fd FileDescriptor,
     ) -> Result<FileDescriptorSetGuard<'set, 'fd>, FileDescriptorSetAddError> {
+        self.insert(fd)?;
+        Ok(FileDescriptorSetGuard { set: self, fd })
+    }
+
+    fn insert(&self, fd: &FileDescriptor) -> Result<(), FileDescriptorSetAddError> {
         let msg = "Unable to add file descriptor";
         if self.internals().file_descriptors.len() >= Self::capacity() {
             fail!(from self, with FileDescriptorSetAddError::CapacityExceeded,
@@ -192,7 +211,7 @@ impl FileDescriptorSet {
             .file_descriptors
             .push(unsafe { fd.file_descriptor().native_handle() });
 
-        Ok(FileDescriptorSetGuard { set: self, fd })
+        Ok(())
     }
 
     fn remove(&self, value: i32) {
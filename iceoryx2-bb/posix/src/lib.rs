@@ -54,6 +54,7 @@ pub mod file_type;
 pub mod group;
 pub mod ipc_capable;
 pub mod memory;
+pub mod memory_advice;
 pub mod memory_lock;
 pub mod metadata;
 pub mod mutex;
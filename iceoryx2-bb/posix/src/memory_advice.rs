@@ -0,0 +1,91 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`MemoryAdvice`] informs the operating system how a specific range in the memory is
+//! used so that it can optimize the paging, for instance by backing it with huge pages to
+//! reduce the TLB pressure of large memory regions. The advice is only a hint, the operating
+//! system may ignore it.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::memory::*;
+//! use iceoryx2_bb_posix::memory_advice::*;
+//! use iceoryx2_bb_posix::system_configuration::SystemInfo;
+//! use core::alloc::Layout;
+//!
+//! let page_size = SystemInfo::PageSize.value();
+//! let layout = Layout::from_size_align(4 * page_size, page_size).unwrap();
+//! let memory = heap::allocate(layout).unwrap();
+//!
+//! match unsafe { MemoryAdvice::HugePages.apply(memory.as_ptr().cast(), layout.size()) } {
+//!     Ok(()) => println!("memory is backed by huge pages when possible"),
+//!     Err(e) => println!("huge pages are not available ({:?})", e),
+//! }
+//!
+//! unsafe { heap::deallocate(memory.cast(), layout) };
+//! ```
+
+use crate::system_configuration::SystemInfo;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MemoryAdviceError {
+    InvalidAddressRange,
+    UnsupportedAdviceOrUnalignedAddress,
+    NotSupported,
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for MemoryAdviceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MemoryAdviceError::{self:?}")
+    }
+}
+
+impl core::error::Error for MemoryAdviceError {}
+
+/// Defines how a memory region is used, see [`MemoryAdvice::apply()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[repr(i32)]
+pub enum MemoryAdvice {
+    /// The memory region shall be backed by huge pages. Only supported on Linux, it
+    /// requires transparent huge pages which are enabled for the type of the memory.
+    HugePages = posix::MADV_HUGEPAGE,
+}
+
+impl MemoryAdvice {
+    /// Applies the [`MemoryAdvice`] to the provided memory region. The address must be a
+    /// multiple of the page size.
+    ///
+    /// # Safety
+    ///   * the memory range [address, len] must be mapped into the address space of the process
+    ///
+    pub unsafe fn apply(
+        self,
+        address: *mut posix::void,
+        len: usize,
+    ) -> Result<(), MemoryAdviceError> {
+        if unsafe { posix::madvise(address, len, self as i32) } == 0 {
+            return Ok(());
+        }
+
+        let msg = "Unable to apply the memory advice";
+        handle_errno!(MemoryAdviceError, from "MemoryAdvice::apply",
+            Errno::ENOMEM => (InvalidAddressRange, "{} {:?} since the specified range beginning from {:#16X} with a length of {} is not mapped into the address space of the current process.", msg, self, address as usize, len),
+            Errno::EINVAL => (UnsupportedAdviceOrUnalignedAddress, "{} {:?} since either the address {:#16X} is not a multiple of the page-size {} or the advice is not supported by the system.", msg, self, address as usize, SystemInfo::PageSize.value()),
+            Errno::ENOSYS => (NotSupported, "{} {:?} since it is not supported by the platform.", msg, self),
+            v => (UnknownError(v as i32), "{} {:?} since an unknown error occurred ({}).", msg, self, v)
+        );
+    }
+}
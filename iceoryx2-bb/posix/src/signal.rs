@@ -395,6 +395,17 @@ impl SignalHandler {
         }
     }
 
+    /// Returns the last signal which was raised without resetting it, so that a later call to
+    /// [`SignalHandler::last_signal()`] or [`SignalHandler::termination_requested()`] still
+    /// observes it.
+    pub fn peek_last_signal() -> Option<NonFatalFetchableSignal> {
+        Self::instance();
+        match LAST_SIGNAL.load(Ordering::Relaxed) {
+            posix::MAX_SIGNAL_VALUE => None,
+            v => Some((v as i32).into()),
+        }
+    }
+
     /// Returns true if ([`NonFatalFetchableSignal::Interrupt`] or
     /// [`NonFatalFetchableSignal::Terminate`]) was emitted
     /// for instance by pressing CTRL+c, otherwise false
@@ -13,6 +13,7 @@
 mod deadline_queue {
     use core::time::Duration;
     use iceoryx2_bb_elementary::CallbackProgression;
+    use iceoryx2_bb_posix::clock::{ClockType, Time, TimeError};
     use iceoryx2_bb_posix::deadline_queue::*;
     use iceoryx2_bb_testing::assert_that;
 
@@ -91,6 +92,38 @@ mod deadline_queue {
         assert_that!(sut.duration_until_next_deadline().unwrap(), le Duration::from_secs(100));
     }
 
+    #[test]
+    fn update_deadline_interval_works() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard_1 = sut
+            .add_deadline_interval(Duration::from_secs(1000))
+            .unwrap();
+        let _guard_2 = sut.add_deadline_interval(Duration::from_secs(100)).unwrap();
+
+        guard_1
+            .update_deadline_interval(Duration::from_secs(5))
+            .unwrap();
+
+        assert_that!(sut.len(), eq 2);
+        assert_that!(sut.duration_until_next_deadline().unwrap(), le Duration::from_secs(5));
+        assert_that!(sut.duration_until_next_deadline().unwrap(), ge Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn update_deadline_interval_of_removed_attachment_panics() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut
+            .add_deadline_interval(Duration::from_secs(1000))
+            .unwrap();
+        let index = guard.index();
+        drop(guard);
+
+        let _ = sut.update_deadline_interval(index, Duration::from_secs(5));
+    }
+
     #[test]
     fn no_missed_deadline_works() {
         let sut = DeadlineQueueBuilder::new().create().unwrap();
@@ -224,4 +257,173 @@ mod deadline_queue {
         let next_deadline = sut.duration_until_next_deadline().unwrap();
         assert_that!(next_deadline, ne Duration::ZERO);
     }
+
+    #[test]
+    fn duration_until_deadline_is_acquired_per_attachment() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard_1 = sut
+            .add_deadline_interval(Duration::from_millis(100))
+            .unwrap();
+        let guard_2 = sut.add_deadline_interval(Duration::from_secs(10)).unwrap();
+
+        let remaining = guard_2.duration_until_deadline().unwrap().unwrap();
+        assert_that!(remaining, gt Duration::from_secs(1));
+        assert_that!(remaining, le Duration::from_secs(10));
+
+        std::thread::sleep(Duration::from_millis(110));
+
+        assert_that!(guard_1.duration_until_deadline().unwrap(), eq Some(Duration::ZERO));
+        assert_that!(guard_2.duration_until_deadline().unwrap(), ne Some(Duration::ZERO));
+
+        let index = guard_1.index();
+        drop(guard_1);
+        assert_that!(sut.duration_until_deadline(index).unwrap(), is_none);
+    }
+
+    #[test]
+    fn deadline_interval_at_waits_until_first_deadline() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let _guard = sut
+            .add_deadline_interval_at(Duration::from_secs(100), Duration::from_secs(1), false)
+            .unwrap();
+
+        assert_that!(sut.duration_until_next_deadline().unwrap(), le Duration::from_secs(100));
+        assert_that!(sut.duration_until_next_deadline().unwrap(), ge Duration::from_secs(99));
+    }
+
+    #[test]
+    fn deadline_interval_at_with_passed_first_deadline_is_missed_immediately() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut
+            .add_deadline_interval_at(Duration::ZERO, Duration::from_secs(100), false)
+            .unwrap();
+
+        let mut missed_deadlines = vec![];
+        sut.missed_deadlines(|idx| {
+            missed_deadlines.push(idx);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadlines, len 1);
+        assert_that!(missed_deadlines, contains guard.index());
+        assert_that!(sut.duration_until_next_deadline().unwrap(), ge Duration::from_secs(99));
+    }
+
+    #[test]
+    fn deadline_interval_at_stays_aligned_on_reset_when_realignment_is_enabled() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut
+            .add_deadline_interval_at(Duration::from_millis(100), Duration::from_secs(100), true)
+            .unwrap();
+
+        std::thread::sleep(Duration::from_millis(10));
+        guard.reset().unwrap();
+
+        assert_that!(sut.duration_until_next_deadline().unwrap(), le Duration::from_millis(90));
+    }
+
+    #[test]
+    fn deadline_interval_at_with_period_larger_than_current_time_hits_first_deadline() {
+        const FIRST_DEADLINE: Duration = Duration::from_millis(10);
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let now = Time::now_with_clock(ClockType::default()).unwrap();
+        let period = now.as_duration() + Duration::from_secs(24 * 3600);
+        let guard = sut
+            .add_deadline_interval_at(FIRST_DEADLINE, period, false)
+            .unwrap();
+
+        assert_that!(sut.duration_until_next_deadline().unwrap(), le FIRST_DEADLINE);
+
+        std::thread::sleep(FIRST_DEADLINE);
+
+        let mut missed_deadlines = vec![];
+        sut.missed_deadlines(|idx| {
+            missed_deadlines.push(idx);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadlines, len 1);
+        assert_that!(missed_deadlines, contains guard.index());
+    }
+
+    #[test]
+    fn reset_multiple_resets_only_provided_deadlines() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard_1 = sut
+            .add_deadline_interval(Duration::from_millis(50))
+            .unwrap();
+        let guard_2 = sut
+            .add_deadline_interval(Duration::from_millis(50))
+            .unwrap();
+        let guard_3 = sut
+            .add_deadline_interval(Duration::from_millis(50))
+            .unwrap();
+
+        std::thread::sleep(Duration::from_millis(30));
+        sut.reset_multiple([guard_1.index(), guard_3.index()])
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(30));
+
+        let mut missed_deadlines = vec![];
+        sut.missed_deadlines(|idx| {
+            missed_deadlines.push(idx);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadlines, len 1);
+        assert_that!(missed_deadlines, contains guard_2.index());
+    }
+
+    #[test]
+    fn missed_deadlines_with_count_provides_number_of_missed_deadlines() {
+        const INTERVAL: Duration = Duration::from_millis(20);
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut.add_deadline_interval(INTERVAL).unwrap();
+        let _guard_2 = sut.add_deadline_interval(Duration::from_secs(100)).unwrap();
+
+        std::thread::sleep(INTERVAL * 3 + INTERVAL / 2);
+
+        let mut missed_deadlines = vec![];
+        sut.missed_deadlines_with_count(|idx, count| {
+            missed_deadlines.push((idx, count));
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadlines, len 1);
+        assert_that!(missed_deadlines[0].0, eq guard.index());
+        assert_that!(missed_deadlines[0].1, ge 3);
+
+        missed_deadlines.clear();
+        sut.missed_deadlines_with_count(|idx, count| {
+            missed_deadlines.push((idx, count));
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(missed_deadlines, len 0);
+    }
+    #[test]
+    fn time_queries_fail_when_time_cannot_be_acquired() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+        let guard = sut.add_deadline_interval(Duration::from_secs(100)).unwrap();
+
+        sut.__internal_inject_time_error(Some(TimeError::UnknownError(0)));
+        assert_that!(sut.duration_until_next_deadline().err(), eq Some(TimeError::UnknownError(0)));
+        assert_that!(guard.reset().err(), eq Some(TimeError::UnknownError(0)));
+        let result = sut.missed_deadlines(|_| CallbackProgression::Continue);
+        assert_that!(result.err(), eq Some(TimeError::UnknownError(0)));
+
+        sut.__internal_inject_time_error(None);
+        assert_that!(sut.duration_until_next_deadline(), is_ok);
+    }
 }
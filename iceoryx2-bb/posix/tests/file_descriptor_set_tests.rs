@@ -129,6 +129,29 @@ fn file_descriptor_set_add_same_fd_twice_fails() {
     assert_that!(result.err(), eq Some(FileDescriptorSetAddError::AlreadyAttached));
 }
 
+#[test]
+fn file_descriptor_set_add_and_remove_unguarded_works() {
+    let fd_set = FileDescriptorSet::new();
+
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let socket = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    fd_set.add_unguarded(socket.file_descriptor()).unwrap();
+    assert_that!(fd_set.contains(&socket), eq true);
+    assert_that!(fd_set.len(), eq 1);
+
+    let result = fd_set.add(&socket);
+    assert_that!(result.err(), eq Some(FileDescriptorSetAddError::AlreadyAttached));
+
+    fd_set.remove_unguarded(unsafe { socket.file_descriptor().native_handle() });
+    assert_that!(fd_set.contains(&socket), eq false);
+    assert_that!(fd_set.len(), eq 0);
+}
+
 #[test]
 fn file_descriptor_set_timed_wait_works() {
     create_test_directory();
@@ -0,0 +1,61 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "linux")]
+mod memory_advice {
+    use core::alloc::Layout;
+
+    use iceoryx2_bb_posix::memory::heap;
+    use iceoryx2_bb_posix::memory_advice::*;
+    use iceoryx2_bb_posix::system_configuration::SystemInfo;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn transparent_huge_pages_are_available() -> bool {
+        std::fs::read_to_string("/sys/kernel/mm/transparent_hugepage/enabled")
+            .map(|v| !v.contains("[never]"))
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn memory_advice_huge_pages_works_when_available() {
+        if !transparent_huge_pages_are_available() {
+            return;
+        }
+
+        let page_size = SystemInfo::PageSize.value();
+        let layout = Layout::from_size_align(4 * page_size, page_size).unwrap();
+        let memory = heap::allocate(layout).unwrap();
+
+        let result =
+            unsafe { MemoryAdvice::HugePages.apply(memory.as_ptr().cast(), layout.size()) };
+        assert_that!(result, is_ok);
+
+        unsafe { heap::deallocate(memory.cast(), layout) };
+    }
+
+    #[test]
+    fn memory_advice_with_unaligned_address_fails() {
+        let page_size = SystemInfo::PageSize.value();
+        let layout = Layout::from_size_align(4 * page_size, page_size).unwrap();
+        let memory = heap::allocate(layout).unwrap();
+
+        let result = unsafe {
+            MemoryAdvice::HugePages.apply(
+                memory.as_ptr().cast::<u8>().add(1).cast(),
+                layout.size() - 1,
+            )
+        };
+        assert_that!(result, eq Err(MemoryAdviceError::UnsupportedAdviceOrUnalignedAddress));
+
+        unsafe { heap::deallocate(memory.cast(), layout) };
+    }
+}
@@ -154,6 +154,23 @@ fn signal_call_and_fetch_works() {
     assert_that!(result, eq Some(NonFatalFetchableSignal::Interrupt));
 }
 
+#[test]
+fn signal_peek_last_signal_does_not_reset_signal() {
+    test_requires!(POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING);
+    let _watchdog = Watchdog::new();
+
+    let _test = TestFixture::new();
+    SignalHandler::call_and_fetch(|| {
+        Process::from_self().send_signal(Signal::Interrupt).ok();
+        nanosleep(TIMEOUT).ok();
+    });
+
+    assert_that!(SignalHandler::peek_last_signal(), eq Some(NonFatalFetchableSignal::Interrupt));
+    assert_that!(SignalHandler::peek_last_signal(), eq Some(NonFatalFetchableSignal::Interrupt));
+    assert_that!(SignalHandler::last_signal(), eq Some(NonFatalFetchableSignal::Interrupt));
+    assert_that!(SignalHandler::peek_last_signal(), eq None);
+}
+
 #[test]
 fn signal_call_and_fetch_with_registered_handler_works() {
     test_requires!(POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING);
@@ -94,6 +94,8 @@ impl core::error::Error for ListenerCreateError {}
 pub struct TriggerId(usize);
 
 impl TriggerId {
+    pub const MAX: Self = Self(usize::MAX);
+
     pub const fn new(value: usize) -> Self {
         Self(value)
     }
@@ -101,6 +103,36 @@ impl TriggerId {
     pub const fn as_value(&self) -> usize {
         self.0
     }
+
+    pub const fn value(&self) -> usize {
+        self.0
+    }
+}
+
+impl From<u8> for TriggerId {
+    fn from(value: u8) -> Self {
+        Self(value as usize)
+    }
+}
+
+impl From<u16> for TriggerId {
+    fn from(value: u16) -> Self {
+        Self(value as usize)
+    }
+}
+
+impl From<u32> for TriggerId {
+    fn from(value: u32) -> Self {
+        Self(value as usize)
+    }
+}
+
+impl TryFrom<u64> for TriggerId {
+    type Error = core::num::TryFromIntError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Ok(Self(usize::try_from(value)?))
+    }
 }
 
 pub trait Notifier: NamedConcept + Debug + Send {
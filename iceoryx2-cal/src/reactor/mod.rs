@@ -52,11 +52,27 @@ pub trait Reactor: Sized + Debug + Send {
     fn len(&self) -> usize;
     fn is_empty(&self) -> bool;
 
+    /// Returns the finest timeout the interface of the [`Reactor`] can express in
+    /// [`Reactor::timed_wait()`], fractions of it are truncated. It is a lower bound, the
+    /// operating system may round the timeout up further, for instance due to the timer
+    /// slack or the tick rate of the kernel.
+    fn min_timeout_resolution(&self) -> Duration;
+
     fn attach<'reactor, 'attachment, F: SynchronousMultiplexing + Debug>(
         &'reactor self,
         value: &'attachment F,
     ) -> Result<Self::Guard<'reactor, 'attachment>, ReactorAttachError>;
 
+    /// Attaches the [`FileDescriptor`] like [`Reactor::attach()`] but without a guard, so that
+    /// the attachment does not borrow the [`Reactor`]. It stays attached until it is detached
+    /// with [`Reactor::detach_unguarded()`] which must happen before the [`Reactor`] waits
+    /// again after the [`FileDescriptor`] was closed.
+    fn attach_unguarded(&self, value: &FileDescriptor) -> Result<(), ReactorAttachError>;
+
+    /// Detaches a [`FileDescriptor`] that was attached with [`Reactor::attach_unguarded()`].
+    /// It is identified by its native handle since it may be already closed.
+    fn detach_unguarded(&self, native_handle: i32);
+
     fn try_wait<F: FnMut(&FileDescriptor)>(&self, fn_call: F) -> Result<usize, ReactorWaitError>;
     fn timed_wait<F: FnMut(&FileDescriptor)>(
         &self,
@@ -42,6 +42,23 @@ impl Reactor {
         }
     }
 
+    fn handle_add_error<T>(
+        &self,
+        error: FileDescriptorSetAddError,
+        msg: &str,
+    ) -> Result<T, ReactorAttachError> {
+        match error {
+            FileDescriptorSetAddError::CapacityExceeded => {
+                fail!(from self, with ReactorAttachError::CapacityExceeded,
+                    "{msg} since the capacity of the underlying file descriptor set was exceeded.");
+            }
+            FileDescriptorSetAddError::AlreadyAttached => {
+                fail!(from self, with ReactorAttachError::AlreadyAttached,
+                    "{msg} since it is already attached.");
+            }
+        }
+    }
+
     fn wait<
         F: FnMut(&FileDescriptor),
         W: FnMut(F, FileEvent) -> Result<usize, FileDescriptorSetWaitError>,
@@ -105,6 +122,12 @@ impl crate::reactor::Reactor for Reactor {
         self.set.is_empty()
     }
 
+    fn min_timeout_resolution(&self) -> Duration {
+        // select() expects the timeout as timeval which has a resolution of microseconds,
+        // the kernel may extend the timeout further by its timer slack
+        Duration::from_micros(1)
+    }
+
     fn attach<
         'reactor,
         'attachment,
@@ -116,17 +139,22 @@ impl crate::reactor::Reactor for Reactor {
         let msg = format!("Unable to attach {value:?} to the reactor");
         match self.set.add(value) {
             Ok(guard) => Ok(guard),
-            Err(FileDescriptorSetAddError::CapacityExceeded) => {
-                fail!(from self, with ReactorAttachError::CapacityExceeded,
-                        "{msg} since the capacity of the underlying file descriptor set was exceeded.");
-            }
-            Err(FileDescriptorSetAddError::AlreadyAttached) => {
-                fail!(from self, with ReactorAttachError::AlreadyAttached,
-                        "{msg} since it is already attached.");
-            }
+            Err(e) => self.handle_add_error(e, &msg),
         }
     }
 
+    fn attach_unguarded(&self, value: &FileDescriptor) -> Result<(), ReactorAttachError> {
+        let msg = format!("Unable to attach {value:?} unguarded to the reactor");
+        match self.set.add_unguarded(value) {
+            Ok(()) => Ok(()),
+            Err(e) => self.handle_add_error(e, &msg),
+        }
+    }
+
+    fn detach_unguarded(&self, native_handle: i32) {
+        self.set.remove_unguarded(native_handle)
+    }
+
     fn try_wait<F: FnMut(&FileDescriptor)>(
         &self,
         fn_call: F,
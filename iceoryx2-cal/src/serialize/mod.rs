@@ -38,6 +38,8 @@
 //! ```
 
 pub mod cdr;
+#[cfg(feature = "json_config")]
+pub mod json;
 pub mod postcard;
 pub mod recommended;
 pub mod toml;
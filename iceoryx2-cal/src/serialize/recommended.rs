@@ -13,4 +13,11 @@
 /// Provides the recommended
 /// [`Serialize`](crate::serialize::Serialize) concept implementation
 /// for the target.
+#[cfg(not(feature = "json_config"))]
 pub type Recommended = crate::serialize::toml::Toml;
+
+/// Provides the recommended
+/// [`Serialize`](crate::serialize::Serialize) concept implementation
+/// for the target.
+#[cfg(feature = "json_config")]
+pub type Recommended = crate::serialize::json::Json;
@@ -18,6 +18,7 @@ use crate::dynamic_storage::*;
 pub use crate::shared_memory::*;
 use iceoryx2_bb_elementary_traits::allocator::BaseAllocator;
 use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::memory_advice::MemoryAdvice;
 use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
@@ -443,6 +444,22 @@ pub mod details {
         fn payload_start_address(&self) -> usize {
             self.payload_start_address
         }
+
+        fn request_huge_pages(&self) -> bool {
+            // the advice must start at a page boundary, the page that contains the start of
+            // the payload belongs to the same mapping
+            let page_size = SystemInfo::PageSize.value();
+            let start = self.payload_start_address - self.payload_start_address % page_size;
+            let len = self.payload_start_address + self.size() - start;
+
+            match unsafe { MemoryAdvice::HugePages.apply(start as *mut _, len) } {
+                Ok(()) => true,
+                Err(e) => {
+                    debug!(from self, "The shared memory is not backed by huge pages ({:?}).", e);
+                    false
+                }
+            }
+        }
     }
 
     impl<Storage: DynamicStorage<AllocatorDetails<PoolAllocator>>> SharedMemoryForPoolAllocator
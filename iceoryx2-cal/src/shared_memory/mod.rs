@@ -153,6 +153,12 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
     /// the actual memory position.
     fn payload_start_address(&self) -> usize;
 
+    /// Requests that the payload memory is backed by huge pages to reduce the TLB pressure of
+    /// large memory regions. It is only a hint, when the platform does not support it the
+    /// memory stays backed by regular pages. Returns true when the request was accepted,
+    /// otherwise false.
+    fn request_huge_pages(&self) -> bool;
+
     /// Allocates memory. The alignment in the layout must be smaller or equal
     /// [`SharedMemory::max_alignment()`] otherwise the method will fail.
     fn allocate(&self, layout: core::alloc::Layout) -> Result<ShmPointer, ShmAllocationError>;
@@ -425,7 +425,8 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
 
         let file = fail!(from origin,
             when FileBuilder::new(&self.config.path_for(&self.storage_name)).open_existing(AccessMode::Read),
-            with StaticStorageOpenError::DoesNotExist,
+            map FileOpenError::InsufficientPermissions => StaticStorageOpenError::InsufficientPermissions,
+            unmatched StaticStorageOpenError::DoesNotExist,
             "{} due to a failure while opening the file.", msg);
 
         let mut wait_for_read_access = fail!(from self,
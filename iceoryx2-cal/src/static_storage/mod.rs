@@ -38,6 +38,7 @@ pub enum StaticStorageCreateError {
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum StaticStorageOpenError {
     DoesNotExist,
+    InsufficientPermissions,
     Read,
     InitializationNotYetFinalized,
     InternalError,
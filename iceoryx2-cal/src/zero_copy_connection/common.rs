@@ -16,6 +16,7 @@ pub mod details {
     use core::fmt::Debug;
     use core::marker::PhantomData;
     use core::sync::atomic::Ordering;
+    use core::time::Duration;
     use iceoryx2_bb_elementary_traits::allocator::{AllocationError, BaseAllocator};
     use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
     use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize};
@@ -202,6 +203,8 @@ pub mod details {
         number_of_samples_per_segment: usize,
         number_of_segments: u8,
         enable_safe_overflow: bool,
+        sender_dropped_samples: IoxAtomicU64,
+        receiver_dropped_samples: IoxAtomicU64,
     }
 
     impl SharedManagementData {
@@ -222,9 +225,17 @@ pub mod details {
                 number_of_samples_per_segment,
                 number_of_segments,
                 state: IoxAtomicU8::new(State::None.value()),
+                sender_dropped_samples: IoxAtomicU64::new(0),
+                receiver_dropped_samples: IoxAtomicU64::new(0),
             }
         }
 
+        fn add_dropped_sample(&self) {
+            self.sender_dropped_samples.fetch_add(1, Ordering::Relaxed);
+            self.receiver_dropped_samples
+                .fetch_add(1, Ordering::Relaxed);
+        }
+
         fn get_segment_details(&self, segment_id: usize, channel_id: usize) -> &SegmentDetails {
             let idx = channel_id * self.number_of_segments as usize + segment_id;
             &self.segment_details[idx]
@@ -650,6 +661,20 @@ pub mod details {
             debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
             &self.storage.get().channels[channel_id.value()].state
         }
+
+        fn number_of_dropped_samples(&self) -> u64 {
+            self.storage
+                .get()
+                .sender_dropped_samples
+                .load(Ordering::Relaxed)
+        }
+
+        fn reset_number_of_dropped_samples(&self) {
+            self.storage
+                .get()
+                .sender_dropped_samples
+                .store(0, Ordering::Relaxed);
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopySender for Sender<Storage> {
@@ -669,6 +694,7 @@ pub mod details {
                     .submission_queue
                     .is_full()
             {
+                storage.add_dropped_sample();
                 fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
                              "{} since the receive buffer is full.", msg);
             }
@@ -710,6 +736,7 @@ pub mod details {
                         "{} since the invalid offset {:?} was returned on overflow.", msg, pointer_offset);
                     }
 
+                    storage.add_dropped_sample();
                     Ok(Some(pointer_offset))
                 }
                 None => Ok(None),
@@ -739,6 +766,40 @@ pub mod details {
             self.try_send(ptr, sample_size, channel_id)
         }
 
+        fn timed_send(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+            channel_id: ChannelId,
+            timeout: Duration,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+
+            if !self.storage.get().enable_safe_overflow {
+                let has_space = AdaptiveWaitBuilder::new()
+                    .create()
+                    .unwrap()
+                    .timed_wait_while(
+                        || -> Result<bool, ()> {
+                            Ok(self.storage.get().channels[channel_id.value()]
+                                .submission_queue
+                                .is_full())
+                        },
+                        timeout,
+                    )
+                    .unwrap();
+
+                if !has_space {
+                    self.storage.get().add_dropped_sample();
+                    fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
+                        "Unable to send sample since the receive buffer is still full after the timeout of {:?}.",
+                        timeout);
+                }
+            }
+
+            self.try_send(ptr, sample_size, channel_id)
+        }
+
         fn reclaim(
             &self,
             channel_id: ChannelId,
@@ -886,6 +947,20 @@ pub mod details {
             debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
             &self.storage.get().channels[channel_id.value()].state
         }
+
+        fn number_of_dropped_samples(&self) -> u64 {
+            self.storage
+                .get()
+                .receiver_dropped_samples
+                .load(Ordering::Relaxed)
+        }
+
+        fn reset_number_of_dropped_samples(&self) {
+            self.storage
+                .get()
+                .receiver_dropped_samples
+                .store(0, Ordering::Relaxed);
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyReceiver for Receiver<Storage> {
@@ -896,6 +971,13 @@ pub mod details {
                 .is_empty()
         }
 
+        fn number_of_samples(&self, channel_id: ChannelId) -> usize {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            self.storage.get().channels[channel_id.value()]
+                .submission_queue
+                .len()
+        }
+
         fn receive(
             &self,
             channel_id: ChannelId,
@@ -164,6 +164,15 @@ pub trait ZeroCopyPortDetails {
     fn max_supported_shared_memory_segments(&self) -> u8;
     fn is_connected(&self) -> bool;
     fn channel_state(&self, channel_id: ChannelId) -> &IoxAtomicU64;
+
+    /// Returns the number of samples that were dropped since the receive buffer was full,
+    /// either because the sender discarded the new sample or because the oldest sample was
+    /// replaced on overflow. The sender and the receiver count independently so that
+    /// [`ZeroCopyPortDetails::reset_number_of_dropped_samples()`] affects only the own side.
+    fn number_of_dropped_samples(&self) -> u64;
+
+    /// Resets the counter of [`ZeroCopyPortDetails::number_of_dropped_samples()`] to zero.
+    fn reset_number_of_dropped_samples(&self);
 }
 
 pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept + Send {
@@ -181,6 +190,17 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept + Send {
         channel_id: ChannelId,
     ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
 
+    /// Like [`ZeroCopySender::blocking_send()`] but waits at most for the provided timeout
+    /// until the receive buffer has space again. If the buffer is still full when the timeout
+    /// has passed it fails with [`ZeroCopySendError::ReceiveBufferFull`].
+    fn timed_send(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+        channel_id: ChannelId,
+        timeout: Duration,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
     fn reclaim(&self, channel_id: ChannelId)
         -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
 
@@ -188,13 +208,14 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept + Send {
     ///
     /// * must ensure that no receiver is still holding data, otherwise data races may occur on
     ///   receiver side
-    /// * must ensure that [`ZeroCopySender::try_send()`] and [`ZeroCopySender::blocking_send()`]
-    ///   are not called after using this method
+    /// * must ensure that [`ZeroCopySender::try_send()`], [`ZeroCopySender::blocking_send()`]
+    ///   and [`ZeroCopySender::timed_send()`] are not called after using this method
     unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, callback: F);
 }
 
 pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept + Send {
     fn has_data(&self, channel_id: ChannelId) -> bool;
+    fn number_of_samples(&self, channel_id: ChannelId) -> usize;
     fn receive(&self, channel_id: ChannelId)
         -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
     fn release(
@@ -100,6 +100,30 @@ mod reactor {
         assert_that!(result.err(), eq Some(ReactorAttachError::AlreadyAttached));
     }
 
+    #[test]
+    fn attach_and_detach_unguarded_works<Sut: Reactor>() {
+        let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
+
+        let attachment = NotifierListenerPair::new();
+        let fd = unsafe { attachment.listener.file_descriptor().native_handle() };
+
+        sut.attach_unguarded(attachment.listener.file_descriptor())
+            .unwrap();
+        assert_that!(sut.len(), eq 1);
+        assert_that!(sut.attach(&attachment.listener).err(), eq Some(ReactorAttachError::AlreadyAttached));
+
+        attachment.notifier.notify(TriggerId::new(123)).unwrap();
+        let mut triggered_fds = vec![];
+        assert_that!(
+            sut.try_wait(|fd| triggered_fds.push(unsafe { fd.native_handle() })),
+            eq Ok(1)
+        );
+        assert_that!(triggered_fds, eq vec![fd]);
+
+        sut.detach_unguarded(fd);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
     #[test]
     fn try_wait_does_not_block_when_triggered_single<Sut: Reactor>() {
         let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
@@ -362,6 +386,21 @@ mod reactor {
         assert_that!(triggered_fds, len 0);
     }
 
+    #[test]
+    fn timed_wait_blocks_for_at_least_min_timeout_resolution<Sut: Reactor>() {
+        let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
+        let resolution = sut.min_timeout_resolution();
+        assert_that!(resolution, gt Duration::ZERO);
+
+        let attachment = NotifierListenerPair::new();
+
+        let _guard = sut.attach(&attachment.listener);
+
+        let start = Instant::now();
+        assert_that!(sut.timed_wait(|_| {}, resolution), eq Ok(0));
+        assert_that!(start.elapsed(), time_at_least resolution);
+    }
+
     #[test]
     fn try_wait_triggers_until_all_data_is_consumed<Sut: Reactor>() {
         let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
@@ -46,4 +46,8 @@ mod serialize {
 
     #[instantiate_tests(<iceoryx2_cal::serialize::postcard::Postcard>)]
     mod postcard {}
+
+    #[cfg(feature = "json_config")]
+    #[instantiate_tests(<iceoryx2_cal::serialize::json::Json>)]
+    mod json {}
 }
@@ -547,6 +547,40 @@ mod zero_copy_connection {
         assert_that!(sut_receiver.has_data(id), eq true);
     }
 
+    #[test]
+    fn number_of_samples_counts_unreceived_data<Sut: ZeroCopyConnection>() {
+        let id = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(4)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(4)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_receiver.number_of_samples(id), eq 0);
+        for n in 0..3 {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * n), SAMPLE_SIZE, id),
+                is_ok
+            );
+            assert_that!(sut_receiver.number_of_samples(id), eq n + 1);
+        }
+
+        let sample = sut_receiver.receive(id).unwrap().unwrap();
+        assert_that!(sut_receiver.number_of_samples(id), eq 2);
+        assert_that!(sut_receiver.release(sample, id), is_ok);
+        assert_that!(sut_receiver.number_of_samples(id), eq 2);
+    }
+
     #[test]
     fn data_can_be_received_only_via_the_same_channel<Sut: ZeroCopyConnection>() {
         const ITERATIONS: usize = 8;
@@ -671,6 +705,71 @@ mod zero_copy_connection {
         }
     }
 
+    #[test]
+    fn dropped_samples_are_counted_on_both_sides<Sut: ZeroCopyConnection>() {
+        let id = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 3;
+        const NUMBER_OF_DROPS: usize = 5;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.number_of_dropped_samples(), eq 0);
+        assert_that!(sut_receiver.number_of_dropped_samples(), eq 0);
+
+        for i in 0..BUFFER_SIZE + NUMBER_OF_DROPS {
+            let _ = sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i), SAMPLE_SIZE, id);
+        }
+
+        assert_that!(sut_sender.number_of_dropped_samples(), eq NUMBER_OF_DROPS as u64);
+        assert_that!(sut_receiver.number_of_dropped_samples(), eq NUMBER_OF_DROPS as u64);
+
+        sut_sender.reset_number_of_dropped_samples();
+        assert_that!(sut_sender.number_of_dropped_samples(), eq 0);
+        assert_that!(sut_receiver.number_of_dropped_samples(), eq NUMBER_OF_DROPS as u64);
+
+        sut_receiver.reset_number_of_dropped_samples();
+        assert_that!(sut_receiver.number_of_dropped_samples(), eq 0);
+    }
+
+    #[test]
+    fn replaced_samples_are_counted_as_dropped_on_overflow<Sut: ZeroCopyConnection>() {
+        let id = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        const BUFFER_SIZE: usize = 4;
+        const NUMBER_OF_DROPS: usize = 7;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .enable_safe_overflow(true)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE + NUMBER_OF_DROPS {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i), SAMPLE_SIZE, id),
+                is_ok
+            );
+        }
+
+        assert_that!(sut_sender.number_of_dropped_samples(), eq NUMBER_OF_DROPS as u64);
+    }
+
     #[test]
     fn receive_can_acquire_data_with_late_connection<Sut: ZeroCopyConnection>() {
         let id = ChannelId::new(0);
@@ -841,6 +940,94 @@ mod zero_copy_connection {
         });
     }
 
+    #[test]
+    fn timed_send_fails_when_buffer_stays_full<Sut: ZeroCopyConnection>() {
+        let id = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(1)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let _sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(1)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(
+            sut_sender.timed_send(PointerOffset::new(0), SAMPLE_SIZE, id, TIMEOUT),
+            is_ok
+        );
+
+        let now = Instant::now();
+        let result =
+            sut_sender.timed_send(PointerOffset::new(SAMPLE_SIZE), SAMPLE_SIZE, id, TIMEOUT);
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ZeroCopySendError::ReceiveBufferFull);
+    }
+
+    #[test]
+    fn timed_send_succeeds_when_buffer_gets_space<Sut: ZeroCopyConnection>() {
+        let id = ChannelId::new(0);
+        let _watchdog = Watchdog::new();
+        let name = generate_name();
+        let config = Mutex::new(generate_isolated_config::<Sut>());
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(1)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .config(&config.lock().unwrap())
+            .create_sender()
+            .unwrap();
+
+        let handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+        let sample_offset_1 = SAMPLE_SIZE * 12;
+        let sample_offset_2 = SAMPLE_SIZE * 234;
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let sut_receiver = Sut::Builder::new(&name)
+                    .buffer_size(1)
+                    .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+                    .config(&config.lock().unwrap())
+                    .create_receiver()
+                    .unwrap();
+
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                let sample = sut_receiver.receive(id).unwrap();
+                assert_that!(sample, is_some);
+                assert_that!(sample.unwrap().offset(), eq sample_offset_1);
+            });
+
+            barrier.wait();
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(sample_offset_1), SAMPLE_SIZE, id),
+                is_ok
+            );
+
+            let now = Instant::now();
+            assert_that!(
+                sut_sender.timed_send(
+                    PointerOffset::new(sample_offset_2),
+                    SAMPLE_SIZE,
+                    id,
+                    TIMEOUT * 100
+                ),
+                is_ok
+            );
+            assert_that!(now.elapsed(), time_at_least TIMEOUT);
+        });
+    }
+
     #[test]
     fn sent_samples_can_be_acquired<Sut: ZeroCopyConnection>() {
         const NUMBER_OF_CHANNELS: usize = 6;
@@ -133,6 +133,10 @@ pub fn list(filter: OutputFilter, format: Format) -> Result<()> {
     let mut services = Vec::<ServiceDescriptor>::new();
 
     ipc::Service::list(Config::global_config(), |service| {
+        // services whose details cannot be acquired are not listed
+        let Ok(service) = service else {
+            return CallbackProgression::Continue;
+        };
         if filter.matches(&service) {
             services.push(ServiceDescriptor::from(&service));
         }
@@ -156,6 +160,9 @@ pub fn details(service_name: String, filter: OutputFilter, format: Format) -> Re
     let mut error: Option<Error> = None;
 
     ipc::Service::list(Config::global_config(), |service| {
+        let Ok(service) = service else {
+            return CallbackProgression::Continue;
+        };
         if service_name == service.static_details.name().to_string() && filter.matches(&service) {
             match format.as_string(&ServiceDescription::from(&service)) {
                 Ok(output) => {
@@ -17,6 +17,8 @@ use iceoryx2::node::NodeId as IceoryxNodeId;
 use iceoryx2::node::NodeState as IceoryxNodeState;
 use iceoryx2::node::NodeView as IceoryxNodeView;
 use iceoryx2::service::attribute::AttributeSet as IceoryxAttributeSet;
+use iceoryx2::service::dynamic_config::publish_subscribe::PublisherDetails as IceoryxPublisherDetails;
+use iceoryx2::service::dynamic_config::publish_subscribe::SubscriberDetails as IceoryxSubscriberDetails;
 use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as IceoryxMessagingPattern;
 use iceoryx2::service::Service as IceoryxService;
 use iceoryx2::service::ServiceDetails as IceoryxServiceDetails;
@@ -58,6 +60,10 @@ pub struct ServiceDescription {
     pub attributes: IceoryxAttributeSet,
     pub pattern: IceoryxMessagingPattern,
     pub nodes: Option<NodeList>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publishers: Option<Vec<PublisherDescriptor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscribers: Option<Vec<SubscriberDescriptor>>,
 }
 
 impl<T> From<&IceoryxServiceDetails<T>> for ServiceDescription
@@ -66,6 +72,10 @@ where
 {
     fn from(service: &IceoryxServiceDetails<T>) -> Self {
         let config = &service.static_details;
+        let is_publish_subscribe = matches!(
+            config.messaging_pattern(),
+            IceoryxMessagingPattern::PublishSubscribe(_)
+        );
 
         ServiceDescription {
             service_id: config.service_id().as_str().to_string(),
@@ -73,6 +83,72 @@ where
             attributes: config.attributes().clone(),
             pattern: config.messaging_pattern().clone(),
             nodes: service.dynamic_details.as_ref().map(NodeList::from),
+            publishers: service
+                .dynamic_details
+                .as_ref()
+                .filter(|_| is_publish_subscribe)
+                .map(|details| {
+                    details
+                        .publishers
+                        .iter()
+                        .map(PublisherDescriptor::from)
+                        .collect()
+                }),
+            subscribers: service
+                .dynamic_details
+                .as_ref()
+                .filter(|_| is_publish_subscribe)
+                .map(|details| {
+                    details
+                        .subscribers
+                        .iter()
+                        .map(SubscriberDescriptor::from)
+                        .collect()
+                }),
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct PublisherDescriptor {
+    id: String,
+    node_id: NodeIdString,
+    pid: pid_t,
+    number_of_samples: usize,
+    max_loaned_samples: usize,
+    max_slice_len: usize,
+}
+
+impl From<&IceoryxPublisherDetails> for PublisherDescriptor {
+    fn from(details: &IceoryxPublisherDetails) -> Self {
+        PublisherDescriptor {
+            id: format!("{:032x}", details.publisher_id.value()),
+            node_id: NodeIdString::from(&details.node_id),
+            pid: details.node_id.pid().value(),
+            number_of_samples: details.number_of_samples,
+            max_loaned_samples: details.max_loaned_samples,
+            max_slice_len: details.max_slice_len,
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct SubscriberDescriptor {
+    id: String,
+    node_id: NodeIdString,
+    pid: pid_t,
+    buffer_size: usize,
+    history_request: usize,
+}
+
+impl From<&IceoryxSubscriberDetails> for SubscriberDescriptor {
+    fn from(details: &IceoryxSubscriberDetails) -> Self {
+        SubscriberDescriptor {
+            id: format!("{:032x}", details.subscriber_id.value()),
+            node_id: NodeIdString::from(&details.node_id),
+            pid: details.node_id.pid().value(),
+            buffer_size: details.buffer_size,
+            history_request: details.history_request,
         }
     }
 }
@@ -269,6 +269,8 @@ constexpr auto from<int, iox2::ServiceDetailsError>(const int value) noexcept ->
         return iox2::ServiceDetailsError::ServiceInInconsistentState;
     case iox2_service_details_error_e_VERSION_MISMATCH:
         return iox2::ServiceDetailsError::VersionMismatch;
+    case iox2_service_details_error_e_INSUFFICIENT_PERMISSIONS:
+        return iox2::ServiceDetailsError::InsufficientPermissions;
     }
 
     IOX_UNREACHABLE();
@@ -293,6 +295,8 @@ from<iox2::ServiceDetailsError, iox2_service_details_error_e>(const iox2::Servic
         return iox2_service_details_error_e_SERVICE_IN_INCONSISTENT_STATE;
     case iox2::ServiceDetailsError::VersionMismatch:
         return iox2_service_details_error_e_VERSION_MISMATCH;
+    case iox2::ServiceDetailsError::InsufficientPermissions:
+        return iox2_service_details_error_e_INSUFFICIENT_PERMISSIONS;
     }
 
     IOX_UNREACHABLE();
@@ -584,6 +588,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ABI:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleAbi;
 
     case iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE:
         return iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState;
@@ -646,6 +652,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::ExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenError::IsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ABI:
+        return iox2::PublishSubscribeOpenError::IncompatibleAbi;
     default:
         IOX_UNREACHABLE();
     }
@@ -689,6 +697,8 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::PublishSubscribeOpenError::IsMarkedForDestruction:
         return iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::PublishSubscribeOpenError::IncompatibleAbi:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ABI;
     default:
         IOX_UNREACHABLE();
     }
@@ -791,6 +801,8 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction:
         return iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleAbi:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_ABI;
     case iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState:
         return iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
     case iox2::PublishSubscribeOpenOrCreateError::CreateSubscriberBufferMustBeLargerThanHistorySize:
@@ -1343,6 +1355,10 @@ constexpr auto from<int, iox2::PublisherCreateError>(const int value) noexcept -
         return iox2::PublisherCreateError::UnableToCreateDataSegment;
     case iox2_publisher_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::PublisherCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_publisher_create_error_e_HISTORY_EXCEEDS_HISTORY_SIZE_OF_SERVICE:
+        return iox2::PublisherCreateError::HistoryExceedsHistorySizeOfService;
+    case iox2_publisher_create_error_e_EXCEEDS_MAX_DATA_SEGMENT_SIZE:
+        return iox2::PublisherCreateError::ExceedsMaxDataSegmentSize;
     }
 
     IOX_UNREACHABLE();
@@ -1359,6 +1375,10 @@ from<iox2::PublisherCreateError, iox2_publisher_create_error_e>(const iox2::Publ
         return iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
     case iox2::PublisherCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_publisher_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::PublisherCreateError::HistoryExceedsHistorySizeOfService:
+        return iox2_publisher_create_error_e_HISTORY_EXCEEDS_HISTORY_SIZE_OF_SERVICE;
+    case iox2::PublisherCreateError::ExceedsMaxDataSegmentSize:
+        return iox2_publisher_create_error_e_EXCEEDS_MAX_DATA_SEGMENT_SIZE;
     }
 
     IOX_UNREACHABLE();
@@ -1380,6 +1400,14 @@ constexpr auto from<int, iox2::SubscriberCreateError>(const int value) noexcept
         return iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers;
     case iox2_subscriber_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::SubscriberCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_subscriber_create_error_e_RESOURCE_CREATION_FAILED:
+        return iox2::SubscriberCreateError::ResourceCreationFailed;
+    case iox2_subscriber_create_error_e_INCOMPATIBLE_SAMPLE_FILTER:
+        return iox2::SubscriberCreateError::IncompatibleSampleFilter;
+    case iox2_subscriber_create_error_e_HISTORY_REQUEST_EXCEEDS_HISTORY_SIZE_OF_SERVICE:
+        return iox2::SubscriberCreateError::HistoryRequestExceedsHistorySizeOfService;
+    case iox2_subscriber_create_error_e_TIMESTAMPS_NOT_ENABLED:
+        return iox2::SubscriberCreateError::TimestampsNotEnabled;
     }
 
     IOX_UNREACHABLE();
@@ -1396,6 +1424,14 @@ from<iox2::SubscriberCreateError, iox2_subscriber_create_error_e>(const iox2::Su
         return iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS;
     case iox2::SubscriberCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_subscriber_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::SubscriberCreateError::ResourceCreationFailed:
+        return iox2_subscriber_create_error_e_RESOURCE_CREATION_FAILED;
+    case iox2::SubscriberCreateError::IncompatibleSampleFilter:
+        return iox2_subscriber_create_error_e_INCOMPATIBLE_SAMPLE_FILTER;
+    case iox2::SubscriberCreateError::HistoryRequestExceedsHistorySizeOfService:
+        return iox2_subscriber_create_error_e_HISTORY_REQUEST_EXCEEDS_HISTORY_SIZE_OF_SERVICE;
+    case iox2::SubscriberCreateError::TimestampsNotEnabled:
+        return iox2_subscriber_create_error_e_TIMESTAMPS_NOT_ENABLED;
     }
 
     IOX_UNREACHABLE();
@@ -1425,6 +1461,8 @@ constexpr auto from<int, iox2::SendError>(const int value) noexcept -> iox2::Sen
         return iox2::SendError::LoanErrorInternalFailure;
     case iox2_send_error_e_CONNECTION_ERROR:
         return iox2::SendError::ConnectionError;
+    case iox2_send_error_e_INTERNAL_FAILURE:
+        return iox2::SendError::InternalFailure;
     }
 
     IOX_UNREACHABLE();
@@ -1447,6 +1485,8 @@ constexpr auto from<iox2::SendError, iox2_send_error_e>(const iox2::SendError va
         return iox2_send_error_e_LOAN_ERROR_INTERNAL_FAILURE;
     case iox2::SendError::ConnectionError:
         return iox2_send_error_e_CONNECTION_ERROR;
+    case iox2::SendError::InternalFailure:
+        return iox2_send_error_e_INTERNAL_FAILURE;
     }
 
     IOX_UNREACHABLE();
@@ -1467,6 +1507,10 @@ constexpr auto from<int, iox2::ReceiveError>(const int value) noexcept -> iox2::
         return iox2::ReceiveError::UnableToMapSendersDataSegment;
     case iox2_receive_error_e_EXCEEDS_MAX_BORROWS:
         return iox2::ReceiveError::ExceedsMaxBorrows;
+    case iox2_receive_error_e_SERVICE_NO_LONGER_EXISTS:
+        return iox2::ReceiveError::ServiceNoLongerExists;
+    case iox2_receive_error_e_INTERNAL_FAILURE:
+        return iox2::ReceiveError::InternalFailure;
     }
 
     IOX_UNREACHABLE();
@@ -1482,6 +1526,10 @@ constexpr auto from<iox2::ReceiveError, iox2_receive_error_e>(const iox2::Receiv
         return iox2_receive_error_e_UNABLE_TO_MAP_SENDERS_DATA_SEGMENT;
     case iox2::ReceiveError::ExceedsMaxBorrows:
         return iox2_receive_error_e_EXCEEDS_MAX_BORROWS;
+    case iox2::ReceiveError::ServiceNoLongerExists:
+        return iox2_receive_error_e_SERVICE_NO_LONGER_EXISTS;
+    case iox2::ReceiveError::InternalFailure:
+        return iox2_receive_error_e_INTERNAL_FAILURE;
     }
 
     IOX_UNREACHABLE();
@@ -1550,6 +1598,8 @@ constexpr auto from<int, iox2::RequestSendError>(const int value) noexcept -> io
         return iox2::RequestSendError::LoanErrorInternalFailure;
     case iox2_request_send_error_e_CONNECTION_ERROR:
         return iox2::RequestSendError::ConnectionError;
+    case iox2_request_send_error_e_INTERNAL_FAILURE:
+        return iox2::RequestSendError::InternalFailure;
     }
 
     IOX_UNREACHABLE();
@@ -1575,6 +1625,8 @@ constexpr auto from<iox2::RequestSendError, iox2_request_send_error_e>(const iox
         return iox2_request_send_error_e_LOAN_ERROR_INTERNAL_FAILURE;
     case iox2::RequestSendError::ConnectionError:
         return iox2_request_send_error_e_CONNECTION_ERROR;
+    case iox2::RequestSendError::InternalFailure:
+        return iox2_request_send_error_e_INTERNAL_FAILURE;
     }
 
     IOX_UNREACHABLE();
@@ -1606,6 +1658,10 @@ constexpr auto from<int, iox2::ServiceListError>(const int value) noexcept -> io
         return iox2::ServiceListError::InsufficientPermissions;
     case iox2_service_list_error_e_INTERNAL_ERROR:
         return iox2::ServiceListError::InternalError;
+    case iox2_service_list_error_e_CORRUPTED_SERVICE:
+        return iox2::ServiceListError::CorruptedService;
+    case iox2_service_list_error_e_VERSION_MISMATCH:
+        return iox2::ServiceListError::VersionMismatch;
     }
 
     IOX_UNREACHABLE();
@@ -1619,6 +1675,10 @@ constexpr auto from<iox2::ServiceListError, iox2_service_list_error_e>(const iox
         return iox2_service_list_error_e_INSUFFICIENT_PERMISSIONS;
     case iox2::ServiceListError::InternalError:
         return iox2_service_list_error_e_INTERNAL_ERROR;
+    case iox2::ServiceListError::CorruptedService:
+        return iox2_service_list_error_e_CORRUPTED_SERVICE;
+    case iox2::ServiceListError::VersionMismatch:
+        return iox2_service_list_error_e_VERSION_MISMATCH;
     }
 
     IOX_UNREACHABLE();
@@ -1856,6 +1916,8 @@ constexpr auto from<int, iox2::WaitSetAttachmentError>(const int value) noexcept
     switch (variant) {
     case iox2_waitset_attachment_error_e_ALREADY_ATTACHED:
         return iox2::WaitSetAttachmentError::AlreadyAttached;
+    case iox2_waitset_attachment_error_e_ALREADY_ATTACHED_WITH_DIFFERENT_SEMANTIC:
+        return iox2::WaitSetAttachmentError::AlreadyAttachedWithDifferentSemantic;
     case iox2_waitset_attachment_error_e_INSUFFICIENT_CAPACITY:
         return iox2::WaitSetAttachmentError::InsufficientCapacity;
     case iox2_waitset_attachment_error_e_INTERNAL_ERROR:
@@ -1872,6 +1934,8 @@ from<iox2::WaitSetAttachmentError, iox2_waitset_attachment_error_e>(const iox2::
     switch (value) {
     case iox2::WaitSetAttachmentError::AlreadyAttached:
         return iox2_waitset_attachment_error_e_ALREADY_ATTACHED;
+    case iox2::WaitSetAttachmentError::AlreadyAttachedWithDifferentSemantic:
+        return iox2_waitset_attachment_error_e_ALREADY_ATTACHED_WITH_DIFFERENT_SEMANTIC;
     case iox2::WaitSetAttachmentError::InsufficientCapacity:
         return iox2_waitset_attachment_error_e_INSUFFICIENT_CAPACITY;
     case iox2::WaitSetAttachmentError::InternalError:
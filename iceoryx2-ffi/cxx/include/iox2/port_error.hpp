@@ -55,6 +55,9 @@ enum class SendError : uint8_t {
     LoanErrorInternalFailure,
     /// A failure occurred while establishing a connection to the ports counterpart port.
     ConnectionError,
+    /// Errors that indicate either an implementation issue or a wrongly configured system,
+    /// for instance when the delivery timeout could not be measured.
+    InternalFailure,
 };
 
 /// Defines the failure that can occur when receiving data with
@@ -69,7 +72,14 @@ enum class ReceiveError : uint8_t {
     FailedToEstablishConnection,
 
     /// Failures when mapping the corresponding data segment
-    UnableToMapSendersDataSegment
+    UnableToMapSendersDataSegment,
+
+    /// The [`Service`] was removed while the receiver was still alive, for instance because
+    /// its [`Node`] was considered dead and cleaned up by another process.
+    ServiceNoLongerExists,
+
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure
 };
 
 /// Failure that can be emitted when a [`RequestMut`] is sent.
@@ -96,6 +106,9 @@ enum class RequestSendError : uint8_t {
     LoanErrorInternalFailure,
     /// A failure occurred while establishing a connection to the ports counterpart port.
     ConnectionError,
+    /// Errors that indicate either an implementation issue or a wrongly configured system,
+    /// for instance when the delivery timeout could not be measured.
+    InternalFailure,
 };
 } // namespace iox2
 
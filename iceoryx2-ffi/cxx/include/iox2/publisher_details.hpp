@@ -16,6 +16,7 @@
 #include "iox2/internal/callback_context.hpp"
 #include "iox2/internal/iceoryx2.hpp"
 #include "iox2/node_id.hpp"
+#include "iox2/unable_to_deliver_strategy.hpp"
 #include "iox2/unique_port_id.hpp"
 
 namespace iox2 {
@@ -38,9 +39,16 @@ class PublisherDetailsView {
     /// The total number of samples available in the [`Publisher`]s data segment
     auto number_of_samples() const -> uint64_t;
 
+    /// The maximum number of samples the [`Publisher`] can loan at the same time.
+    auto max_loaned_samples() const -> uint64_t;
+
     /// The current maximum length of a slice.
     auto max_slice_len() const -> uint64_t;
 
+    /// The [`UnableToDeliverStrategy`] the [`Publisher`] pursues when the buffer of a
+    /// [`Subscriber`] is full.
+    auto unable_to_deliver_strategy() const -> UnableToDeliverStrategy;
+
   private:
     template <typename T, typename>
     friend auto internal::list_ports_callback(void* context, T port_details_view) -> iox2_callback_progression_e;
@@ -32,6 +32,12 @@ enum class PublisherCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The requested history depth is larger than the history size of the
+    /// [`Service`].
+    HistoryExceedsHistorySizeOfService,
+    /// The data segment that is required to store all samples of the
+    /// [`Publisher`] exceeds the maximum supported size.
+    ExceedsMaxDataSegmentSize,
 };
 } // namespace iox2
 
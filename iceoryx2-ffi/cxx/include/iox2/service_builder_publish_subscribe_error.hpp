@@ -67,6 +67,9 @@ enum class PublishSubscribeOpenError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] was created on a platform with a different byte order,
+    /// pointer width or ABI name.
+    IncompatibleAbi,
 };
 
 /// Errors that can occur when a new [`MessagingPattern::PublishSubscribe`] [`Service`] shall be created.
@@ -145,6 +148,9 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The [`Service`] was created on a platform with a different byte order,
+    /// pointer width or ABI name.
+    OpenIncompatibleAbi,
 
     /// Some underlying resources of the [`Service`] are either missing,
     /// corrupted or unaccessible.
@@ -34,14 +34,23 @@ enum class ServiceDetailsError : uint8_t {
     InternalError,
     /// The [`NodeState`] could not be acquired.
     FailedToAcquireNodeState,
+    /// The process has insufficient permissions to read the static [`Service`]
+    /// information.
+    InsufficientPermissions,
 };
 
 enum class ServiceListError : uint8_t {
-    /// The process has insufficient permissions to list all [`Service`]s.
+    /// The process has insufficient permissions to list all [`Service`]s or to
+    /// read the static information of a single [`Service`].
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly
     /// configured system.
     InternalError,
+    /// The static information of the [`Service`] could not be read, could not
+    /// be deserialized or is inconsistent.
+    CorruptedService,
+    /// The [`Service`] was created with a different iceoryx2 version.
+    VersionMismatch,
 };
 
 } // namespace iox2
@@ -30,6 +30,18 @@ enum class SubscriberCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The notification channel of the [`Subscriber`] could not be created. It
+    /// is only required when the [`Service`] has notifications enabled.
+    ResourceCreationFailed,
+    /// The compared range of the [`SampleFilter`] exceeds the user header or
+    /// the fixed size payload of the [`Service`].
+    IncompatibleSampleFilter,
+    /// The [`Subscriber`] requested more historical [`Sample`]s than the
+    /// history size of the [`Service`].
+    HistoryRequestExceedsHistorySizeOfService,
+    /// A maximum sample age was defined but the [`Service`] was created without
+    /// timestamps.
+    TimestampsNotEnabled,
 };
 
 } // namespace iox2
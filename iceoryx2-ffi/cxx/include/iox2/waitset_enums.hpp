@@ -41,6 +41,9 @@ enum class WaitSetAttachmentError : uint8_t {
     InsufficientCapacity,
     /// The attachment is already attached.
     AlreadyAttached,
+    /// The attachment is already attached as notification and shall be attached as deadline
+    /// or vice versa.
+    AlreadyAttachedWithDifferentSemantic,
     /// An internal error has occurred.
     InternalError
 };
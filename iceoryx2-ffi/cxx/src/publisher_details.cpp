@@ -48,7 +48,15 @@ auto PublisherDetailsView::number_of_samples() const -> uint64_t {
     return iox2_publisher_details_number_of_samples(m_handle);
 }
 
+auto PublisherDetailsView::max_loaned_samples() const -> uint64_t {
+    return iox2_publisher_details_max_loaned_samples(m_handle);
+}
+
 auto PublisherDetailsView::max_slice_len() const -> uint64_t {
     return iox2_publisher_details_max_slice_len(m_handle);
 }
+
+auto PublisherDetailsView::unable_to_deliver_strategy() const -> UnableToDeliverStrategy {
+    return iox::into<UnableToDeliverStrategy>(iox2_publisher_details_unable_to_deliver_strategy(m_handle));
+}
 } // namespace iox2
@@ -88,6 +88,7 @@ TEST(EnumConversionTest, publisher_create_into_c_str) {
     using Sut = iox2::PublisherCreateError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSupportedPublishers)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToCreateDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxDataSegmentSize)), 1U);
 }
 
 TEST(EnumConversionTest, publisher_loan_into_c_str) {
@@ -107,6 +108,7 @@ TEST(EnumConversionTest, publisher_send_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorExceedsMaxLoanSize)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorInternalFailure)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ConnectionError)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalFailure)), 1U);
 }
 
 TEST(EnumConversionTest, event_open_into_c_str) {
@@ -231,12 +233,15 @@ TEST(EnumConversionTest, service_details_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::VersionMismatch)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalError)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::FailedToAcquireNodeState)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
 }
 
 TEST(EnumConversionTest, service_list_into_c_str) {
     using Sut = iox2::ServiceListError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalError)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::CorruptedService)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::VersionMismatch)), 1U);
 }
 
 TEST(EnumConversionTest, subscriber_receive_into_c_str) {
@@ -244,6 +249,7 @@ TEST(EnumConversionTest, subscriber_receive_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxBorrows)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::FailedToEstablishConnection)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToMapSendersDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalFailure)), 1U);
 }
 
 TEST(EnumConversionTest, subscriber_create_into_c_str) {
@@ -261,6 +267,7 @@ TEST(EnumConversionTest, waitset_attachment_into_c_str) {
     using Sut = iox2::WaitSetAttachmentError;
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientCapacity)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::AlreadyAttached)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::AlreadyAttachedWithDifferentSemantic)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalError)), 1U);
 }
 
@@ -1520,14 +1520,18 @@ TYPED_TEST(ServicePublishSubscribeTest, listing_all_publishers_stops_on_request)
 TYPED_TEST(ServicePublishSubscribeTest, publisher_details_are_correct) {
     constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
     constexpr uint64_t INITIAL_MAX_SLICE_LEN = 5;
+    constexpr uint64_t MAX_LOANED_SAMPLES = 3;
 
     const auto service_name = iox2_testing::generate_service_name();
     auto node = NodeBuilder().create<SERVICE_TYPE>().expect("");
     auto sut =
         node.service_builder(service_name).template publish_subscribe<iox::Slice<uint64_t>>().create().expect("");
 
-    iox2::Publisher<SERVICE_TYPE, iox::Slice<uint64_t>, void> publisher =
-        sut.publisher_builder().initial_max_slice_len(INITIAL_MAX_SLICE_LEN).create().expect("");
+    iox2::Publisher<SERVICE_TYPE, iox::Slice<uint64_t>, void> publisher = sut.publisher_builder()
+                                                                              .initial_max_slice_len(INITIAL_MAX_SLICE_LEN)
+                                                                              .max_loaned_samples(MAX_LOANED_SAMPLES)
+                                                                              .create()
+                                                                              .expect("");
 
     auto counter = 0;
     sut.dynamic_config().list_publishers([&](auto publisher_details_view) {
@@ -1535,6 +1539,7 @@ TYPED_TEST(ServicePublishSubscribeTest, publisher_details_are_correct) {
         EXPECT_TRUE(publisher_details_view.publisher_id() == publisher.id());
         EXPECT_TRUE(publisher_details_view.node_id() == node.id());
         EXPECT_TRUE(publisher_details_view.max_slice_len() == INITIAL_MAX_SLICE_LEN);
+        EXPECT_TRUE(publisher_details_view.max_loaned_samples() == MAX_LOANED_SAMPLES);
         return CallbackProgression::Stop;
     });
 
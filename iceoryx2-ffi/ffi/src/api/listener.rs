@@ -19,7 +19,7 @@ use crate::api::{
 };
 use crate::iox2_file_descriptor_ptr;
 
-use iceoryx2::port::listener::Listener;
+use iceoryx2::port::listener::{Listener, ListenerReceiveResult};
 use iceoryx2_bb_elementary::static_assert::*;
 use iceoryx2_bb_elementary_traits::AsCStr;
 use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
@@ -490,11 +490,11 @@ pub unsafe extern "C" fn iox2_listener_try_wait_one(
     *has_received_one = false;
 
     match wait_result {
-        Ok(Some(e)) => {
+        Ok(ListenerReceiveResult::Event(e)) => {
             *event_id = e.into();
             *has_received_one = true;
         }
-        Ok(None) => (),
+        Ok(ListenerReceiveResult::NoEvents) | Ok(ListenerReceiveResult::AllNotifiersGone) => (),
         Err(error) => {
             return error.into_c_int();
         }
@@ -96,6 +96,8 @@ pub enum iox2_publisher_create_error_e {
     EXCEEDS_MAX_SUPPORTED_PUBLISHERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    HISTORY_EXCEEDS_HISTORY_SIZE_OF_SERVICE,
+    EXCEEDS_MAX_DATA_SEGMENT_SIZE,
 }
 
 impl IntoCInt for PublisherCreateError {
@@ -110,6 +112,12 @@ impl IntoCInt for PublisherCreateError {
             PublisherCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_publisher_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            PublisherCreateError::HistoryExceedsHistorySizeOfService => {
+                iox2_publisher_create_error_e::HISTORY_EXCEEDS_HISTORY_SIZE_OF_SERVICE
+            }
+            PublisherCreateError::ExceedsMaxDataSegmentSize => {
+                iox2_publisher_create_error_e::EXCEEDS_MAX_DATA_SEGMENT_SIZE
+            }
         }) as c_int
     }
 }
@@ -140,7 +148,7 @@ impl PortFactoryPublisherBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactoryPublisherBuilderUnion>
 pub struct iox2_port_factory_publisher_builder_storage_t {
-    internal: [u8; 128], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
+    internal: [u8; 144], // magic number obtained with size_of::<Option<PortFactoryPublisherBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -35,6 +35,10 @@ pub enum iox2_subscriber_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS = IOX2_OK as isize + 1,
     BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    RESOURCE_CREATION_FAILED,
+    INCOMPATIBLE_SAMPLE_FILTER,
+    HISTORY_REQUEST_EXCEEDS_HISTORY_SIZE_OF_SERVICE,
+    TIMESTAMPS_NOT_ENABLED,
 }
 
 impl IntoCInt for SubscriberCreateError {
@@ -49,6 +53,18 @@ impl IntoCInt for SubscriberCreateError {
             SubscriberCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_subscriber_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            SubscriberCreateError::ResourceCreationFailed => {
+                iox2_subscriber_create_error_e::RESOURCE_CREATION_FAILED
+            }
+            SubscriberCreateError::IncompatibleSampleFilter => {
+                iox2_subscriber_create_error_e::INCOMPATIBLE_SAMPLE_FILTER
+            }
+            SubscriberCreateError::HistoryRequestExceedsHistorySizeOfService => {
+                iox2_subscriber_create_error_e::HISTORY_REQUEST_EXCEEDS_HISTORY_SIZE_OF_SERVICE
+            }
+            SubscriberCreateError::TimestampsNotEnabled => {
+                iox2_subscriber_create_error_e::TIMESTAMPS_NOT_ENABLED
+            }
         }) as c_int
     }
 }
@@ -85,7 +101,7 @@ impl PortFactorySubscriberBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactorySubscriberBuilderUnion>
 pub struct iox2_port_factory_subscriber_builder_storage_t {
-    internal: [u8; 112], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
+    internal: [u8; 240], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
 }
 
 #[repr(C)]
@@ -45,6 +45,7 @@ pub enum iox2_send_error_e {
     LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE,
     LOAN_ERROR_INTERNAL_FAILURE,
     CONNECTION_ERROR,
+    INTERNAL_FAILURE,
 }
 
 impl IntoCInt for SendError {
@@ -67,6 +68,7 @@ impl IntoCInt for SendError {
                 iox2_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
             }
             SendError::ConnectionError(_) => iox2_send_error_e::CONNECTION_ERROR,
+            SendError::InternalFailure => iox2_send_error_e::INTERNAL_FAILURE,
         }) as c_int
     }
 }
@@ -14,7 +14,10 @@
 
 use iceoryx2::service::dynamic_config::publish_subscribe::PublisherDetails;
 
-use super::{c_size_t, iox2_node_id_ptr, iox2_unique_publisher_id_h, iox2_unique_publisher_id_t};
+use super::{
+    c_size_t, iox2_node_id_ptr, iox2_unable_to_deliver_strategy_e, iox2_unique_publisher_id_h,
+    iox2_unique_publisher_id_t,
+};
 
 /// The immutable pointer to the underlying `PublisherDetails`
 pub type iox2_publisher_details_ptr = *const PublisherDetails;
@@ -80,6 +83,20 @@ pub unsafe extern "C" fn iox2_publisher_details_number_of_samples(
     (*handle).number_of_samples as _
 }
 
+/// Returns the maximum number of samples the publisher can loan at the same time.
+///
+/// # Safety
+///
+/// * `handle` valid pointer to the publisher details
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publisher_details_max_loaned_samples(
+    handle: iox2_publisher_details_ptr,
+) -> c_size_t {
+    debug_assert!(!handle.is_null());
+
+    (*handle).max_loaned_samples as _
+}
+
 /// Returns the current maximum length of a slice.
 ///
 /// # Safety
@@ -93,3 +110,17 @@ pub unsafe extern "C" fn iox2_publisher_details_max_slice_len(
 
     (*handle).max_slice_len as _
 }
+
+/// Returns the [`iox2_unable_to_deliver_strategy_e`] of the publisher.
+///
+/// # Safety
+///
+/// * `handle` valid pointer to the publisher details
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publisher_details_unable_to_deliver_strategy(
+    handle: iox2_publisher_details_ptr,
+) -> iox2_unable_to_deliver_strategy_e {
+    debug_assert!(!handle.is_null());
+
+    (*handle).unable_to_deliver_strategy.into()
+}
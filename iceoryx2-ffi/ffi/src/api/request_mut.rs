@@ -45,6 +45,7 @@ pub enum iox2_request_send_error_e {
     LOAN_ERROR_INTERNAL_FAILURE,
     CONNECTION_ERROR,
     EXCEEDS_MAX_ACTIVE_REQUESTS,
+    INTERNAL_FAILURE,
 }
 
 impl IntoCInt for RequestSendError {
@@ -71,6 +72,9 @@ impl IntoCInt for RequestSendError {
             RequestSendError::SendError(SendError::ConnectionError(_)) => {
                 iox2_request_send_error_e::CONNECTION_ERROR
             }
+            RequestSendError::SendError(SendError::InternalFailure) => {
+                iox2_request_send_error_e::INTERNAL_FAILURE
+            }
             RequestSendError::ExceedsMaxActiveRequests => {
                 iox2_request_send_error_e::EXCEEDS_MAX_ACTIVE_REQUESTS
             }
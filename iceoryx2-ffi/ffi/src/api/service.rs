@@ -89,6 +89,7 @@ pub enum iox2_service_details_error_e {
     VERSION_MISMATCH,
     INTERNAL_ERROR,
     FAILED_TO_ACQUIRE_NODE_STATE,
+    INSUFFICIENT_PERMISSIONS,
 }
 
 impl IntoCInt for ServiceDetailsError {
@@ -111,6 +112,9 @@ impl IntoCInt for ServiceDetailsError {
             ServiceDetailsError::FailedToAcquireNodeState => {
                 iox2_service_details_error_e::FAILED_TO_ACQUIRE_NODE_STATE
             }
+            ServiceDetailsError::InsufficientPermissions => {
+                iox2_service_details_error_e::INSUFFICIENT_PERMISSIONS
+            }
         }) as c_int
     }
 }
@@ -120,6 +124,8 @@ impl IntoCInt for ServiceDetailsError {
 pub enum iox2_service_list_error_e {
     INSUFFICIENT_PERMISSIONS = IOX2_OK as isize + 1,
     INTERNAL_ERROR,
+    CORRUPTED_SERVICE,
+    VERSION_MISMATCH,
 }
 
 impl IntoCInt for ServiceListError {
@@ -129,6 +135,8 @@ impl IntoCInt for ServiceListError {
             ServiceListError::InsufficientPermissions => {
                 iox2_service_list_error_e::INSUFFICIENT_PERMISSIONS as _
             }
+            ServiceListError::CorruptedService => iox2_service_list_error_e::CORRUPTED_SERVICE as _,
+            ServiceListError::VersionMismatch => iox2_service_list_error_e::VERSION_MISMATCH as _,
         }
     }
 }
@@ -288,9 +296,15 @@ pub unsafe extern "C" fn iox2_service_details(
 fn list_callback<S: Service>(
     callback: iox2_service_list_callback,
     callback_ctx: iox2_callback_context,
-    service_details: &ServiceDetails<S>,
+    service_details: Result<ServiceDetails<S>, ServiceListError>,
 ) -> CallbackProgression {
-    callback(&(&service_details.static_details).into(), callback_ctx).into()
+    match service_details {
+        Ok(service_details) => {
+            callback(&(&service_details.static_details).into(), callback_ctx).into()
+        }
+        // services whose details cannot be acquired are skipped
+        Err(_) => CallbackProgression::Continue,
+    }
 }
 
 /// Iterates over the all accessible services and calls the provided callback for
@@ -312,10 +326,10 @@ pub unsafe extern "C" fn iox2_service_list(
 
     let result = match service_type {
         iox2_service_type_e::IPC => IpcService::list(&*config_ptr, |service_details| {
-            list_callback::<IpcService>(callback, callback_ctx, &service_details)
+            list_callback::<IpcService>(callback, callback_ctx, service_details)
         }),
         iox2_service_type_e::LOCAL => LocalService::list(&*config_ptr, |service_details| {
-            list_callback::<LocalService>(callback, callback_ctx, &service_details)
+            list_callback::<LocalService>(callback, callback_ctx, service_details)
         }),
     };
 
@@ -93,6 +93,8 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     C_HANGS_IN_CREATION,
     #[CStr = "same service is created and removed repeatedly"]
     SYSTEM_IN_FLUX,
+    #[CStr = "incompatible abi"]
+    O_INCOMPATIBLE_ABI,
 }
 
 impl IntoCInt for PublishSubscribeOpenError {
@@ -105,6 +107,9 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IncompatibleTypes => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_TYPES
          }
+         PublishSubscribeOpenError::IncompatibleAbi => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_ABI
+         }
          PublishSubscribeOpenError::IncompatibleMessagingPattern => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_MESSAGING_PATTERN
          }
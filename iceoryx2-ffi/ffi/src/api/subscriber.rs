@@ -37,6 +37,8 @@ pub enum iox2_receive_error_e {
     EXCEEDS_MAX_BORROWS = IOX2_OK as isize + 1,
     FAILED_TO_ESTABLISH_CONNECTION,
     UNABLE_TO_MAP_SENDERS_DATA_SEGMENT,
+    SERVICE_NO_LONGER_EXISTS,
+    INTERNAL_FAILURE,
 }
 
 impl IntoCInt for ReceiveError {
@@ -49,6 +51,8 @@ impl IntoCInt for ReceiveError {
             ReceiveError::ConnectionFailure(ConnectionFailure::UnableToMapSendersDataSegment(
                 _,
             )) => iox2_receive_error_e::UNABLE_TO_MAP_SENDERS_DATA_SEGMENT,
+            ReceiveError::ServiceNoLongerExists => iox2_receive_error_e::SERVICE_NO_LONGER_EXISTS,
+            ReceiveError::InternalFailure => iox2_receive_error_e::INTERNAL_FAILURE,
         }) as c_int
     }
 }
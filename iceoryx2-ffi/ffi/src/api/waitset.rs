@@ -84,6 +84,7 @@ impl From<WaitSetRunResult> for iox2_waitset_run_result_e {
 pub enum iox2_waitset_attachment_error_e {
     INSUFFICIENT_CAPACITY = IOX2_OK as isize + 1,
     ALREADY_ATTACHED,
+    ALREADY_ATTACHED_WITH_DIFFERENT_SEMANTIC,
     INTERNAL_ERROR,
 }
 
@@ -96,6 +97,9 @@ impl IntoCInt for WaitSetAttachmentError {
             WaitSetAttachmentError::AlreadyAttached => {
                 iox2_waitset_attachment_error_e::ALREADY_ATTACHED
             }
+            WaitSetAttachmentError::AlreadyAttachedWithDifferentSemantic => {
+                iox2_waitset_attachment_error_e::ALREADY_ATTACHED_WITH_DIFFERENT_SEMANTIC
+            }
             WaitSetAttachmentError::InternalError => {
                 iox2_waitset_attachment_error_e::INTERNAL_ERROR
             }
@@ -24,9 +24,9 @@ use iceoryx2_bb_elementary::static_assert::*;
 use iceoryx2_ffi_macros::iceoryx2_ffi;
 
 #[repr(C)]
-#[repr(align(1))] // alignment of Option<WaitSetBuilder>
+#[repr(align(8))] // alignment of Option<WaitSetBuilder>
 pub struct iox2_waitset_builder_storage_t {
-    internal: [u8; 1], // magic number obtained with size_of::<Option<WaitSetBuilder>>()
+    internal: [u8; 16], // magic number obtained with size_of::<Option<WaitSetBuilder>>()
 }
 
 #[repr(C)]
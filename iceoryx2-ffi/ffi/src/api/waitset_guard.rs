@@ -44,7 +44,7 @@ impl GuardUnion {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<GuardUnion>
 pub struct iox2_waitset_guard_storage_t {
-    internal: [u8; 56], // magic number obtained with size_of::<Option<GuardUnion>>()
+    internal: [u8; 64], // magic number obtained with size_of::<Option<GuardUnion>>()
 }
 
 #[repr(C)]
@@ -51,10 +51,12 @@ impl Listener {
             ListenerType::Ipc(Some(v)) => Ok(v
                 .try_wait_one()
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?
+                .event_id()
                 .map(EventId)),
             ListenerType::Local(Some(v)) => Ok(v
                 .try_wait_one()
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?
+                .event_id()
                 .map(EventId)),
             _ => fatal_panic!(from "Listener::try_wait_one()",
                     "Accessing a released listener."),
@@ -86,12 +86,18 @@ impl Service {
         let mut ret_val = vec![];
         match service_type {
             ServiceType::Ipc => crate::IpcService::list(&config.0.lock(), |service| {
-                ret_val.push(ServiceDetails(ServiceDetailsType::Ipc(service)));
+                // services whose details cannot be acquired are skipped
+                if let Ok(service) = service {
+                    ret_val.push(ServiceDetails(ServiceDetailsType::Ipc(service)));
+                }
                 iceoryx2::prelude::CallbackProgression::Continue
             })
             .map_err(|e| ServiceListError::new_err(format!("{e:?}")))?,
             ServiceType::Local => crate::LocalService::list(&config.0.lock(), |service| {
-                ret_val.push(ServiceDetails(ServiceDetailsType::Local(service)));
+                // services whose details cannot be acquired are skipped
+                if let Ok(service) = service {
+                    ret_val.push(ServiceDetails(ServiceDetailsType::Local(service)));
+                }
                 iceoryx2::prelude::CallbackProgression::Continue
             })
             .map_err(|e| ServiceListError::new_err(format!("{e:?}")))?,
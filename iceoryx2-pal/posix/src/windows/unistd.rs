@@ -16,16 +16,19 @@
 
 use windows_sys::Win32::{
     Foundation::{
-        CloseHandle, ERROR_FILE_NOT_FOUND, ERROR_NO_MORE_FILES, FALSE, INVALID_HANDLE_VALUE, TRUE,
+        CloseHandle, ERROR_ACCESS_DENIED, ERROR_FILE_NOT_FOUND, ERROR_NO_MORE_FILES, FALSE,
+        GENERIC_READ, INVALID_HANDLE_VALUE, TRUE,
     },
     Networking::WinSock::{
         closesocket, WSADuplicateSocketA, WSASocketA, INVALID_SOCKET, SOCKET_ERROR,
         WSAPROTOCOL_INFOA,
     },
     Storage::FileSystem::{
-        FlushFileBuffers, GetFileAttributesA, ReadFile, RemoveDirectoryA, SetEndOfFile,
-        SetFilePointerEx, WriteFile, FILE_ATTRIBUTE_DIRECTORY, FILE_ATTRIBUTE_READONLY, FILE_BEGIN,
-        FILE_CURRENT, FILE_END, INVALID_FILE_ATTRIBUTES,
+        CreateFileA, FlushFileBuffers, GetFileAttributesA, ReadFile, RemoveDirectoryA,
+        SetEndOfFile, SetFilePointerEx, WriteFile, FILE_ATTRIBUTE_DIRECTORY,
+        FILE_ATTRIBUTE_READONLY, FILE_BEGIN, FILE_CURRENT, FILE_END, FILE_FLAG_BACKUP_SEMANTICS,
+        FILE_SHARE_DELETE, FILE_SHARE_READ, FILE_SHARE_WRITE, INVALID_FILE_ATTRIBUTES,
+        OPEN_EXISTING,
     },
     System::{
         Diagnostics::ToolHelp::{
@@ -196,7 +199,8 @@ pub unsafe fn close(fd: int) -> int {
             0
         }
         Some(FdHandleEntry::UdsDatagramSocket(handle)) => {
-            win32call! { winsock closesocket(handle.fd)};
+            HandleTranslator::get_instance().remove(fd);
+            win32call! { winsock closesocket(handle.fd) };
             0
         }
         _ => {
@@ -254,6 +258,9 @@ pub unsafe fn write(fd: int, buf: *const void, count: size_t) -> ssize_t {
     }
 }
 
+/// Windows has no execute permission, therefore [`X_OK`] succeeds for directories and for
+/// files with an executable extension (`.exe`, `.com`, `.bat`, `.cmd`). [`R_OK`] opens the
+/// file for reading to verify that the security descriptor grants read access.
 pub unsafe fn access(pathname: *const c_char, mode: int) -> int {
     let (attributes, _) =
         win32call! {GetFileAttributesA(pathname as *const u8), ignore ERROR_FILE_NOT_FOUND};
@@ -264,12 +271,23 @@ pub unsafe fn access(pathname: *const c_char, mode: int) -> int {
         }
         -1
     } else {
-        if mode == F_OK && attributes & FILE_ATTRIBUTE_DIRECTORY != 0 {
+        let is_directory = attributes & FILE_ATTRIBUTE_DIRECTORY != 0;
+
+        if mode == F_OK && is_directory {
             Errno::set(Errno::ENOENT);
             return -1;
         }
 
-        if mode == W_OK && attributes & FILE_ATTRIBUTE_READONLY != 0 {
+        if mode & W_OK != 0 && attributes & FILE_ATTRIBUTE_READONLY != 0 {
+            Errno::set(Errno::EACCES);
+            return -1;
+        }
+
+        if mode & R_OK != 0 && !has_read_access(pathname) {
+            return -1;
+        }
+
+        if mode & X_OK != 0 && !is_directory && !has_executable_extension(pathname) {
             Errno::set(Errno::EACCES);
             return -1;
         }
@@ -278,6 +296,41 @@ pub unsafe fn access(pathname: *const c_char, mode: int) -> int {
     }
 }
 
+unsafe fn has_read_access(pathname: *const c_char) -> bool {
+    // FILE_FLAG_BACKUP_SEMANTICS is required to acquire a handle to a directory
+    let (handle, _) = win32call! {CreateFileA(
+        pathname as *const u8,
+        GENERIC_READ,
+        FILE_SHARE_READ | FILE_SHARE_WRITE | FILE_SHARE_DELETE,
+        core::ptr::null(),
+        OPEN_EXISTING,
+        FILE_FLAG_BACKUP_SEMANTICS,
+        0,
+    ), ignore ERROR_ACCESS_DENIED};
+
+    if handle == INVALID_HANDLE_VALUE {
+        return false;
+    }
+
+    win32call! { CloseHandle(handle) };
+    true
+}
+
+unsafe fn has_executable_extension(pathname: *const c_char) -> bool {
+    const EXECUTABLE_EXTENSIONS: [&[u8]; 4] = [b"exe", b"com", b"bat", b"cmd"];
+
+    let pathname = core::ffi::CStr::from_ptr(pathname).to_bytes();
+    match pathname.iter().rposition(|c| *c == b'.') {
+        Some(position) => {
+            let extension = &pathname[position + 1..];
+            EXECUTABLE_EXTENSIONS
+                .iter()
+                .any(|e| e.eq_ignore_ascii_case(extension))
+        }
+        None => false,
+    }
+}
+
 pub unsafe fn unlink(pathname: *const c_char) -> int {
     -1
 }
@@ -353,7 +406,7 @@ pub unsafe fn ftruncate(fd: int, length: off_t) -> int {
         }
         _ => {
             Errno::set(Errno::EBADF);
-            0
+            -1
         }
     }
 }
@@ -27,6 +27,12 @@ use iceoryx2_pal_concurrency_sync::WaitAction;
 use super::win32_udp_port_to_uds_name::PortToUds;
 
 const MAX_SUPPORTED_FD_HANDLES: usize = 1024;
+// A file descriptor consists of the index of its entry in the lower bits and the generation of
+// the entry in the upper bits. The generation is incremented whenever an entry is removed, so
+// that a stale file descriptor does not refer to the entry that reuses the index.
+const FD_INDEX_BITS: u32 = MAX_SUPPORTED_FD_HANDLES.trailing_zeros();
+const FD_INDEX_MASK: int = (MAX_SUPPORTED_FD_HANDLES - 1) as int;
+const MAX_FD_GENERATION: u32 = (int::MAX >> FD_INDEX_BITS) as u32;
 
 #[doc(hidden)]
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -106,6 +112,7 @@ impl Eq for SocketHandle {}
 #[doc(hidden)]
 pub struct HandleTranslator {
     fd2handle: [UnsafeCell<FdHandleEntry>; MAX_SUPPORTED_FD_HANDLES],
+    generations: [UnsafeCell<u32>; MAX_SUPPORTED_FD_HANDLES],
     free_fd_list_start: UnsafeCell<usize>,
     port_to_uds_translator: UnsafeCell<Option<PortToUds>>,
     uds_datagram_counter: IoxAtomicUsize,
@@ -120,9 +127,12 @@ impl HandleTranslator {
         #[allow(clippy::declare_interior_mutable_const)]
         const NEXT_FREE_FD: UnsafeCell<FdHandleEntry> =
             UnsafeCell::new(FdHandleEntry::NextFreeFd(0));
+        #[allow(clippy::declare_interior_mutable_const)]
+        const INITIAL_GENERATION: UnsafeCell<u32> = UnsafeCell::new(0);
         #[deny(clippy::declare_interior_mutable_const)]
         Self {
             fd2handle: [NEXT_FREE_FD; MAX_SUPPORTED_FD_HANDLES],
+            generations: [INITIAL_GENERATION; MAX_SUPPORTED_FD_HANDLES],
             free_fd_list_start: UnsafeCell::new(0),
             port_to_uds_translator: UnsafeCell::new(None),
             uds_datagram_counter: IoxAtomicUsize::new(0),
@@ -171,6 +181,27 @@ impl HandleTranslator {
         });
     }
 
+    // must be called while holding the lock
+    fn index_of(&self, fd: int) -> Option<usize> {
+        if fd < 0 {
+            return None;
+        }
+
+        let index = (fd & FD_INDEX_MASK) as usize;
+        let generation = (fd >> FD_INDEX_BITS) as u32;
+        if unsafe { *self.generations[index].get() } == generation {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    // must be called while holding the lock
+    fn fd_of(&self, index: usize) -> int {
+        let generation = unsafe { *self.generations[index].get() };
+        ((generation as int) << FD_INDEX_BITS) | index as int
+    }
+
     pub fn add(&self, entry: FdHandleEntry) -> int {
         self.lock();
         let free_fd_list_start = unsafe { *self.free_fd_list_start.get() };
@@ -203,16 +234,17 @@ impl HandleTranslator {
             }
         }
 
+        let fd = self.fd_of(free_fd_list_start);
         self.unlock();
 
-        free_fd_list_start as _
+        fd
     }
 
     pub(crate) fn get(&self, fd: int) -> Option<FdHandleEntry> {
         let mut ret_val = None;
         self.lock();
-        if (fd as usize) < self.fd2handle.len() {
-            ret_val = Some(unsafe { *self.fd2handle[fd as usize].get() });
+        if let Some(index) = self.index_of(fd) {
+            ret_val = Some(unsafe { *self.fd2handle[index].get() });
             if let Some(FdHandleEntry::NextFreeFd(_)) = ret_val {
                 ret_val = None;
             }
@@ -236,8 +268,9 @@ impl HandleTranslator {
         self.lock();
         for i in 0..MAX_SUPPORTED_FD_HANDLES {
             if unsafe { *self.fd2handle[i].get() == entry } {
+                let fd = self.fd_of(i);
                 self.unlock();
-                return i as int;
+                return fd;
             }
         }
         self.unlock();
@@ -247,7 +280,14 @@ impl HandleTranslator {
     #[allow(clippy::mut_from_ref)]
     pub(crate) unsafe fn get_shm_handle_mut(&self, fd: int) -> &mut ShmHandle {
         self.lock();
-        match unsafe { &mut *self.fd2handle[fd as usize].get() } {
+        let index = match self.index_of(fd) {
+            Some(index) => index,
+            None => {
+                self.unlock();
+                panic!("Accessed invalid file descriptor.");
+            }
+        };
+        match unsafe { &mut *self.fd2handle[index].get() } {
             FdHandleEntry::SharedMemory(ref mut handle) => {
                 self.unlock();
                 handle
@@ -262,7 +302,14 @@ impl HandleTranslator {
     #[allow(clippy::mut_from_ref)]
     pub(crate) unsafe fn get_file_handle_mut(&self, fd: int) -> &mut FileHandle {
         self.lock();
-        match unsafe { &mut *self.fd2handle[fd as usize].get() } {
+        let index = match self.index_of(fd) {
+            Some(index) => index,
+            None => {
+                self.unlock();
+                panic!("Accessed invalid file descriptor.");
+            }
+        };
+        match unsafe { &mut *self.fd2handle[index].get() } {
             FdHandleEntry::File(ref mut handle) => {
                 self.unlock();
                 handle
@@ -276,8 +323,11 @@ impl HandleTranslator {
 
     pub(crate) unsafe fn get_socket(&self, fd: int) -> Option<SocketHandle> {
         self.lock();
-        match unsafe { *self.fd2handle[fd as usize].get() } {
-            FdHandleEntry::Socket(handle) => {
+        let entry = self
+            .index_of(fd)
+            .map(|index| unsafe { *self.fd2handle[index].get() });
+        match entry {
+            Some(FdHandleEntry::Socket(handle)) => {
                 self.unlock();
                 Some(handle)
             }
@@ -290,7 +340,9 @@ impl HandleTranslator {
 
     pub(crate) fn remove(&self, fd: int) {
         self.lock();
-        self.remove_impl(fd);
+        if let Some(index) = self.index_of(fd) {
+            self.remove_impl(index);
+        }
         self.unlock();
     }
 
@@ -298,7 +350,7 @@ impl HandleTranslator {
         self.lock();
         for i in 0..MAX_SUPPORTED_FD_HANDLES {
             if unsafe { *self.fd2handle[i].get() == entry } {
-                self.remove_impl(i as _);
+                self.remove_impl(i);
                 break;
             }
         }
@@ -355,9 +407,10 @@ impl HandleTranslator {
 
         unsafe {
             self.lock();
-            if let FdHandleEntry::UdsDatagramSocket(ref mut s) = *self.fd2handle[fd as usize].get()
-            {
-                s.address = Some(address);
+            if let Some(index) = self.index_of(fd) {
+                if let FdHandleEntry::UdsDatagramSocket(ref mut s) = *self.fd2handle[index].get() {
+                    s.address = Some(address);
+                }
             }
 
             (*self.port_to_uds_translator.get())
@@ -388,9 +441,9 @@ impl HandleTranslator {
         result
     }
 
-    fn remove_impl(&self, fd: int) {
+    fn remove_impl(&self, index: usize) {
         unsafe {
-            if let FdHandleEntry::UdsDatagramSocket(s) = *self.fd2handle[fd as usize].get() {
+            if let FdHandleEntry::UdsDatagramSocket(s) = *self.fd2handle[index].get() {
                 if self.uds_datagram_counter.fetch_sub(1, Ordering::Relaxed) == 1 {
                     *self.port_to_uds_translator.get() = None;
                 } else if s.is_set() {
@@ -401,9 +454,16 @@ impl HandleTranslator {
                 }
             }
 
-            *self.fd2handle[fd as usize].get() =
+            *self.fd2handle[index].get() =
                 FdHandleEntry::NextFreeFd(*self.free_fd_list_start.get());
-            *self.free_fd_list_start.get() = fd as usize;
+            *self.free_fd_list_start.get() = index;
+
+            let generation = &mut *self.generations[index].get();
+            *generation = if *generation == MAX_FD_GENERATION {
+                0
+            } else {
+                *generation + 1
+            };
         }
     }
 }
@@ -0,0 +1,47 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod unistd {
+    use iceoryx2_pal_posix::posix::{self, Errno};
+    use iceoryx2_pal_testing::assert_that;
+
+    const INVALID_FD: posix::int = 123456;
+
+    #[test]
+    fn ftruncate_with_invalid_fd_fails() {
+        Errno::set(Errno::ESUCCES);
+
+        let result = unsafe { posix::ftruncate(INVALID_FD, 128) };
+
+        assert_that!(result, eq - 1);
+        assert_that!(Errno::get(), eq Errno::EBADF);
+    }
+
+    #[test]
+    fn close_releases_datagram_socket_fd() {
+        let sut = unsafe { posix::socket(posix::PF_UNIX as posix::int, posix::SOCK_DGRAM, 0) };
+        assert_that!(sut, ge 0);
+        assert_that!(unsafe { posix::close(sut) }, eq 0);
+
+        Errno::set(Errno::ESUCCES);
+        assert_that!(unsafe { posix::close(sut) }, eq - 1);
+        assert_that!(Errno::get(), eq Errno::EBADF);
+
+        let reused = unsafe { posix::socket(posix::PF_UNIX as posix::int, posix::SOCK_DGRAM, 0) };
+        assert_that!(reused, ge 0);
+        assert_that!(unsafe { posix::close(reused) }, eq 0);
+
+        Errno::set(Errno::ESUCCES);
+        assert_that!(unsafe { posix::close(reused) }, eq - 1);
+        assert_that!(Errno::get(), eq Errno::EBADF);
+    }
+}
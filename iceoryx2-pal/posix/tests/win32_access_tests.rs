@@ -0,0 +1,98 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "windows")]
+mod win32_access {
+    use std::ffi::CString;
+    use std::path::PathBuf;
+
+    use iceoryx2_pal_posix::posix::{self, Errno};
+    use iceoryx2_pal_testing::assert_that;
+
+    struct ReadOnlyFile {
+        path: PathBuf,
+        c_path: CString,
+    }
+
+    impl ReadOnlyFile {
+        fn new(extension: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "win32_access_tests_{}_{}.{}",
+                std::process::id(),
+                extension,
+                extension
+            ));
+            std::fs::write(&path, b"hypnotoad").unwrap();
+            let mut permissions = std::fs::metadata(&path).unwrap().permissions();
+            permissions.set_readonly(true);
+            std::fs::set_permissions(&path, permissions).unwrap();
+
+            let c_path = CString::new(path.to_str().unwrap()).unwrap();
+            Self { path, c_path }
+        }
+
+        fn access(&self, mode: posix::int) -> posix::int {
+            Errno::set(Errno::ESUCCES);
+            unsafe { posix::access(self.c_path.as_ptr(), mode) }
+        }
+    }
+
+    impl Drop for ReadOnlyFile {
+        fn drop(&mut self) {
+            let mut permissions = std::fs::metadata(&self.path).unwrap().permissions();
+            #[allow(clippy::permissions_set_readonly_false)]
+            permissions.set_readonly(false);
+            std::fs::set_permissions(&self.path, permissions).unwrap();
+            std::fs::remove_file(&self.path).unwrap();
+        }
+    }
+
+    #[test]
+    fn access_existence_of_read_only_file_succeeds() {
+        let sut = ReadOnlyFile::new("txt");
+
+        assert_that!(sut.access(posix::F_OK), eq 0);
+    }
+
+    #[test]
+    fn access_read_of_read_only_file_succeeds() {
+        let sut = ReadOnlyFile::new("txt");
+
+        assert_that!(sut.access(posix::R_OK), eq 0);
+    }
+
+    #[test]
+    fn access_write_of_read_only_file_fails() {
+        let sut = ReadOnlyFile::new("txt");
+
+        assert_that!(sut.access(posix::W_OK), eq - 1);
+        assert_that!(Errno::get(), eq Errno::EACCES);
+        assert_that!(sut.access(posix::R_OK | posix::W_OK), eq - 1);
+        assert_that!(Errno::get(), eq Errno::EACCES);
+    }
+
+    #[test]
+    fn access_execute_of_read_only_file_without_executable_extension_fails() {
+        let sut = ReadOnlyFile::new("txt");
+
+        assert_that!(sut.access(posix::X_OK), eq - 1);
+        assert_that!(Errno::get(), eq Errno::EACCES);
+    }
+
+    #[test]
+    fn access_execute_of_read_only_file_with_executable_extension_succeeds() {
+        let sut = ReadOnlyFile::new("exe");
+
+        assert_that!(sut.access(posix::X_OK), eq 0);
+        assert_that!(sut.access(posix::R_OK | posix::X_OK), eq 0);
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "windows")]
+mod win32_handle_translator {
+    use std::collections::HashSet;
+    use std::sync::Barrier;
+
+    use iceoryx2_pal_posix::posix::{self, Errno, MemZeroedStruct};
+    use iceoryx2_pal_testing::assert_that;
+
+    const NUMBER_OF_ITERATIONS: usize = 4096;
+    const NUMBER_OF_THREADS: usize = 8;
+
+    fn create_socket() -> posix::int {
+        let fd = unsafe { posix::socket(posix::PF_UNIX as posix::int, posix::SOCK_DGRAM, 0) };
+        assert_that!(fd, ge 0);
+        fd
+    }
+
+    fn assert_fd_is_invalid(fd: posix::int) {
+        Errno::set(Errno::ESUCCES);
+        assert_that!(unsafe { posix::close(fd) }, eq - 1);
+        assert_that!(Errno::get(), eq Errno::EBADF);
+    }
+
+    #[test]
+    fn reused_fd_index_is_not_accessible_with_stale_fd() {
+        let stale_fd = create_socket();
+        assert_that!(unsafe { posix::close(stale_fd) }, eq 0);
+
+        let sut = create_socket();
+        assert_that!(sut, ne stale_fd);
+
+        let mut fd_set = posix::fd_set::new_zeroed();
+        unsafe { posix::FD_SET(stale_fd, &mut fd_set) };
+        assert_that!(unsafe { posix::FD_ISSET(sut, &fd_set) }, eq false);
+        unsafe { posix::FD_SET(sut, &mut fd_set) };
+        assert_that!(unsafe { posix::FD_ISSET(stale_fd, &fd_set) }, eq false);
+
+        assert_fd_is_invalid(stale_fd);
+        assert_that!(unsafe { posix::close(sut) }, eq 0);
+    }
+
+    #[test]
+    fn opening_and_closing_many_fds_never_hands_out_a_stale_fd() {
+        let mut stale_fds = HashSet::new();
+
+        for _ in 0..NUMBER_OF_ITERATIONS {
+            let sut = create_socket();
+            assert_that!(stale_fds.contains(&sut), eq false);
+            assert_that!(unsafe { posix::close(sut) }, eq 0);
+            stale_fds.insert(sut);
+        }
+
+        for fd in stale_fds {
+            assert_fd_is_invalid(fd);
+        }
+    }
+
+    #[test]
+    fn concurrently_opening_and_closing_fds_has_no_cross_talk() {
+        let barrier = Barrier::new(NUMBER_OF_THREADS);
+
+        std::thread::scope(|s| {
+            for _ in 0..NUMBER_OF_THREADS {
+                s.spawn(|| {
+                    barrier.wait();
+                    for _ in 0..NUMBER_OF_ITERATIONS / NUMBER_OF_THREADS {
+                        let sut = create_socket();
+                        assert_that!(unsafe { posix::close(sut) }, eq 0);
+                        // other threads may have acquired the index of the fd in the meantime
+                        assert_fd_is_invalid(sut);
+                    }
+                });
+            }
+        });
+    }
+}
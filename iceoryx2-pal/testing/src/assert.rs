@@ -356,3 +356,78 @@ macro_rules! assert_that {
         );
     }
 }
+
+#[macro_export(local_inner_macros)]
+macro_rules! assert_ok {
+    ($lhs:expr, eq $rhs:expr) => {{
+        let lval = assert_ok!($lhs);
+        let rval = &$rhs;
+
+        if !(&lval == rval) {
+            assert_result_message!($lhs, $rhs, "Ok", lval, rval);
+        }
+
+        lval
+    }};
+    ($lhs:expr) => {
+        match $lhs {
+            core::result::Result::Ok(value) => value,
+            core::result::Result::Err(e) => {
+                core::panic!(
+                    "assertion failed: {}{}.is_ok();  value: Err({:?}){}",
+                    assert_that![color_start],
+                    core::stringify!($lhs),
+                    e,
+                    assert_that![color_end]
+                );
+            }
+        }
+    };
+}
+
+#[macro_export(local_inner_macros)]
+macro_rules! assert_err {
+    ($lhs:expr, eq $rhs:expr) => {{
+        let lval = assert_err!($lhs);
+        let rval = &$rhs;
+
+        if !(&lval == rval) {
+            assert_result_message!($lhs, $rhs, "Err", lval, rval);
+        }
+
+        lval
+    }};
+    ($lhs:expr) => {
+        match $lhs {
+            core::result::Result::Err(e) => e,
+            core::result::Result::Ok(value) => {
+                core::panic!(
+                    "assertion failed: {}{}.is_err();  value: Ok({:?}){}",
+                    assert_that![color_start],
+                    core::stringify!($lhs),
+                    value,
+                    assert_that![color_end]
+                );
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! assert_result_message {
+    ($lhs:expr, $rhs:expr, $variant:expr, $lval:expr, $rval:expr) => {
+        core::panic!(
+            "assertion failed: {}expr: {} == {}({});  value: {}({:?}) == {}({:?}){}",
+            assert_that![color_start],
+            core::stringify!($lhs),
+            $variant,
+            core::stringify!($rhs),
+            $variant,
+            $lval,
+            $variant,
+            $rval,
+            assert_that![color_end]
+        );
+    };
+}
@@ -108,7 +108,9 @@ impl From<PublisherCreateError> for CreationError {
                 CreationError::PublisherAlreadyExists
             }
             PublisherCreateError::UnableToCreateDataSegment
-            | PublisherCreateError::FailedToDeployThreadsafetyPolicy => {
+            | PublisherCreateError::FailedToDeployThreadsafetyPolicy
+            | PublisherCreateError::HistoryExceedsHistorySizeOfService
+            | PublisherCreateError::ExceedsMaxDataSegmentSize => {
                 CreationError::PublisherCreationError
             }
         }
@@ -39,7 +39,9 @@ impl From<ServiceListError> for SyncError {
     fn from(error: ServiceListError) -> Self {
         match error {
             ServiceListError::InsufficientPermissions => Self::InsufficientPermissions,
-            ServiceListError::InternalError => Self::ServiceLookupFailure,
+            ServiceListError::InternalError
+            | ServiceListError::CorruptedService
+            | ServiceListError::VersionMismatch => Self::ServiceLookupFailure,
         }
     }
 }
@@ -96,6 +98,10 @@ impl<S: Service> Tracker<S> {
         let mut added_ids = Vec::<ServiceId>::new();
 
         S::list(config, |service| {
+            // services whose details cannot be acquired are not tracked
+            let Ok(service) = service else {
+                return CallbackProgression::Continue;
+            };
             let id = service.static_details.service_id().clone();
             discovered_ids.insert(id.clone());
 
@@ -139,7 +139,7 @@ mod service_discovery_service {
 
         let result = listener.try_wait_one();
         assert_that!(result, is_ok);
-        let result = result.unwrap();
+        let result = result.unwrap().event_id();
         assert_that!(result, is_some);
 
         // remove a service
@@ -148,7 +148,7 @@ mod service_discovery_service {
 
         let result = listener.try_wait_one();
         assert_that!(result, is_ok);
-        let result = result.unwrap();
+        let result = result.unwrap().event_id();
         assert_that!(result, is_some);
     }
 
@@ -81,7 +81,7 @@ impl<ServiceType: iceoryx2::service::Service> Channel for NotifierChannel<'_, Se
         // Propagate all notified ids once
         let mut notified_ids: HashSet<usize> = HashSet::new();
         while let Ok(sample) = self.iox_listener.try_wait_one() {
-            match sample {
+            match sample.event_id() {
                 Some(event_id) => {
                     if !notified_ids.contains(&event_id.as_value()) {
                         fail!(
@@ -373,7 +373,7 @@ mod zenoh_tunnel_events {
 
         // Receive with retry
         retry(
-            || match iox_listener_b.try_wait_one().unwrap() {
+            || match iox_listener_b.try_wait_one().unwrap().event_id() {
                 Some(_event_id) => Ok(()),
                 None => {
                     tunnel_a.propagate().unwrap();
@@ -492,7 +492,7 @@ mod zenoh_tunnel_events {
 
         // Receive at listener b with retry
         retry(
-            || match iox_listener_b.try_wait_one().unwrap() {
+            || match iox_listener_b.try_wait_one().unwrap().event_id() {
                 Some(_event_id) => Ok(()),
                 None => {
                     tunnel_a.propagate().unwrap();
@@ -514,7 +514,7 @@ mod zenoh_tunnel_events {
         // Notification should not have looped back from b to a
         let result = iox_listener_a.try_wait_one();
         assert_that!(result, is_ok);
-        let sample = result.unwrap();
+        let sample = result.unwrap().event_id();
         assert_that!(sample, is_none);
     }
 
@@ -180,6 +180,10 @@ pub struct Node {
     pub static_config_suffix: FileName,
     /// The suffix of the service tags.
     pub service_tag_suffix: FileName,
+    /// The suffix of the heartbeat a [`Node`](crate::node::Node) updates periodically when it
+    /// was created with a
+    /// [`NodeBuilder::heartbeat_interval()`](crate::node::NodeBuilder::heartbeat_interval()).
+    pub heartbeat_suffix: FileName,
     /// When true, the [`NodeBuilder`](crate::node::NodeBuilder) checks for dead nodes and
     /// cleans up all their stale resources whenever a new [`Node`](crate::node::Node) is
     /// created.
@@ -197,6 +201,7 @@ impl Default for Node {
             monitor_suffix: FileName::new(b".node_monitor").unwrap(),
             static_config_suffix: FileName::new(b".details").unwrap(),
             service_tag_suffix: FileName::new(b".service_tag").unwrap(),
+            heartbeat_suffix: FileName::new(b".heartbeat").unwrap(),
             cleanup_dead_nodes_on_creation: true,
             cleanup_dead_nodes_on_destruction: true,
         }
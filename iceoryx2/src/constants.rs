@@ -17,3 +17,4 @@ pub const MAX_ATTRIBUTE_KEY_LENGTH: usize = 64;
 pub const MAX_ATTRIBUTE_VALUE_LENGTH: usize = 256;
 pub const MAX_NODE_NAME_LENGTH: usize = 128;
 pub const MAX_TYPE_NAME_LENGTH: usize = 256;
+pub const MAX_ABI_NAME_LENGTH: usize = 64;
@@ -564,6 +564,10 @@ pub mod sample_mut;
 /// The uninitialized payload that is sent by a [`Publisher`](crate::port::publisher::Publisher).
 pub mod sample_mut_uninit;
 
+/// A borrow of the next payload of a [`Subscriber`](crate::port::subscriber::Subscriber)
+/// that is acquired without receiving it.
+pub mod sample_ref;
+
 /// The foundation of communication the service with its
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
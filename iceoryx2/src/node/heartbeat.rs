@@ -0,0 +1,179 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`Node`](crate::node::Node) that was created with
+//! [`NodeBuilder::heartbeat_interval()`](crate::node::NodeBuilder::heartbeat_interval())
+//! periodically writes the current time into its [`Heartbeat`]. Other processes can read it
+//! while listing the [`Node`](crate::node::Node)s to detect nodes that are stuck or crashed.
+
+use alloc::sync::Arc;
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time, TimeBuilder};
+use iceoryx2_bb_posix::thread::{Thread, ThreadBuilder};
+use iceoryx2_cal::dynamic_storage::*;
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt, NamedConceptRemoveError};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+use crate::config::Config;
+use crate::node::{NodeCleanupFailure, NodeCreationFailure, NodeId};
+use crate::service;
+use crate::service::config_scheme::node_heartbeat_config;
+
+extern crate alloc;
+
+/// The shared state of a [`Node`](crate::node::Node) heartbeat. It contains the
+/// [`ClockType::Realtime`] of the last update in nanoseconds.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Heartbeat {
+    timestamp: IoxAtomicU64,
+}
+
+impl Heartbeat {
+    fn new() -> Self {
+        let heartbeat = Self {
+            timestamp: IoxAtomicU64::new(0),
+        };
+        heartbeat.update();
+        heartbeat
+    }
+
+    fn update(&self) {
+        match Time::now_with_clock(ClockType::Realtime) {
+            Ok(now) => self
+                .timestamp
+                .store(now.as_duration().as_nanos() as u64, Ordering::Relaxed),
+            Err(e) => {
+                warn!(from self, "Unable to update the heartbeat since the current time could not be acquired ({:?}).", e);
+            }
+        }
+    }
+
+    fn last_update(&self) -> Time {
+        let timestamp = Duration::from_nanos(self.timestamp.load(Ordering::Relaxed));
+        TimeBuilder::new()
+            .clock_type(ClockType::Realtime)
+            .seconds(timestamp.as_secs())
+            .nanoseconds(timestamp.subsec_nanos())
+            .create()
+    }
+}
+
+/// Owns the [`Heartbeat`] of the own [`Node`](crate::node::Node) and the thread that updates it
+/// in a fixed interval. The thread is stopped and joined when it goes out of scope.
+#[derive(Debug)]
+pub(crate) struct HeartbeatWriter<Service: service::Service> {
+    storage: Arc<Service::HeartbeatStorage>,
+    stop: Option<Sender<()>>,
+    thread: Option<Thread>,
+}
+
+impl<Service: service::Service> HeartbeatWriter<Service> {
+    pub(crate) fn new(
+        config: &Config,
+        node_id: &NodeId,
+        interval: Duration,
+    ) -> Result<Self, NodeCreationFailure> {
+        let origin = format!("HeartbeatWriter::new({node_id:?}, {interval:?})");
+        let msg = "Unable to create the node heartbeat";
+
+        let storage = match <Service::HeartbeatStorage as DynamicStorage<Heartbeat>>::Builder::new(
+            &node_id.as_file_name(),
+        )
+        .config(&node_heartbeat_config::<Service>(config, node_id))
+        .has_ownership(false)
+        .create(Heartbeat::new())
+        {
+            Ok(storage) => Arc::new(storage),
+            Err(DynamicStorageCreateError::InsufficientPermissions) => {
+                fail!(from origin, with NodeCreationFailure::InsufficientPermissions,
+                    "{msg} due to insufficient permissions to create the heartbeat storage.");
+            }
+            Err(e) => {
+                fail!(from origin, with NodeCreationFailure::InternalError,
+                    "{msg} since the heartbeat storage could not be created ({:?}).", e);
+            }
+        };
+
+        let (stop, stop_receiver) = channel::<()>();
+        let thread_storage = storage.clone();
+        let thread = fail!(from origin, when ThreadBuilder::new().spawn(move || loop {
+                thread_storage.get().update();
+                match stop_receiver.recv_timeout(interval) {
+                    Err(RecvTimeoutError::Timeout) => continue,
+                    _ => break,
+                }
+            }),
+            with NodeCreationFailure::InternalError,
+            "{msg} since the heartbeat thread could not be spawned.");
+
+        Ok(Self {
+            storage,
+            stop: Some(stop),
+            thread: Some(thread),
+        })
+    }
+
+    pub(crate) fn last_heartbeat(&self) -> Time {
+        self.storage.get().last_update()
+    }
+}
+
+impl<Service: service::Service> Drop for HeartbeatWriter<Service> {
+    fn drop(&mut self) {
+        // disconnecting the channel wakes up and stops the heartbeat thread
+        drop(self.stop.take());
+        drop(self.thread.take());
+    }
+}
+
+/// Reads the last [`Heartbeat`] of a [`Node`](crate::node::Node). Returns [`None`] when the
+/// [`Node`](crate::node::Node) has no heartbeat or it is not accessible.
+pub(crate) fn read_heartbeat<Service: service::Service>(
+    config: &Config,
+    node_id: &NodeId,
+) -> Option<Time> {
+    <Service::HeartbeatStorage as DynamicStorage<Heartbeat>>::Builder::new(&node_id.as_file_name())
+        .config(&node_heartbeat_config::<Service>(config, node_id))
+        .has_ownership(false)
+        .open()
+        .ok()
+        .map(|storage| storage.get().last_update())
+}
+
+pub(crate) fn remove_heartbeat<Service: service::Service>(
+    config: &Config,
+    node_id: &NodeId,
+) -> Result<(), NodeCleanupFailure> {
+    let origin = format!("remove_heartbeat({node_id:?})");
+    let msg = "Unable to remove the node heartbeat";
+    match unsafe {
+        <Service::HeartbeatStorage as NamedConceptMgmt>::remove_cfg(
+            &node_id.as_file_name(),
+            &node_heartbeat_config::<Service>(config, node_id),
+        )
+    } {
+        Ok(_) => Ok(()),
+        Err(NamedConceptRemoveError::InsufficientPermissions) => {
+            fail!(from origin, with NodeCleanupFailure::InsufficientPermissions,
+                "{} due to insufficient permissions.", msg);
+        }
+        Err(NamedConceptRemoveError::InternalError) => {
+            fail!(from origin, with NodeCleanupFailure::InternalError,
+                "{} due to an internal failure.", msg);
+        }
+    }
+}
@@ -139,12 +139,15 @@
 //! # }
 //! ```
 
+/// The periodically updated liveness signal of a node.
+pub mod heartbeat;
 /// The name for a node.
 pub mod node_name;
 
 #[doc(hidden)]
 pub mod testing;
 
+use crate::node::heartbeat::{read_heartbeat, remove_heartbeat, HeartbeatWriter};
 use crate::node::node_name::NodeName;
 use crate::service::builder::{Builder, OpenDynamicStorageFailure};
 use crate::service::config_scheme::{
@@ -153,7 +156,8 @@ use crate::service::config_scheme::{
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{
-    self, remove_service_tag, remove_static_service_config, ServiceRemoveNodeError,
+    self, remove_service_tag, remove_static_service_config, ServiceInspectError,
+    ServiceRemoveNodeError,
 };
 use crate::signal_handling_mode::SignalHandlingMode;
 use crate::{config::Config, service::config_scheme::node_details_config};
@@ -398,6 +402,7 @@ impl<Service: service::Service> NodeState<Service> {
         let node_view = AliveNodeView::<Service> {
             id: *node_id,
             details,
+            last_heartbeat: read_heartbeat::<Service>(config, node_id),
             _service: PhantomData,
         };
 
@@ -443,6 +448,10 @@ pub trait NodeView {
     fn id(&self) -> &NodeId;
     /// Returns the [`NodeDetails`].
     fn details(&self) -> &Option<NodeDetails>;
+    /// Returns the time of the last heartbeat that was acquired while listing the [`Node`]s. It
+    /// is [`None`] when the [`Node`] was created without
+    /// [`NodeBuilder::heartbeat_interval()`] or the heartbeat is not accessible.
+    fn last_heartbeat(&self) -> Option<Time>;
 }
 
 /// All the informations of a [`Node`] that is alive.
@@ -450,6 +459,7 @@ pub trait NodeView {
 pub struct AliveNodeView<Service: service::Service> {
     id: NodeId,
     details: Option<NodeDetails>,
+    last_heartbeat: Option<Time>,
     _service: PhantomData<Service>,
 }
 
@@ -458,6 +468,7 @@ impl<Service: service::Service> Clone for AliveNodeView<Service> {
         Self {
             id: self.id,
             details: self.details.clone(),
+            last_heartbeat: self.last_heartbeat,
             _service: PhantomData,
         }
     }
@@ -471,6 +482,10 @@ impl<Service: service::Service> NodeView for AliveNodeView<Service> {
     fn details(&self) -> &Option<NodeDetails> {
         &self.details
     }
+
+    fn last_heartbeat(&self) -> Option<Time> {
+        self.last_heartbeat
+    }
 }
 
 /// All the informations and management operations belonging to a dead [`Node`].
@@ -491,6 +506,10 @@ impl<Service: service::Service> NodeView for DeadNodeView<Service> {
     fn details(&self) -> &Option<NodeDetails> {
         self.0.details()
     }
+
+    fn last_heartbeat(&self) -> Option<Time> {
+        self.0.last_heartbeat()
+    }
 }
 
 impl<Service: service::Service> DeadNodeView<Service> {
@@ -502,6 +521,7 @@ impl<Service: service::Service> DeadNodeView<Service> {
         DeadNodeView(AliveNodeView {
             id,
             details: Some(details),
+            last_heartbeat: None,
             _service: PhantomData::<Service>,
         })
         .remove_stale_resources()
@@ -729,6 +749,7 @@ fn remove_node<Service: service::Service>(
     let details_config = node_details_config::<Service>(config, &id);
     let detail_storages = acquire_all_node_detail_storages::<Service>(&origin, &details_config)?;
     remove_detail_storages::<Service>(&origin, detail_storages, &details_config)?;
+    remove_heartbeat::<Service>(config, &id)?;
     remove_node_details_directory::<Service>(config, &id)?;
 
     Ok(true)
@@ -801,6 +822,7 @@ pub(crate) struct SharedNode<Service: service::Service> {
     monitoring_token: UnsafeCell<Option<<Service::Monitoring as Monitoring>::Token>>,
     registered_services: RegisteredServices,
     signal_handling_mode: SignalHandlingMode,
+    heartbeat: Option<HeartbeatWriter<Service>>,
     _details_storage: Service::StaticStorage,
 }
 
@@ -819,10 +841,17 @@ impl<Service: service::Service> SharedNode<Service> {
     pub(crate) fn registered_services(&self) -> &RegisteredServices {
         &self.registered_services
     }
+
+    pub(crate) fn signal_handling_mode(&self) -> SignalHandlingMode {
+        self.signal_handling_mode
+    }
 }
 
 impl<Service: service::Service> Drop for SharedNode<Service> {
     fn drop(&mut self) {
+        // stop the heartbeat thread before the heartbeat is removed with the node resources
+        self.heartbeat = None;
+
         if self.monitoring_token.get_mut().is_some() {
             if self.config().global.node.cleanup_dead_nodes_on_destruction {
                 Node::<Service>::cleanup_dead_nodes(self.config());
@@ -869,6 +898,36 @@ impl<Service: service::Service> Node<Service> {
         Builder::new(name, self.shared.clone())
     }
 
+    /// Instantiates a [`ServiceBuilder`](Builder) for the existing service with the provided
+    /// [`ServiceId`]. The [`ServiceName`] is read from the static config of the service, see
+    /// [`Service::static_config_by_id()`](crate::service::Service::static_config_by_id()).
+    ///
+    /// ```
+    /// use core::str::FromStr;
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::service_id::ServiceId;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .event()
+    /// #     .open_or_create()?;
+    /// # let service_id_from_cli = service.service_id().to_string();
+    /// let service_id = ServiceId::from_str(&service_id_from_cli)?;
+    /// let service = node.service_builder_by_id(&service_id)?.event().open()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn service_builder_by_id(
+        &self,
+        service_id: &ServiceId,
+    ) -> Result<Builder<Service>, ServiceInspectError> {
+        let static_config = fail!(from self,
+            when Service::static_config_by_id(service_id, self.config()),
+            "Unable to instantiate a service builder for the service {:?}.", service_id);
+        Ok(Builder::new(static_config.name(), self.shared.clone()))
+    }
+
     /// Calls the provided callback for all [`Node`]s in the system under a given [`Config`] and
     /// provides [`NodeState<Service>`] as input argument. With every iteration the callback has to
     /// return [`CallbackProgression::Continue`] to perform the next iteration or
@@ -956,6 +1015,16 @@ impl<Service: service::Service> Node<Service> {
         }
     }
 
+    /// Returns the time of the last heartbeat of the [`Node`] or [`None`] when it was created
+    /// without [`NodeBuilder::heartbeat_interval()`]. Other processes acquire it with
+    /// [`NodeView::last_heartbeat()`] while listing the [`Node`]s with [`Node::list()`].
+    pub fn last_heartbeat(&self) -> Option<Time> {
+        self.shared
+            .heartbeat
+            .as_ref()
+            .map(|heartbeat| heartbeat.last_heartbeat())
+    }
+
     /// Returns the [`SignalHandlingMode`] with which the [`Node`] was created.
     pub fn signal_handling_mode(&self) -> SignalHandlingMode {
         self.shared.signal_handling_mode
@@ -1107,6 +1176,10 @@ impl<Service: service::Service> Node<Service> {
 
         match result.err().unwrap() {
             StaticStorageOpenError::DoesNotExist => Ok(None),
+            StaticStorageOpenError::InsufficientPermissions => {
+                fail!(from origin, with NodeReadStorageFailure::ReadError,
+                    "{} due to insufficient permissions.", msg);
+            }
             StaticStorageOpenError::Read => {
                 fail!(from origin, with NodeReadStorageFailure::ReadError,
                     "{} since the node config storage could not be read.", msg);
@@ -1207,6 +1280,7 @@ pub struct NodeBuilder {
     name: Option<NodeName>,
     signal_handling_mode: SignalHandlingMode,
     config: Option<Config>,
+    heartbeat_interval: Option<Duration>,
 }
 
 impl NodeBuilder {
@@ -1215,7 +1289,11 @@ impl NodeBuilder {
         Self::default()
     }
 
-    /// Sets the [`NodeName`] of the to be created [`Node`].
+    /// Sets the [`NodeName`] of the to be created [`Node`]. The length of the name is
+    /// validated when the [`NodeName`] is constructed, [`NodeName::new()`] returns
+    /// [`SemanticStringError::ExceedsMaximumLength`](iceoryx2_bb_container::semantic_string::SemanticStringError::ExceedsMaximumLength)
+    /// when it exceeds [`NodeName::max_len()`]. Therefore, [`NodeBuilder::create()`] never
+    /// fails or panics due to the length of the name.
     pub fn name(mut self, value: &NodeName) -> Self {
         self.name = Some(value.clone());
         self
@@ -1236,6 +1314,14 @@ impl NodeBuilder {
         self
     }
 
+    /// The [`Node`] spawns a background thread that writes the current time into the nodes
+    /// heartbeat in the provided interval. Other processes can read it with
+    /// [`NodeView::last_heartbeat()`] to detect [`Node`]s that are stuck or crashed.
+    pub fn heartbeat_interval(mut self, value: Duration) -> Self {
+        self.heartbeat_interval = Some(value);
+        self
+    }
+
     /// Creates a new [`Node`] for a specific [`service::Service`]. All entities owned by the
     /// [`Node`] will have the same [`service::Service`].
     pub fn create<Service: service::Service>(self) -> Result<Node<Service>, NodeCreationFailure> {
@@ -1267,6 +1353,10 @@ impl NodeBuilder {
         let (details_storage, details) =
             self.create_node_details_storage::<Service>(&config, &NodeId(node_id))?;
         let monitoring_token = self.create_token::<Service>(&config, &monitor_name)?;
+        let heartbeat = match self.heartbeat_interval {
+            Some(interval) => Some(HeartbeatWriter::new(&config, &NodeId(node_id), interval)?),
+            None => None,
+        };
 
         Ok(Node {
             shared: Arc::new(SharedNode {
@@ -1277,6 +1367,7 @@ impl NodeBuilder {
                 },
                 _details_storage: details_storage,
                 signal_handling_mode: self.signal_handling_mode,
+                heartbeat,
                 details,
             }),
         })
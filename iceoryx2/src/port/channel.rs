@@ -0,0 +1,163 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let builder = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .channel::<u64, u64>();
+//!
+//! let initiator = builder.clone().open_or_create_initiator()?;
+//! let responder = builder.open_or_create_responder()?;
+//!
+//! initiator.send(1234)?;
+//!
+//! // waits until the initiator sent new data or the timeout has passed
+//! responder.timed_wait(Duration::from_millis(10))?;
+//! while let Some(request) = responder.recv()? {
+//!     responder.send(request + 1)?;
+//! }
+//!
+//! initiator.timed_wait(Duration::from_millis(10))?;
+//! while let Some(response) = initiator.recv()? {
+//!     println!("received: {:?}", response);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::time::Duration;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::event::{Event, ListenerWaitError};
+
+use crate::port::notifying_publisher::{NotifyingPublisher, NotifyingSendError};
+use crate::port::notifying_subscriber::NotifyingSubscriber;
+use crate::port::ReceiveError;
+use crate::service;
+
+/// Bidirectional endpoint created by the
+/// [`crate::service::builder::channel::Builder`]. It sends `SendPayload` to the opposite
+/// [`Channel`] and receives `ReceivePayload` from it. Both directions are
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// [`Service`](crate::service::Service)s that notify the receiver about new data, therefore
+/// the [`Channel`] can be attached to a [`WaitSet`](crate::waitset::WaitSet) to wait for
+/// incoming data.
+#[derive(Debug)]
+pub struct Channel<
+    Service: service::Service,
+    SendPayload: Debug + ZeroCopySend + 'static,
+    ReceivePayload: Debug + ZeroCopySend + 'static,
+> {
+    publisher: NotifyingPublisher<Service, SendPayload>,
+    subscriber: NotifyingSubscriber<Service, ReceivePayload>,
+}
+
+impl<
+        Service: service::Service,
+        SendPayload: Debug + ZeroCopySend,
+        ReceivePayload: Debug + ZeroCopySend,
+    > FileDescriptorBased for Channel<Service, SendPayload, ReceivePayload>
+where
+    <Service::Event as Event>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.subscriber.file_descriptor()
+    }
+}
+
+impl<
+        Service: service::Service,
+        SendPayload: Debug + ZeroCopySend,
+        ReceivePayload: Debug + ZeroCopySend,
+    > SynchronousMultiplexing for Channel<Service, SendPayload, ReceivePayload>
+where
+    <Service::Event as Event>::Listener: SynchronousMultiplexing,
+{
+}
+
+impl<
+        Service: service::Service,
+        SendPayload: Debug + ZeroCopySend,
+        ReceivePayload: Debug + ZeroCopySend,
+    > Channel<Service, SendPayload, ReceivePayload>
+{
+    pub(crate) fn new(
+        publisher: NotifyingPublisher<Service, SendPayload>,
+        subscriber: NotifyingSubscriber<Service, ReceivePayload>,
+    ) -> Self {
+        Self {
+            publisher,
+            subscriber,
+        }
+    }
+
+    /// Returns the underlying [`NotifyingPublisher`] of the outgoing direction.
+    pub fn publisher(&self) -> &NotifyingPublisher<Service, SendPayload> {
+        &self.publisher
+    }
+
+    /// Returns the underlying [`NotifyingSubscriber`] of the incoming direction.
+    pub fn subscriber(&self) -> &NotifyingSubscriber<Service, ReceivePayload> {
+        &self.subscriber
+    }
+
+    /// Copies the `value` into a [`crate::sample_mut::SampleMut`], delivers it to the
+    /// opposite [`Channel`] and notifies it afterwards.
+    pub fn send(&self, value: SendPayload) -> Result<(), NotifyingSendError> {
+        self.publisher.send_copy(value)?;
+        Ok(())
+    }
+
+    /// Non-blocking check for new notifications, see [`NotifyingSubscriber::try_wait()`].
+    pub fn try_wait(&self) -> Result<bool, ListenerWaitError> {
+        self.subscriber.try_wait()
+    }
+
+    /// Blocks until either a notification was received or the timeout has passed, see
+    /// [`NotifyingSubscriber::timed_wait()`].
+    pub fn timed_wait(&self, timeout: Duration) -> Result<bool, ListenerWaitError> {
+        self.subscriber.timed_wait(timeout)
+    }
+
+    /// Blocks until a notification was received, see
+    /// [`NotifyingSubscriber::blocking_wait()`].
+    pub fn blocking_wait(&self) -> Result<bool, ListenerWaitError> {
+        self.subscriber.blocking_wait()
+    }
+}
+
+impl<
+        Service: service::Service,
+        SendPayload: Debug + ZeroCopySend,
+        ReceivePayload: Debug + ZeroCopySend + Clone,
+    > Channel<Service, SendPayload, ReceivePayload>
+{
+    /// Receives the next value from the opposite [`Channel`]. The value is copied out of the
+    /// [`crate::sample::Sample`] so that it is released immediately. If no value could be
+    /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    pub fn recv(&self) -> Result<Option<ReceivePayload>, ReceiveError> {
+        Ok(self
+            .subscriber
+            .receive()?
+            .map(|sample| sample.payload().clone()))
+    }
+}
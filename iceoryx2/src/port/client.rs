@@ -223,6 +223,7 @@ impl<Service: service::Service> ClientSharedState<Service> {
             // All requests are delivered on the same channel, therefore we can use
             // ChannelId::new(0).
             ChannelId::new(0),
+            None,
         )?)
     }
 
@@ -270,6 +271,9 @@ impl<Service: service::Service> ClientSharedState<Service> {
                     ReceiverDetails {
                         port_id: port.server_id.value(),
                         buffer_size: port.request_buffer_size,
+                        filter: None,
+                        history_request: 0,
+                        keep_latest: false,
                     },
                     |_| {},
                 );
@@ -436,6 +440,7 @@ impl<
             // but the requests have one shared buffer that the user can configure, therefore
             // one channel suffices
             number_of_channels: 1,
+            notify_receivers: false,
         };
 
         let number_of_to_be_removed_connections = service
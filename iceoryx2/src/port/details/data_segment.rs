@@ -162,6 +162,13 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
+    pub(crate) fn request_huge_pages(&self) -> bool {
+        match &self.memory {
+            MemoryType::Static(memory) => memory.request_huge_pages(),
+            MemoryType::Dynamic(_) => false,
+        }
+    }
+
     pub(crate) fn max_number_of_segments(data_segment_type: DataSegmentType) -> u8 {
         match data_segment_type {
             DataSegmentType::Static => 1,
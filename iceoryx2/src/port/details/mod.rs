@@ -14,6 +14,7 @@ pub(crate) mod channel_management;
 pub(crate) mod chunk;
 pub(crate) mod chunk_details;
 pub(crate) mod data_segment;
+pub(crate) mod port_wait;
 pub(crate) mod receiver;
 pub(crate) mod segment_state;
 pub(crate) mod sender;
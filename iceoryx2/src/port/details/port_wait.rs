@@ -0,0 +1,73 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt::Debug;
+use core::time::Duration;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::signal::{NonFatalFetchableSignal, SignalHandler};
+
+use crate::port::WaitForPortsError;
+use crate::signal_handling_mode::SignalHandlingMode;
+
+// The signal is only peeked so that the main loop of the user, like Node::wait(), observes
+// the termination request as well.
+pub(crate) fn termination_requested(signal_handling_mode: SignalHandlingMode) -> bool {
+    signal_handling_mode == SignalHandlingMode::HandleTerminationRequests
+        && matches!(
+            SignalHandler::peek_last_signal(),
+            Some(NonFatalFetchableSignal::Interrupt | NonFatalFetchableSignal::Terminate)
+        )
+}
+
+// Polls the number of connected ports in a less busy wait until at least `count` ports are
+// connected or the timeout has passed and returns the last observed number of ports.
+pub(crate) fn wait_for_ports<O: Debug, F: FnMut() -> usize>(
+    origin: &O,
+    signal_handling_mode: SignalHandlingMode,
+    count: usize,
+    timeout: Duration,
+    mut number_of_ports: F,
+) -> Result<usize, WaitForPortsError> {
+    let msg = "Unable to wait for the ports to connect";
+    let start = fail!(from origin, when Time::now(),
+            with WaitForPortsError::InternalFailure,
+            "{} since the current time could not be acquired.", msg);
+    let mut adaptive_wait = fail!(from origin, when AdaptiveWaitBuilder::new().create(),
+            with WaitForPortsError::InternalFailure,
+            "{} since the adaptive wait could not be created.", msg);
+
+    loop {
+        let connected_ports = number_of_ports();
+        if connected_ports >= count {
+            return Ok(connected_ports);
+        }
+
+        if termination_requested(signal_handling_mode) {
+            fail!(from origin, with WaitForPortsError::Interrupt,
+                "{} since a termination request was received.", msg);
+        }
+
+        let elapsed = fail!(from origin, when start.elapsed(),
+                with WaitForPortsError::InternalFailure,
+                "{} since the elapsed time could not be acquired.", msg);
+        if elapsed >= timeout {
+            return Ok(connected_ports);
+        }
+
+        fail!(from origin, when adaptive_wait.wait(),
+            with WaitForPortsError::InternalFailure,
+            "{} since the adaptive wait failed.", msg);
+    }
+}
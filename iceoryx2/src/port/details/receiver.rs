@@ -242,6 +242,13 @@ impl<Service: service::Service> Receiver<Service> {
         unsafe { *self.connections[index].get() = None };
     }
 
+    pub(crate) fn is_connected_to(&self, sender_port_id: u128) -> bool {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        connection_storage
+            .iter()
+            .any(|(_, connection)| connection.sender_port_id == sender_port_id)
+    }
+
     pub(crate) fn has_samples(&self, channel_id: ChannelId) -> bool {
         let connection_storage = unsafe { &mut *self.connection_storage.get() };
         for (_, connection) in connection_storage.iter() {
@@ -253,6 +260,43 @@ impl<Service: service::Service> Receiver<Service> {
         false
     }
 
+    pub(crate) fn number_of_samples(&self, channel_id: ChannelId) -> usize {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        connection_storage
+            .iter()
+            .map(|(_, connection)| connection.receiver.number_of_samples(channel_id))
+            .sum()
+    }
+
+    pub(crate) fn number_of_dropped_samples(&self) -> u64 {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        connection_storage
+            .iter()
+            .map(|(_, connection)| connection.receiver.number_of_dropped_samples())
+            .sum()
+    }
+
+    pub(crate) fn reset_dropped_samples_counters(&self) {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        for (_, connection) in connection_storage.iter() {
+            connection.receiver.reset_number_of_dropped_samples();
+        }
+    }
+
+    pub(crate) fn for_each_active_connection<F: FnMut(&Connection<Service>)>(
+        &self,
+        mut callback: F,
+    ) {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        for connection_key in self.connections.iter() {
+            if let Some(connection_key) = unsafe { &*connection_key.get() } {
+                if let Some(connection) = connection_storage.get(*connection_key) {
+                    callback(connection);
+                }
+            }
+        }
+    }
+
     pub(crate) fn has_samples_in_active_connection(&self, channel_id: ChannelId) -> bool {
         let connection_storage = unsafe { &mut *self.connection_storage.get() };
         for connection_key in self.connections.iter() {
@@ -403,6 +447,69 @@ impl<Service: service::Service> Receiver<Service> {
         Ok(None)
     }
 
+    // Receives up to max samples and calls the callback for every one of them. Every pass
+    // receives at most one sample from every connection, so that a sender with a full buffer
+    // cannot starve the others.
+    pub(crate) fn receive_batch<F: FnMut(ChunkDetails, Chunk)>(
+        &self,
+        channel_id: ChannelId,
+        max: usize,
+        mut callback: F,
+    ) -> Result<usize, ReceiveError> {
+        let mut number_of_received_samples = 0;
+        while number_of_received_samples < max {
+            match self.receive_from_to_be_removed_connections(channel_id)? {
+                Some((details, chunk)) => {
+                    callback(details, chunk);
+                    number_of_received_samples += 1;
+                }
+                None => break,
+            }
+        }
+
+        let msg = "Unable to receive a batch of data";
+        let mut exceeds_max_borrows = false;
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        while number_of_received_samples < max {
+            let mut has_received_samples = false;
+            for (connection_key, connection) in connection_storage.iter() {
+                if number_of_received_samples == max {
+                    break;
+                }
+
+                if !connection.receiver.has_data(channel_id) {
+                    continue;
+                }
+
+                if connection.receiver.borrow_count(channel_id)
+                    >= connection.receiver.max_borrowed_samples()
+                {
+                    exceeds_max_borrows = true;
+                    continue;
+                }
+
+                if let Some((details, chunk)) =
+                    self.receive_from_connection(connection, connection_key, channel_id)?
+                {
+                    callback(details, chunk);
+                    number_of_received_samples += 1;
+                    has_received_samples = true;
+                }
+            }
+
+            if !has_received_samples {
+                break;
+            }
+        }
+
+        if exceeds_max_borrows && number_of_received_samples == 0 {
+            fail!(from self, with ReceiveError::ExceedsMaxBorrows,
+                 "{msg} since every channel exceeds the max number of borrows.");
+        }
+
+        Ok(number_of_received_samples)
+    }
+
     pub(crate) fn start_update_connection_cycle(&self) {
         self.tagger.next_cycle();
     }
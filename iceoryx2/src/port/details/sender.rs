@@ -14,21 +14,26 @@ use alloc::sync::Arc;
 use core::alloc::Layout;
 use core::cell::UnsafeCell;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
 
 use iceoryx2_bb_elementary::cyclic_tagger::*;
 use iceoryx2_bb_log::{error, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_cal::event::{Event, Notifier, NotifierBuilder, TriggerId};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::shm_allocator::{AllocationError, PointerOffset, ShmAllocationError};
 use iceoryx2_cal::zero_copy_connection::{
     ChannelId, ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
-    ZeroCopySendError, ZeroCopySender,
+    ZeroCopyPortDetails, ZeroCopySendError, ZeroCopySender,
 };
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
 use crate::node::SharedNode;
-use crate::port::{DegradationAction, DegradationCallback, LoanError, SendError};
+use crate::port::sample_filter::SampleFilter;
+use crate::port::{DegradationAction, DegradationCallback, DeliveryState, LoanError, SendError};
 use crate::prelude::UnableToDeliverStrategy;
-use crate::service::config_scheme::connection_config;
+use crate::service::config_scheme::{connection_config, event_config};
+use crate::service::naming_scheme::subscriber_notification_name;
 use crate::service::static_config::message_type_details::{MessageTypeDetails, TypeVariant};
 use crate::service::{NoResource, ServiceState};
 use crate::{service, service::naming_scheme::connection_name};
@@ -43,12 +48,26 @@ use super::segment_state::SegmentState;
 pub(crate) struct ReceiverDetails {
     pub(crate) port_id: u128,
     pub(crate) buffer_size: usize,
+    pub(crate) filter: Option<SampleFilter>,
+    pub(crate) history_request: usize,
+    pub(crate) keep_latest: bool,
+}
+
+/// The user header and payload of a sample, required to evaluate the [`SampleFilter`] of
+/// a receiver.
+#[derive(Clone, Copy)]
+pub(crate) struct SampleContent<'a> {
+    pub(crate) user_header: &'a [u8],
+    pub(crate) payload: &'a [u8],
 }
 
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) receiver_port_id: u128,
+    pub(crate) filter: Option<SampleFilter>,
+    pub(crate) history_request: usize,
+    notifier: Option<<Service::Event as Event>::Notifier>,
     tag: Tag,
 }
 
@@ -59,13 +78,21 @@ impl<Service: service::Service> Taggable for Connection<Service> {
 }
 
 impl<Service: service::Service> Connection<Service> {
+    pub(crate) fn accepts(&self, content: Option<SampleContent>) -> bool {
+        match (&self.filter, content) {
+            (Some(filter), Some(content)) => filter.matches(content.user_header, content.payload),
+            _ => true,
+        }
+    }
+
     fn new(
         this: &Sender<Service>,
-        receiver_port_id: u128,
-        buffer_size: usize,
+        receiver_details: ReceiverDetails,
         number_of_samples: usize,
         tag: Tag,
     ) -> Result<Self, ZeroCopyCreationError> {
+        let receiver_port_id = receiver_details.port_id;
+        let buffer_size = receiver_details.buffer_size;
         let msg = format!(
             "Unable to establish connection to receiver port {:?} from sender port {:?}",
             receiver_port_id, this.sender_port_id
@@ -81,7 +108,7 @@ impl<Service: service::Service> Connection<Service> {
                                 .config(&connection_config::<Service>(this.shared_node.config()))
                                 .buffer_size(buffer_size)
                                 .receiver_max_borrowed_samples_per_channel(this.receiver_max_borrowed_samples)
-                                .enable_safe_overflow(this.enable_safe_overflow)
+                                .enable_safe_overflow(this.enable_safe_overflow || receiver_details.keep_latest)
                                 .number_of_samples_per_segment(number_of_samples)
                                 .max_supported_shared_memory_segments(this.max_number_of_segments)
                                 .initial_channel_state(INVALID_CHANNEL_STATE)
@@ -90,9 +117,31 @@ impl<Service: service::Service> Connection<Service> {
                                 .create_sender(),
                         "{}.", msg);
 
+        let notifier = if this.notify_receivers {
+            match <Service::Event as Event>::NotifierBuilder::new(&subscriber_notification_name(
+                receiver_port_id,
+            ))
+            .config(&event_config::<Service>(this.shared_node.config()))
+            .open()
+            {
+                Ok(notifier) => Some(notifier),
+                Err(e) => {
+                    warn!(from this,
+                        "Unable to open the notification channel to receiver port {:?} ({:?}). The receiver will not be woken up on new deliveries.",
+                        receiver_port_id, e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         Ok(Self {
             sender,
             receiver_port_id,
+            filter: receiver_details.filter,
+            history_request: receiver_details.history_request,
+            notifier,
             tag,
         })
     }
@@ -118,6 +167,7 @@ pub(crate) struct Sender<Service: service::Service> {
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) message_type_details: MessageTypeDetails,
     pub(crate) number_of_channels: usize,
+    pub(crate) notify_receivers: bool,
 }
 
 impl<Service: service::Service> Sender<Service> {
@@ -152,61 +202,108 @@ impl<Service: service::Service> Sender<Service> {
         sample_size: usize,
         channel_id: ChannelId,
         connection_id: usize,
-    ) -> Result<usize, SendError> {
-        let deliver_call = match self.unable_to_deliver_strategy {
-            UnableToDeliverStrategy::Block => {
-                <Service::Connection as ZeroCopyConnection>::Sender::blocking_send
-            }
-            UnableToDeliverStrategy::DiscardSample => {
-                <Service::Connection as ZeroCopyConnection>::Sender::try_send
+        content: Option<SampleContent>,
+        timeout: Option<Duration>,
+    ) -> Result<Option<DeliveryState>, SendError> {
+        if let Some(ref connection) = self.get(connection_id) {
+            if !connection.accepts(content) {
+                return Ok(Some(DeliveryState::Filtered));
             }
-        };
 
-        let mut number_of_recipients = 0;
-        if let Some(ref connection) = self.get(connection_id) {
-            match deliver_call(&connection.sender, offset, sample_size, channel_id) {
+            let result =
+                match (self.unable_to_deliver_strategy, timeout) {
+                    (UnableToDeliverStrategy::Block, None) => {
+                        connection
+                            .sender
+                            .blocking_send(offset, sample_size, channel_id)
+                    }
+                    (UnableToDeliverStrategy::Block, Some(timeout)) => connection
+                        .sender
+                        .timed_send(offset, sample_size, channel_id, timeout),
+                    (UnableToDeliverStrategy::DiscardSample, _) => {
+                        connection.sender.try_send(offset, sample_size, channel_id)
+                    }
+                };
+
+            let state = match result {
                 Err(ZeroCopySendError::ReceiveBufferFull)
                 | Err(ZeroCopySendError::UsedChunkListFull) => {
                     /* causes no problem
                      *   blocking_send => can never happen
+                     *   timed_send => the timeout has passed and the sample is not delivered
                      *   try_send => we tried and expect that the buffer is full
                      * */
+                    DeliveryState::DroppedDueToFullBuffer
                 }
-                Err(ZeroCopySendError::ConnectionCorrupted) => match &self.degradation_callback {
-                    Some(c) => match c.call(
-                        &self.service_state.static_config,
-                        self.sender_port_id,
-                        connection.receiver_port_id,
-                    ) {
-                        DegradationAction::Ignore => (),
-                        DegradationAction::Warn => {
+                Err(ZeroCopySendError::ConnectionCorrupted) => {
+                    match &self.degradation_callback {
+                        Some(c) => match c.call(
+                            &self.service_state.static_config,
+                            self.sender_port_id,
+                            connection.receiver_port_id,
+                        ) {
+                            DegradationAction::Ignore => (),
+                            DegradationAction::Warn => {
+                                error!(from self,
+                                            "While delivering the sample: {:?} a corrupted connection was detected with receiver {:?}.",
+                                            offset, connection.receiver_port_id);
+                            }
+                            DegradationAction::Fail => {
+                                fail!(from self, with SendError::ConnectionCorrupted,
+                                            "While delivering the sample: {:?} a corrupted connection was detected with receiver {:?}.",
+                                            offset, connection.receiver_port_id);
+                            }
+                        },
+                        None => {
                             error!(from self,
                                         "While delivering the sample: {:?} a corrupted connection was detected with receiver {:?}.",
                                         offset, connection.receiver_port_id);
                         }
-                        DegradationAction::Fail => {
-                            fail!(from self, with SendError::ConnectionCorrupted,
-                                        "While delivering the sample: {:?} a corrupted connection was detected with receiver {:?}.",
-                                        offset, connection.receiver_port_id);
-                        }
-                    },
-                    None => {
-                        error!(from self,
-                                    "While delivering the sample: {:?} a corrupted connection was detected with receiver {:?}.",
-                                    offset, connection.receiver_port_id);
                     }
-                },
+                    DeliveryState::ConnectionBroken
+                }
                 Ok(overflow) => {
                     self.borrow_sample(offset);
-                    number_of_recipients += 1;
 
                     if let Some(old) = overflow {
                         self.release_sample(old)
                     }
+
+                    if let Some(ref notifier) = connection.notifier {
+                        if let Err(e) = notifier.notify(TriggerId::new(0)) {
+                            warn!(from self,
+                                "Unable to notify receiver {:?} about the delivered sample {:?} ({:?}).",
+                                connection.receiver_port_id, offset, e);
+                        }
+                    }
+
+                    DeliveryState::Delivered
                 }
+            };
+
+            Ok(Some(state))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub(crate) fn for_each_dropped_samples_counter<F: FnMut(u128, u64)>(&self, mut callback: F) {
+        for i in 0..self.len() {
+            if let Some(connection) = self.get(i) {
+                callback(
+                    connection.receiver_port_id,
+                    connection.sender.number_of_dropped_samples(),
+                );
+            }
+        }
+    }
+
+    pub(crate) fn reset_dropped_samples_counters(&self) {
+        for i in 0..self.len() {
+            if let Some(connection) = self.get(i) {
+                connection.sender.reset_number_of_dropped_samples();
             }
         }
-        Ok(number_of_recipients)
     }
 
     pub(crate) fn has_channel_state(
@@ -243,7 +340,19 @@ impl<Service: service::Service> Sender<Service> {
         connection_id: usize,
     ) -> Result<usize, SendError> {
         self.retrieve_returned_samples();
-        self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, connection_id)
+        Ok(
+            match self.deliver_offset_to_connection_impl(
+                offset,
+                sample_size,
+                channel_id,
+                connection_id,
+                None,
+                None,
+            )? {
+                Some(DeliveryState::Delivered) => 1,
+                _ => 0,
+            },
+        )
     }
 
     pub(crate) fn deliver_offset(
@@ -251,15 +360,72 @@ impl<Service: service::Service> Sender<Service> {
         offset: PointerOffset,
         sample_size: usize,
         channel_id: ChannelId,
+        content: Option<SampleContent>,
     ) -> Result<usize, SendError> {
+        let mut number_of_recipients = 0;
+        self.deliver_offset_with_callback(
+            offset,
+            sample_size,
+            channel_id,
+            content,
+            None,
+            |_, state| {
+                if state == DeliveryState::Delivered {
+                    number_of_recipients += 1;
+                }
+            },
+        )?;
+        Ok(number_of_recipients)
+    }
+
+    // Delivers the offset to every connection and calls the callback with the receiver port id
+    // and the DeliveryState of every connection.
+    // The timeout is only relevant for the UnableToDeliverStrategy::Block and applies to the
+    // whole delivery, receivers that still have a full buffer after the timeout has passed do
+    // not receive the sample.
+    pub(crate) fn deliver_offset_with_callback<F: FnMut(u128, DeliveryState)>(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        channel_id: ChannelId,
+        content: Option<SampleContent>,
+        timeout: Option<Duration>,
+        mut callback: F,
+    ) -> Result<(), SendError> {
         self.retrieve_returned_samples();
+        let msg = "Unable to deliver the sample";
+        let start = fail!(from self, when timeout.map(|_| Time::now()).transpose(),
+            with SendError::InternalFailure,
+            "{} since the current time could not be acquired.", msg);
 
-        let mut number_of_recipients = 0;
         for i in 0..self.len() {
-            number_of_recipients +=
-                self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, i)?;
+            let receiver_port_id = match self.get(i) {
+                Some(connection) => connection.receiver_port_id,
+                None => continue,
+            };
+
+            let remaining_timeout = match (timeout, &start) {
+                (Some(timeout), Some(start)) => {
+                    let elapsed = fail!(from self, when start.elapsed(),
+                        with SendError::InternalFailure,
+                        "{} since the elapsed time could not be acquired.", msg);
+                    Some(timeout.saturating_sub(elapsed))
+                }
+                _ => None,
+            };
+
+            if let Some(state) = self.deliver_offset_to_connection_impl(
+                offset,
+                sample_size,
+                channel_id,
+                i,
+                content,
+                remaining_timeout,
+            )? {
+                callback(receiver_port_id, state);
+            }
         }
-        Ok(number_of_recipients)
+        Ok(())
     }
 
     pub(crate) fn return_loaned_sample(&self, distance_to_chunk: PointerOffset) {
@@ -274,8 +440,7 @@ impl<Service: service::Service> Sender<Service> {
     ) -> Result<(), ZeroCopyCreationError> {
         *self.get_mut(index) = Some(Connection::new(
             self,
-            receiver_details.port_id,
-            receiver_details.buffer_size,
+            receiver_details,
             self.number_of_samples,
             self.tagger.create_tag(),
         )?);
@@ -303,8 +468,11 @@ impl<Service: service::Service> Sender<Service> {
                 fail!(from self, with LoanError::OutOfMemory,
                     "{} {:?} since the underlying shared memory is out of memory.", msg, layout);
             }
-            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
-            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge)) => {
+                fail!(from self, with LoanError::ExceedsMaxLoanSize,
+                    "{} {:?} since the requested size exceeds the maximum size of a sample.", msg, layout);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
                 fatal_panic!(from self, "{} {:?} since the system seems to be corrupted.", msg, layout);
             }
             Err(v) => {
@@ -320,11 +488,14 @@ impl<Service: service::Service> Sender<Service> {
         }
 
         self.loan_counter.fetch_add(1, Ordering::Relaxed);
-        Ok(ChunkMut::new(
-            &self.message_type_details,
-            shm_pointer,
-            sample_size,
-        ))
+        let chunk = ChunkMut::new(&self.message_type_details, shm_pointer, sample_size);
+        debug_assert!(
+            (chunk.payload as usize) % self.message_type_details.payload.alignment == 0,
+            "The payload of the loaned sample does not satisfy the required alignment of {}.",
+            self.message_type_details.payload.alignment
+        );
+
+        Ok(chunk)
     }
 
     pub(crate) fn borrow_sample(&self, offset: PointerOffset) -> (u64, usize) {
@@ -30,7 +30,7 @@
 //!
 //! notifier.notify_with_custom_event_id(EventId::new(5));
 //!
-//! while let Some(event_id) = listener.try_wait_one()? {
+//! while let Some(event_id) = listener.try_wait_one()?.event_id() {
 //!     println!("event was triggered with id: {:?}", event_id);
 //! }
 //!
@@ -38,5 +38,71 @@
 //! # }
 //! ```
 
+//!
+//! ## Enums As Event Ids
+//!
+//! Enums without fields can derive [`IntoEventId`] to be converted into an [`EventId`] and
+//! back.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::event_id::EventIdConversionError;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, IntoEventId)]
+//! enum MachineState {
+//!     Idle = 1,
+//!     Running = 2,
+//!     Failure = 7,
+//! }
+//!
+//! let event = node.service_builder(&"MyMachineEvents".try_into()?)
+//!     .event()
+//!     .event_id_max_value(8)
+//!     .open_or_create()?;
+//!
+//! let listener = event.listener_builder().create()?;
+//! let notifier = event.notifier_builder().create()?;
+//!
+//! notifier.notify_with_custom_event_id(MachineState::Running.into())?;
+//!
+//! if let Some(event_id) = listener.try_wait_one()?.event_id() {
+//!     assert_eq!(MachineState::try_from(event_id), Ok(MachineState::Running));
+//! }
+//!
+//! assert_eq!(
+//!     MachineState::try_from(EventId::new(3)),
+//!     Err(EventIdConversionError::UnknownEventId)
+//! );
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+pub use iceoryx2_bb_derive_macros::IntoEventId;
+
 /// Type that allows to identify an event uniquely.
+///
+/// It can be converted from [`u8`], [`u16`] and [`u32`]. The conversion from [`u64`] fails
+/// when the value exceeds [`EventId::MAX`]. The
+/// [`event_id_max_value`](crate::service::builder::event::Builder::event_id_max_value())
+/// of a [`Service`](crate::service::Service) is verified when the [`EventId`] is used by a
+/// [`Notifier`](crate::port::notifier::Notifier).
 pub type EventId = iceoryx2_cal::event::TriggerId;
+
+/// Failures that can occur when an [`EventId`] is converted into a type that derives
+/// [`IntoEventId`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EventIdConversionError {
+    /// The [`EventId`] does not correspond to any variant of the type.
+    UnknownEventId,
+}
+
+impl core::fmt::Display for EventIdConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EventIdConversionError::{self:?}")
+    }
+}
+
+impl core::error::Error for EventIdConversionError {}
@@ -22,6 +22,7 @@
 //!
 //! ```
 //! use iceoryx2::prelude::*;
+//! use iceoryx2::port::listener::ListenerReceiveResult;
 //! # fn main() -> Result<(), Box<dyn core::error::Error>> {
 //! let node = NodeBuilder::new().create::<ipc::Service>()?;
 //! let event = node.service_builder(&"MyEventName".try_into()?)
@@ -30,8 +31,17 @@
 //!
 //! let mut listener = event.listener_builder().create()?;
 //!
-//! for event_id in listener.try_wait_one()? {
-//!     println!("event was triggered with id: {:?}", event_id);
+//! loop {
+//!     match listener.try_wait_one()? {
+//!         ListenerReceiveResult::Event(event_id) => {
+//!             println!("event was triggered with id: {:?}", event_id);
+//!         }
+//!         ListenerReceiveResult::NoEvents => break,
+//!         ListenerReceiveResult::AllNotifiersGone => {
+//!             println!("all notifiers are gone, shutting down");
+//!             break;
+//!         }
+//!     }
 //! }
 //!
 //! # Ok(())
@@ -66,6 +76,7 @@ use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError, NamedConceptMgmt, TriggerId};
 use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptRemoveError};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 
 use crate::config::Config;
 use crate::service::config_scheme::event_config;
@@ -103,6 +114,31 @@ impl core::fmt::Display for ListenerCreateError {
 
 impl core::error::Error for ListenerCreateError {}
 
+/// The result of [`Listener::try_wait_one()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ListenerReceiveResult {
+    /// An [`EventId`] was received.
+    Event(EventId),
+    /// No [`EventId`] was pending but at least one
+    /// [`Notifier`](crate::port::notifier::Notifier) is still connected.
+    NoEvents,
+    /// No [`EventId`] was pending and no [`Notifier`](crate::port::notifier::Notifier) is
+    /// connected to the [`Service`](crate::service::Service) anymore. Only reported after the
+    /// [`Listener`] has seen at least one [`Notifier`](crate::port::notifier::Notifier) or
+    /// received an [`EventId`].
+    AllNotifiersGone,
+}
+
+impl ListenerReceiveResult {
+    /// Returns the received [`EventId`] or [`None`] when no [`EventId`] was received.
+    pub fn event_id(&self) -> Option<EventId> {
+        match self {
+            ListenerReceiveResult::Event(event_id) => Some(*event_id),
+            _ => None,
+        }
+    }
+}
+
 /// Represents the receiving endpoint of an event based communication.
 #[derive(Debug)]
 pub struct Listener<Service: service::Service> {
@@ -111,6 +147,7 @@ pub struct Listener<Service: service::Service> {
         Service::ArcThreadSafetyPolicy<<Service::Event as iceoryx2_cal::event::Event>::Listener>,
     service_state: Arc<ServiceState<Service, NoResource>>,
     listener_id: UniqueListenerId,
+    had_notifier: IoxAtomicBool,
 }
 
 unsafe impl<Service: service::Service> Send for Listener<Service> where
@@ -185,6 +222,7 @@ impl<Service: service::Service> Listener<Service> {
             dynamic_listener_handle: None,
             listener,
             listener_id,
+            had_notifier: IoxAtomicBool::new(false),
         };
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -255,13 +293,50 @@ impl<Service: service::Service> Listener<Service> {
         Ok(())
     }
 
-    /// Non-blocking wait for a new [`EventId`]. If no [`EventId`] was notified it returns [`None`].
+    /// Non-blocking wait for a new [`EventId`]. If an [`EventId`] was notified it returns
+    /// [`ListenerReceiveResult::Event`]. Otherwise it returns
+    /// [`ListenerReceiveResult::AllNotifiersGone`] when no
+    /// [`Notifier`](crate::port::notifier::Notifier) is connected to the
+    /// [`Service`](crate::service::Service) anymore and [`ListenerReceiveResult::NoEvents`]
+    /// otherwise. As long as the [`Listener`] has neither seen a connected
+    /// [`Notifier`](crate::port::notifier::Notifier) nor received an [`EventId`], it returns
+    /// [`ListenerReceiveResult::NoEvents`] so that a newly created [`Listener`] does not report
+    /// that all [`Notifier`](crate::port::notifier::Notifier)s are gone before the first one
+    /// has connected.
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
-    pub fn try_wait_one(&self) -> Result<Option<EventId>, ListenerWaitError> {
+    pub fn try_wait_one(&self) -> Result<ListenerReceiveResult, ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(fail!(from self, when self.listener.lock().try_wait_one(),
-            "Failed to while calling try_wait on underlying event::Listener"))
+        let msg = "Failed to while calling try_wait on underlying event::Listener";
+        if let Some(event_id) =
+            fail!(from self, when self.listener.lock().try_wait_one(), "{}", msg)
+        {
+            self.had_notifier.store(true, Ordering::Relaxed);
+            return Ok(ListenerReceiveResult::Event(event_id));
+        }
+
+        let number_of_notifiers = self
+            .service_state
+            .dynamic_storage
+            .get()
+            .event()
+            .number_of_notifiers();
+        if number_of_notifiers != 0 {
+            self.had_notifier.store(true, Ordering::Relaxed);
+            return Ok(ListenerReceiveResult::NoEvents);
+        }
+
+        // the last notifier may have sent an event right before it disconnected
+        match fail!(from self, when self.listener.lock().try_wait_one(), "{}", msg) {
+            Some(event_id) => {
+                self.had_notifier.store(true, Ordering::Relaxed);
+                Ok(ListenerReceiveResult::Event(event_id))
+            }
+            None if self.had_notifier.load(Ordering::Relaxed) => {
+                Ok(ListenerReceiveResult::AllNotifiersGone)
+            }
+            None => Ok(ListenerReceiveResult::NoEvents),
+        }
     }
 
     /// Blocking wait for a new [`EventId`] until either an [`EventId`] was received or the timeout
@@ -292,6 +367,21 @@ impl<Service: service::Service> Listener<Service> {
     pub fn id(&self) -> UniqueListenerId {
         self.listener_id
     }
+
+    /// Returns the number of notifications that were dropped since the underlying buffer of
+    /// the [`Listener`] was full when a [`Notifier`](crate::port::notifier::Notifier) tried to
+    /// deliver it.
+    pub fn missed_notification_count(&self) -> u64 {
+        match self.dynamic_listener_handle {
+            Some(handle) => self
+                .service_state
+                .dynamic_storage
+                .get()
+                .event()
+                .missed_notifications(handle),
+            None => 0,
+        }
+    }
 }
 
 pub(crate) unsafe fn remove_connection_of_listener<Service: service::Service>(
@@ -18,6 +18,9 @@ use update_connections::ConnectionFailure;
 pub(crate) mod details;
 pub use details::data_segment::DataSegmentType;
 
+/// Bidirectional endpoint that combines a sending and a receiving endpoint of two
+/// publish-subscribe services
+pub mod channel;
 /// Sends requests to a [`Server`](crate::port::server::Server) and receives responses.
 pub mod client;
 /// Defines the event id used to identify the source of an event.
@@ -26,12 +29,25 @@ pub mod event_id;
 pub mod listener;
 /// Sending endpoint (port) for event based communication
 pub mod notifier;
+/// Sending endpoint (port) for publish-subscribe based communication that notifies the
+/// receivers about new data
+pub mod notifying_publisher;
+/// Receiving endpoint (port) for publish-subscribe based communication that can wait for
+/// notifications about new data
+pub mod notifying_subscriber;
 /// Defines port specific unique ids. Used to identify source/destination while communicating.
 pub mod port_identifiers;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
 /// Reading endpoint (port) for blackboard based communication
 pub mod reader;
+/// Defines a restricted predicate that is evaluated by the publisher before a sample is
+/// delivered to a subscriber.
+pub mod sample_filter;
+/// Sending endpoint (port) for publish-subscribe based communication of serialized types
+pub mod serde_publisher;
+/// Receiving endpoint (port) for publish-subscribe based communication of serialized types
+pub mod serde_subscriber;
 /// Receives requests from a [`Client`](crate::port::client::Client) port and sends back responses.
 pub mod server;
 /// Receiving endpoint (port) for publish-subscribe based communication
@@ -96,6 +112,17 @@ pub enum LoanError {
     InternalFailure,
 }
 
+impl LoanError {
+    /// Returns true when the failure is temporary and the loan may succeed later without
+    /// changing the port, for instance when the data segment is exhausted or all samples
+    /// are loaned and become available again once they are sent, dropped or released by the
+    /// receivers. Returns false when the request itself cannot be fulfilled, like
+    /// [`LoanError::ExceedsMaxLoanSize`].
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, LoanError::OutOfMemory | LoanError::ExceedsMaxLoans)
+    }
+}
+
 impl core::fmt::Display for LoanError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "LoanError::{self:?}")
@@ -104,6 +131,57 @@ impl core::fmt::Display for LoanError {
 
 impl core::error::Error for LoanError {}
 
+/// Failure that can be emitted when a [`SampleMut`](crate::sample_mut::SampleMut) is loaned
+/// with [`Publisher::loan_blocking()`](crate::port::publisher::Publisher::loan_blocking()) or
+/// [`Publisher::loan_timeout()`](crate::port::publisher::Publisher::loan_timeout()).
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum BlockingLoanError {
+    /// The loan failed with an error that does not vanish by waiting, see
+    /// [`LoanError::is_retryable()`].
+    LoanError(LoanError),
+    /// No sample was released in time.
+    Timeout,
+    /// A termination request (`SIGINT` or `SIGTERM`) was received while waiting. Only
+    /// reported when the [`Node`](crate::node::Node) handles termination requests, see
+    /// [`SignalHandlingMode`](crate::signal_handling_mode::SignalHandlingMode).
+    Interrupt,
+}
+
+impl From<LoanError> for BlockingLoanError {
+    fn from(value: LoanError) -> Self {
+        BlockingLoanError::LoanError(value)
+    }
+}
+
+impl core::fmt::Display for BlockingLoanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BlockingLoanError::{self:?}")
+    }
+}
+
+impl core::error::Error for BlockingLoanError {}
+
+/// Failure that can be emitted when a port waits for its counterparts with
+/// [`Publisher::wait_for_subscribers()`](crate::port::publisher::Publisher::wait_for_subscribers())
+/// or [`Subscriber::wait_for_publishers()`](crate::port::subscriber::Subscriber::wait_for_publishers()).
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum WaitForPortsError {
+    /// A termination request (`SIGINT` or `SIGTERM`) was received while waiting. Only
+    /// reported when the [`Node`](crate::node::Node) handles termination requests, see
+    /// [`SignalHandlingMode`](crate::signal_handling_mode::SignalHandlingMode).
+    Interrupt,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl core::fmt::Display for WaitForPortsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "WaitForPortsError::{self:?}")
+    }
+}
+
+impl core::error::Error for WaitForPortsError {}
+
 /// Failure that can be emitted when data is sent.
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub enum SendError {
@@ -115,6 +193,9 @@ pub enum SendError {
     LoanError(LoanError),
     /// A failure occurred while establishing a connection to the ports counterpart port.
     ConnectionError(ConnectionFailure),
+    /// Errors that indicate either an implementation issue or a wrongly configured system,
+    /// for instance when the delivery timeout could not be measured.
+    InternalFailure,
 }
 
 impl From<LoanError> for SendError {
@@ -137,6 +218,21 @@ impl core::fmt::Display for SendError {
 
 impl core::error::Error for SendError {}
 
+/// Describes the outcome of the delivery of data to a single receiver, see
+/// [`SampleMut::send_detailed()`](crate::sample_mut::SampleMut::send_detailed()).
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum DeliveryState {
+    /// The receiver received the data.
+    Delivered,
+    /// The data was not delivered since the buffer of the receiver was full.
+    DroppedDueToFullBuffer,
+    /// The data was not delivered since the connection to the receiver is corrupted.
+    ConnectionBroken,
+    /// The data was not delivered since it was rejected by the
+    /// [`SampleFilter`](crate::port::sample_filter::SampleFilter) of the receiver.
+    Filtered,
+}
+
 /// Defines the failure that can occur when receiving data with
 /// [`Subscriber::receive()`](crate::port::subscriber::Subscriber::receive()).
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -148,6 +244,16 @@ pub enum ReceiveError {
 
     /// Occurs when a receiver is unable to connect to a corresponding sender.
     ConnectionFailure(ConnectionFailure),
+
+    /// The [`Service`](crate::service::Service) was removed while the receiver was still alive,
+    /// for instance because its [`Node`](crate::node::Node) was considered dead and cleaned up
+    /// by another process. No more data can be received.
+    ServiceNoLongerExists,
+
+    /// Errors that indicate either an implementation issue or a wrongly configured system,
+    /// for instance when waiting for new data failed in
+    /// [`Subscriber::timed_receive()`](crate::port::subscriber::Subscriber::timed_receive()).
+    InternalFailure,
 }
 
 impl From<ConnectionFailure> for ReceiveError {
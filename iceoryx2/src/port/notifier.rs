@@ -403,6 +403,14 @@ impl<Service: service::Service> Notifier<Service> {
         self.notify_with_custom_event_id(self.default_event_id)
     }
 
+    /// Like [`Notifier::notify()`] but reports only whether at least one
+    /// [`crate::port::listener::Listener`] was notified. Returns `Ok(false)` when no
+    /// [`crate::port::listener::Listener`] is connected and the notification was skipped,
+    /// otherwise `Ok(true)`. A missing [`crate::port::listener::Listener`] is never a failure.
+    pub fn try_notify(&self) -> Result<bool, NotifierNotifyError> {
+        Ok(self.notify()? > 0)
+    }
+
     /// Returns the deadline of the corresponding [`Service`](crate::service::Service).
     pub fn deadline(&self) -> Option<Duration> {
         self.listener_connections
@@ -461,6 +469,16 @@ impl<Service: service::Service> Notifier<Service> {
                         Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
                             listener_connections.remove(i);
                         }
+                        Err(iceoryx2_cal::event::NotifierNotifyError::FailedToDeliverSignal) => {
+                            listener_connections
+                                .service_state
+                                .dynamic_storage
+                                .get()
+                                .event()
+                                .add_missed_notification(i);
+                            warn!(from self, "Unable to send notification via connection {:?} since the buffer of the listener is full.",
+                                    connection)
+                        }
                         Err(e) => {
                             warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
                                     connection, e)
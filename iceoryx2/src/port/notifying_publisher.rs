@@ -0,0 +1,182 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .notifying_publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//!
+//! // delivers the sample and notifies all listening subscribers
+//! publisher.send_copy(1234)?;
+//!
+//! let sample = publisher.publisher().loan_uninit()?;
+//! let sample = sample.write_payload(5678);
+//! publisher.send(sample)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use crate::port::notifier::{Notifier, NotifierCreateError, NotifierNotifyError};
+use crate::port::publisher::{Publisher, PublisherCreateError};
+use crate::port::SendError;
+use crate::sample_mut::SampleMut;
+use crate::service;
+
+/// Failures that can occur when a new [`NotifyingPublisher`] is created with the
+/// [`crate::service::port_factory::notifying_publisher::PortFactoryNotifyingPublisher`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum NotifyingPublisherCreateError {
+    /// The underlying [`Publisher`] could not be created.
+    PublisherCreateError(PublisherCreateError),
+    /// The underlying [`Notifier`] could not be created.
+    NotifierCreateError(NotifierCreateError),
+}
+
+impl From<PublisherCreateError> for NotifyingPublisherCreateError {
+    fn from(value: PublisherCreateError) -> Self {
+        NotifyingPublisherCreateError::PublisherCreateError(value)
+    }
+}
+
+impl From<NotifierCreateError> for NotifyingPublisherCreateError {
+    fn from(value: NotifierCreateError) -> Self {
+        NotifyingPublisherCreateError::NotifierCreateError(value)
+    }
+}
+
+impl core::fmt::Display for NotifyingPublisherCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NotifyingPublisherCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for NotifyingPublisherCreateError {}
+
+/// Failures that can occur when a [`NotifyingPublisher`] sends a
+/// [`crate::sample_mut::SampleMut`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum NotifyingSendError {
+    /// The [`crate::sample_mut::SampleMut`] could not be delivered.
+    SendError(SendError),
+    /// The [`crate::sample_mut::SampleMut`] was delivered but the
+    /// [`Listener`](crate::port::listener::Listener)s could not be notified.
+    NotifyError(NotifierNotifyError),
+}
+
+impl From<SendError> for NotifyingSendError {
+    fn from(value: SendError) -> Self {
+        NotifyingSendError::SendError(value)
+    }
+}
+
+impl From<NotifierNotifyError> for NotifyingSendError {
+    fn from(value: NotifierNotifyError) -> Self {
+        NotifyingSendError::NotifyError(value)
+    }
+}
+
+impl core::fmt::Display for NotifyingSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NotifyingSendError::{self:?}")
+    }
+}
+
+impl core::error::Error for NotifyingSendError {}
+
+/// Combines a [`Publisher`] with a [`Notifier`]. Whenever a
+/// [`crate::sample_mut::SampleMut`] is sent, the [`Notifier`] emits the
+/// [`EventId`](crate::port::event_id::EventId) of the
+/// [`crate::service::port_factory::notifying_publish_subscribe::PortFactory`] so that a
+/// [`NotifyingSubscriber`](crate::port::notifying_subscriber::NotifyingSubscriber) can wait
+/// for new data.
+#[derive(Debug)]
+pub struct NotifyingPublisher<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized + 'static,
+> {
+    publisher: Publisher<Service, Payload, ()>,
+    notifier: Notifier<Service>,
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend + ?Sized>
+    NotifyingPublisher<Service, Payload>
+{
+    pub(crate) fn new(
+        publisher: Publisher<Service, Payload, ()>,
+        notifier: Notifier<Service>,
+    ) -> Self {
+        Self {
+            publisher,
+            notifier,
+        }
+    }
+
+    /// Returns the underlying [`Publisher`]. It can be used to loan
+    /// [`crate::sample_mut::SampleMut`]s that are sent with [`NotifyingPublisher::send()`].
+    pub fn publisher(&self) -> &Publisher<Service, Payload, ()> {
+        &self.publisher
+    }
+
+    /// Returns the underlying [`Notifier`].
+    pub fn notifier(&self) -> &Notifier<Service> {
+        &self.notifier
+    }
+
+    /// Sends the [`crate::sample_mut::SampleMut`] and notifies all
+    /// [`Listener`](crate::port::listener::Listener)s afterwards. On success it returns the
+    /// number of [`crate::port::subscriber::Subscriber`]s that received the data, otherwise a
+    /// [`NotifyingSendError`] describing the failure.
+    pub fn send(
+        &self,
+        sample: SampleMut<Service, Payload, ()>,
+    ) -> Result<usize, NotifyingSendError> {
+        let number_of_recipients = fail!(from self, when sample.send(),
+            "Unable to send sample.");
+        self.notify()?;
+        Ok(number_of_recipients)
+    }
+
+    fn notify(&self) -> Result<(), NotifyingSendError> {
+        fail!(from self, when self.notifier.notify(),
+            "The sample was delivered but the listeners could not be notified.");
+        Ok(())
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend + Sized>
+    NotifyingPublisher<Service, Payload>
+{
+    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`], delivers it and
+    /// notifies all [`Listener`](crate::port::listener::Listener)s afterwards.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that
+    /// received the data, otherwise a [`NotifyingSendError`] describing the failure.
+    pub fn send_copy(&self, value: Payload) -> Result<usize, NotifyingSendError> {
+        let number_of_recipients = fail!(from self, when self.publisher.send_copy(value),
+            "Unable to send copy of payload.");
+        self.notify()?;
+        Ok(number_of_recipients)
+    }
+}
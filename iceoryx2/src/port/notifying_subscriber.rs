@@ -0,0 +1,188 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .notifying_publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! // waits until a publisher sent new data or the timeout has passed
+//! subscriber.timed_wait(Duration::from_millis(10))?;
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     println!("received: {:?}", *sample);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::time::Duration;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::event::{Event, ListenerWaitError};
+
+use crate::port::listener::{Listener, ListenerCreateError};
+use crate::port::subscriber::{Subscriber, SubscriberCreateError};
+use crate::port::ReceiveError;
+use crate::sample::Sample;
+use crate::service;
+
+/// Failures that can occur when a new [`NotifyingSubscriber`] is created with the
+/// [`crate::service::port_factory::notifying_subscriber::PortFactoryNotifyingSubscriber`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum NotifyingSubscriberCreateError {
+    /// The underlying [`Subscriber`] could not be created.
+    SubscriberCreateError(SubscriberCreateError),
+    /// The underlying [`Listener`] could not be created.
+    ListenerCreateError(ListenerCreateError),
+}
+
+impl From<SubscriberCreateError> for NotifyingSubscriberCreateError {
+    fn from(value: SubscriberCreateError) -> Self {
+        NotifyingSubscriberCreateError::SubscriberCreateError(value)
+    }
+}
+
+impl From<ListenerCreateError> for NotifyingSubscriberCreateError {
+    fn from(value: ListenerCreateError) -> Self {
+        NotifyingSubscriberCreateError::ListenerCreateError(value)
+    }
+}
+
+impl core::fmt::Display for NotifyingSubscriberCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NotifyingSubscriberCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for NotifyingSubscriberCreateError {}
+
+/// Combines a [`Subscriber`] with a [`Listener`]. The [`NotifyingSubscriber`] waits until a
+/// [`NotifyingPublisher`](crate::port::notifying_publisher::NotifyingPublisher) signals new
+/// data and the user drains the received samples afterwards with
+/// [`NotifyingSubscriber::receive()`]. It can also be attached to a
+/// [`WaitSet`](crate::waitset::WaitSet).
+#[derive(Debug)]
+pub struct NotifyingSubscriber<
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized + 'static,
+> {
+    subscriber: Subscriber<Service, Payload, ()>,
+    listener: Listener<Service>,
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend + ?Sized> FileDescriptorBased
+    for NotifyingSubscriber<Service, Payload>
+where
+    <Service::Event as Event>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend + ?Sized> SynchronousMultiplexing
+    for NotifyingSubscriber<Service, Payload>
+where
+    <Service::Event as Event>::Listener: SynchronousMultiplexing,
+{
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend + ?Sized>
+    NotifyingSubscriber<Service, Payload>
+{
+    pub(crate) fn new(
+        subscriber: Subscriber<Service, Payload, ()>,
+        listener: Listener<Service>,
+    ) -> Self {
+        Self {
+            subscriber,
+            listener,
+        }
+    }
+
+    /// Returns the underlying [`Subscriber`].
+    pub fn subscriber(&self) -> &Subscriber<Service, Payload, ()> {
+        &self.subscriber
+    }
+
+    /// Returns the underlying [`Listener`].
+    pub fn listener(&self) -> &Listener<Service> {
+        &self.listener
+    }
+
+    /// Non-blocking check for new notifications. Consumes all pending notifications and
+    /// returns true when at least one notification was received.
+    pub fn try_wait(&self) -> Result<bool, ListenerWaitError> {
+        let mut has_notification = false;
+        fail!(from self, when self.listener.try_wait_all(|_| has_notification = true),
+            "Failed to check for new notifications.");
+        Ok(has_notification)
+    }
+
+    /// Blocks until either a notification was received or the timeout has passed. Consumes all
+    /// pending notifications and returns true when at least one notification was received.
+    pub fn timed_wait(&self, timeout: Duration) -> Result<bool, ListenerWaitError> {
+        let mut has_notification = false;
+        fail!(from self, when self.listener.timed_wait_all(|_| has_notification = true, timeout),
+            "Failed to wait for new notifications with a timeout of {:?}.", timeout);
+        Ok(has_notification)
+    }
+
+    /// Blocks until a notification was received. Consumes all pending notifications and
+    /// returns true when at least one notification was received. Sporadic wakeups can occur,
+    /// in this case false is returned.
+    pub fn blocking_wait(&self) -> Result<bool, ListenerWaitError> {
+        let mut has_notification = false;
+        fail!(from self, when self.listener.blocking_wait_all(|_| has_notification = true),
+            "Failed to wait for new notifications.");
+        Ok(has_notification)
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend>
+    NotifyingSubscriber<Service, Payload>
+{
+    /// Receives a [`crate::sample::Sample`] from a
+    /// [`NotifyingPublisher`](crate::port::notifying_publisher::NotifyingPublisher). If no
+    /// sample could be received [`None`] is returned. If a failure occurs [`ReceiveError`] is
+    /// returned.
+    pub fn receive(&self) -> Result<Option<Sample<Service, Payload, ()>>, ReceiveError> {
+        self.subscriber.receive()
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend>
+    NotifyingSubscriber<Service, [Payload]>
+{
+    /// Receives a [`crate::sample::Sample`] from a
+    /// [`NotifyingPublisher`](crate::port::notifying_publisher::NotifyingPublisher). If no
+    /// sample could be received [`None`] is returned. If a failure occurs [`ReceiveError`] is
+    /// returned.
+    pub fn receive(&self) -> Result<Option<Sample<Service, [Payload], ()>>, ReceiveError> {
+        self.subscriber.receive()
+    }
+}
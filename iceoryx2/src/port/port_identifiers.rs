@@ -32,6 +32,12 @@ macro_rules! generate_id {
             }
         }
 
+        impl core::fmt::Display for $id_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
         impl $id_name {
             /// Creates a new instance
             pub fn new() -> Self {
@@ -101,29 +101,35 @@
 //! # }
 //! ```
 
+use super::details::channel_management::ChannelManagement;
 use super::details::data_segment::{DataSegment, DataSegmentType};
 use super::details::segment_state::SegmentState;
-use super::port_identifiers::UniquePublisherId;
-use super::{LoanError, SendError};
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use super::{BlockingLoanError, DeliveryState, LoanError, SendError, WaitForPortsError};
+use crate::port::details::port_wait::{termination_requested, wait_for_ports};
 use crate::port::details::sender::*;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
 use crate::prelude::UnableToDeliverStrategy;
 use crate::raw_sample::RawSampleMut;
+use crate::sample::Sample;
 use crate::sample_mut::SampleMut;
 use crate::sample_mut_uninit::SampleMutUninit;
 use crate::service::builder::CustomPayloadMarker;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::data_segment_name;
-use crate::service::port_factory::publisher::LocalPublisherConfig;
+use crate::service::port_factory::publisher::{LocalPublisherConfig, MemoryHint};
+use crate::service::stale_resource_cleanup::remove_dead_publishers;
 use crate::service::static_config::message_type_details::TypeVariant;
 use crate::service::static_config::publish_subscribe;
 use crate::service::{self, NoResource, ServiceState};
+use crate::signal_handling_mode::SignalHandlingMode;
 use alloc::sync::Arc;
 use core::any::TypeId;
 use core::cell::UnsafeCell;
 use core::fmt::Debug;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
 use core::{marker::PhantomData, mem::MaybeUninit};
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::cyclic_tagger::CyclicTagger;
@@ -131,6 +137,8 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::adaptive_wait::{AdaptiveWait, AdaptiveWaitBuilder};
+use iceoryx2_bb_posix::clock::Time;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
@@ -138,7 +146,7 @@ use iceoryx2_cal::shm_allocator::{AllocationStrategy, PointerOffset};
 use iceoryx2_cal::zero_copy_connection::{
     ChannelId, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopySender,
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
 
 /// Defines a failure that can occur when a [`Publisher`] is created with
 /// [`crate::service::port_factory::publisher::PortFactoryPublisher`].
@@ -154,6 +162,14 @@ pub enum PublisherCreateError {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The history depth requested with
+    /// [`PortFactoryPublisher::history()`](crate::service::port_factory::publisher::PortFactoryPublisher::history())
+    /// is larger than the history size of the [`Service`](crate::service::Service).
+    HistoryExceedsHistorySizeOfService,
+    /// The data segment that is required to store all samples of the [`Publisher`] exceeds the
+    /// maximum supported size, for instance when a too large value was requested with
+    /// [`PortFactoryPublisher::max_loaned_samples()`](crate::service::port_factory::publisher::PortFactoryPublisher::max_loaned_samples()).
+    ExceedsMaxDataSegmentSize,
 }
 
 impl core::fmt::Display for PublisherCreateError {
@@ -164,10 +180,78 @@ impl core::fmt::Display for PublisherCreateError {
 
 impl core::error::Error for PublisherCreateError {}
 
+/// Lists the [`DeliveryState`] of a sent [`SampleMut`] for every connected
+/// [`Subscriber`](crate::port::subscriber::Subscriber), see
+/// [`SampleMut::send_detailed()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryReport {
+    deliveries: Vec<(UniqueSubscriberId, DeliveryState)>,
+}
+
+impl DeliveryReport {
+    /// Returns the [`UniqueSubscriberId`] and the [`DeliveryState`] of every connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub fn deliveries(&self) -> &[(UniqueSubscriberId, DeliveryState)] {
+        &self.deliveries
+    }
+
+    /// Returns the [`DeliveryState`] of a specific
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) or [`None`] when it was not
+    /// connected while the [`SampleMut`] was sent.
+    pub fn state_of(&self, subscriber_id: &UniqueSubscriberId) -> Option<DeliveryState> {
+        self.deliveries
+            .iter()
+            .find(|(id, _)| id == subscriber_id)
+            .map(|(_, state)| *state)
+    }
+
+    /// Returns the number of [`Subscriber`](crate::port::subscriber::Subscriber)s that
+    /// received the [`SampleMut`].
+    pub fn number_of_recipients(&self) -> usize {
+        self.deliveries
+            .iter()
+            .filter(|(_, state)| *state == DeliveryState::Delivered)
+            .count()
+    }
+}
+
+/// The number of [`SampleMut`]s a [`Publisher`] dropped since the buffer of a connected
+/// [`Subscriber`](crate::port::subscriber::Subscriber) was full, see
+/// [`Publisher::dropped_samples()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DroppedSamples {
+    per_subscriber: Vec<(UniqueSubscriberId, u64)>,
+}
+
+impl DroppedSamples {
+    /// Returns the total number of dropped [`SampleMut`]s of all connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s.
+    pub fn total(&self) -> u64 {
+        self.per_subscriber.iter().map(|(_, n)| n).sum()
+    }
+
+    /// Returns the [`UniqueSubscriberId`] and the number of dropped [`SampleMut`]s of every
+    /// connected [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub fn per_subscriber(&self) -> &[(UniqueSubscriberId, u64)] {
+        &self.per_subscriber
+    }
+
+    /// Returns the number of dropped [`SampleMut`]s of a specific
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) or [`None`] when it is not
+    /// connected.
+    pub fn of(&self, subscriber_id: &UniqueSubscriberId) -> Option<u64> {
+        self.per_subscriber
+            .iter()
+            .find(|(id, _)| id == subscriber_id)
+            .map(|(_, n)| *n)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct OffsetAndSize {
     offset: u64,
     size: usize,
+    header_address: usize,
 }
 
 #[derive(Debug)]
@@ -177,10 +261,35 @@ pub(crate) struct PublisherSharedState<Service: service::Service> {
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
     history: Option<UnsafeCell<Queue<OffsetAndSize>>>,
     is_active: IoxAtomicBool,
+    sequence_number: IoxAtomicU64,
+    enable_timestamps: bool,
+    memory_hint_applied: bool,
 }
 
 impl<Service: service::Service> PublisherSharedState<Service> {
-    fn add_sample_to_history(&self, offset: PointerOffset, sample_size: usize) {
+    // # SAFETY: the header must point to a valid header of a sample that is borrowed by the
+    //          publisher
+    unsafe fn sample_content(&self, header: *const Header) -> SampleContent<'_> {
+        let type_details = &self.sender.message_type_details;
+        let user_header = type_details.user_header_ptr_from_header(header as *const u8);
+        let payload = type_details.payload_ptr_from_header(header as *const u8);
+        let number_of_elements = (*header).number_of_elements() as usize;
+
+        SampleContent {
+            user_header: core::slice::from_raw_parts(user_header, type_details.user_header.size),
+            payload: core::slice::from_raw_parts(
+                payload,
+                type_details.payload.size * number_of_elements,
+            ),
+        }
+    }
+
+    fn add_sample_to_history(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        header: *const Header,
+    ) {
         match &self.history {
             None => (),
             Some(history) => {
@@ -189,6 +298,7 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                 match history.push_with_overflow(OffsetAndSize {
                     offset: offset.as_value(),
                     size: sample_size,
+                    header_address: header as usize,
                 }) {
                     None => (),
                     Some(old) => self
@@ -209,6 +319,9 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                     ReceiverDetails {
                         port_id: port.subscriber_id.value(),
                         buffer_size: port.buffer_size,
+                        filter: port.filter,
+                        history_request: port.history_request,
+                        keep_latest: port.keep_latest,
                     },
                     |connection| self.deliver_sample_history(connection),
                 );
@@ -245,14 +358,25 @@ impl<Service: service::Service> PublisherSharedState<Service> {
 
     fn deliver_sample_history(&self, connection: &Connection<Service>) {
         match &self.history {
-            None => (),
+            // without a history there is nothing to deliver, the history is completed
+            None => {
+                connection.sender.set_channel_state(ChannelId::new(0), 0);
+            }
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
                 let buffer_size = connection.sender.buffer_size();
-                let history_start = history.len().saturating_sub(buffer_size);
+                let history_start = history.len().saturating_sub(
+                    buffer_size.min(connection.history_request.max(self.config.retention)),
+                );
 
                 for i in history_start..history.len() {
                     let old_sample = unsafe { history.get_unchecked(i) };
+                    // # SAFETY: the history borrows the sample, therefore the header stays valid
+                    if !connection.accepts(Some(unsafe {
+                        self.sample_content(old_sample.header_address as *const Header)
+                    })) {
+                        continue;
+                    }
                     self.sender.retrieve_returned_samples();
 
                     let offset = PointerOffset::from_value(old_sample.offset);
@@ -272,15 +396,61 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                         }
                     }
                 }
+
+                // the subscriber has received its whole history as soon as it received the
+                // sample with the sequence number preceding the stored one
+                let history_end = match history_start < history.len() {
+                    true => {
+                        let last_sample = unsafe { history.get_unchecked(history.len() - 1) };
+                        let header = unsafe { &*(last_sample.header_address as *const Header) };
+                        header.sequence_number() + 1
+                    }
+                    false => 0,
+                };
+                connection
+                    .sender
+                    .set_channel_state(ChannelId::new(0), history_end);
             }
         }
     }
 
     pub(crate) fn send_sample(
         &self,
+        header: &mut Header,
         offset: PointerOffset,
         sample_size: usize,
+        timeout: Option<Duration>,
     ) -> Result<usize, SendError> {
+        let mut number_of_recipients = 0;
+        self.send_sample_with_callback(header, offset, sample_size, timeout, |_, state| {
+            if state == DeliveryState::Delivered {
+                number_of_recipients += 1;
+            }
+        })?;
+        Ok(number_of_recipients)
+    }
+
+    pub(crate) fn send_sample_with_report(
+        &self,
+        header: &mut Header,
+        offset: PointerOffset,
+        sample_size: usize,
+    ) -> Result<DeliveryReport, SendError> {
+        let mut deliveries = vec![];
+        self.send_sample_with_callback(header, offset, sample_size, None, |port_id, state| {
+            deliveries.push((UniqueSubscriberId(UniqueSystemId::from(port_id)), state))
+        })?;
+        Ok(DeliveryReport { deliveries })
+    }
+
+    fn send_sample_with_callback<F: FnMut(u128, DeliveryState)>(
+        &self,
+        header: &mut Header,
+        offset: PointerOffset,
+        sample_size: usize,
+        timeout: Option<Duration>,
+        callback: F,
+    ) -> Result<(), SendError> {
         let msg = "Unable to send sample";
         if !self.is_active.load(Ordering::Relaxed) {
             fail!(from self, with SendError::ConnectionBrokenSinceSenderNoLongerExists,
@@ -290,9 +460,27 @@ impl<Service: service::Service> PublisherSharedState<Service> {
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
-        self.add_sample_to_history(offset, sample_size);
-        self.sender
-            .deliver_offset(offset, sample_size, ChannelId::new(0))
+        header.set_sequence_number(self.sequence_number.fetch_add(1, Ordering::Relaxed));
+        if self.enable_timestamps {
+            match Time::now() {
+                Ok(now) => header.set_timestamp(now.as_duration()),
+                Err(e) => {
+                    warn!(from self, "The sample is sent without timestamp since the current time could not be acquired ({:?}).", e)
+                }
+            }
+        }
+        let header: *const Header = header;
+        self.add_sample_to_history(offset, sample_size, header);
+        // # SAFETY: the header belongs to the loaned sample that is currently sent
+        let content = Some(unsafe { self.sample_content(header) });
+        self.sender.deliver_offset_with_callback(
+            offset,
+            sample_size,
+            ChannelId::new(0),
+            content,
+            timeout,
+            callback,
+        )
     }
 }
 
@@ -346,7 +534,11 @@ impl<
                 .dynamic_storage
                 .get()
                 .publish_subscribe()
-                .release_publisher_handle(handle)
+                .release_publisher_handle(handle);
+
+            if let Some(bridge) = &shared_state.sender.service_state.connection_bridge {
+                bridge.port_removed(bridge.event_ids().publisher_disconnected);
+            }
         }
     }
 }
@@ -371,9 +563,27 @@ impl<
             .publish_subscribe()
             .subscribers;
 
+        let history_size = match config.history {
+            Some(history_size) => {
+                if static_config.history_size < history_size {
+                    fail!(from origin, with PublisherCreateError::HistoryExceedsHistorySizeOfService,
+                        "{} since the requested history depth of {} exceeds the history size of {} of the service.",
+                        msg, history_size, static_config.history_size);
+                }
+                history_size
+            }
+            None => static_config.history_size,
+        };
+        let history_size = history_size.max(config.retention);
+
+        // retained samples that exceed the history of the service require additional space
         let number_of_samples =
             unsafe { service.static_config.messaging_pattern.publish_subscribe() }
-                .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
+                .required_amount_of_samples_per_data_segment(0)
+                .checked_add(config.max_loaned_samples)
+                .and_then(|v| {
+                    v.checked_add(config.retention.saturating_sub(static_config.history_size))
+                });
 
         let data_segment_type =
             DataSegmentType::new_from_allocation_strategy(config.allocation_strategy);
@@ -382,6 +592,23 @@ impl<
             .message_type_details
             .sample_layout(config.initial_max_slice_len);
 
+        // the data segment must be able to store every sample, otherwise the publisher would
+        // fail later on when the samples are loaned
+        let number_of_samples = match number_of_samples.filter(|number_of_samples| {
+            sample_layout
+                .size()
+                .checked_mul(*number_of_samples)
+                .and_then(|size| size.checked_add(sample_layout.align() - 1))
+                .is_some_and(|size| size <= isize::MAX as usize)
+        }) {
+            Some(number_of_samples) => number_of_samples,
+            None => {
+                fail!(from origin, with PublisherCreateError::ExceedsMaxDataSegmentSize,
+                    "{} since a data segment that can store {} loaned and {} retained samples would exceed the maximum supported size.",
+                    msg, config.max_loaned_samples, config.retention);
+            }
+        };
+
         let max_slice_len = config.initial_max_slice_len;
         let max_number_of_segments =
             DataSegment::<Service>::max_number_of_segments(data_segment_type);
@@ -389,9 +616,11 @@ impl<
             data_segment_type,
             publisher_id: port_id,
             number_of_samples,
+            max_loaned_samples: config.max_loaned_samples,
             max_slice_len,
             node_id: *service.shared_node.id(),
             max_number_of_segments,
+            unable_to_deliver_strategy: config.unable_to_deliver_strategy,
         };
         let global_config = service.shared_node.config();
 
@@ -417,9 +646,25 @@ impl<
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
 
+        let memory_hint_applied = match config.memory_hint {
+            MemoryHint::Default => false,
+            MemoryHint::HugePages => {
+                let is_applied = data_segment.request_huge_pages();
+                if !is_applied {
+                    warn!(from origin,
+                        "The memory hint {:?} could not be applied, the data segment is backed by regular pages.",
+                        config.memory_hint);
+                }
+                is_applied
+            }
+        };
+
         let publisher_shared_state =
             <Service as service::Service>::ArcThreadSafetyPolicy::new(PublisherSharedState {
                 is_active: IoxAtomicBool::new(true),
+                sequence_number: IoxAtomicU64::new(0),
+                enable_timestamps: static_config.enable_timestamps,
+                memory_hint_applied,
                 sender: Sender {
                     data_segment,
                     segment_states: {
@@ -448,12 +693,13 @@ impl<
                     unable_to_deliver_strategy: config.unable_to_deliver_strategy,
                     message_type_details: static_config.message_type_details.clone(),
                     number_of_channels: 1,
+                    notify_receivers: static_config.enable_notifications,
                 },
                 config,
                 subscriber_list_state: UnsafeCell::new(unsafe { subscriber_list.get_state() }),
-                history: match static_config.history_size == 0 {
+                history: match history_size == 0 {
                     true => None,
-                    false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
+                    false => Some(UnsafeCell::new(Queue::new(history_size))),
                 },
             });
 
@@ -486,12 +732,16 @@ impl<
 
         // !MUST! be the last task otherwise a publisher is added to the dynamic config without the
         // creation of all required resources
-        let dynamic_publisher_handle = match service
-            .dynamic_storage
-            .get()
-            .publish_subscribe()
-            .add_publisher_id(publisher_details)
+        let dynamic_config = service.dynamic_storage.get().publish_subscribe();
+        let mut dynamic_publisher_handle = dynamic_config.add_publisher_id(publisher_details);
+        // publishers of crashed processes occupy their slot until their node is cleaned up
+        if dynamic_publisher_handle.is_none()
+            && remove_dead_publishers::<Service>(dynamic_config, service.shared_node.config()) > 0
         {
+            dynamic_publisher_handle = dynamic_config.add_publisher_id(publisher_details);
+        }
+
+        let dynamic_publisher_handle = match dynamic_publisher_handle {
             Some(unique_index) => unique_index,
             None => {
                 fail!(from origin, with PublisherCreateError::ExceedsMaxSupportedPublishers,
@@ -502,9 +752,20 @@ impl<
 
         new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
 
+        if let Some(bridge) = &service.connection_bridge {
+            bridge.port_added(&service.shared_node, bridge.event_ids().publisher_connected);
+        }
+
         Ok(new_self)
     }
 
+    /// Returns true when the [`MemoryHint`] that was defined with
+    /// [`PortFactoryPublisher::memory_hint()`](crate::service::port_factory::publisher::PortFactoryPublisher::memory_hint())
+    /// was applied to the data segment, otherwise false.
+    pub fn memory_hint_applied(&self) -> bool {
+        self.publisher_shared_state.lock().memory_hint_applied
+    }
+
     /// Returns the [`UniquePublisherId`] of the [`Publisher`]
     pub fn id(&self) -> UniquePublisherId {
         UniquePublisherId(UniqueSystemId::from(
@@ -520,6 +781,211 @@ impl<
             .sender
             .unable_to_deliver_strategy
     }
+
+    /// Returns the maximum number of [`SampleMut`]s the [`Publisher`] can loan in parallel.
+    pub fn max_loaned_samples(&self) -> usize {
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .sender_max_borrowed_samples
+    }
+
+    /// Returns the number of [`SampleMut`]s that are currently loaned from the [`Publisher`]
+    /// and neither sent nor dropped.
+    pub fn currently_loaned_samples(&self) -> usize {
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .loan_counter
+            .load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of [`SampleMut`]s that were dropped since the buffer of a connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) was full. It contains the
+    /// [`SampleMut`]s that were discarded with [`UnableToDeliverStrategy::DiscardSample`] as
+    /// well as the ones that replaced an older sample on overflow. Only the connections to
+    /// the currently connected [`Subscriber`](crate::port::subscriber::Subscriber)s are
+    /// considered.
+    pub fn dropped_samples(&self) -> DroppedSamples {
+        let mut per_subscriber = vec![];
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .for_each_dropped_samples_counter(|port_id, number_of_dropped_samples| {
+                per_subscriber.push((
+                    UniqueSubscriberId(UniqueSystemId::from(port_id)),
+                    number_of_dropped_samples,
+                ))
+            });
+        DroppedSamples { per_subscriber }
+    }
+
+    /// Resets the counters of [`Publisher::dropped_samples()`] to zero. The counters of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s are not affected.
+    pub fn reset_dropped_samples(&self) {
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .reset_dropped_samples_counters()
+    }
+
+    /// Returns the number of samples the [`Publisher`] retains and delivers to late joining
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s.
+    pub fn history_depth(&self) -> usize {
+        match &self.publisher_shared_state.lock().history {
+            None => 0,
+            Some(history) => unsafe { &*history.get() }.capacity(),
+        }
+    }
+
+    /// Executes a full memory barrier so that all writes into the memory of previously sent
+    /// [`SampleMut`]s are completed before any subsequent operation, for instance the
+    /// notification of a [`Listener`](crate::port::listener::Listener). It provides an explicit
+    /// synchronization point for architectures where the shared memory is not cache coherent.
+    /// For the shared memory used by the provided [`Service`](crate::service::Service)s it is
+    /// sufficient and never fails, since the memory is cache coherent and the send operation
+    /// already synchronizes with the receiving side.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// publisher.send_copy(1234)?;
+    /// publisher.flush()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flush(&self) -> Result<(), SendError> {
+        core::sync::atomic::fence(Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Blocks until at least `count` [`Subscriber`](crate::port::subscriber::Subscriber)s are
+    /// connected to the [`Service`](crate::service::Service) or the timeout has passed, for
+    /// instance to avoid that the first samples are sent before any
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) is connected. Returns the number of
+    /// connected [`Subscriber`](crate::port::subscriber::Subscriber)s, which is less than
+    /// `count` when the timeout has passed. When the [`Node`](crate::node::Node) handles
+    /// termination requests it returns [`WaitForPortsError::Interrupt`] as soon as `SIGINT` or
+    /// `SIGTERM` was received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    /// # let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// if publisher.wait_for_subscribers(1, Duration::from_secs(1))? >= 1 {
+    ///     publisher.send_copy(1234)?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_subscribers(
+        &self,
+        count: usize,
+        timeout: Duration,
+    ) -> Result<usize, WaitForPortsError> {
+        let service_state = self
+            .publisher_shared_state
+            .lock()
+            .sender
+            .service_state
+            .clone();
+        wait_for_ports(self, self.signal_handling_mode(), count, timeout, || {
+            service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .number_of_subscribers()
+        })
+    }
+
+    fn signal_handling_mode(&self) -> SignalHandlingMode {
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .service_state
+            .shared_node
+            .signal_handling_mode()
+    }
+
+    // Retries the loan until a previously sent sample was released by the subscribers. The
+    // subscribers do not notify the publisher when they release a sample, therefore it polls
+    // in a less busy wait.
+    fn wait_for_loan<T, F: FnMut() -> Result<T, LoanError>>(
+        &self,
+        timeout: Option<Duration>,
+        mut loan: F,
+    ) -> Result<T, BlockingLoanError> {
+        let msg = "Unable to wait for a sample to loan";
+        let start = fail!(from self, when Time::now(),
+                with BlockingLoanError::LoanError(LoanError::InternalFailure),
+                "{} since the current time could not be acquired.", msg);
+        let signal_handling_mode = self.signal_handling_mode();
+        let mut adaptive_wait: Option<AdaptiveWait> = None;
+
+        loop {
+            match loan() {
+                Ok(sample) => return Ok(sample),
+                Err(e) if !e.is_retryable() => {
+                    fail!(from self, with BlockingLoanError::LoanError(e),
+                        "{} since the loan failed with a non-retryable error ({:?}).", msg, e);
+                }
+                Err(_) => (),
+            }
+
+            if termination_requested(signal_handling_mode) {
+                fail!(from self, with BlockingLoanError::Interrupt,
+                    "{} since a termination request was received.", msg);
+            }
+
+            if let Some(timeout) = timeout {
+                let elapsed = fail!(from self, when start.elapsed(),
+                        with BlockingLoanError::LoanError(LoanError::InternalFailure),
+                        "{} since the elapsed time could not be acquired.", msg);
+                if elapsed >= timeout {
+                    fail!(from self, with BlockingLoanError::Timeout,
+                        "{} since no sample was released within the timeout of {:?}.", msg, timeout);
+                }
+            }
+
+            let adaptive_wait = match adaptive_wait {
+                Some(ref mut adaptive_wait) => adaptive_wait,
+                None => {
+                    adaptive_wait.insert(fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                        with BlockingLoanError::LoanError(LoanError::InternalFailure),
+                        "{} since the adaptive wait could not be created.", msg))
+                }
+            };
+
+            fail!(from self, when adaptive_wait.wait(),
+                with BlockingLoanError::LoanError(LoanError::InternalFailure),
+                "{} since the adaptive wait failed.", msg);
+        }
+    }
 }
 
 ////////////////////////
@@ -611,6 +1077,61 @@ impl<
             ),
         )
     }
+
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] from the underlying data segment of the
+    /// [`Publisher`] and initializes its payload and user header with a copy of the provided
+    /// [`Sample`], for instance to republish a received [`Sample`] with some modifications.
+    /// The payload is copied with a single memcpy.
+    ///
+    /// The loaned [`crate::sample_mut::SampleMut`] is independent of the [`Sample`], both can be
+    /// dropped in any order and the [`Sample`] is not returned to its
+    /// [`crate::port::subscriber::Subscriber`] until it is dropped.
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let input = node.service_builder(&"My/Input".try_into()?)
+    /// #     .publish_subscribe::<[u64; 4]>()
+    /// #     .open_or_create()?;
+    /// # let output = node.service_builder(&"My/Output".try_into()?)
+    /// #     .publish_subscribe::<[u64; 4]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let subscriber = input.subscriber_builder().create()?;
+    /// # let publisher = output.publisher_builder().create()?;
+    /// # input.publisher_builder().create()?.send_copy([1, 2, 3, 4])?;
+    ///
+    /// if let Some(received) = subscriber.receive()? {
+    ///     let mut sample = publisher.loan_from(&received)?;
+    ///     sample.payload_mut()[0] = 42;
+    ///     sample.send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_from(
+        &self,
+        sample: &Sample<Service, Payload, UserHeader>,
+    ) -> Result<SampleMut<Service, Payload, UserHeader>, LoanError> {
+        let mut new_sample = self.loan_uninit()?;
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(sample.user_header(), new_sample.user_header_mut(), 1);
+            core::ptr::copy_nonoverlapping(
+                sample.payload(),
+                new_sample.payload_mut().as_mut_ptr(),
+                1,
+            );
+            Ok(new_sample.assume_init())
+        }
+    }
 }
 
 impl<
@@ -649,6 +1170,76 @@ impl<
     pub fn loan(&self) -> Result<SampleMut<Service, Payload, UserHeader>, LoanError> {
         Ok(self.loan_uninit()?.write_payload(Payload::default()))
     }
+
+    /// Like [`Publisher::loan()`] but when all samples are loaned or the data segment is
+    /// exhausted it blocks until a previously sent sample was released by the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s. When the [`Node`](crate::node::Node)
+    /// handles termination requests it returns [`BlockingLoanError::Interrupt`] as soon as
+    /// `SIGINT` or `SIGTERM` was received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan_blocking()?;
+    /// *sample.payload_mut() = 42;
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_blocking(
+        &self,
+    ) -> Result<SampleMut<Service, Payload, UserHeader>, BlockingLoanError> {
+        self.wait_for_loan(None, || self.loan())
+    }
+
+    /// Like [`Publisher::loan_blocking()`] but waits at most for the provided timeout. When no
+    /// sample was released in time it returns [`BlockingLoanError::Timeout`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::BlockingLoanError;
+    /// use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// match publisher.loan_timeout(Duration::from_millis(100)) {
+    ///     Ok(sample) => {
+    ///         sample.send()?;
+    ///     }
+    ///     Err(BlockingLoanError::Timeout) => println!("subscribers are too slow"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<SampleMut<Service, Payload, UserHeader>, BlockingLoanError> {
+        self.wait_for_loan(Some(timeout), || self.loan())
+    }
 }
 ////////////////////////
 // END: typed API
@@ -754,6 +1345,62 @@ impl<
         unsafe { self.loan_slice_uninit_impl(slice_len, slice_len) }
     }
 
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] with the slice length of the provided
+    /// [`Sample`] from the underlying data segment of the [`Publisher`] and initializes its
+    /// payload and user header with a copy of the [`Sample`]. The payload is copied with a
+    /// single memcpy. The loaned [`crate::sample_mut::SampleMut`] is independent of the
+    /// [`Sample`], both can be dropped in any order.
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let input = node.service_builder(&"My/Input".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// # let output = node.service_builder(&"My/Output".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let subscriber = input.subscriber_builder().create()?;
+    /// # let publisher = output.publisher_builder()
+    /// #                       .initial_max_slice_len(16)
+    /// #                       .create()?;
+    /// # input.publisher_builder().initial_max_slice_len(16).create()?
+    /// #      .loan_slice_uninit(3)?.write_from_slice(&[1, 2, 3]).send()?;
+    ///
+    /// if let Some(received) = subscriber.receive()? {
+    ///     let mut sample = publisher.loan_slice_from(&received)?;
+    ///     sample.payload_mut()[0] = 42;
+    ///     sample.send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_slice_from(
+        &self,
+        sample: &Sample<Service, [Payload], UserHeader>,
+    ) -> Result<SampleMut<Service, [Payload], UserHeader>, LoanError> {
+        let slice_len = sample.payload().len();
+        let mut new_sample = self.loan_slice_uninit(slice_len)?;
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(sample.user_header(), new_sample.user_header_mut(), 1);
+            core::ptr::copy_nonoverlapping(
+                sample.payload().as_ptr(),
+                new_sample.payload_mut().as_mut_ptr().cast(),
+                slice_len,
+            );
+            Ok(new_sample.assume_init())
+        }
+    }
+
     unsafe fn loan_slice_uninit_impl(
         &self,
         slice_len: usize,
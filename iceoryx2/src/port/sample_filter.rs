@@ -0,0 +1,177 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::sample_filter::SampleFilter;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u8>()
+//!     .open_or_create()?;
+//!
+//! // receives only samples where the payload is 42
+//! let subscriber = service.subscriber_builder()
+//!     .filter(SampleFilter::offset_equals(0, &[42])?)
+//!     .create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//! publisher.send_copy(42)?;
+//! publisher.send_copy(43)?;
+//!
+//! assert_eq!(*subscriber.receive()?.unwrap(), 42);
+//! assert!(subscriber.receive()?.is_none());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_log::fail;
+
+/// Failures that can occur when a [`SampleFilter`] is created.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SampleFilterCreateError {
+    /// The compared bytes are either empty or exceed [`SampleFilter::MAX_LENGTH`].
+    InvalidLength,
+    /// The mask and the compared bytes do not have the same length.
+    MaskLengthMismatch,
+}
+
+impl core::fmt::Display for SampleFilterCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SampleFilterCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for SampleFilterCreateError {}
+
+/// Defines on which part of the sample a [`SampleFilter`] is evaluated.
+#[repr(C)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SampleFilterTarget {
+    /// The offset is relative to the start of the user header.
+    UserHeader,
+    /// The offset is relative to the start of the payload.
+    Payload,
+}
+
+/// A restricted predicate a [`Subscriber`](crate::port::subscriber::Subscriber) can register
+/// with [`PortFactorySubscriber::filter()`](crate::service::port_factory::subscriber::PortFactorySubscriber::filter()).
+/// It is stored in the dynamic config of the service and evaluated by the
+/// [`Publisher`](crate::port::publisher::Publisher) before a sample is delivered. Samples
+/// that do not match are never delivered to the
+/// [`Subscriber`](crate::port::subscriber::Subscriber).
+///
+/// A sample matches when for every compared byte `(data[offset + i] & mask[i]) ==
+/// (value[i] & mask[i])` holds. When the compared range lies outside of the sample, for
+/// instance for a too short slice, the sample does not match.
+#[repr(C)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct SampleFilter {
+    target: SampleFilterTarget,
+    offset: usize,
+    length: usize,
+    mask: [u8; SampleFilter::MAX_LENGTH],
+    value: [u8; SampleFilter::MAX_LENGTH],
+}
+
+impl SampleFilter {
+    /// The maximum number of bytes a [`SampleFilter`] can compare.
+    pub const MAX_LENGTH: usize = 32;
+
+    /// Creates a [`SampleFilter`] that matches when the payload bytes starting at `offset`
+    /// are equal to `bytes`.
+    pub fn offset_equals(offset: usize, bytes: &[u8]) -> Result<Self, SampleFilterCreateError> {
+        let mask = [u8::MAX; Self::MAX_LENGTH];
+        Self::masked_offset_equals(offset, bytes, &mask[..bytes.len().min(Self::MAX_LENGTH)])
+    }
+
+    /// Creates a [`SampleFilter`] that matches when the payload bytes starting at `offset`
+    /// are equal to `bytes` after both were combined with `mask` by a bitwise and.
+    pub fn masked_offset_equals(
+        offset: usize,
+        bytes: &[u8],
+        mask: &[u8],
+    ) -> Result<Self, SampleFilterCreateError> {
+        let msg = "Unable to create sample filter";
+        if bytes.is_empty() || Self::MAX_LENGTH < bytes.len() {
+            fail!(from "SampleFilter::masked_offset_equals()", with SampleFilterCreateError::InvalidLength,
+                "{} since the number of compared bytes {} must be in the range of [1, {}].",
+                msg, bytes.len(), Self::MAX_LENGTH);
+        }
+
+        if bytes.len() != mask.len() {
+            fail!(from "SampleFilter::masked_offset_equals()", with SampleFilterCreateError::MaskLengthMismatch,
+                "{} since the mask length {} differs from the number of compared bytes {}.",
+                msg, mask.len(), bytes.len());
+        }
+
+        let mut new_self = Self {
+            target: SampleFilterTarget::Payload,
+            offset,
+            length: bytes.len(),
+            mask: [0; Self::MAX_LENGTH],
+            value: [0; Self::MAX_LENGTH],
+        };
+        new_self.mask[..mask.len()].copy_from_slice(mask);
+        new_self.value[..bytes.len()].copy_from_slice(bytes);
+
+        Ok(new_self)
+    }
+
+    /// Evaluates the [`SampleFilter`] on the user header instead of the payload.
+    pub fn on_user_header(mut self) -> Self {
+        self.target = SampleFilterTarget::UserHeader;
+        self
+    }
+
+    /// Returns the [`SampleFilterTarget`] the [`SampleFilter`] is evaluated on.
+    pub fn target(&self) -> SampleFilterTarget {
+        self.target
+    }
+
+    /// Returns the offset of the first compared byte.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of compared bytes.
+    pub fn number_of_bytes(&self) -> usize {
+        self.length
+    }
+
+    /// Returns the end of the compared range, relative to the start of the
+    /// [`SampleFilterTarget`].
+    pub(crate) fn end(&self) -> usize {
+        self.offset.saturating_add(self.length)
+    }
+
+    /// Returns true when the provided user header and payload match the [`SampleFilter`].
+    pub fn matches(&self, user_header: &[u8], payload: &[u8]) -> bool {
+        let data = match self.target {
+            SampleFilterTarget::UserHeader => user_header,
+            SampleFilterTarget::Payload => payload,
+        };
+
+        if data.len() < self.end() {
+            return false;
+        }
+
+        data[self.offset..self.end()]
+            .iter()
+            .zip(self.mask.iter().zip(self.value.iter()))
+            .all(|(data, (mask, value))| data & mask == value & mask)
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serde::<Vec<String>>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//!
+//! // serializes the value into a sample and delivers it
+//! publisher.send_serialized(&vec!["hello".to_string(), "world".to_string()])?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+
+use crate::port::publisher::Publisher;
+use crate::port::{LoanError, SendError};
+use crate::service;
+use crate::service::builder::publish_subscribe_serde::SerializationFormat;
+
+/// Failures that can occur when a [`SerdePublisher`] sends a value with
+/// [`SerdePublisher::send_serialized()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SerdeSendError {
+    /// The value could not be serialized with the [`SerializationFormat`].
+    SerializationFailed,
+    /// The serialized value could not be delivered.
+    SendError(SendError),
+}
+
+impl From<SendError> for SerdeSendError {
+    fn from(value: SendError) -> Self {
+        SerdeSendError::SendError(value)
+    }
+}
+
+impl From<LoanError> for SerdeSendError {
+    fn from(value: LoanError) -> Self {
+        SerdeSendError::SendError(SendError::LoanError(value))
+    }
+}
+
+impl core::fmt::Display for SerdeSendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SerdeSendError::{self:?}")
+    }
+}
+
+impl core::error::Error for SerdeSendError {}
+
+/// Sends values of a type `T` that is not shared-memory compatible. Every value is
+/// serialized with the [`SerializationFormat`] into a byte slice sample of the underlying
+/// [`Publisher`].
+pub struct SerdePublisher<
+    Service: service::Service,
+    T: serde::Serialize + serde::de::DeserializeOwned,
+    Format: SerializationFormat,
+> {
+    publisher: Publisher<Service, [u8], ()>,
+    _data: PhantomData<fn() -> (T, Format)>,
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Format: SerializationFormat,
+    > Debug for SerdePublisher<Service, T, Format>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SerdePublisher<{}, {}, {}> {{ publisher: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<T>(),
+            Format::FORMAT_ID,
+            self.publisher
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Format: SerializationFormat,
+    > SerdePublisher<Service, T, Format>
+{
+    pub(crate) fn new(publisher: Publisher<Service, [u8], ()>) -> Self {
+        Self {
+            publisher,
+            _data: PhantomData,
+        }
+    }
+
+    /// Returns the underlying [`Publisher`] that sends the serialized bytes.
+    pub fn publisher(&self) -> &Publisher<Service, [u8], ()> {
+        &self.publisher
+    }
+
+    /// Serializes the `value` into a sample and delivers it. On success it returns the number
+    /// of [`crate::port::subscriber::Subscriber`]s that received the data, otherwise a
+    /// [`SerdeSendError`] describing the failure.
+    pub fn send_serialized(&self, value: &T) -> Result<usize, SerdeSendError> {
+        let bytes = match Format::serialize(value) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                fail!(from self, with SerdeSendError::SerializationFailed,
+                    "Unable to send value since it could not be serialized ({:?}).", e);
+            }
+        };
+
+        let sample = fail!(from self, when self.publisher.loan_slice_uninit(bytes.len()),
+            "Unable to loan a sample for the serialized value with {} bytes.", bytes.len());
+        let sample = sample.write_from_slice(&bytes);
+        Ok(fail!(from self, when sample.send(),
+            "Unable to send the serialized value."))
+    }
+}
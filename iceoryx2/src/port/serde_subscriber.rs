@@ -0,0 +1,134 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serde::<Vec<String>>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! while let Some(value) = subscriber.receive_deserialized()? {
+//!     println!("received: {:?}", value);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+
+use crate::port::subscriber::Subscriber;
+use crate::port::ReceiveError;
+use crate::service;
+use crate::service::builder::publish_subscribe_serde::SerializationFormat;
+
+/// Failures that can occur when a [`SerdeSubscriber`] receives a value with
+/// [`SerdeSubscriber::receive_deserialized()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SerdeReceiveError {
+    /// The received bytes could not be deserialized with the [`SerializationFormat`].
+    DeserializationFailed,
+    /// The underlying sample could not be received.
+    ReceiveError(ReceiveError),
+}
+
+impl From<ReceiveError> for SerdeReceiveError {
+    fn from(value: ReceiveError) -> Self {
+        SerdeReceiveError::ReceiveError(value)
+    }
+}
+
+impl core::fmt::Display for SerdeReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SerdeReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for SerdeReceiveError {}
+
+/// Receives values of a type `T` that is not shared-memory compatible. Every received byte
+/// slice sample of the underlying [`Subscriber`] is deserialized with the
+/// [`SerializationFormat`] and released afterwards.
+pub struct SerdeSubscriber<
+    Service: service::Service,
+    T: serde::Serialize + serde::de::DeserializeOwned,
+    Format: SerializationFormat,
+> {
+    subscriber: Subscriber<Service, [u8], ()>,
+    _data: PhantomData<fn() -> (T, Format)>,
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Format: SerializationFormat,
+    > Debug for SerdeSubscriber<Service, T, Format>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SerdeSubscriber<{}, {}, {}> {{ subscriber: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<T>(),
+            Format::FORMAT_ID,
+            self.subscriber
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Format: SerializationFormat,
+    > SerdeSubscriber<Service, T, Format>
+{
+    pub(crate) fn new(subscriber: Subscriber<Service, [u8], ()>) -> Self {
+        Self {
+            subscriber,
+            _data: PhantomData,
+        }
+    }
+
+    /// Returns the underlying [`Subscriber`] that receives the serialized bytes.
+    pub fn subscriber(&self) -> &Subscriber<Service, [u8], ()> {
+        &self.subscriber
+    }
+
+    /// Receives the next sample and deserializes it. If no sample could be received
+    /// [`None`] is returned. If a failure occurs a [`SerdeReceiveError`] is returned.
+    pub fn receive_deserialized(&self) -> Result<Option<T>, SerdeReceiveError> {
+        let sample = match fail!(from self, when self.subscriber.receive(),
+                        "Unable to receive the next serialized value.")
+        {
+            Some(sample) => sample,
+            None => return Ok(None),
+        };
+
+        match Format::deserialize(sample.payload()) {
+            Ok(value) => Ok(Some(value)),
+            Err(e) => {
+                fail!(from self, with SerdeReceiveError::DeserializationFailed,
+                    "Unable to deserialize the received value ({:?}).", e);
+            }
+        }
+    }
+}
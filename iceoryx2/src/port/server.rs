@@ -191,6 +191,9 @@ impl<Service: service::Service> SharedServerState<Service> {
                     ReceiverDetails {
                         port_id: details.client_id.value(),
                         buffer_size: details.response_buffer_size,
+                        filter: None,
+                        history_request: 0,
+                        keep_latest: false,
                     },
                     |_| {},
                 );
@@ -398,6 +401,7 @@ impl<
             unable_to_deliver_strategy: server_factory.config.unable_to_deliver_strategy,
             message_type_details: static_config.response_message_type_details.clone(),
             number_of_channels: number_of_requests_per_client,
+            notify_receivers: false,
         };
 
         let shared_state = Service::ArcThreadSafetyPolicy::new(SharedServerState {
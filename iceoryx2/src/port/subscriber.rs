@@ -36,6 +36,7 @@ use core::cell::UnsafeCell;
 use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
 
 use iceoryx2_bb_container::slotmap::SlotMap;
 use iceoryx2_bb_container::vec::Vec;
@@ -44,29 +45,46 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::adaptive_wait::{AdaptiveWait, AdaptiveWaitBuilder};
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
-use iceoryx2_cal::zero_copy_connection::ChannelId;
+use iceoryx2_cal::event::{Event, Listener, ListenerBuilder, NamedConceptMgmt, TriggerId};
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptRemoveError};
+use iceoryx2_cal::zero_copy_connection::{ChannelId, ZeroCopyReceiver};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
+use crate::config::Config;
 use crate::port::update_connections::UpdateConnections;
 use crate::service::builder::CustomPayloadMarker;
+use crate::service::config_scheme::event_config;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
+use crate::service::naming_scheme::subscriber_notification_name;
 use crate::service::port_factory::subscriber::SubscriberConfig;
+use crate::service::stale_resource_cleanup::remove_dead_publishers;
+use crate::service::static_config::message_type_details::TypeVariant;
 use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::service::{NoResource, ServiceState};
-use crate::{raw_sample::RawSample, sample::Sample, service};
+use crate::{raw_sample::RawSample, sample::Sample, sample_ref::SampleRef, service};
 
+use super::details::channel_management::{ChannelManagement, INVALID_CHANNEL_STATE};
 use super::details::chunk::Chunk;
 use super::details::chunk_details::ChunkDetails;
+use super::details::port_wait::wait_for_ports;
 use super::details::receiver::*;
 use super::port_identifiers::UniqueSubscriberId;
+use super::sample_filter::SampleFilterTarget;
 use super::update_connections::ConnectionFailure;
-use super::ReceiveError;
+use super::{ReceiveError, WaitForPortsError};
 
 use alloc::sync::Arc;
 
+const MAX_NOTIFICATION_WAIT_TIME: Duration = Duration::from_millis(10);
+
 /// Describes the failures when a new [`Subscriber`] is created via the
 /// [`crate::service::port_factory::subscriber::PortFactorySubscriber`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -82,6 +100,18 @@ pub enum SubscriberCreateError {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The notification channel of the [`Subscriber`] could not be created. It is only
+    /// required when the [`Service`](crate::service::Service) has notifications enabled.
+    ResourceCreationFailed,
+    /// The compared range of the [`SampleFilter`](crate::port::sample_filter::SampleFilter) exceeds the user header or the fixed size
+    /// payload of the [`Service`](crate::service::Service).
+    IncompatibleSampleFilter,
+    /// The [`Subscriber`] requested more historical [`Sample`]s than the history size of the
+    /// [`Service`](crate::service::Service).
+    HistoryRequestExceedsHistorySizeOfService,
+    /// A maximum sample age was defined but the [`Service`](crate::service::Service) was
+    /// created without timestamps.
+    TimestampsNotEnabled,
 }
 
 impl core::fmt::Display for SubscriberCreateError {
@@ -92,10 +122,84 @@ impl core::fmt::Display for SubscriberCreateError {
 
 impl core::error::Error for SubscriberCreateError {}
 
+#[derive(Debug, Clone, Copy)]
+struct LastSequenceNumber {
+    publisher_port_id: u128,
+    value: u64,
+}
+
 #[derive(Debug)]
 pub(crate) struct SubscriberSharedState<Service: service::Service> {
     pub(crate) receiver: Receiver<Service>,
     pub(crate) publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    last_sequence_numbers: UnsafeCell<Vec<LastSequenceNumber>>,
+    lost_samples: IoxAtomicU64,
+    expired_samples: IoxAtomicU64,
+    max_sample_age: Option<Duration>,
+    has_filter: bool,
+    history_request: usize,
+    // the chunk details and the translated address of the sample that was acquired with
+    // Subscriber::peek() and not yet received
+    peeked_sample: UnsafeCell<Option<(ChunkDetails, usize)>>,
+}
+
+impl<Service: service::Service> SubscriberSharedState<Service> {
+    fn track_sequence_number(&self, publisher_port_id: u128, sequence_number: u64) {
+        let last_sequence_numbers = unsafe { &mut *self.last_sequence_numbers.get() };
+
+        if let Some(last) = last_sequence_numbers
+            .iter_mut()
+            .find(|last| last.publisher_port_id == publisher_port_id)
+        {
+            // a subscriber with a filter does not receive every sample by design
+            if last.value < sequence_number && !self.has_filter {
+                self.lost_samples
+                    .fetch_add(sequence_number - last.value - 1, Ordering::Relaxed);
+            }
+            last.value = sequence_number;
+            return;
+        }
+
+        // an unknown publisher is treated as a new stream, the samples it sent before the
+        // connection was established are not considered as lost
+        if last_sequence_numbers.is_full() {
+            for n in (0..last_sequence_numbers.len()).rev() {
+                if !self
+                    .receiver
+                    .is_connected_to(last_sequence_numbers[n].publisher_port_id)
+                {
+                    last_sequence_numbers.remove(n);
+                }
+            }
+        }
+
+        if last_sequence_numbers.is_full() {
+            last_sequence_numbers.remove(0);
+        }
+
+        last_sequence_numbers.push(LastSequenceNumber {
+            publisher_port_id,
+            value: sequence_number,
+        });
+    }
+
+    // returns true when the sample is older than the maximum sample age
+    fn is_expired(&self, header: &Header, now: Option<Duration>) -> bool {
+        match (self.max_sample_age, header.timestamp(), now) {
+            (Some(max_sample_age), Some(timestamp), Some(now)) => {
+                now.saturating_sub(timestamp) > max_sample_age
+            }
+            _ => false,
+        }
+    }
+
+    fn last_sequence_number(&self, publisher_port_id: u128) -> Option<u64> {
+        let last_sequence_numbers = unsafe { &*self.last_sequence_numbers.get() };
+        last_sequence_numbers
+            .iter()
+            .find(|last| last.publisher_port_id == publisher_port_id)
+            .map(|last| last.value)
+    }
 }
 
 /// The receiving endpoint of a publish-subscribe communication.
@@ -107,6 +211,8 @@ pub struct Subscriber<
 > {
     dynamic_subscriber_handle: Option<ContainerHandle>,
     subscriber_shared_state: Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>,
+    // is not part of the shared state so that waiting on it does not block the other threads
+    notification: Option<Service::ArcThreadSafetyPolicy<<Service::Event as Event>::Listener>>,
 
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
@@ -119,6 +225,7 @@ unsafe impl<
     > Send for Subscriber<Service, Payload, UserHeader>
 where
     Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>: Send + Sync,
+    Service::ArcThreadSafetyPolicy<<Service::Event as Event>::Listener>: Send + Sync,
 {
 }
 
@@ -129,9 +236,55 @@ unsafe impl<
     > Sync for Subscriber<Service, Payload, UserHeader>
 where
     Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>: Send + Sync,
+    Service::ArcThreadSafetyPolicy<<Service::Event as Event>::Listener>: Send + Sync,
 {
 }
 
+/// The notification channel of a [`Subscriber`], acquired with [`Subscriber::notification()`].
+/// It can be attached to a [`WaitSet`](crate::waitset::WaitSet) which wakes up whenever a
+/// [`crate::port::publisher::Publisher`] delivered new data to the [`Subscriber`]. It keeps
+/// waking up the [`WaitSet`](crate::waitset::WaitSet) until all delivered samples were
+/// received.
+#[derive(Debug)]
+pub struct SubscriberNotification<'subscriber> {
+    file_descriptor: &'subscriber FileDescriptor,
+}
+
+impl FileDescriptorBased for SubscriberNotification<'_> {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.file_descriptor
+    }
+}
+
+impl SynchronousMultiplexing for SubscriberNotification<'_> {}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend + ?Sized,
+        UserHeader: Debug + ZeroCopySend,
+    > Subscriber<Service, Payload, UserHeader>
+where
+    <Service::Event as Event>::Listener: SynchronousMultiplexing,
+{
+    /// Returns the [`SubscriberNotification`] that can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet). If the [`Service`](crate::service::Service) was
+    /// not created with
+    /// [`enable_notifications()`](crate::service::builder::publish_subscribe::Builder::enable_notifications())
+    /// the [`Subscriber`] has no notification channel and [`None`] is returned.
+    pub fn notification(&self) -> Option<SubscriberNotification<'_>> {
+        let file_descriptor = self
+            .notification
+            .as_ref()
+            .map(|notification| notification.lock().file_descriptor() as *const FileDescriptor)?;
+
+        // the notification channel and its file descriptor never change during the lifetime
+        // of the subscriber port
+        Some(SubscriberNotification {
+            file_descriptor: unsafe { &*file_descriptor },
+        })
+    }
+}
+
 impl<
         Service: service::Service,
         Payload: Debug + ZeroCopySend + ?Sized,
@@ -139,15 +292,28 @@ impl<
     > Drop for Subscriber<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
-        if let Some(handle) = self.dynamic_subscriber_handle {
-            self.subscriber_shared_state
-                .lock()
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        if let Some((details, _)) =
+            unsafe { &mut *subscriber_shared_state.peeked_sample.get() }.take()
+        {
+            subscriber_shared_state
                 .receiver
-                .service_state
+                .release_offset(&details, ChannelId::new(0));
+        }
+        drop(subscriber_shared_state);
+
+        if let Some(handle) = self.dynamic_subscriber_handle {
+            let subscriber_shared_state = self.subscriber_shared_state.lock();
+            let service_state = &subscriber_shared_state.receiver.service_state;
+            service_state
                 .dynamic_storage
                 .get()
                 .publish_subscribe()
-                .release_subscriber_handle(handle)
+                .release_subscriber_handle(handle);
+
+            if let Some(bridge) = &service_state.connection_bridge {
+                bridge.port_removed(bridge.event_ids().subscriber_disconnected);
+            }
         }
     }
 }
@@ -170,6 +336,7 @@ impl<
         let publisher_list = &service.dynamic_storage.get().publish_subscribe().publishers;
 
         let buffer_size = match config.buffer_size {
+            _ if config.keep_latest => 1,
             Some(buffer_size) => {
                 if static_config.subscriber_max_buffer_size < buffer_size {
                     fail!(from origin, with SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService,
@@ -181,6 +348,46 @@ impl<
             None => static_config.subscriber_max_buffer_size,
         };
 
+        let history_request = match config.history_request {
+            Some(history_request) if static_config.history_size < history_request => {
+                if !config.clamp_history_request {
+                    fail!(from origin, with SubscriberCreateError::HistoryRequestExceedsHistorySizeOfService,
+                        "{} since the requested history of {} samples exceeds the history size {} of the service.",
+                        msg, history_request, static_config.history_size);
+                }
+
+                warn!(from origin,
+                    "The requested history of {} samples exceeds the history size {} of the service and is reduced to the history size.",
+                    history_request, static_config.history_size);
+                static_config.history_size
+            }
+            Some(history_request) => history_request,
+            None => static_config.history_size,
+        };
+
+        if let Some(filter) = &config.filter {
+            let type_details = &static_config.message_type_details;
+            let (target_size, is_dynamic) = match filter.target() {
+                SampleFilterTarget::UserHeader => (type_details.user_header.size, false),
+                SampleFilterTarget::Payload => (
+                    type_details.payload.size,
+                    type_details.payload.variant == TypeVariant::Dynamic,
+                ),
+            };
+
+            if !is_dynamic && target_size < filter.end() {
+                fail!(from origin, with SubscriberCreateError::IncompatibleSampleFilter,
+                    "{} since the sample filter {:?} compares bytes beyond the {:?} of size {}.",
+                    msg, filter, filter.target(), target_size);
+            }
+        }
+
+        if config.max_sample_age.is_some() && !static_config.enable_timestamps {
+            fail!(from origin, with SubscriberCreateError::TimestampsNotEnabled,
+                "{} since a maximum sample age was defined but the service does not provide timestamps.",
+                msg);
+        }
+
         let number_of_to_be_removed_connections = service
             .shared_node
             .config()
@@ -191,15 +398,37 @@ impl<
         let number_of_connections =
             number_of_to_be_removed_connections + number_of_active_connections;
 
+        // the notification channel must exist before the subscriber is added to the dynamic
+        // config, otherwise a publisher may connect without being able to notify it
+        let notification = if static_config.enable_notifications {
+            let event_name = subscriber_notification_name(subscriber_id.value());
+            Some(fail!(from origin,
+                when <Service::Event as Event>::ListenerBuilder::new(&event_name)
+                    .config(&event_config::<Service>(service.shared_node.config()))
+                    .trigger_id_max(TriggerId::new(0))
+                    .create(),
+                with SubscriberCreateError::ResourceCreationFailed,
+                "{} since the underlying notification event concept \"{}\" could not be created.", msg, event_name))
+        } else {
+            None
+        };
+
         let subscriber_shared_state = Service::ArcThreadSafetyPolicy::new(SubscriberSharedState {
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
+            last_sequence_numbers: UnsafeCell::new(Vec::new(number_of_connections)),
+            lost_samples: IoxAtomicU64::new(0),
+            expired_samples: IoxAtomicU64::new(0),
+            max_sample_age: config.max_sample_age,
+            has_filter: config.filter.is_some(),
+            history_request,
+            peeked_sample: UnsafeCell::new(None),
             receiver: Receiver {
                 connections: Vec::from_fn(number_of_active_connections, |_| UnsafeCell::new(None)),
                 receiver_port_id: subscriber_id.value(),
                 service_state: service.clone(),
                 message_type_details: static_config.message_type_details.clone(),
                 receiver_max_borrowed_samples: static_config.subscriber_max_borrowed_samples,
-                enable_safe_overflow: static_config.enable_safe_overflow,
+                enable_safe_overflow: static_config.enable_safe_overflow || config.keep_latest,
                 buffer_size,
                 tagger: CyclicTagger::new(),
                 to_be_removed_connections: Some(UnsafeCell::new(Vec::new(
@@ -220,8 +449,19 @@ impl<
             }
         };
 
+        let notification = match notification.map(Service::ArcThreadSafetyPolicy::new) {
+            None => None,
+            Some(Ok(v)) => Some(v),
+            Some(Err(e)) => {
+                fail!(from origin,
+                            with SubscriberCreateError::FailedToDeployThreadsafetyPolicy,
+                            "{msg} since the threadsafety policy of the notification channel could not be instantiated ({e:?}).");
+            }
+        };
+
         let mut new_self = Self {
             subscriber_shared_state,
+            notification,
             dynamic_subscriber_handle: None,
             _payload: PhantomData,
             _user_header: PhantomData,
@@ -244,6 +484,9 @@ impl<
                 subscriber_id,
                 buffer_size,
                 node_id: *service.shared_node.id(),
+                filter: config.filter,
+                history_request,
+                keep_latest: config.keep_latest,
             }) {
             Some(unique_index) => unique_index,
             None => {
@@ -255,6 +498,13 @@ impl<
 
         new_self.dynamic_subscriber_handle = Some(dynamic_subscriber_handle);
 
+        if let Some(bridge) = &service.connection_bridge {
+            bridge.port_added(
+                &service.shared_node,
+                bridge.event_ids().subscriber_connected,
+            );
+        }
+
         Ok(new_self)
     }
 
@@ -308,25 +558,477 @@ impl<
         self.subscriber_shared_state.lock().receiver.buffer_size
     }
 
+    /// Blocks until at least `count` [`Publisher`](crate::port::publisher::Publisher)s are
+    /// connected to the [`Service`](crate::service::Service) or the timeout has passed. Returns
+    /// the number of connected [`Publisher`](crate::port::publisher::Publisher)s, which is less
+    /// than `count` when the timeout has passed. It is the counterpart of
+    /// [`Publisher::wait_for_subscribers()`](crate::port::publisher::Publisher::wait_for_subscribers()).
+    pub fn wait_for_publishers(
+        &self,
+        count: usize,
+        timeout: Duration,
+    ) -> Result<usize, WaitForPortsError> {
+        let service_state = self
+            .subscriber_shared_state
+            .lock()
+            .receiver
+            .service_state
+            .clone();
+        let signal_handling_mode = service_state.shared_node.signal_handling_mode();
+        wait_for_ports(self, signal_handling_mode, count, timeout, || {
+            service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .number_of_publishers()
+        })
+    }
+
+    /// Returns the number of [`Sample`]s that never reached the [`Subscriber`] since its
+    /// buffer was full, summed up over all connected
+    /// [`Publisher`](crate::port::publisher::Publisher)s. It contains the [`Sample`]s a
+    /// [`Publisher`](crate::port::publisher::Publisher) discarded as well as the ones that were
+    /// replaced by newer [`Sample`]s on overflow. It mirrors
+    /// [`Publisher::dropped_samples()`](crate::port::publisher::Publisher::dropped_samples())
+    /// from the receiving side.
+    pub fn missed_due_to_overflow(&self) -> u64 {
+        self.subscriber_shared_state
+            .lock()
+            .receiver
+            .number_of_dropped_samples()
+    }
+
+    /// Resets the counter of [`Subscriber::missed_due_to_overflow()`] to zero. The counters of
+    /// the [`Publisher`](crate::port::publisher::Publisher)s are not affected.
+    pub fn reset_missed_due_to_overflow(&self) {
+        self.subscriber_shared_state
+            .lock()
+            .receiver
+            .reset_dropped_samples_counters()
+    }
+
+    /// Checks whether the [`Node`](crate::node::Node) of a connected
+    /// [`Publisher`](crate::port::publisher::Publisher) died, for instance because its process
+    /// was killed while it had loaned [`Sample`]s. The stale resources of every dead
+    /// [`Node`](crate::node::Node) are removed, so that its data segments and connections are
+    /// released and the slots of its [`Publisher`](crate::port::publisher::Publisher)s can be
+    /// used by new ones. Every detected dead [`Publisher`](crate::port::publisher::Publisher)
+    /// is reported with a log entry and the connection to it is closed.
+    ///
+    /// Returns the number of removed [`Publisher`](crate::port::publisher::Publisher)s.
+    pub fn remove_dead_publishers(&self) -> Result<usize, ConnectionFailure> {
+        let number_of_removed_publishers = {
+            let subscriber_shared_state = self.subscriber_shared_state.lock();
+            let service_state = &subscriber_shared_state.receiver.service_state;
+            remove_dead_publishers::<Service>(
+                service_state.dynamic_storage.get().publish_subscribe(),
+                service_state.shared_node.config(),
+            )
+        };
+
+        fail!(from self, when self.update_connections(),
+            "Unable to close the connections to the dead publishers since the connections could not be updated.");
+
+        Ok(number_of_removed_publishers)
+    }
+
+    /// Returns true when the [`Subscriber`] has received the historical [`Sample`]s of every
+    /// connected [`Publisher`](crate::port::publisher::Publisher). A
+    /// [`Publisher`](crate::port::publisher::Publisher) delivers the history when it
+    /// connects to the [`Subscriber`], this happens with its next
+    /// [`Publisher::update_connections()`](crate::port::update_connections::UpdateConnections::update_connections())
+    /// or send call. Until then the history is not completed.
+    ///
+    /// All [`Sample`]s that are received after this function returned true are live
+    /// [`Sample`]s, except for the history of [`Publisher`](crate::port::publisher::Publisher)s
+    /// that connect later.
+    pub fn has_history_completed(&self) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+                "Unable to acquire the history state since not all connections to publishers could be established.");
+
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        if subscriber_shared_state.history_request == 0 {
+            return Ok(true);
+        }
+
+        let mut has_completed = true;
+        subscriber_shared_state
+            .receiver
+            .for_each_active_connection(|connection| {
+                let channel_id = ChannelId::new(0);
+                has_completed &= match connection.receiver.get_channel_state(channel_id) {
+                    INVALID_CHANNEL_STATE => false,
+                    0 => true,
+                    history_end => {
+                        !connection.receiver.has_data(channel_id)
+                            || subscriber_shared_state
+                                .last_sequence_number(connection.sender_port_id)
+                                .is_some_and(|last| history_end <= last + 1)
+                    }
+                };
+            });
+
+        Ok(has_completed)
+    }
+
     /// Returns true if the [`Subscriber`] has samples in the buffer that can be received with [`Subscriber::receive`].
     pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
-        Ok(self
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        Ok(
+            unsafe { &*subscriber_shared_state.peeked_sample.get() }.is_some()
+                || subscriber_shared_state
+                    .receiver
+                    .has_samples(ChannelId::new(0)),
+        )
+    }
+
+    /// Returns the number of [`Sample`]s that are buffered from all connected
+    /// [`Publisher`](crate::port::publisher::Publisher)s and can be received with
+    /// [`Subscriber::receive()`] without dequeuing any of them. The value is a snapshot, the
+    /// [`Publisher`](crate::port::publisher::Publisher)s can deliver new [`Sample`]s
+    /// concurrently or the [`Sample`]s can be received by another thread.
+    /// [`Sample`]s of [`Publisher`](crate::port::publisher::Publisher)s to which no connection
+    /// could be established are not accounted.
+    pub fn len(&self) -> usize {
+        if let Err(e) = self.update_connections() {
+            warn!(from self, "The number of samples is incomplete since not all connections to publishers could be established ({:?}).", e);
+        }
+
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let peeked_samples =
+            unsafe { &*subscriber_shared_state.peeked_sample.get() }.is_some() as usize;
+        peeked_samples
+            + subscriber_shared_state
+                .receiver
+                .number_of_samples(ChannelId::new(0))
+    }
+
+    /// Returns true if no [`Sample`]s are buffered, see [`Subscriber::len()`].
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of [`Sample`]s that were lost since the [`Subscriber`] was created.
+    /// A [`Sample`] is lost when it was sent by a connected
+    /// [`Publisher`](crate::port::publisher::Publisher) but never received, for instance when
+    /// it was discarded due to a buffer overflow.
+    ///
+    /// The lost [`Sample`]s are detected with the
+    /// [`Sample::sequence_number()`](crate::sample::Sample::sequence_number()) and are
+    /// therefore only accounted once a later [`Sample`] of the same
+    /// [`Publisher`](crate::port::publisher::Publisher) was received. Every new
+    /// [`Publisher`](crate::port::publisher::Publisher) starts a new stream, [`Sample`]s that
+    /// were lost before the first [`Sample`] of a
+    /// [`Publisher`](crate::port::publisher::Publisher) was received are not accounted.
+    ///
+    /// A [`Subscriber`] with a [`SampleFilter`](crate::port::sample_filter::SampleFilter) does not receive every [`Sample`] by design,
+    /// therefore it never accounts any lost [`Sample`]s.
+    pub fn lost_samples(&self) -> u64 {
+        self.subscriber_shared_state
+            .lock()
+            .lost_samples
+            .load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of [`Sample`]s that were skipped and released by the [`Subscriber`]
+    /// since they were older than the maximum sample age defined with
+    /// [`PortFactorySubscriber::max_sample_age()`](crate::service::port_factory::subscriber::PortFactorySubscriber::max_sample_age()).
+    pub fn expired_samples(&self) -> u64 {
+        self.subscriber_shared_state
+            .lock()
+            .expired_samples
+            .load(Ordering::Relaxed)
+    }
+
+    /// Returns an [`Iter`] that receives [`Sample`]s until no more data is available.
+    /// Every [`Sample`] is owned by the loop body and released when it advances. The
+    /// iteration stops when no further [`Sample`] can be borrowed, see
+    /// [`ReceiveError::ExceedsMaxBorrows`], or when receiving failed.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// for sample in subscriber.iter() {
+    ///     println!("received: {:?}", *sample);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(&self) -> Iter<'_, Service, Payload, UserHeader> {
+        Iter {
+            inner: self.try_iter(),
+        }
+    }
+
+    /// Returns a [`TryIter`] that receives [`Sample`]s until no more data is available. In
+    /// contrast to [`Subscriber::iter()`] it yields the failures that occur while receiving.
+    /// The iteration stops when no further [`Sample`] can be borrowed, see
+    /// [`ReceiveError::ExceedsMaxBorrows`].
+    pub fn try_iter(&self) -> TryIter<'_, Service, Payload, UserHeader> {
+        TryIter { subscriber: self }
+    }
+
+    fn current_time_for_expiration(
+        &self,
+        subscriber_shared_state: &SubscriberSharedState<Service>,
+    ) -> Result<Option<Duration>, ReceiveError> {
+        if subscriber_shared_state.max_sample_age.is_none() {
+            return Ok(None);
+        }
+
+        let now = fail!(from self, when Time::now(),
+                with ReceiveError::InternalFailure,
+                "Unable to receive samples since the current time could not be acquired.");
+        Ok(Some(now.as_duration()))
+    }
+
+    fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        if let Some(data) = self.take_peeked_sample() {
+            return Ok(Some(data));
+        }
+
+        self.receive_from_buffer()
+    }
+
+    fn take_peeked_sample(&self) -> Option<(ChunkDetails, Chunk)> {
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        unsafe { &mut *subscriber_shared_state.peeked_sample.get() }
+            .take()
+            .map(|(details, address)| {
+                let chunk = Chunk::new(
+                    &subscriber_shared_state.receiver.message_type_details,
+                    address,
+                );
+                (details, chunk)
+            })
+    }
+
+    // Receives the next sample and keeps it in the subscriber until it is received with
+    // Subscriber::receive(). Returns the origin and the chunk of the peeked sample.
+    fn peek_impl(&mut self) -> Result<Option<(u128, Chunk)>, ReceiveError> {
+        {
+            let subscriber_shared_state = self.subscriber_shared_state.lock();
+            if let Some((details, address)) =
+                unsafe { &*subscriber_shared_state.peeked_sample.get() }
+            {
+                return Ok(Some((
+                    details.origin,
+                    Chunk::new(
+                        &subscriber_shared_state.receiver.message_type_details,
+                        *address,
+                    ),
+                )));
+            }
+        }
+
+        match self.receive_from_buffer()? {
+            Some((details, chunk)) => {
+                let origin = details.origin;
+                let subscriber_shared_state = self.subscriber_shared_state.lock();
+                unsafe {
+                    *subscriber_shared_state.peeked_sample.get() =
+                        Some((details, chunk.header as usize))
+                };
+                Ok(Some((origin, chunk)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn verify_service_exists(&self, msg: &str) -> Result<(), ReceiveError> {
+        if self
             .subscriber_shared_state
             .lock()
             .receiver
-            .has_samples(ChannelId::new(0)))
+            .service_state
+            .dynamic_storage
+            .get()
+            .is_marked_for_destruction()
+        {
+            fail!(from self, with ReceiveError::ServiceNoLongerExists,
+                "{msg} since the service no longer exists.");
+        }
+
+        Ok(())
     }
 
-    fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+    fn receive_from_buffer(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        self.verify_service_exists("Unable to receive a sample")?;
+
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
 
-        self.subscriber_shared_state
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let now = self.current_time_for_expiration(&subscriber_shared_state)?;
+
+        loop {
+            let data = subscriber_shared_state
+                .receiver
+                .receive(ChannelId::new(0))?;
+
+            if let Some((details, chunk)) = &data {
+                self.consume_notification();
+                let header = unsafe { &*(chunk.header as *const Header) };
+                subscriber_shared_state
+                    .track_sequence_number(details.origin, header.sequence_number());
+
+                if subscriber_shared_state.is_expired(header, now) {
+                    subscriber_shared_state
+                        .receiver
+                        .release_offset(details, ChannelId::new(0));
+                    subscriber_shared_state
+                        .expired_samples
+                        .fetch_add(1, Ordering::Relaxed);
+                    continue;
+                }
+            }
+
+            return Ok(data);
+        }
+    }
+
+    // every delivered sample comes with one notification, it is consumed when the sample is
+    // taken out of the buffer so that a WaitSet is woken up again as long as samples remain
+    fn consume_notification(&self) {
+        if let Some(ref notification) = self.notification {
+            if let Err(e) = notification.lock().try_wait_one() {
+                warn!(from self, "Unable to consume the notification of a received sample ({:?}).", e);
+            }
+        }
+    }
+
+    fn receive_batch_impl<F: FnMut(ChunkDetails, Chunk)>(
+        &self,
+        max: usize,
+        mut callback: F,
+    ) -> Result<(), ReceiveError> {
+        let msg = "Unable to receive a batch of samples";
+        let max_borrowed_samples = self
+            .subscriber_shared_state
             .lock()
             .receiver
-            .receive(ChannelId::new(0))
+            .receiver_max_borrowed_samples;
+        if max_borrowed_samples < max {
+            fail!(from self, with ReceiveError::ExceedsMaxBorrows,
+                "{msg} since the batch size of {max} exceeds the maximum of {max_borrowed_samples} samples that can be borrowed in parallel.");
+        }
+
+        if max == 0 {
+            return Ok(());
+        }
+
+        self.verify_service_exists(msg)?;
+
+        fail!(from self, when self.update_connections(),
+                "{msg} since not all connections to publishers could be established.");
+
+        let mut max = max;
+        if let Some((details, chunk)) = self.take_peeked_sample() {
+            callback(details, chunk);
+            max -= 1;
+        }
+
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let now = self.current_time_for_expiration(&subscriber_shared_state)?;
+        subscriber_shared_state.receiver.receive_batch(
+            ChannelId::new(0),
+            max,
+            |details, chunk| {
+                self.consume_notification();
+                let header = unsafe { &*(chunk.header as *const Header) };
+                subscriber_shared_state
+                    .track_sequence_number(details.origin, header.sequence_number());
+
+                if subscriber_shared_state.is_expired(header, now) {
+                    subscriber_shared_state
+                        .receiver
+                        .release_offset(&details, ChannelId::new(0));
+                    subscriber_shared_state
+                        .expired_samples
+                        .fetch_add(1, Ordering::Relaxed);
+                } else {
+                    callback(details, chunk);
+                }
+            },
+        )?;
+
+        Ok(())
+    }
+
+    fn timed_receive_impl(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        let msg = "Unable to wait for new data";
+        let start = fail!(from self, when Time::now(),
+                with ReceiveError::InternalFailure,
+                "{} since the current time could not be acquired.", msg);
+        let mut adaptive_wait: Option<AdaptiveWait> = None;
+
+        loop {
+            if let Some(data) = self.receive_impl()? {
+                return Ok(Some(data));
+            }
+
+            let remaining_time = match timeout {
+                Some(timeout) => {
+                    let elapsed = fail!(from self, when start.elapsed(),
+                            with ReceiveError::InternalFailure,
+                            "{} since the elapsed time could not be acquired.", msg);
+                    if elapsed >= timeout {
+                        return Ok(None);
+                    }
+                    timeout - elapsed
+                }
+                None => MAX_NOTIFICATION_WAIT_TIME,
+            };
+
+            if self.wait_for_notification(remaining_time)? {
+                continue;
+            }
+
+            // the service has no notifications enabled, the wakeup primitive is created lazily
+            // and polls for new data in a less busy wait
+            let adaptive_wait = match adaptive_wait {
+                Some(ref mut adaptive_wait) => adaptive_wait,
+                None => {
+                    adaptive_wait.insert(fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                        with ReceiveError::InternalFailure,
+                        "{} since the adaptive wait could not be created.", msg))
+                }
+            };
+
+            fail!(from self, when adaptive_wait.wait(),
+                with ReceiveError::InternalFailure,
+                "{} since the adaptive wait failed.", msg);
+        }
+    }
+
+    // Returns false when the service has no notifications enabled, otherwise it waits at most
+    // for the provided time on the notification channel. The shared state is not locked while
+    // waiting and the wait is bounded so that the notification channel is not locked forever
+    // when the subscriber is used from multiple threads.
+    fn wait_for_notification(&self, timeout: Duration) -> Result<bool, ReceiveError> {
+        match self.notification {
+            Some(ref notification) => {
+                fail!(from self,
+                    when notification.lock().timed_wait_one(timeout.min(MAX_NOTIFICATION_WAIT_TIME)),
+                    with ReceiveError::InternalFailure,
+                    "Unable to wait for new data since the notification channel could not be waited on.");
+                Ok(true)
+            }
+            None => Ok(false),
+        }
     }
 }
 
@@ -365,17 +1067,96 @@ impl<
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
     pub fn receive(&self) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, chunk)| Sample {
+        Ok(self
+            .receive_impl()?
+            .map(|(details, chunk)| self.create_sample(details, chunk)))
+    }
+
+    /// Receives up to `max` [`crate::sample::Sample`]s from the connected
+    /// [`crate::port::publisher::Publisher`]s in one call. The
+    /// [`crate::port::publisher::Publisher`]s are visited in a round-robin order, so that every
+    /// one of them contributes to the batch. Returns an empty [`alloc::vec::Vec`] when no sample
+    /// could be received.
+    ///
+    /// All returned samples are borrowed in parallel, therefore it fails with
+    /// [`ReceiveError::ExceedsMaxBorrows`] when `max` exceeds the
+    /// [`subscriber_max_borrowed_samples()`](crate::service::builder::publish_subscribe::Builder::subscriber_max_borrowed_samples())
+    /// of the [`Service`](crate::service::Service).
+    pub fn receive_batch(
+        &self,
+        max: usize,
+    ) -> Result<alloc::vec::Vec<Sample<Service, Payload, UserHeader>>, ReceiveError> {
+        let mut samples = alloc::vec::Vec::with_capacity(max);
+        self.receive_batch_impl(max, |details, chunk| {
+            samples.push(self.create_sample(details, chunk))
+        })?;
+        Ok(samples)
+    }
+
+    /// Blocks until a [`crate::sample::Sample`] from a [`crate::port::publisher::Publisher`]
+    /// was received. If a failure occurs [`ReceiveError`] is returned.
+    ///
+    /// When the [`Service`](crate::service::Service) was created with
+    /// [`enable_notifications()`](crate::service::builder::publish_subscribe::Builder::enable_notifications())
+    /// the [`Subscriber`] is woken up by the [`crate::port::publisher::Publisher`], otherwise
+    /// it polls for new data in a less busy wait.
+    pub fn blocking_receive(&self) -> Result<Sample<Service, Payload, UserHeader>, ReceiveError> {
+        loop {
+            if let Some((details, chunk)) = self.timed_receive_impl(None)? {
+                return Ok(self.create_sample(details, chunk));
+            }
+        }
+    }
+
+    /// Blocks until a [`crate::sample::Sample`] from a [`crate::port::publisher::Publisher`]
+    /// was received or the timeout has passed. If no sample could be received in time
+    /// [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    ///
+    /// See [`Subscriber::blocking_receive()`] for details on how the [`Subscriber`] waits.
+    pub fn timed_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError> {
+        Ok(self
+            .timed_receive_impl(Some(timeout))?
+            .map(|(details, chunk)| self.create_sample(details, chunk)))
+    }
+
+    /// Returns a [`SampleRef`] to the next [`crate::sample::Sample`] without removing it from
+    /// the [`Subscriber`]. The next call to [`Subscriber::receive()`] returns the same data.
+    /// If no sample could be received [`None`] is returned. If a failure occurs
+    /// [`ReceiveError`] is returned.
+    ///
+    /// The [`SampleRef`] mutably borrows the [`Subscriber`], therefore it must be dropped
+    /// before the data can be received.
+    pub fn peek(&mut self) -> Result<Option<SampleRef<'_, Payload, UserHeader>>, ReceiveError> {
+        Ok(self.peek_impl()?.map(|(origin, chunk)| SampleRef {
+            ptr: Self::raw_sample(&chunk),
+            origin,
+            _subscriber: PhantomData,
+        }))
+    }
+
+    fn raw_sample(chunk: &Chunk) -> RawSample<Header, UserHeader, Payload> {
+        unsafe {
+            RawSample::new_unchecked(
+                chunk.header.cast(),
+                chunk.user_header.cast(),
+                chunk.payload.cast(),
+            )
+        }
+    }
+
+    fn create_sample(
+        &self,
+        details: ChunkDetails,
+        chunk: Chunk,
+    ) -> Sample<Service, Payload, UserHeader> {
+        Sample {
             subscriber_shared_state: self.subscriber_shared_state.clone(),
             details,
-            ptr: unsafe {
-                RawSample::new_unchecked(
-                    chunk.header.cast(),
-                    chunk.user_header.cast(),
-                    chunk.payload.cast(),
-                )
-            },
-        }))
+            ptr: Self::raw_sample(&chunk),
+        }
     }
 }
 
@@ -390,23 +1171,108 @@ impl<
     pub fn receive(&self) -> Result<Option<Sample<Service, [Payload], UserHeader>>, ReceiveError> {
         debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
 
-        Ok(self.receive_impl()?.map(|(details, chunk)| {
-            let header_ptr = chunk.header as *const Header;
-            let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+        Ok(self
+            .receive_impl()?
+            .map(|(details, chunk)| self.create_sample(details, chunk)))
+    }
 
-            Sample {
-                subscriber_shared_state: self.subscriber_shared_state.clone(),
-                details,
-                ptr: unsafe {
-                    RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
-                        header_ptr,
-                        chunk.user_header.cast(),
-                        core::slice::from_raw_parts(chunk.payload.cast(), number_of_elements as _),
-                    )
-                },
+    /// Receives up to `max` [`crate::sample::Sample`]s from the connected
+    /// [`crate::port::publisher::Publisher`]s in one call. The
+    /// [`crate::port::publisher::Publisher`]s are visited in a round-robin order, so that every
+    /// one of them contributes to the batch. Returns an empty [`alloc::vec::Vec`] when no sample
+    /// could be received.
+    ///
+    /// All returned samples are borrowed in parallel, therefore it fails with
+    /// [`ReceiveError::ExceedsMaxBorrows`] when `max` exceeds the
+    /// [`subscriber_max_borrowed_samples()`](crate::service::builder::publish_subscribe::Builder::subscriber_max_borrowed_samples())
+    /// of the [`Service`](crate::service::Service).
+    pub fn receive_batch(
+        &self,
+        max: usize,
+    ) -> Result<alloc::vec::Vec<Sample<Service, [Payload], UserHeader>>, ReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        let mut samples = alloc::vec::Vec::with_capacity(max);
+        self.receive_batch_impl(max, |details, chunk| {
+            samples.push(self.create_sample(details, chunk))
+        })?;
+        Ok(samples)
+    }
+
+    /// Blocks until a [`crate::sample::Sample`] from a [`crate::port::publisher::Publisher`]
+    /// was received. If a failure occurs [`ReceiveError`] is returned.
+    ///
+    /// When the [`Service`](crate::service::Service) was created with
+    /// [`enable_notifications()`](crate::service::builder::publish_subscribe::Builder::enable_notifications())
+    /// the [`Subscriber`] is woken up by the [`crate::port::publisher::Publisher`], otherwise
+    /// it polls for new data in a less busy wait.
+    pub fn blocking_receive(&self) -> Result<Sample<Service, [Payload], UserHeader>, ReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        loop {
+            if let Some((details, chunk)) = self.timed_receive_impl(None)? {
+                return Ok(self.create_sample(details, chunk));
             }
+        }
+    }
+
+    /// Blocks until a [`crate::sample::Sample`] from a [`crate::port::publisher::Publisher`]
+    /// was received or the timeout has passed. If no sample could be received in time
+    /// [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    ///
+    /// See [`Subscriber::blocking_receive()`] for details on how the [`Subscriber`] waits.
+    pub fn timed_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<Service, [Payload], UserHeader>>, ReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        Ok(self
+            .timed_receive_impl(Some(timeout))?
+            .map(|(details, chunk)| self.create_sample(details, chunk)))
+    }
+
+    /// Returns a [`SampleRef`] to the next [`crate::sample::Sample`] without removing it from
+    /// the [`Subscriber`]. The next call to [`Subscriber::receive()`] returns the same data.
+    /// If no sample could be received [`None`] is returned. If a failure occurs
+    /// [`ReceiveError`] is returned.
+    ///
+    /// The [`SampleRef`] mutably borrows the [`Subscriber`], therefore it must be dropped
+    /// before the data can be received.
+    pub fn peek(&mut self) -> Result<Option<SampleRef<'_, [Payload], UserHeader>>, ReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        Ok(self.peek_impl()?.map(|(origin, chunk)| SampleRef {
+            ptr: Self::raw_sample(&chunk),
+            origin,
+            _subscriber: PhantomData,
         }))
     }
+
+    fn raw_sample(chunk: &Chunk) -> RawSample<Header, UserHeader, [Payload]> {
+        let header_ptr = chunk.header as *const Header;
+        let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+
+        unsafe {
+            RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
+                header_ptr,
+                chunk.user_header.cast(),
+                core::ptr::slice_from_raw_parts(chunk.payload.cast(), number_of_elements as _),
+            )
+        }
+    }
+
+    fn create_sample(
+        &self,
+        details: ChunkDetails,
+        chunk: Chunk,
+    ) -> Sample<Service, [Payload], UserHeader> {
+        Sample {
+            subscriber_shared_state: self.subscriber_shared_state.clone(),
+            details,
+            ptr: Self::raw_sample(&chunk),
+        }
+    }
 }
 
 impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
@@ -445,3 +1311,133 @@ impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
         }))
     }
 }
+
+/// Iterator that receives [`Sample`]s from a [`Subscriber`] and yields the failures that
+/// occur while receiving, see [`Subscriber::try_iter()`].
+#[derive(Debug)]
+pub struct TryIter<
+    'a,
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized + 'static,
+    UserHeader: Debug + ZeroCopySend,
+> {
+    subscriber: &'a Subscriber<Service, Payload, UserHeader>,
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > Iterator for TryIter<'_, Service, Payload, UserHeader>
+{
+    type Item = Result<Sample<Service, Payload, UserHeader>, ReceiveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        next_sample(self.subscriber.receive())
+    }
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > Iterator for TryIter<'_, Service, [Payload], UserHeader>
+{
+    type Item = Result<Sample<Service, [Payload], UserHeader>, ReceiveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        next_sample(self.subscriber.receive())
+    }
+}
+
+fn next_sample<T>(received: Result<Option<T>, ReceiveError>) -> Option<Result<T, ReceiveError>> {
+    match received {
+        Ok(sample) => sample.map(Ok),
+        Err(ReceiveError::ExceedsMaxBorrows) => None,
+        Err(e) => Some(Err(e)),
+    }
+}
+
+/// Iterator that receives [`Sample`]s from a [`Subscriber`], see [`Subscriber::iter()`].
+#[derive(Debug)]
+pub struct Iter<
+    'a,
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized + 'static,
+    UserHeader: Debug + ZeroCopySend,
+> {
+    inner: TryIter<'a, Service, Payload, UserHeader>,
+}
+
+impl<
+        'a,
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend + ?Sized,
+        UserHeader: Debug + ZeroCopySend,
+    > Iterator for Iter<'a, Service, Payload, UserHeader>
+where
+    TryIter<'a, Service, Payload, UserHeader>:
+        Iterator<Item = Result<Sample<Service, Payload, UserHeader>, ReceiveError>>,
+{
+    type Item = Sample<Service, Payload, UserHeader>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.next()? {
+            Ok(sample) => Some(sample),
+            Err(e) => {
+                warn!(from self.inner.subscriber,
+                    "Stop iterating over the received samples since a failure occurred ({:?}).", e);
+                None
+            }
+        }
+    }
+}
+
+impl<
+        'a,
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > IntoIterator for &'a Subscriber<Service, Payload, UserHeader>
+{
+    type Item = Sample<Service, Payload, UserHeader>;
+    type IntoIter = Iter<'a, Service, Payload, UserHeader>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<
+        'a,
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > IntoIterator for &'a Subscriber<Service, [Payload], UserHeader>
+{
+    type Item = Sample<Service, [Payload], UserHeader>;
+    type IntoIter = Iter<'a, Service, [Payload], UserHeader>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub(crate) unsafe fn remove_notification_of_subscriber<Service: service::Service>(
+    subscriber_id: &UniqueSubscriberId,
+    config: &Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_notification_of_subscriber::<{}>({:?})",
+        core::any::type_name::<Service>(),
+        subscriber_id
+    );
+    let msg = "Unable to remove the subscriber notification";
+    let event_name = subscriber_notification_name(subscriber_id.value());
+    let event_config = event_config::<Service>(config);
+
+    fail!(from origin,
+            when <Service::Event as NamedConceptMgmt>::remove_cfg(&event_name, &event_config),
+            "{} since the underlying concept could not be removed.", msg);
+    Ok(())
+}
@@ -14,6 +14,7 @@ use serde::{de::Visitor, Deserialize, Serialize};
 
 /// Defines the strategy a sender shall pursue when the buffer of the receiver is full
 /// and the service does not overflow.
+#[repr(C)]
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum UnableToDeliverStrategy {
     /// Blocks until the receiver has consumed the
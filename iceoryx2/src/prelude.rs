@@ -20,9 +20,13 @@ pub use crate::service::{
     Service, ServiceDetails,
 };
 pub use crate::signal_handling_mode::SignalHandlingMode;
-pub use crate::waitset::{WaitSet, WaitSetAttachmentId, WaitSetBuilder, WaitSetGuard};
+pub use crate::waitset::{
+    WaitSet, WaitSetAttachmentError, WaitSetAttachmentId, WaitSetBuilder, WaitSetCreateError,
+    WaitSetGuard, WaitSetRunError, WaitSetRunResult,
+};
 pub use iceoryx2_bb_container::semantic_string::SemanticString;
 pub use iceoryx2_bb_container::semantic_string::SemanticStringError;
+pub use iceoryx2_bb_derive_macros::IntoEventId;
 pub use iceoryx2_bb_derive_macros::PlacementDefault;
 pub use iceoryx2_bb_derive_macros::ZeroCopySend;
 pub use iceoryx2_bb_elementary::alignment::Alignment;
@@ -30,6 +30,7 @@
 //! # }
 //! ```
 
+use core::time::Duration;
 use core::{fmt::Debug, ops::Deref};
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -74,14 +75,20 @@ impl<
     > Debug for Sample<Service, Payload, UserHeader>
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // the payload itself is not printed, only the metadata of the sample
+        let header = self.header();
         write!(
             f,
-            "Sample<{}, {}, {}> {{ ptr: {:?}, details: {:?} }}",
+            "Sample<{}, {}, {}> {{ publisher_id: {:?}, sequence_number: {}, timestamp: {:?}, number_of_elements: {}, payload_size: {}, offset: {:?} }}",
             core::any::type_name::<Payload>(),
             core::any::type_name::<UserHeader>(),
             core::any::type_name::<Service>(),
-            self.ptr,
-            self.details,
+            header.publisher_id(),
+            header.sequence_number(),
+            header.timestamp(),
+            header.number_of_elements(),
+            core::mem::size_of_val(self.payload()),
+            self.details.offset,
         )
     }
 }
@@ -137,4 +144,20 @@ impl<
     pub fn origin(&self) -> UniquePublisherId {
         UniquePublisherId(UniqueSystemId::from(self.details.origin))
     }
+
+    /// Returns the sequence number the [`Publisher`](crate::port::publisher::Publisher)
+    /// assigned to the [`Sample`]. See [`Header::sequence_number()`].
+    pub fn sequence_number(&self) -> u64 {
+        self.header().sequence_number()
+    }
+
+    /// Returns the time at which the [`Publisher`](crate::port::publisher::Publisher) sent the
+    /// [`Sample`], see [`Header::timestamp()`]. It is measured with the default, monotonic if
+    /// available, clock of [`Time`](iceoryx2_bb_posix::clock::Time), so that the end-to-end
+    /// latency is `Time::now()?.as_duration() - publish_timestamp`. Returns [`None`] when the
+    /// [`Service`](crate::service::Service) was created without
+    /// [`Builder::enable_timestamps()`](crate::service::builder::publish_subscribe::Builder::enable_timestamps()).
+    pub fn publish_timestamp(&self) -> Option<Duration> {
+        self.header().timestamp()
+    }
 }
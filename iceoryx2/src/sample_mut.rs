@@ -64,7 +64,9 @@
 //! ```
 
 use crate::{
-    port::publisher::PublisherSharedState, port::SendError, raw_sample::RawSampleMut,
+    port::publisher::{DeliveryReport, PublisherSharedState},
+    port::SendError,
+    raw_sample::RawSampleMut,
     service::header::publish_subscribe::Header,
 };
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -73,6 +75,7 @@ use iceoryx2_cal::shared_memory::*;
 
 use core::fmt::{Debug, Formatter};
 use core::ops::{Deref, DerefMut};
+use core::time::Duration;
 
 /// Acquired by a [`crate::port::publisher::Publisher`] via
 ///  * [`crate::port::publisher::Publisher::loan()`],
@@ -133,15 +136,21 @@ impl<
     > Debug for SampleMut<Service, Payload, UserHeader>
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        // the payload itself is not printed since it might not be initialized yet
+        let header = self.header();
         write!(
             f,
-            "SampleMut<{}, {}, {}> {{ publisher_shared_state: {:?}, offset_to_chunk: {:?}, sample_size: {} }}",
+            "SampleMut<{}, {}, {}> {{ publisher_id: {:?}, sequence_number: {}, timestamp: {:?}, number_of_elements: {}, payload_size: {}, sample_size: {}, offset: {:?} }}",
             core::any::type_name::<Service>(),
             core::any::type_name::<Payload>(),
             core::any::type_name::<UserHeader>(),
-            self.publisher_shared_state,
+            header.publisher_id(),
+            header.sequence_number(),
+            header.timestamp(),
+            header.number_of_elements(),
+            core::mem::size_of_val(self.ptr.as_payload_ref()),
+            self.sample_size,
             self.offset_to_chunk,
-            self.sample_size
         )
     }
 }
@@ -331,9 +340,99 @@ impl<
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<usize, SendError> {
-        self.publisher_shared_state
-            .lock()
-            .send_sample(self.offset_to_chunk, self.sample_size)
+    pub fn send(mut self) -> Result<usize, SendError> {
+        self.publisher_shared_state.lock().send_sample(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            self.sample_size,
+            None,
+        )
+    }
+
+    /// Like [`SampleMut::send()`] but instead of the number of recipients it returns a
+    /// [`DeliveryReport`] with the [`DeliveryState`](crate::port::DeliveryState) of every
+    /// connected [`crate::port::subscriber::Subscriber`], for instance to find out which
+    /// [`crate::port::subscriber::Subscriber`] did not receive the [`SampleMut`] due to a full
+    /// buffer. Since the report has to be allocated, [`SampleMut::send()`] shall be preferred
+    /// when the details are not required.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::DeliveryState;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 4567;
+    ///
+    /// let report = sample.send_detailed()?;
+    /// for (subscriber_id, state) in report.deliveries() {
+    ///     if *state != DeliveryState::Delivered {
+    ///         println!("subscriber {:?} missed the sample ({:?})", subscriber_id, state);
+    ///     }
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_detailed(mut self) -> Result<DeliveryReport, SendError> {
+        self.publisher_shared_state.lock().send_sample_with_report(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            self.sample_size,
+        )
+    }
+
+    /// Like [`SampleMut::send()`] but when the [`crate::port::publisher::Publisher`] uses
+    /// [`UnableToDeliverStrategy::Block`](crate::prelude::UnableToDeliverStrategy::Block) it
+    /// waits at most for the provided timeout until the
+    /// [`crate::port::subscriber::Subscriber`]s have space in their buffers.
+    /// [`crate::port::subscriber::Subscriber`]s whose buffer is still full after the timeout
+    /// has passed do not receive the [`SampleMut`].
+    ///
+    /// On success the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data is returned, otherwise a [`SendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .enable_safe_overflow(false)
+    /// #     .open_or_create()?;
+    /// let publisher = service
+    ///     .publisher_builder()
+    ///     .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+    ///     .create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 4567;
+    ///
+    /// sample.send_timeout(Duration::from_millis(10))?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_timeout(mut self, timeout: Duration) -> Result<usize, SendError> {
+        self.publisher_shared_state.lock().send_sample(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            self.sample_size,
+            Some(timeout),
+        )
     }
 }
@@ -0,0 +1,116 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .publish_subscribe::<u64>()
+//! #   .open_or_create()?;
+//! # let publisher = service.publisher_builder().create()?;
+//! let mut subscriber = service.subscriber_builder().create()?;
+//! # publisher.send_copy(1234)?;
+//!
+//! if let Some(sample) = subscriber.peek()? {
+//!     println!("next sample: {:?}", *sample);
+//! }
+//!
+//! // the peeked sample is still in the buffer and returned by the next receive call
+//! if let Some(sample) = subscriber.receive()? {
+//!     println!("received: {:?}", *sample);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::{fmt::Debug, marker::PhantomData, ops::Deref};
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+
+use crate::port::port_identifiers::UniquePublisherId;
+use crate::raw_sample::RawSample;
+use crate::service::header::publish_subscribe::Header;
+
+/// A borrow of the next [`Sample`](crate::sample::Sample) that is acquired with
+/// [`Subscriber::peek()`](crate::port::subscriber::Subscriber::peek()). In contrast to the
+/// [`Sample`](crate::sample::Sample) it does not remove the data from the
+/// [`Subscriber`](crate::port::subscriber::Subscriber) so that the next call to
+/// [`Subscriber::receive()`](crate::port::subscriber::Subscriber::receive()) returns the
+/// same data.
+///
+/// The [`SampleRef`] mutably borrows the [`Subscriber`](crate::port::subscriber::Subscriber),
+/// therefore no data can be received while it is alive.
+pub struct SampleRef<'subscriber, Payload: Debug + ?Sized + ZeroCopySend, UserHeader: ZeroCopySend>
+{
+    pub(crate) ptr: RawSample<Header, UserHeader, Payload>,
+    pub(crate) origin: u128,
+    pub(crate) _subscriber: PhantomData<&'subscriber mut ()>,
+}
+
+impl<Payload: Debug + ZeroCopySend + ?Sized, UserHeader: ZeroCopySend> Debug
+    for SampleRef<'_, Payload, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SampleRef<{}, {}> {{ ptr: {:?}, origin: {} }}",
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<UserHeader>(),
+            self.ptr,
+            self.origin,
+        )
+    }
+}
+
+impl<Payload: Debug + ZeroCopySend + ?Sized, UserHeader: ZeroCopySend> Deref
+    for SampleRef<'_, Payload, UserHeader>
+{
+    type Target = Payload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<Payload: Debug + ZeroCopySend + ?Sized, UserHeader: ZeroCopySend>
+    SampleRef<'_, Payload, UserHeader>
+{
+    /// Returns a reference to the payload of the [`SampleRef`]
+    pub fn payload(&self) -> &Payload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a reference to the user_header of the [`SampleRef`]
+    pub fn user_header(&self) -> &UserHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a reference to the [`Header`] of the [`SampleRef`].
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
+    pub fn origin(&self) -> UniquePublisherId {
+        UniquePublisherId(UniqueSystemId::from(self.origin))
+    }
+
+    /// Returns the sequence number the [`Publisher`](crate::port::publisher::Publisher)
+    /// assigned to the [`SampleRef`]. See [`Header::sequence_number()`].
+    pub fn sequence_number(&self) -> u64 {
+        self.header().sequence_number()
+    }
+}
@@ -90,7 +90,9 @@
 //!
 //! # fn main() -> Result<(), Box<dyn core::error::Error>> {
 //! let services = ipc::Service::list(Config::global_config(), |service| {
-//!     println!("\n{:#?}", &service.static_details.attributes());
+//!     if let Ok(service) = service {
+//!         println!("\n{:#?}", &service.static_details.attributes());
+//!     }
 //!     CallbackProgression::Continue
 //! })?;
 //! # Ok(())
@@ -0,0 +1,234 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! // creates or opens the services "My/Funk/ServiceName/forward" and
+//! // "My/Funk/ServiceName/backward"
+//! let initiator = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .channel::<u64, u64>()
+//!     .subscriber_max_buffer_size(4)
+//!     .open_or_create_initiator()?;
+//!
+//! initiator.send(1234)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::sync::Arc;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use crate::node::SharedNode;
+use crate::port::channel::Channel;
+use crate::port::notifying_publisher::NotifyingPublisherCreateError;
+use crate::port::notifying_subscriber::NotifyingSubscriberCreateError;
+use crate::service;
+use crate::service::port_factory::notifying_publish_subscribe::PortFactory;
+use crate::service::service_name::ServiceName;
+
+use super::notifying_publish_subscribe::NotifyingPublishSubscribeOpenOrCreateError;
+
+const FORWARD_SUFFIX: &str = "/forward";
+const BACKWARD_SUFFIX: &str = "/backward";
+
+/// Failures that can occur when a [`Channel`] is created with the [`Builder`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ChannelCreateError {
+    /// The [`ServiceName`] of one direction, the [`ServiceName`] of the channel followed by
+    /// `/forward` or `/backward`, is not a valid [`ServiceName`], for instance because it
+    /// is too long.
+    InvalidServiceName,
+    /// The service of one direction could not be opened or created.
+    NotifyingPublishSubscribeOpenOrCreateError(NotifyingPublishSubscribeOpenOrCreateError),
+    /// The sending endpoint could not be created.
+    NotifyingPublisherCreateError(NotifyingPublisherCreateError),
+    /// The receiving endpoint could not be created.
+    NotifyingSubscriberCreateError(NotifyingSubscriberCreateError),
+}
+
+impl From<NotifyingPublishSubscribeOpenOrCreateError> for ChannelCreateError {
+    fn from(value: NotifyingPublishSubscribeOpenOrCreateError) -> Self {
+        ChannelCreateError::NotifyingPublishSubscribeOpenOrCreateError(value)
+    }
+}
+
+impl From<NotifyingPublisherCreateError> for ChannelCreateError {
+    fn from(value: NotifyingPublisherCreateError) -> Self {
+        ChannelCreateError::NotifyingPublisherCreateError(value)
+    }
+}
+
+impl From<NotifyingSubscriberCreateError> for ChannelCreateError {
+    fn from(value: NotifyingSubscriberCreateError) -> Self {
+        ChannelCreateError::NotifyingSubscriberCreateError(value)
+    }
+}
+
+impl core::fmt::Display for ChannelCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ChannelCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for ChannelCreateError {}
+
+/// Builder to create a bidirectional [`Channel`]. The channel consists of two
+/// notifying publish-subscribe [`Service`](crate::service::Service)s, see
+/// [`super::notifying_publish_subscribe::Builder`]. The initiator sends `RequestPayload` on the
+/// service `{name}/forward` and receives `ResponsePayload` on the service `{name}/backward`,
+/// the responder does the opposite.
+///
+/// The settings are applied to both [`Service`](crate::service::Service)s.
+#[derive(Debug)]
+pub struct Builder<
+    RequestPayload: Debug + ZeroCopySend,
+    ResponsePayload: Debug + ZeroCopySend,
+    ServiceType: service::Service,
+> {
+    name: ServiceName,
+    shared_node: Arc<SharedNode<ServiceType>>,
+    history_size: Option<usize>,
+    subscriber_max_buffer_size: Option<usize>,
+    max_nodes: Option<usize>,
+    _payload: PhantomData<fn() -> (RequestPayload, ResponsePayload)>,
+}
+
+impl<
+        RequestPayload: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend,
+        ServiceType: service::Service,
+    > Clone for Builder<RequestPayload, ResponsePayload, ServiceType>
+{
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            shared_node: self.shared_node.clone(),
+            history_size: self.history_size,
+            subscriber_max_buffer_size: self.subscriber_max_buffer_size,
+            max_nodes: self.max_nodes,
+            _payload: PhantomData,
+        }
+    }
+}
+
+impl<
+        RequestPayload: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend,
+        ServiceType: service::Service,
+    > Builder<RequestPayload, ResponsePayload, ServiceType>
+{
+    pub(crate) fn new(name: ServiceName, shared_node: Arc<SharedNode<ServiceType>>) -> Self {
+        Self {
+            name,
+            shared_node,
+            history_size: None,
+            subscriber_max_buffer_size: None,
+            max_nodes: None,
+            _payload: PhantomData,
+        }
+    }
+
+    /// See [`super::notifying_publish_subscribe::Builder::history_size()`].
+    pub fn history_size(mut self, value: usize) -> Self {
+        self.history_size = Some(value);
+        self
+    }
+
+    /// See [`super::notifying_publish_subscribe::Builder::subscriber_max_buffer_size()`].
+    pub fn subscriber_max_buffer_size(mut self, value: usize) -> Self {
+        self.subscriber_max_buffer_size = Some(value);
+        self
+    }
+
+    /// See [`super::notifying_publish_subscribe::Builder::max_nodes()`].
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.max_nodes = Some(value);
+        self
+    }
+
+    /// Opens or creates both [`Service`](crate::service::Service)s and returns the
+    /// [`Channel`] of the initiator that sends `RequestPayload` and receives
+    /// `ResponsePayload`.
+    pub fn open_or_create_initiator(
+        self,
+    ) -> Result<Channel<ServiceType, RequestPayload, ResponsePayload>, ChannelCreateError> {
+        let forward = self.open_or_create_direction::<RequestPayload>(FORWARD_SUFFIX)?;
+        let backward = self.open_or_create_direction::<ResponsePayload>(BACKWARD_SUFFIX)?;
+        self.create_channel(&forward, &backward)
+    }
+
+    /// Opens or creates both [`Service`](crate::service::Service)s and returns the
+    /// [`Channel`] of the responder that receives `RequestPayload` and sends
+    /// `ResponsePayload`.
+    pub fn open_or_create_responder(
+        self,
+    ) -> Result<Channel<ServiceType, ResponsePayload, RequestPayload>, ChannelCreateError> {
+        let forward = self.open_or_create_direction::<RequestPayload>(FORWARD_SUFFIX)?;
+        let backward = self.open_or_create_direction::<ResponsePayload>(BACKWARD_SUFFIX)?;
+        self.create_channel(&backward, &forward)
+    }
+
+    fn create_channel<SendPayload: Debug + ZeroCopySend, ReceivePayload: Debug + ZeroCopySend>(
+        &self,
+        outgoing: &PortFactory<ServiceType, SendPayload>,
+        incoming: &PortFactory<ServiceType, ReceivePayload>,
+    ) -> Result<Channel<ServiceType, SendPayload, ReceivePayload>, ChannelCreateError> {
+        let publisher = fail!(from self, when outgoing.publisher_builder().create(),
+            "Unable to create the sending endpoint of the channel.");
+        let subscriber = fail!(from self, when incoming.subscriber_builder().create(),
+            "Unable to create the receiving endpoint of the channel.");
+
+        Ok(Channel::new(publisher, subscriber))
+    }
+
+    fn open_or_create_direction<Payload: Debug + ZeroCopySend>(
+        &self,
+        suffix: &str,
+    ) -> Result<PortFactory<ServiceType, Payload>, ChannelCreateError> {
+        let name = format!("{}{}", self.name, suffix);
+        let name = match ServiceName::new(&name) {
+            Ok(name) => name,
+            Err(e) => {
+                fail!(from self, with ChannelCreateError::InvalidServiceName,
+                    "Unable to derive the service name \"{}\" of the channel ({:?}).", name, e);
+            }
+        };
+
+        let mut builder = super::Builder::new(&name, self.shared_node.clone())
+            .notifying_publish_subscribe::<Payload>();
+        if let Some(value) = self.history_size {
+            builder = builder.history_size(value);
+        }
+        if let Some(value) = self.subscriber_max_buffer_size {
+            builder = builder.subscriber_max_buffer_size(value);
+        }
+        if let Some(value) = self.max_nodes {
+            builder = builder.max_nodes(value);
+        }
+
+        Ok(fail!(from self, when builder.open_or_create(),
+            "Unable to open or create the service \"{}\" of the channel.", name))
+    }
+}
@@ -17,13 +17,13 @@
 pub use crate::port::event_id::EventId;
 use crate::service::builder::OpenDynamicStorageFailure;
 use crate::service::dynamic_config::MessagingPatternSettings;
-use crate::service::port_factory::event;
+use crate::service::port_factory::{event, ServiceTiming};
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 use crate::service::{self, dynamic_config::event::DynamicConfigSettings};
-use builder::RETRY_LIMIT;
+use builder::{Backoff, BackoffPolicy, RETRY_LIMIT};
 use iceoryx2_bb_log::{fail, fatal_panic};
-use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use static_config::event::Deadline;
 
@@ -166,7 +166,15 @@ impl core::fmt::Display for EventOpenOrCreateError {
     }
 }
 
-impl core::error::Error for EventOpenOrCreateError {}
+impl core::error::Error for EventOpenOrCreateError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::EventOpenError(e) => Some(e),
+            Self::EventCreateError(e) => Some(e),
+            Self::SystemInFlux => None,
+        }
+    }
+}
 
 impl From<ServiceState> for EventOpenOrCreateError {
     fn from(value: ServiceState) -> Self {
@@ -190,6 +198,9 @@ pub struct Builder<ServiceType: service::Service> {
     verify_notifier_created_event: bool,
     verify_notifier_dropped_event: bool,
     verify_notifier_dead_event: bool,
+    backoff_policy: Option<BackoffPolicy>,
+    creation_start: Option<Time>,
+    creation_attempts: usize,
 }
 
 impl<ServiceType: service::Service> Builder<ServiceType> {
@@ -204,6 +215,9 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             verify_notifier_dead_event: false,
             verify_notifier_created_event: false,
             verify_notifier_dropped_event: false,
+            backoff_policy: None,
+            creation_start: None,
+            creation_attempts: 0,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
@@ -213,6 +227,22 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         new_self
     }
 
+    fn start_creation_timing(&mut self) {
+        if self.creation_start.is_none() {
+            self.creation_start = Time::now_with_clock(ClockType::Monotonic).ok();
+        }
+    }
+
+    fn creation_stats(&self) -> ServiceTiming {
+        ServiceTiming {
+            waited: self
+                .creation_start
+                .and_then(|start| start.elapsed().ok())
+                .unwrap_or(Duration::ZERO),
+            attempts: self.creation_attempts,
+        }
+    }
+
     fn config_details(&mut self) -> &mut static_config::event::StaticConfig {
         match self.base.service_config.messaging_pattern {
             MessagingPattern::Event(ref mut v) => v,
@@ -325,6 +355,14 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// Defines the [`BackoffPolicy`] that is used in [`Builder::open_or_create()`] when the
+    /// [`Service`] is concurrently created or removed by another instance. Without a
+    /// [`BackoffPolicy`], the builder retries a few times without waiting.
+    pub fn backoff_policy(mut self, value: BackoffPolicy) -> Self {
+        self.backoff_policy = Some(value);
+        self
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(self) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
@@ -340,14 +378,30 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         verifier: &AttributeVerifier,
     ) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
         let msg = "Unable to open or create event service";
+        self.start_creation_timing();
+        let creation_timeout = self
+            .base
+            .shared_node
+            .config()
+            .global
+            .service
+            .creation_timeout;
+        let mut backoff = self
+            .backoff_policy
+            .map(|policy| Backoff::new(policy, creation_timeout));
 
         let mut retry_count = 0;
         loop {
-            if RETRY_LIMIT < retry_count {
+            let retries_exhausted = match backoff.as_mut() {
+                None => RETRY_LIMIT < retry_count,
+                Some(backoff) => 0 < retry_count && !backoff.wait(),
+            };
+
+            if retries_exhausted {
                 fail!(from self,
                       with EventOpenOrCreateError::SystemInFlux,
-                      "{} since an instance is creating and removing the same service repeatedly.",
-                      msg);
+                      "{} since an instance is creating and removing the same service repeatedly ({} attempts, waited {:?}).",
+                      msg, retry_count, backoff.map_or(Duration::ZERO, |b| b.total_wait_time));
             }
             retry_count += 1;
 
@@ -374,6 +428,16 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self.open_with_attributes(&AttributeVerifier::new())
     }
 
+    /// Opens an existing [`Service`] for a process that creates only
+    /// [`Listener`](crate::port::listener::Listener)s. The requirement defined with
+    /// [`Builder::max_notifiers()`] is not verified, all other requirements, like the
+    /// messaging pattern, the listener count and the event id range, are verified like in
+    /// [`Builder::open()`].
+    pub fn open_listener_only(mut self) -> Result<event::PortFactory<ServiceType>, EventOpenError> {
+        self.verify_max_notifiers = false;
+        self.open()
+    }
+
     /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
     /// requirements are not satisfied the open process will fail.
     pub fn open_with_attributes(
@@ -381,9 +445,11 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         verifier: &AttributeVerifier,
     ) -> Result<event::PortFactory<ServiceType>, EventOpenError> {
         let msg = "Unable to open event service";
+        self.start_creation_timing();
 
         let mut service_open_retry_count = 0;
         loop {
+            self.creation_attempts += 1;
             match self.base.is_service_available(msg)? {
                 None => {
                     fail!(from self, with EventOpenError::DoesNotExist,
@@ -438,13 +504,17 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                         service_tag.release_ownership();
                     }
 
-                    return Ok(event::PortFactory::new(service::ServiceState::new(
-                        static_config,
-                        self.base.shared_node,
-                        dynamic_config,
-                        static_storage,
-                        NoResource,
-                    )));
+                    let creation_stats = self.creation_stats();
+                    return Ok(event::PortFactory::new(
+                        service::ServiceState::new(
+                            static_config,
+                            self.base.shared_node,
+                            dynamic_config,
+                            static_storage,
+                            NoResource,
+                        )
+                        .with_creation_stats(creation_stats),
+                    ));
                 }
             }
         }
@@ -470,6 +540,8 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self.adjust_attributes_to_meaningful_values();
 
         let msg = "Unable to create event service";
+        self.start_creation_timing();
+        self.creation_attempts += 1;
 
         match self.base.is_service_available(msg)? {
             None => {
@@ -544,13 +616,16 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     service_tag.release_ownership();
                 }
 
-                Ok(event::PortFactory::new(service::ServiceState::new(
-                    self.base.service_config.clone(),
-                    self.base.shared_node.clone(),
-                    dynamic_config,
-                    unlocked_static_details,
-                    NoResource,
-                )))
+                Ok(event::PortFactory::new(
+                    service::ServiceState::new(
+                        self.base.service_config.clone(),
+                        self.base.shared_node.clone(),
+                        dynamic_config,
+                        unlocked_static_details,
+                        NoResource,
+                    )
+                    .with_creation_stats(self.creation_stats()),
+                ))
             }
             Some(_) => {
                 fail!(from self, with EventCreateError::AlreadyExists,
@@ -14,12 +14,25 @@
 //!
 //! See [`crate::service`]
 
+/// Builder for a bidirectional [`Channel`](crate::port::channel::Channel) between two
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) services
+pub mod channel;
+
 /// Builder for [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 pub mod event;
 
 /// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 pub mod publish_subscribe;
 
+/// Builder for a [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// [`Service`] that is bound to a [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
+/// [`Service`] to notify subscribers about new data
+pub mod notifying_publish_subscribe;
+
+/// Builder for a [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// [`Service`] that transfers serialized types which are not shared-memory compatible
+pub mod publish_subscribe_serde;
+
 /// Builder for [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
 pub mod request_response;
 
@@ -35,6 +48,7 @@ use alloc::sync::Arc;
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::marker::PhantomData;
+use core::time::Duration;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -42,6 +56,7 @@ use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_log::warn;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::clock::nanosleep;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::dynamic_storage::DynamicStorageOpenError;
 use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
@@ -68,6 +83,72 @@ enum ServiceState {
     Corrupted,
 }
 
+/// Defines how long a service builder waits before it retries to open or create a
+/// [`Service`] that is concurrently created or removed by another instance. The wait time
+/// starts with the initial duration and is multiplied with every retry until the maximum
+/// duration is reached. The retries stop when the accumulated wait time would exceed the
+/// `creation_timeout` of the [`Config`](crate::config::Config).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackoffPolicy {
+    initial: Duration,
+    max: Duration,
+    multiplier: u32,
+}
+
+impl BackoffPolicy {
+    /// Creates a new [`BackoffPolicy`]. A `multiplier` of `0` is adjusted to `1`.
+    pub fn new(initial: Duration, max: Duration, multiplier: u32) -> Self {
+        Self {
+            initial,
+            max: max.max(initial),
+            multiplier: multiplier.max(1),
+        }
+    }
+
+    /// Returns the time that is waited before the retry with the number `attempt`, starting
+    /// with `0` for the first retry.
+    pub fn wait_time(&self, attempt: u32) -> Duration {
+        self.multiplier
+            .checked_pow(attempt)
+            .and_then(|factor| self.initial.checked_mul(factor))
+            .map_or(self.max, |wait_time| wait_time.min(self.max))
+    }
+}
+
+/// Tracks the retries of a [`BackoffPolicy`].
+#[derive(Debug)]
+struct Backoff {
+    policy: BackoffPolicy,
+    timeout: Duration,
+    attempts: u32,
+    total_wait_time: Duration,
+}
+
+impl Backoff {
+    fn new(policy: BackoffPolicy, timeout: Duration) -> Self {
+        Self {
+            policy,
+            timeout,
+            attempts: 0,
+            total_wait_time: Duration::ZERO,
+        }
+    }
+
+    /// Waits before the next retry. Returns false when the timeout would be exceeded.
+    fn wait(&mut self) -> bool {
+        let wait_time = self.policy.wait_time(self.attempts);
+        if self.timeout < self.total_wait_time + wait_time {
+            return false;
+        }
+
+        // an interrupted sleep only shortens the wait, the retry is performed anyway
+        let _ = nanosleep(wait_time);
+        self.attempts += 1;
+        self.total_wait_time += wait_time;
+        true
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, ZeroCopySend, Clone)]
 #[doc(hidden)]
@@ -166,6 +247,41 @@ impl<S: Service> Builder<S> {
         .publish_subscribe()
     }
 
+    /// Create a new builder to create a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// together with a [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`]
+    /// of the same [`ServiceName`] that notifies the subscribers whenever new data was sent.
+    pub fn notifying_publish_subscribe<PayloadType: Debug + ?Sized + ZeroCopySend>(
+        self,
+    ) -> notifying_publish_subscribe::Builder<PayloadType, S> {
+        notifying_publish_subscribe::Builder::new(
+            self.clone().publish_subscribe::<PayloadType>(),
+            self.event(),
+        )
+    }
+
+    /// Create a new builder to create a bidirectional
+    /// [`Channel`](crate::port::channel::Channel) that sends `RequestPayload` from the
+    /// initiator to the responder and `ResponsePayload` back. Every direction is a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// with notifications, see [`channel::Builder`].
+    pub fn channel<RequestPayload: Debug + ZeroCopySend, ResponsePayload: Debug + ZeroCopySend>(
+        self,
+    ) -> channel::Builder<RequestPayload, ResponsePayload, S> {
+        channel::Builder::new(self.name, self.shared_node)
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// that transfers values of a type that is not shared-memory compatible. The values are
+    /// serialized into a byte slice, by default with
+    /// [`Postcard`](iceoryx2_cal::serialize::postcard::Postcard).
+    pub fn publish_subscribe_serde<T: serde::Serialize + serde::de::DeserializeOwned>(
+        self,
+    ) -> publish_subscribe_serde::Builder<T, S> {
+        publish_subscribe_serde::Builder::new(self.publish_subscribe::<[u8]>())
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
     pub fn event(self) -> event::Builder<S> {
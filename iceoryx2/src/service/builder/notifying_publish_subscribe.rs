@@ -0,0 +1,352 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .notifying_publish_subscribe::<u64>()
+//!     // the limits are applied to the publish-subscribe and the event service
+//!     .max_publishers(2)
+//!     .max_subscribers(4)
+//!     .event_id(EventId::new(7))
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! publisher.send_copy(1234)?;
+//!
+//! if subscriber.try_wait()? {
+//!     while let Some(sample) = subscriber.receive()? {
+//!         println!("received: {:?}", *sample);
+//!     }
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use crate::port::event_id::EventId;
+use crate::service;
+use crate::service::port_factory::{event as event_factory, notifying_publish_subscribe};
+
+use super::event::{EventCreateError, EventOpenError, EventOpenOrCreateError};
+use super::publish_subscribe::{
+    PublishSubscribeCreateError, PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
+};
+use super::{event, publish_subscribe};
+
+/// Failures that can occur when a notifying publish-subscribe service is created.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum NotifyingPublishSubscribeCreateError {
+    /// The underlying publish-subscribe [`Service`](crate::service::Service) could not be
+    /// created.
+    PublishSubscribeCreateError(PublishSubscribeCreateError),
+    /// The underlying event [`Service`](crate::service::Service) could not be created.
+    EventCreateError(EventCreateError),
+}
+
+impl From<PublishSubscribeCreateError> for NotifyingPublishSubscribeCreateError {
+    fn from(value: PublishSubscribeCreateError) -> Self {
+        NotifyingPublishSubscribeCreateError::PublishSubscribeCreateError(value)
+    }
+}
+
+impl From<EventCreateError> for NotifyingPublishSubscribeCreateError {
+    fn from(value: EventCreateError) -> Self {
+        NotifyingPublishSubscribeCreateError::EventCreateError(value)
+    }
+}
+
+impl core::fmt::Display for NotifyingPublishSubscribeCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NotifyingPublishSubscribeCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for NotifyingPublishSubscribeCreateError {}
+
+/// Failures that can occur when a notifying publish-subscribe service is opened.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum NotifyingPublishSubscribeOpenError {
+    /// The underlying publish-subscribe [`Service`](crate::service::Service) could not be
+    /// opened.
+    PublishSubscribeOpenError(PublishSubscribeOpenError),
+    /// The underlying event [`Service`](crate::service::Service) could not be opened.
+    EventOpenError(EventOpenError),
+}
+
+impl From<PublishSubscribeOpenError> for NotifyingPublishSubscribeOpenError {
+    fn from(value: PublishSubscribeOpenError) -> Self {
+        NotifyingPublishSubscribeOpenError::PublishSubscribeOpenError(value)
+    }
+}
+
+impl From<EventOpenError> for NotifyingPublishSubscribeOpenError {
+    fn from(value: EventOpenError) -> Self {
+        NotifyingPublishSubscribeOpenError::EventOpenError(value)
+    }
+}
+
+impl core::fmt::Display for NotifyingPublishSubscribeOpenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NotifyingPublishSubscribeOpenError::{self:?}")
+    }
+}
+
+impl core::error::Error for NotifyingPublishSubscribeOpenError {}
+
+/// Failures that can occur when a notifying publish-subscribe service is opened or created.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum NotifyingPublishSubscribeOpenOrCreateError {
+    /// The underlying publish-subscribe [`Service`](crate::service::Service) could not be
+    /// opened or created.
+    PublishSubscribeOpenOrCreateError(PublishSubscribeOpenOrCreateError),
+    /// The underlying event [`Service`](crate::service::Service) could not be opened or
+    /// created.
+    EventOpenOrCreateError(EventOpenOrCreateError),
+}
+
+impl From<PublishSubscribeOpenOrCreateError> for NotifyingPublishSubscribeOpenOrCreateError {
+    fn from(value: PublishSubscribeOpenOrCreateError) -> Self {
+        NotifyingPublishSubscribeOpenOrCreateError::PublishSubscribeOpenOrCreateError(value)
+    }
+}
+
+impl From<EventOpenOrCreateError> for NotifyingPublishSubscribeOpenOrCreateError {
+    fn from(value: EventOpenOrCreateError) -> Self {
+        NotifyingPublishSubscribeOpenOrCreateError::EventOpenOrCreateError(value)
+    }
+}
+
+impl core::fmt::Display for NotifyingPublishSubscribeOpenOrCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NotifyingPublishSubscribeOpenOrCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for NotifyingPublishSubscribeOpenOrCreateError {}
+
+/// Builder to create or open a publish-subscribe [`Service`](crate::service::Service) together
+/// with an event [`Service`](crate::service::Service) of the same
+/// [`ServiceName`](crate::service::service_name::ServiceName). The port limits are applied to
+/// both [`Service`](crate::service::Service)s so that every
+/// [`NotifyingPublisher`](crate::port::notifying_publisher::NotifyingPublisher) and
+/// [`NotifyingSubscriber`](crate::port::notifying_subscriber::NotifyingSubscriber) always
+/// acquires both of its underlying ports.
+///
+/// When the second [`Service`](crate::service::Service) cannot be created or opened, the
+/// first one is released again, so that either both or none of them are acquired.
+#[derive(Debug)]
+pub struct Builder<Payload: Debug + ?Sized + ZeroCopySend, ServiceType: service::Service> {
+    publish_subscribe: publish_subscribe::Builder<Payload, (), ServiceType>,
+    event: event::Builder<ServiceType>,
+    event_id: EventId,
+}
+
+impl<Payload: Debug + ?Sized + ZeroCopySend, ServiceType: service::Service>
+    Builder<Payload, ServiceType>
+{
+    pub(crate) fn new(
+        publish_subscribe: publish_subscribe::Builder<Payload, (), ServiceType>,
+        event: event::Builder<ServiceType>,
+    ) -> Self {
+        Self {
+            publish_subscribe,
+            event,
+            event_id: EventId::default(),
+        }
+    }
+
+    /// Defines the [`EventId`] that is emitted whenever a
+    /// [`NotifyingPublisher`](crate::port::notifying_publisher::NotifyingPublisher) sends data.
+    /// It must not exceed the maximum [`EventId`] supported by the event
+    /// [`Service`](crate::service::Service).
+    pub fn event_id(mut self, value: EventId) -> Self {
+        self.event_id = value;
+        self
+    }
+
+    /// Defines how many publishers and notifiers shall be supported at most. See
+    /// [`publish_subscribe::Builder::max_publishers()`] and
+    /// [`event::Builder::max_notifiers()`].
+    pub fn max_publishers(mut self, value: usize) -> Self {
+        self.publish_subscribe = self.publish_subscribe.max_publishers(value);
+        self.event = self.event.max_notifiers(value);
+        self
+    }
+
+    /// Defines how many subscribers and listeners shall be supported at most. See
+    /// [`publish_subscribe::Builder::max_subscribers()`] and
+    /// [`event::Builder::max_listeners()`].
+    pub fn max_subscribers(mut self, value: usize) -> Self {
+        self.publish_subscribe = self.publish_subscribe.max_subscribers(value);
+        self.event = self.event.max_listeners(value);
+        self
+    }
+
+    /// Defines how many [`Node`](crate::node::Node)s shall be able to open both
+    /// [`Service`](crate::service::Service)s in parallel.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.publish_subscribe = self.publish_subscribe.max_nodes(value);
+        self.event = self.event.max_nodes(value);
+        self
+    }
+
+    /// See [`publish_subscribe::Builder::history_size()`].
+    pub fn history_size(mut self, value: usize) -> Self {
+        self.publish_subscribe = self.publish_subscribe.history_size(value);
+        self
+    }
+
+    /// See [`publish_subscribe::Builder::subscriber_max_buffer_size()`].
+    pub fn subscriber_max_buffer_size(mut self, value: usize) -> Self {
+        self.publish_subscribe = self.publish_subscribe.subscriber_max_buffer_size(value);
+        self
+    }
+
+    /// See [`publish_subscribe::Builder::subscriber_max_borrowed_samples()`].
+    pub fn subscriber_max_borrowed_samples(mut self, value: usize) -> Self {
+        self.publish_subscribe = self
+            .publish_subscribe
+            .subscriber_max_borrowed_samples(value);
+        self
+    }
+
+    /// See [`publish_subscribe::Builder::enable_safe_overflow()`].
+    pub fn enable_safe_overflow(mut self, value: bool) -> Self {
+        self.publish_subscribe = self.publish_subscribe.enable_safe_overflow(value);
+        self
+    }
+
+    fn compose(
+        publish_subscribe: crate::service::port_factory::publish_subscribe::PortFactory<
+            ServiceType,
+            Payload,
+            (),
+        >,
+        event: event_factory::PortFactory<ServiceType>,
+        event_id: EventId,
+    ) -> notifying_publish_subscribe::PortFactory<ServiceType, Payload> {
+        notifying_publish_subscribe::PortFactory {
+            publish_subscribe,
+            event,
+            event_id,
+        }
+    }
+}
+
+impl<Payload: Debug + ZeroCopySend, ServiceType: service::Service> Builder<Payload, ServiceType> {
+    /// If the [`Service`](crate::service::Service)s exist, they will be opened otherwise new
+    /// [`Service`](crate::service::Service)s will be created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        notifying_publish_subscribe::PortFactory<ServiceType, Payload>,
+        NotifyingPublishSubscribeOpenOrCreateError,
+    > {
+        let origin = format!("{self:?}");
+        let publish_subscribe = fail!(from origin, when self.publish_subscribe.open_or_create(),
+            "Unable to open or create the publish subscribe service.");
+        let event = fail!(from origin, when self.event.open_or_create(),
+            "Unable to open or create the event service.");
+        Ok(Self::compose(publish_subscribe, event, self.event_id))
+    }
+
+    /// Opens the existing [`Service`](crate::service::Service)s.
+    pub fn open(
+        self,
+    ) -> Result<
+        notifying_publish_subscribe::PortFactory<ServiceType, Payload>,
+        NotifyingPublishSubscribeOpenError,
+    > {
+        let origin = format!("{self:?}");
+        let publish_subscribe = fail!(from origin, when self.publish_subscribe.open(),
+            "Unable to open the publish subscribe service.");
+        let event = fail!(from origin, when self.event.open(),
+            "Unable to open the event service.");
+        Ok(Self::compose(publish_subscribe, event, self.event_id))
+    }
+
+    /// Creates new [`Service`](crate::service::Service)s.
+    pub fn create(
+        self,
+    ) -> Result<
+        notifying_publish_subscribe::PortFactory<ServiceType, Payload>,
+        NotifyingPublishSubscribeCreateError,
+    > {
+        let origin = format!("{self:?}");
+        let publish_subscribe = fail!(from origin, when self.publish_subscribe.create(),
+            "Unable to create the publish subscribe service.");
+        let event = fail!(from origin, when self.event.create(),
+            "Unable to create the event service.");
+        Ok(Self::compose(publish_subscribe, event, self.event_id))
+    }
+}
+
+impl<Payload: Debug + ZeroCopySend, ServiceType: service::Service> Builder<[Payload], ServiceType> {
+    /// If the [`Service`](crate::service::Service)s exist, they will be opened otherwise new
+    /// [`Service`](crate::service::Service)s will be created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        notifying_publish_subscribe::PortFactory<ServiceType, [Payload]>,
+        NotifyingPublishSubscribeOpenOrCreateError,
+    > {
+        let origin = format!("{self:?}");
+        let publish_subscribe = fail!(from origin, when self.publish_subscribe.open_or_create(),
+            "Unable to open or create the publish subscribe service.");
+        let event = fail!(from origin, when self.event.open_or_create(),
+            "Unable to open or create the event service.");
+        Ok(Self::compose(publish_subscribe, event, self.event_id))
+    }
+
+    /// Opens the existing [`Service`](crate::service::Service)s.
+    pub fn open(
+        self,
+    ) -> Result<
+        notifying_publish_subscribe::PortFactory<ServiceType, [Payload]>,
+        NotifyingPublishSubscribeOpenError,
+    > {
+        let origin = format!("{self:?}");
+        let publish_subscribe = fail!(from origin, when self.publish_subscribe.open(),
+            "Unable to open the publish subscribe service.");
+        let event = fail!(from origin, when self.event.open(),
+            "Unable to open the event service.");
+        Ok(Self::compose(publish_subscribe, event, self.event_id))
+    }
+
+    /// Creates new [`Service`](crate::service::Service)s.
+    pub fn create(
+        self,
+    ) -> Result<
+        notifying_publish_subscribe::PortFactory<ServiceType, [Payload]>,
+        NotifyingPublishSubscribeCreateError,
+    > {
+        let origin = format!("{self:?}");
+        let publish_subscribe = fail!(from origin, when self.publish_subscribe.create(),
+            "Unable to create the publish subscribe service.");
+        let event = fail!(from origin, when self.event.create(),
+            "Unable to create the event service.");
+        Ok(Self::compose(publish_subscribe, event, self.event_id))
+    }
+}
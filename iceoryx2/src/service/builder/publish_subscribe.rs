@@ -16,9 +16,12 @@
 //!
 use core::marker::PhantomData;
 
+use crate::port::event_id::EventId;
+use crate::service::connection_bridge::ConnectionBridge;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::publish_subscribe;
+use crate::service::static_config::abi_tag::AbiNameString;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 use crate::service::{self, dynamic_config::MessagingPatternSettings};
@@ -46,6 +49,10 @@ pub enum PublishSubscribeOpenError {
     InternalFailure,
     /// The [`Service`] has the wrong payload type.
     IncompatibleTypes,
+    /// The [`Service`] was created on a platform with a different
+    /// [`AbiTag`](crate::service::static_config::abi_tag::AbiTag), see
+    /// [`Builder::allow_abi_mismatch()`].
+    IncompatibleAbi,
     /// The [`Service`] has the wrong messaging pattern.
     IncompatibleMessagingPattern,
     /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
@@ -93,6 +100,7 @@ impl From<ServiceAvailabilityState> for PublishSubscribeOpenError {
             ServiceAvailabilityState::IncompatibleTypes => {
                 PublishSubscribeOpenError::IncompatibleTypes
             }
+            ServiceAvailabilityState::IncompatibleAbi => PublishSubscribeOpenError::IncompatibleAbi,
             ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 PublishSubscribeOpenError::IncompatibleMessagingPattern
             }
@@ -143,6 +151,7 @@ impl From<ServiceAvailabilityState> for PublishSubscribeCreateError {
     fn from(value: ServiceAvailabilityState) -> Self {
         match value {
             ServiceAvailabilityState::IncompatibleTypes
+            | ServiceAvailabilityState::IncompatibleAbi
             | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
                 PublishSubscribeCreateError::AlreadyExists
             }
@@ -163,6 +172,7 @@ impl From<ServiceAvailabilityState> for PublishSubscribeCreateError {
 enum ServiceAvailabilityState {
     ServiceState(ServiceState),
     IncompatibleTypes,
+    IncompatibleAbi,
 }
 
 /// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
@@ -202,7 +212,40 @@ impl core::fmt::Display for PublishSubscribeOpenOrCreateError {
     }
 }
 
-impl core::error::Error for PublishSubscribeOpenOrCreateError {}
+impl core::error::Error for PublishSubscribeOpenOrCreateError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::PublishSubscribeOpenError(e) => Some(e),
+            Self::PublishSubscribeCreateError(e) => Some(e),
+            Self::SystemInFlux => None,
+        }
+    }
+}
+
+/// Defines the [`EventId`]s that are emitted when the connections of a publish-subscribe
+/// [`Service`] change, see [`Builder::notify_connection_changes()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventIdScheme {
+    /// Emitted when a [`crate::port::publisher::Publisher`] was created.
+    pub publisher_connected: EventId,
+    /// Emitted when a [`crate::port::publisher::Publisher`] was dropped.
+    pub publisher_disconnected: EventId,
+    /// Emitted when a [`crate::port::subscriber::Subscriber`] was created.
+    pub subscriber_connected: EventId,
+    /// Emitted when a [`crate::port::subscriber::Subscriber`] was dropped.
+    pub subscriber_disconnected: EventId,
+}
+
+impl Default for EventIdScheme {
+    fn default() -> Self {
+        Self {
+            publisher_connected: EventId::new(0),
+            publisher_disconnected: EventId::new(1),
+            subscriber_connected: EventId::new(2),
+            subscriber_disconnected: EventId::new(3),
+        }
+    }
+}
 
 /// Builder to create new [`MessagingPattern::PublishSubscribe`] based [`Service`]s
 ///
@@ -226,6 +269,9 @@ pub struct Builder<
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
     verify_max_nodes: bool,
+    verify_type_hash: bool,
+    verify_abi_tag: bool,
+    connection_notification: Option<(ServiceName, EventIdScheme)>,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -249,6 +295,9 @@ impl<
             verify_publisher_history_size: self.verify_publisher_history_size,
             verify_enable_safe_overflow: self.verify_enable_safe_overflow,
             verify_max_nodes: self.verify_max_nodes,
+            verify_type_hash: self.verify_type_hash,
+            verify_abi_tag: self.verify_abi_tag,
+            connection_notification: self.connection_notification.clone(),
             _data: PhantomData,
             _user_header: PhantomData,
         }
@@ -271,6 +320,9 @@ impl<
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
             verify_max_nodes: false,
+            verify_type_hash: true,
+            verify_abi_tag: true,
+            connection_notification: None,
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
@@ -320,6 +372,25 @@ impl<
                         error_msg, &config.publish_subscribe().message_type_details , self.config_details().message_type_details);
                 }
 
+                if self.verify_type_hash
+                    && self.config_details().type_hash != config.publish_subscribe().type_hash
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
+                        "{} since the service offers the type hash {:?} which differs from the requested type hash {:?}.",
+                        error_msg, config.publish_subscribe().type_hash, self.config_details().type_hash);
+                }
+
+                if self.verify_abi_tag
+                    && !self
+                        .config_details()
+                        .abi_tag
+                        .is_compatible_to(&config.publish_subscribe().abi_tag)
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleAbi,
+                        "{} since the service was created with the ABI \"{}\" which is not compatible to the ABI \"{}\".",
+                        error_msg, config.publish_subscribe().abi_tag, self.config_details().abi_tag);
+                }
+
                 Ok(Some((config, storage)))
             }
             Ok(None) => Ok(None),
@@ -341,6 +412,44 @@ impl<
         self
     }
 
+    /// Defines a hash that describes the layout of the transmitted types, for instance derived
+    /// from the field names and offsets of the payload and user header. Types with the same
+    /// name, size and alignment but a different layout can only be distinguished by it.
+    /// If the [`Service`] is created, the hash is stored in its static config. If an existing
+    /// [`Service`] is opened, it requires the service to have the same hash, otherwise it fails
+    /// with [`PublishSubscribeOpenError::IncompatibleTypes`].
+    pub fn type_hash(mut self, value: [u8; 32]) -> Self {
+        self.config_details_mut().type_hash = Some(value);
+        self
+    }
+
+    /// When an existing [`Service`] is opened, the [`Builder::type_hash()`] of the service is not
+    /// verified. It allows to open services that were created by a participant that cannot
+    /// provide the hash, for instance via the language bindings.
+    pub fn skip_type_hash_check(mut self) -> Self {
+        self.verify_type_hash = false;
+        self
+    }
+
+    /// Defines the name of the ABI, for instance the compiler and its version, that is stored
+    /// together with the byte order and pointer width in the
+    /// [`AbiTag`](crate::service::static_config::abi_tag::AbiTag) of the [`Service`]. If an
+    /// existing [`Service`] is opened and both sides defined a name, the names must be equal.
+    pub fn abi_name(mut self, value: AbiNameString) -> Self {
+        self.config_details_mut().abi_tag.abi_name = Some(value);
+        self
+    }
+
+    /// When an existing [`Service`] is opened, the
+    /// [`AbiTag`](crate::service::static_config::abi_tag::AbiTag) of the service is not
+    /// verified. Useful for tools like recorders that treat the payload as opaque bytes.
+    /// Otherwise, opening a [`Service`] that was created on a platform with a different byte
+    /// order, pointer width or ABI name fails with [`PublishSubscribeOpenError::IncompatibleAbi`].
+    pub fn allow_abi_mismatch(mut self) -> Self {
+        self.verify_abi_tag = false;
+        self
+    }
+
     /// If the [`Service`] is created, defines the overflow behavior of the service. If an existing
     /// [`Service`] is opened it requires the service to have the defined overflow behavior.
     pub fn enable_safe_overflow(mut self, value: bool) -> Self {
@@ -349,6 +458,52 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created, defines if the [`crate::port::publisher::Publisher`]
+    /// notifies the [`crate::port::subscriber::Subscriber`] whenever a
+    /// [`crate::sample::Sample`] was delivered. It is required to attach a
+    /// [`crate::port::subscriber::Subscriber`] to a [`WaitSet`](crate::waitset::WaitSet).
+    /// If an existing [`Service`] is opened the setting of the existing [`Service`] is used.
+    pub fn enable_notifications(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_notifications = value;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if the [`crate::port::publisher::Publisher`]
+    /// stores the send time in the
+    /// [`Header`](crate::service::header::publish_subscribe::Header) of every
+    /// [`crate::sample::Sample`]. It is required to define a
+    /// [`PortFactorySubscriber::max_sample_age()`](crate::service::port_factory::subscriber::PortFactorySubscriber::max_sample_age()).
+    /// If an existing [`Service`] is opened the setting of the existing [`Service`] is used.
+    pub fn enable_timestamps(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_timestamps = value;
+        self
+    }
+
+    /// Emits the [`EventId`]s of the [`EventIdScheme`] on the event [`Service`] with the
+    /// provided [`ServiceName`] whenever a [`crate::port::publisher::Publisher`] or
+    /// [`crate::port::subscriber::Subscriber`] of the returned port factory is created or
+    /// dropped. The event [`Service`] is opened or created with the first port and released
+    /// again when the last port of the port factory is dropped. This is a local setting,
+    /// ports of other processes are only announced when they enable it as well.
+    ///
+    /// The delivery is best effort: failures are only logged and a
+    /// [`crate::port::listener::Listener`] may receive multiple changes as one event
+    /// when the same [`EventId`] is emitted several times before it waits.
+    pub fn notify_connection_changes(
+        mut self,
+        event_service_name: &ServiceName,
+        event_ids: EventIdScheme,
+    ) -> Self {
+        self.connection_notification = Some((event_service_name.clone(), event_ids));
+        self
+    }
+
+    fn connection_bridge(&self) -> Option<ConnectionBridge<ServiceType>> {
+        self.connection_notification
+            .as_ref()
+            .map(|(name, event_ids)| ConnectionBridge::new(name.clone(), *event_ids))
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
@@ -616,7 +771,8 @@ impl<
                         dynamic_config,
                         unlocked_static_details,
                         NoResource,
-                    ),
+                    )
+                    .with_connection_bridge(self.connection_bridge()),
                 ))
             }
             Some(_) => {
@@ -698,7 +854,8 @@ impl<
                             dynamic_config,
                             static_storage,
                             NoResource,
-                        ),
+                        )
+                        .with_connection_bridge(self.connection_bridge()),
                     ));
                 }
             }
@@ -761,6 +918,15 @@ impl<
     }
 }
 
+impl<Payload: Debug + ?Sized + ZeroCopySend, ServiceType: service::Service>
+    Builder<Payload, (), ServiceType>
+{
+    pub(crate) fn override_payload_type(mut self, value: TypeDetail) -> Self {
+        self.override_payload_type = Some(value);
+        self
+    }
+}
+
 impl<UserHeader: Debug + ZeroCopySend, ServiceType: service::Service>
     Builder<[CustomPayloadMarker], UserHeader, ServiceType>
 {
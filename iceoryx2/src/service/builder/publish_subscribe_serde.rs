@@ -0,0 +1,240 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize)]
+//! struct Message {
+//!     text: String,
+//!     values: Vec<u64>,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serde::<Message>()
+//!     .max_publishers(2)
+//!     .max_subscribers(4)
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! publisher.send_serialized(&Message { text: "hello".into(), values: vec![1, 2, 3] })?;
+//!
+//! while let Some(message) = subscriber.receive_deserialized()? {
+//!     println!("received: {:?}", message);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::serialize::{cdr::Cdr, postcard::Postcard, toml::Toml};
+
+use crate::service;
+use crate::service::port_factory::{publish_subscribe, publish_subscribe_serde};
+use crate::service::static_config::message_type_details::{
+    TypeDetail, TypeNameString, TypeVariant,
+};
+
+use super::publish_subscribe::{
+    Builder as PublishSubscribeBuilder, PublishSubscribeCreateError, PublishSubscribeOpenError,
+    PublishSubscribeOpenOrCreateError,
+};
+
+/// A serialization format that can be used by a
+/// [`publish_subscribe_serde`](crate::service::builder::Builder::publish_subscribe_serde())
+/// [`Service`](crate::service::Service). The [`SerializationFormat::FORMAT_ID`] is stored in
+/// the static config of the [`Service`](crate::service::Service) so that processes that use
+/// different formats cannot open the same [`Service`](crate::service::Service).
+pub trait SerializationFormat: iceoryx2_cal::serialize::Serialize {
+    /// Unique identifier of the serialization format.
+    const FORMAT_ID: &'static str;
+}
+
+impl SerializationFormat for Postcard {
+    const FORMAT_ID: &'static str = "postcard";
+}
+
+impl SerializationFormat for Cdr {
+    const FORMAT_ID: &'static str = "cdr";
+}
+
+impl SerializationFormat for Toml {
+    const FORMAT_ID: &'static str = "toml";
+}
+
+/// Builder to create or open a publish-subscribe [`Service`](crate::service::Service) that
+/// transfers types which are not shared-memory compatible. The values are serialized with
+/// the [`SerializationFormat`] into a byte slice, therefore the communication is not
+/// zero-copy.
+pub struct Builder<
+    T: serde::Serialize + serde::de::DeserializeOwned,
+    ServiceType: service::Service,
+    Format: SerializationFormat = Postcard,
+> {
+    publish_subscribe: PublishSubscribeBuilder<[u8], (), ServiceType>,
+    _data: PhantomData<fn() -> (T, Format)>,
+}
+
+impl<
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        ServiceType: service::Service,
+        Format: SerializationFormat,
+    > Debug for Builder<T, ServiceType, Format>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "publish_subscribe_serde::Builder<{}, {}, {}> {{ publish_subscribe: {:?} }}",
+            core::any::type_name::<T>(),
+            core::any::type_name::<ServiceType>(),
+            Format::FORMAT_ID,
+            self.publish_subscribe
+        )
+    }
+}
+
+impl<
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        ServiceType: service::Service,
+        Format: SerializationFormat,
+    > Builder<T, ServiceType, Format>
+{
+    pub(crate) fn new(publish_subscribe: PublishSubscribeBuilder<[u8], (), ServiceType>) -> Self {
+        // the format and the transferred type are stored as payload type name, so that
+        // incompatible processes fail with an incompatible type error when the service is
+        // opened
+        let type_name = format!("{}::{}", Format::FORMAT_ID, core::any::type_name::<T>());
+        let payload_type = TypeDetail {
+            variant: TypeVariant::Dynamic,
+            type_name: TypeNameString::from_str_truncated(&type_name),
+            size: core::mem::size_of::<u8>(),
+            alignment: core::mem::align_of::<u8>(),
+        };
+
+        Self {
+            publish_subscribe: publish_subscribe.override_payload_type(payload_type),
+            _data: PhantomData,
+        }
+    }
+
+    /// Defines the [`SerializationFormat`] that is used to transfer the values. By default,
+    /// [`Postcard`] is used.
+    pub fn format<NewFormat: SerializationFormat>(self) -> Builder<T, ServiceType, NewFormat> {
+        Builder::new(self.publish_subscribe)
+    }
+
+    /// See [`PublishSubscribeBuilder::max_publishers()`].
+    pub fn max_publishers(mut self, value: usize) -> Self {
+        self.publish_subscribe = self.publish_subscribe.max_publishers(value);
+        self
+    }
+
+    /// See [`PublishSubscribeBuilder::max_subscribers()`].
+    pub fn max_subscribers(mut self, value: usize) -> Self {
+        self.publish_subscribe = self.publish_subscribe.max_subscribers(value);
+        self
+    }
+
+    /// See [`PublishSubscribeBuilder::max_nodes()`].
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.publish_subscribe = self.publish_subscribe.max_nodes(value);
+        self
+    }
+
+    /// See [`PublishSubscribeBuilder::history_size()`].
+    pub fn history_size(mut self, value: usize) -> Self {
+        self.publish_subscribe = self.publish_subscribe.history_size(value);
+        self
+    }
+
+    /// See [`PublishSubscribeBuilder::subscriber_max_buffer_size()`].
+    pub fn subscriber_max_buffer_size(mut self, value: usize) -> Self {
+        self.publish_subscribe = self.publish_subscribe.subscriber_max_buffer_size(value);
+        self
+    }
+
+    /// See [`PublishSubscribeBuilder::subscriber_max_borrowed_samples()`].
+    pub fn subscriber_max_borrowed_samples(mut self, value: usize) -> Self {
+        self.publish_subscribe = self
+            .publish_subscribe
+            .subscriber_max_borrowed_samples(value);
+        self
+    }
+
+    /// See [`PublishSubscribeBuilder::enable_safe_overflow()`].
+    pub fn enable_safe_overflow(mut self, value: bool) -> Self {
+        self.publish_subscribe = self.publish_subscribe.enable_safe_overflow(value);
+        self
+    }
+
+    fn compose(
+        publish_subscribe: publish_subscribe::PortFactory<ServiceType, [u8], ()>,
+    ) -> publish_subscribe_serde::PortFactory<ServiceType, T, Format> {
+        publish_subscribe_serde::PortFactory {
+            publish_subscribe,
+            _data: PhantomData,
+        }
+    }
+
+    /// If the [`Service`](crate::service::Service) exists, it will be opened otherwise a new
+    /// [`Service`](crate::service::Service) will be created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        publish_subscribe_serde::PortFactory<ServiceType, T, Format>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        let origin = format!("{self:?}");
+        let publish_subscribe = fail!(from origin, when self.publish_subscribe.open_or_create(),
+            "Unable to open or create the serde publish subscribe service.");
+        Ok(Self::compose(publish_subscribe))
+    }
+
+    /// Opens an existing [`Service`](crate::service::Service). It fails with
+    /// [`PublishSubscribeOpenError::IncompatibleTypes`] when the
+    /// [`Service`](crate::service::Service) uses a different type or [`SerializationFormat`].
+    pub fn open(
+        self,
+    ) -> Result<
+        publish_subscribe_serde::PortFactory<ServiceType, T, Format>,
+        PublishSubscribeOpenError,
+    > {
+        let origin = format!("{self:?}");
+        let publish_subscribe = fail!(from origin, when self.publish_subscribe.open(),
+            "Unable to open the serde publish subscribe service.");
+        Ok(Self::compose(publish_subscribe))
+    }
+
+    /// Creates a new [`Service`](crate::service::Service).
+    pub fn create(
+        self,
+    ) -> Result<
+        publish_subscribe_serde::PortFactory<ServiceType, T, Format>,
+        PublishSubscribeCreateError,
+    > {
+        let origin = format!("{self:?}");
+        let publish_subscribe = fail!(from origin, when self.publish_subscribe.create(),
+            "Unable to create the serde publish subscribe service.");
+        Ok(Self::compose(publish_subscribe))
+    }
+}
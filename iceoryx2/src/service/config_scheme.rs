@@ -117,6 +117,16 @@ pub(crate) fn service_tag_config<Service: crate::service::Service>(
         .path_hint(&node_details_path(global_config, node_id))
 }
 
+pub(crate) fn node_heartbeat_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+    node_id: &NodeId,
+) -> <Service::HeartbeatStorage as NamedConceptMgmt>::Configuration {
+    <<Service::HeartbeatStorage as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.node.heartbeat_suffix)
+        .path_hint(&node_details_path(global_config, node_id))
+}
+
 pub(crate) fn blackboard_mgmt_config<
     Service: crate::service::Service,
     T: ZeroCopySend + Send + Sync + Debug + 'static,
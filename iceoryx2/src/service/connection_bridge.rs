@@ -0,0 +1,140 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::sync::Arc;
+use std::sync::Mutex;
+
+use iceoryx2_bb_log::warn;
+
+use crate::node::SharedNode;
+use crate::port::event_id::EventId;
+use crate::service::builder::publish_subscribe::EventIdScheme;
+use crate::service::builder::Builder;
+use crate::service::port_factory::event;
+use crate::service::service_name::ServiceName;
+use crate::service::Service;
+
+extern crate alloc;
+
+#[derive(Debug)]
+struct BridgeState<S: Service> {
+    event_service: Option<event::PortFactory<S>>,
+    number_of_ports: usize,
+}
+
+/// Emits the [`EventIdScheme`] on an event service whenever a local port of a
+/// publish-subscribe service is created or dropped. The event service is acquired with the
+/// first local port and released with the last one. Every change is emitted by a
+/// [`Notifier`](crate::port::notifier::Notifier) that only lives for the notification, since
+/// the [`Notifier`](crate::port::notifier::Notifier) of a single threaded
+/// [`Service`] cannot be shared between the ports.
+#[derive(Debug)]
+pub(crate) struct ConnectionBridge<S: Service> {
+    event_service_name: ServiceName,
+    event_ids: EventIdScheme,
+    state: Mutex<BridgeState<S>>,
+}
+
+impl<S: Service> ConnectionBridge<S> {
+    pub(crate) fn new(event_service_name: ServiceName, event_ids: EventIdScheme) -> Self {
+        Self {
+            event_service_name,
+            event_ids,
+            state: Mutex::new(BridgeState {
+                event_service: None,
+                number_of_ports: 0,
+            }),
+        }
+    }
+
+    pub(crate) fn event_ids(&self) -> &EventIdScheme {
+        &self.event_ids
+    }
+
+    pub(crate) fn port_added(&self, shared_node: &Arc<SharedNode<S>>, event_id: EventId) {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(e) => {
+                warn!(from self,
+                    "Unable to notify the connection change with {:?} since the state of the bridge could not be locked ({:?}).",
+                    event_id, e);
+                return;
+            }
+        };
+        state.number_of_ports += 1;
+
+        if state.event_service.is_none() {
+            state.event_service = self.open_event_service(shared_node);
+        }
+
+        self.notify(&state, event_id);
+    }
+
+    pub(crate) fn port_removed(&self, event_id: EventId) {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(e) => {
+                warn!(from self,
+                    "Unable to notify the connection change with {:?} since the state of the bridge could not be locked ({:?}).",
+                    event_id, e);
+                return;
+            }
+        };
+        self.notify(&state, event_id);
+
+        state.number_of_ports = state.number_of_ports.saturating_sub(1);
+        if state.number_of_ports == 0 {
+            state.event_service = None;
+        }
+    }
+
+    fn open_event_service(
+        &self,
+        shared_node: &Arc<SharedNode<S>>,
+    ) -> Option<event::PortFactory<S>> {
+        match Builder::new(&self.event_service_name, shared_node.clone())
+            .event()
+            .open_or_create()
+        {
+            Ok(service) => Some(service),
+            Err(e) => {
+                warn!(from self,
+                    "Unable to open or create the event service \"{}\" to notify connection changes ({:?}).",
+                    self.event_service_name, e);
+                None
+            }
+        }
+    }
+
+    fn notify(&self, state: &BridgeState<S>, event_id: EventId) {
+        let event_service = match &state.event_service {
+            Some(event_service) => event_service,
+            None => return,
+        };
+
+        let notifier = match event_service.notifier_builder().create() {
+            Ok(notifier) => notifier,
+            Err(e) => {
+                warn!(from self,
+                    "Unable to create a notifier on the event service \"{}\" to notify the connection change with {:?} ({:?}).",
+                    self.event_service_name, event_id, e);
+                return;
+            }
+        };
+
+        if let Err(e) = notifier.notify_with_custom_event_id(event_id) {
+            warn!(from self,
+                "Unable to notify the connection change with {:?} on the event service \"{}\" ({:?}).",
+                event_id, self.event_service_name, e);
+        }
+    }
+}
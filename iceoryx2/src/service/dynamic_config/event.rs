@@ -26,6 +26,9 @@
 //! # Ok(())
 //! # }
 //! ```
+use core::sync::atomic::Ordering;
+
+use iceoryx2_bb_container::vec::RelocatableVec;
 use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
@@ -54,6 +57,9 @@ pub struct DynamicConfig {
     pub(crate) listeners: Container<ListenerDetails>,
     pub(crate) notifiers: Container<NotifierDetails>,
     pub(crate) elapsed_time_since_last_notification: IoxAtomicU64,
+    // the number of notifications that could not be delivered, indexed by the container
+    // handle of the listener
+    missed_notifications: RelocatableVec<IoxAtomicU64>,
 }
 
 /// Contains the communication settings of the connected
@@ -86,6 +92,7 @@ impl DynamicConfig {
             listeners: unsafe { Container::new_uninit(config.number_of_listeners) },
             notifiers: unsafe { Container::new_uninit(config.number_of_notifiers) },
             elapsed_time_since_last_notification: IoxAtomicU64::new(0),
+            missed_notifications: unsafe { RelocatableVec::new_uninit(config.number_of_listeners) },
         }
     }
 
@@ -96,11 +103,16 @@ impl DynamicConfig {
         fatal_panic!(from "event::DynamicConfig::init",
             when self.notifiers.init(allocator),
             "This should never happen! Unable to initialize notifier port id container.");
+        fatal_panic!(from "event::DynamicConfig::init",
+            when self.missed_notifications.init(allocator),
+            "This should never happen! Unable to initialize missed notifications counter.");
+        self.missed_notifications.fill_with(|| IoxAtomicU64::new(0));
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<ListenerDetails>::memory_size(config.number_of_listeners)
             + Container::<NotifierDetails>::memory_size(config.number_of_notifiers)
+            + RelocatableVec::<IoxAtomicU64>::memory_size(config.number_of_listeners)
     }
 
     /// Returns how many [`Listener`](crate::port::listener::Listener) ports are currently connected.
@@ -178,9 +190,19 @@ impl DynamicConfig {
     }
 
     pub(crate) fn release_listener_handle(&self, handle: ContainerHandle) {
+        // the counter is reset before the slot can be acquired by a new listener
+        self.missed_notifications[handle.index() as usize].store(0, Ordering::Relaxed);
         unsafe { self.listeners.remove(handle, ReleaseMode::Default) };
     }
 
+    pub(crate) fn add_missed_notification(&self, listener_index: usize) {
+        self.missed_notifications[listener_index].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn missed_notifications(&self, handle: ContainerHandle) -> u64 {
+        self.missed_notifications[handle.index() as usize].load(Ordering::Relaxed)
+    }
+
     pub(crate) fn add_notifier_id(&self, id: NotifierDetails) -> Option<ContainerHandle> {
         unsafe { self.notifiers.add(id).ok() }
     }
@@ -36,6 +36,8 @@ use crate::{
     port::{
         details::data_segment::DataSegmentType,
         port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId},
+        sample_filter::SampleFilter,
+        unable_to_deliver_strategy::UnableToDeliverStrategy,
     },
 };
 
@@ -61,6 +63,9 @@ pub struct PublisherDetails {
     /// The total number of samples contained in the
     /// [`Publisher`](crate::port::publisher::Publisher)s data segment.
     pub number_of_samples: usize,
+    /// The maximum number of [`SampleMut`](crate::sample_mut::SampleMut)s the
+    /// [`Publisher`](crate::port::publisher::Publisher) can loan at the same time.
+    pub max_loaned_samples: usize,
     /// The current maximum length of a slice.
     pub max_slice_len: usize,
     /// The type of data segment the [`Publisher`](crate::port::publisher::Publisher)
@@ -70,6 +75,10 @@ pub struct PublisherDetails {
     /// [`DataSegmentType::Dynamic`] it defines how many segment the
     /// [`Publisher`](crate::port::publisher::Publisher) can have at most.
     pub max_number_of_segments: u8,
+    /// The [`UnableToDeliverStrategy`] the [`Publisher`](crate::port::publisher::Publisher)
+    /// pursues when the buffer of a [`Subscriber`](crate::port::subscriber::Subscriber) is
+    /// full.
+    pub unable_to_deliver_strategy: UnableToDeliverStrategy,
 }
 
 /// Contains the communication settings of the connected
@@ -84,6 +93,16 @@ pub struct SubscriberDetails {
     pub node_id: NodeId,
     /// The size of the receive buffer that stores [`Sample`](crate::sample::Sample).
     pub buffer_size: usize,
+    /// The [`SampleFilter`] the [`Publisher`](crate::port::publisher::Publisher) applies
+    /// before a [`Sample`](crate::sample::Sample) is delivered.
+    pub filter: Option<SampleFilter>,
+    /// The number of most recent historical [`Sample`](crate::sample::Sample)s the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) requested.
+    pub history_request: usize,
+    /// True when the [`Subscriber`](crate::port::subscriber::Subscriber) keeps only the most
+    /// recent [`Sample`](crate::sample::Sample) of every
+    /// [`Publisher`](crate::port::publisher::Publisher).
+    pub keep_latest: bool,
 }
 
 /// The dynamic configuration of an
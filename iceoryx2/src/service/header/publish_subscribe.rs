@@ -31,6 +31,8 @@
 //! # }
 //! ```
 
+use core::time::Duration;
+
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 
@@ -44,6 +46,8 @@ pub struct Header {
     node_id: NodeId,
     publisher_port_id: UniquePublisherId,
     number_of_elements: u64,
+    sequence_number: u64,
+    timestamp: u64,
 }
 
 impl Header {
@@ -56,9 +60,19 @@ impl Header {
             node_id,
             publisher_port_id,
             number_of_elements,
+            sequence_number: 0,
+            timestamp: 0,
         }
     }
 
+    pub(crate) fn set_sequence_number(&mut self, value: u64) {
+        self.sequence_number = value;
+    }
+
+    pub(crate) fn set_timestamp(&mut self, value: Duration) {
+        self.timestamp = value.as_nanos() as u64;
+    }
+
     /// Returns the [`NodeId`] of the source node that published the
     /// [`Sample`](crate::sample::Sample).
     pub fn node_id(&self) -> NodeId {
@@ -83,4 +97,22 @@ impl Header {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns the sequence number of the [`Sample`](crate::sample::Sample). Every
+    /// [`Publisher`](crate::port::publisher::Publisher) numbers its sent samples
+    /// consecutively, starting with 0. The sequence number is assigned when the sample is sent.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the time at which the [`Sample`](crate::sample::Sample) was sent, measured with
+    /// the default clock of [`Time`](iceoryx2_bb_posix::clock::Time). It is only available when
+    /// the [`Service`](crate::service::Service) was created with
+    /// [`Builder::enable_timestamps()`](crate::service::builder::publish_subscribe::Builder::enable_timestamps()).
+    pub fn timestamp(&self) -> Option<Duration> {
+        match self.timestamp {
+            0 => None,
+            v => Some(Duration::from_nanos(v)),
+        }
+    }
 }
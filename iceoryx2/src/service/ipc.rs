@@ -32,6 +32,7 @@
 //!
 //! See [`Service`](crate::service) for more detailed examples.
 
+use crate::node::heartbeat::Heartbeat;
 use crate::service::dynamic_config::DynamicConfig;
 use core::fmt::Debug;
 use iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator;
@@ -58,6 +59,7 @@ impl crate::service::Service for Service {
     type BlackboardMgmt<KeyType: Send + Sync + Debug + 'static> =
         dynamic_storage::recommended::Ipc<KeyType>;
     type BlackboardPayload = shared_memory::recommended::Ipc<BumpAllocator>;
+    type HeartbeatStorage = dynamic_storage::recommended::Ipc<Heartbeat>;
 }
 
 impl crate::service::internal::ServiceInternal<Service> for Service {}
@@ -34,6 +34,7 @@
 
 use core::fmt::Debug;
 
+use crate::node::heartbeat::Heartbeat;
 use crate::service::dynamic_config::DynamicConfig;
 use iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator;
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
@@ -59,6 +60,7 @@ impl crate::service::Service for Service {
     type BlackboardMgmt<KeyType: Send + Sync + Debug + 'static> =
         dynamic_storage::recommended::Local<KeyType>;
     type BlackboardPayload = shared_memory::recommended::Local<BumpAllocator>;
+    type HeartbeatStorage = dynamic_storage::recommended::Local<Heartbeat>;
 }
 
 impl crate::service::internal::ServiceInternal<Service> for Service {}
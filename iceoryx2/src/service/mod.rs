@@ -250,7 +250,11 @@ pub mod ipc;
 /// [`Send`] but at the cost of an additional internal mutex.
 pub mod ipc_threadsafe;
 
+/// Object-safe representation of a [`Service`] type that can be selected at runtime.
+pub mod service_dyn;
+
 pub(crate) mod config_scheme;
+pub(crate) mod connection_bridge;
 pub(crate) mod naming_scheme;
 
 use alloc::sync::Arc;
@@ -259,9 +263,13 @@ use core::time::Duration;
 
 use crate::config;
 use crate::constants::MAX_TYPE_NAME_LENGTH;
-use crate::node::{NodeId, NodeListFailure, NodeState, SharedNode};
+use crate::node::{Node, NodeId, NodeListFailure, NodeState, SharedNode};
 use crate::service::config_scheme::dynamic_config_storage_config;
+use crate::service::connection_bridge::ConnectionBridge;
+use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::dynamic_config::DynamicConfig;
+use crate::service::port_factory::any::{AnyPortFactory, AnyPortFactoryOpenError};
+use crate::service::port_factory::ServiceTiming;
 use crate::service::static_config::*;
 use config_scheme::service_tag_config;
 use iceoryx2_bb_container::semantic_string::SemanticString;
@@ -321,6 +329,8 @@ pub enum ServiceDetailsError {
     InternalError,
     /// The [`NodeState`] could not be acquired.
     FailedToAcquireNodeState,
+    /// The process has insufficient permissions to read the static [`Service`] information.
+    InsufficientPermissions,
 }
 
 impl core::fmt::Display for ServiceDetailsError {
@@ -331,13 +341,37 @@ impl core::fmt::Display for ServiceDetailsError {
 
 impl core::error::Error for ServiceDetailsError {}
 
-/// Failure that can be reported by [`Service::list()`].
+/// Failure that can be reported by [`Service::list()`], either as return value when the
+/// [`Service`]s could not be listed at all or for a single [`Service`] in the callback.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ServiceListError {
-    /// The process has insufficient permissions to list all [`Service`]s.
+    /// The process has insufficient permissions to list all [`Service`]s or to read the
+    /// static information of a single [`Service`].
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalError,
+    /// The static information of the [`Service`] could not be read, could not be
+    /// deserialized or is inconsistent.
+    CorruptedService,
+    /// The [`Service`] was created with a different iceoryx2 version.
+    VersionMismatch,
+}
+
+impl From<ServiceDetailsError> for ServiceListError {
+    fn from(value: ServiceDetailsError) -> Self {
+        match value {
+            ServiceDetailsError::InsufficientPermissions => {
+                ServiceListError::InsufficientPermissions
+            }
+            ServiceDetailsError::FailedToReadStaticServiceInfo
+            | ServiceDetailsError::FailedToDeserializeStaticServiceInfo
+            | ServiceDetailsError::ServiceInInconsistentState => ServiceListError::CorruptedService,
+            ServiceDetailsError::VersionMismatch => ServiceListError::VersionMismatch,
+            ServiceDetailsError::FailedToOpenStaticServiceInfo
+            | ServiceDetailsError::InternalError
+            | ServiceDetailsError::FailedToAcquireNodeState => ServiceListError::InternalError,
+        }
+    }
 }
 
 impl core::fmt::Display for ServiceListError {
@@ -348,12 +382,66 @@ impl core::fmt::Display for ServiceListError {
 
 impl core::error::Error for ServiceListError {}
 
+/// Failure that can be reported when the [`StaticConfig`] of a [`Service`] is acquired with
+/// [`Service::static_config()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceInspectError {
+    /// No [`Service`] with the given [`ServiceName`] or [`ServiceId`] exists.
+    DoesNotExist,
+    /// The process has insufficient permissions to read the static [`Service`] information.
+    InsufficientPermissions,
+    /// The static information of the [`Service`] could not be read, could not be
+    /// deserialized or is inconsistent.
+    CorruptedService,
+    /// The [`Service`] was created with a different iceoryx2 version.
+    VersionMismatch,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+}
+
+impl From<ServiceDetailsError> for ServiceInspectError {
+    fn from(value: ServiceDetailsError) -> Self {
+        match value {
+            ServiceDetailsError::InsufficientPermissions => {
+                ServiceInspectError::InsufficientPermissions
+            }
+            ServiceDetailsError::FailedToReadStaticServiceInfo
+            | ServiceDetailsError::FailedToDeserializeStaticServiceInfo
+            | ServiceDetailsError::ServiceInInconsistentState => {
+                ServiceInspectError::CorruptedService
+            }
+            ServiceDetailsError::VersionMismatch => ServiceInspectError::VersionMismatch,
+            ServiceDetailsError::FailedToOpenStaticServiceInfo
+            | ServiceDetailsError::InternalError
+            | ServiceDetailsError::FailedToAcquireNodeState => ServiceInspectError::InternalError,
+        }
+    }
+}
+
+impl core::fmt::Display for ServiceInspectError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ServiceInspectError::{self:?}")
+    }
+}
+
+impl core::error::Error for ServiceInspectError {}
+
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`]
 /// when the [`Service`] is accessible by the current process.
 #[derive(Debug, Clone)]
 pub struct ServiceDynamicDetails<S: Service> {
     /// A list of all [`Node`](crate::node::Node)s that are registered at the [`Service`]
     pub nodes: Vec<NodeState<S>>,
+    /// A list of all [`Publisher`](crate::port::publisher::Publisher)s of the [`Service`]. It
+    /// is empty when the [`Service`] is not a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+    /// based [`Service`].
+    pub publishers: Vec<PublisherDetails>,
+    /// A list of all [`Subscriber`](crate::port::subscriber::Subscriber)s of the [`Service`].
+    /// It is empty when the [`Service`] is not a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+    /// based [`Service`].
+    pub subscribers: Vec<SubscriberDetails>,
 }
 
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`].
@@ -378,6 +466,8 @@ pub struct ServiceState<S: Service, R: ServiceResource> {
     pub(crate) additional_resource: R,
     pub(crate) static_config: StaticConfig,
     pub(crate) shared_node: Arc<SharedNode<S>>,
+    pub(crate) creation_stats: ServiceTiming,
+    pub(crate) connection_bridge: Option<ConnectionBridge<S>>,
     // must be destructed last, otherwise other processes might create a new service with the same
     // name and their resources are then removed by another process while they are creating them
     // which would end up in a completely corrupted service
@@ -398,11 +488,23 @@ impl<S: Service, R: ServiceResource> ServiceState<S, R> {
             dynamic_storage,
             static_storage,
             additional_resource,
+            creation_stats: ServiceTiming::default(),
+            connection_bridge: None,
         };
         trace!(from "Service::open()", "open service: {} ({:?})",
             new_self.static_config.name(), new_self.static_config.service_id());
         new_self
     }
+
+    pub(crate) fn with_creation_stats(mut self, value: ServiceTiming) -> Self {
+        self.creation_stats = value;
+        self
+    }
+
+    pub(crate) fn with_connection_bridge(mut self, value: Option<ConnectionBridge<S>>) -> Self {
+        self.connection_bridge = value;
+        self
+    }
 }
 
 impl<S: Service, R: ServiceResource> Drop for ServiceState<S, R> {
@@ -444,7 +546,7 @@ pub(crate) mod internal {
         node::{NodeBuilder, NodeId},
         port::{
             listener::remove_connection_of_listener, notifier::Notifier,
-            port_identifiers::UniquePortId,
+            port_identifiers::UniquePortId, subscriber::remove_notification_of_subscriber,
         },
         prelude::EventId,
         service::stale_resource_cleanup::{
@@ -676,6 +778,13 @@ pub(crate) mod internal {
                             debug!(from origin, "Failed to remove the subscriber ({:?}) from all of its connections ({:?}).", id, e);
                             return PortCleanupAction::SkipPort;
                         }
+
+                        if let Err(e) =
+                            unsafe { remove_notification_of_subscriber::<S>(id, config) }
+                        {
+                            debug!(from origin, "Failed to remove the subscribers ({:?}) notification ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
                     }
                     UniquePortId::Notifier(_) => {
                         number_of_dead_node_notifications += 1;
@@ -860,6 +969,10 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> + Clone {
     /// Defines the construct used to store the payload data of the blackboard service.
     type BlackboardPayload: SharedMemory<BumpAllocator>;
 
+    /// Defines the construct used to store the [`Heartbeat`](crate::node::heartbeat::Heartbeat)
+    /// of a [`Node`](crate::node::Node).
+    type HeartbeatStorage: DynamicStorage<crate::node::heartbeat::Heartbeat>;
+
     /// Checks if a service under a given [`config::Config`] does exist
     ///
     /// # Example
@@ -917,7 +1030,138 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> + Clone {
         details::<Self>(config, &service_id.0.into())
     }
 
-    /// Returns a list of all services created under a given [`config::Config`].
+    /// Acquires only the [`StaticConfig`] of a [`Service`] without opening its dynamic
+    /// information or creating any port. The [`Service`] is searched for all
+    /// [`MessagingPattern`]s, if services with the same [`ServiceName`] exist for multiple
+    /// [`MessagingPattern`]s the first one in declaration order of [`MessagingPattern`] is
+    /// returned. Use [`Service::details()`] to acquire a specific one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    /// use iceoryx2::service::ServiceInspectError;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let name = ServiceName::new("Some/Name")?;
+    /// match ipc::Service::static_config(&name, Config::global_config()) {
+    ///     Ok(static_config) => println!("Service schema: {:?}", static_config),
+    ///     Err(ServiceInspectError::DoesNotExist) => println!("Service does not exist"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn static_config(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Result<StaticConfig, ServiceInspectError> {
+        let origin = format!("Service::static_config({service_name:?})");
+
+        for messaging_pattern in [
+            MessagingPattern::PublishSubscribe,
+            MessagingPattern::Event,
+            MessagingPattern::RequestResponse,
+            MessagingPattern::Blackboard,
+        ] {
+            let service_id =
+                ServiceId::new::<Self::ServiceNameHasher>(service_name, messaging_pattern);
+            if let Some(static_config) = fail!(from origin,
+                    when read_static_config::<Self>(config, &service_id.0.into()),
+                    "Unable to acquire the static config of the service.")
+            {
+                return Ok(static_config);
+            }
+        }
+
+        fail!(from origin, with ServiceInspectError::DoesNotExist,
+            "Unable to acquire the static config since the service does not exist.");
+    }
+
+    /// Acquires the [`StaticConfig`] of the [`Service`] with the provided [`ServiceId`], for
+    /// instance one that was parsed from a command line argument. See
+    /// [`Service::static_config()`].
+    fn static_config_by_id(
+        service_id: &ServiceId,
+        config: &config::Config,
+    ) -> Result<StaticConfig, ServiceInspectError> {
+        let origin = format!("Service::static_config_by_id({service_id:?})");
+
+        match fail!(from origin, when read_static_config::<Self>(config, &service_id.0.clone().into()),
+                "Unable to acquire the static config of the service.")
+        {
+            Some(static_config) => Ok(static_config),
+            None => {
+                fail!(from origin, with ServiceInspectError::DoesNotExist,
+                    "Unable to acquire the static config since the service does not exist.");
+            }
+        }
+    }
+
+    /// Opens the [`Service`] described by the provided [`StaticConfig`] without requiring
+    /// its [`MessagingPattern`] or its types at compile time. The [`StaticConfig`] is usually
+    /// acquired via [`Service::list()`] or [`Service::static_config()`]. Only
+    /// [`MessagingPattern::Event`] and [`MessagingPattern::PublishSubscribe`] are supported,
+    /// the payload and user header of the latter are type-erased.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::port_factory::any::AnyPortFactory;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let name = ServiceName::new("Some/Name")?;
+    /// # let _service = node.service_builder(&name).publish_subscribe::<u64>().open_or_create()?;
+    ///
+    /// let static_config = ipc::Service::static_config(&name, node.config())?;
+    /// if let AnyPortFactory::PublishSubscribe(service) =
+    ///     ipc::Service::open_any(&node, &static_config)?
+    /// {
+    ///     println!("payload: {:?}", service.static_config().message_type_details().payload);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn open_any(
+        node: &Node<Self>,
+        static_config: &StaticConfig,
+    ) -> Result<AnyPortFactory<Self>, AnyPortFactoryOpenError> {
+        let origin = format!("Service::open_any({:?})", static_config.name());
+        let builder = node.service_builder(static_config.name());
+
+        match static_config.messaging_pattern() {
+            static_config::messaging_pattern::MessagingPattern::Event(_) => {
+                let service = fail!(from origin, when builder.event().open(),
+                    "Unable to open the event service.");
+                Ok(AnyPortFactory::Event(service))
+            }
+            static_config::messaging_pattern::MessagingPattern::PublishSubscribe(config) => {
+                let types = config.message_type_details();
+                let service = fail!(from origin,
+                    when unsafe {
+                        builder
+                            .publish_subscribe::<[builder::CustomPayloadMarker]>()
+                            .user_header::<builder::CustomHeaderMarker>()
+                            .__internal_set_payload_type_details(&types.payload)
+                            .__internal_set_user_header_type_details(&types.user_header)
+                            .allow_abi_mismatch()
+                            .open()
+                    },
+                    "Unable to open the publish-subscribe service.");
+                Ok(AnyPortFactory::PublishSubscribe(service))
+            }
+            _ => {
+                fail!(from origin, with AnyPortFactoryOpenError::UnsupportedMessagingPattern,
+                    "Unable to open the service since its messaging pattern is not supported.");
+            }
+        }
+    }
+
+    /// Returns a list of all services created under a given [`config::Config`]. Services
+    /// whose static information cannot be acquired are reported with a [`ServiceListError`]
+    /// in the callback, so that for instance corrupted services can be distinguished from
+    /// services that are not accessible by the current process.
     ///
     /// # Example
     ///
@@ -927,13 +1171,16 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> + Clone {
     ///
     /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
     /// ipc::Service::list(Config::global_config(), |service| {
-    ///     println!("\n{:#?}", &service);
+    ///     match service {
+    ///         Ok(service) => println!("\n{:#?}", &service),
+    ///         Err(e) => println!("\nservice not available ({e})"),
+    ///     }
     ///     CallbackProgression::Continue
     /// })?;
     /// # Ok(())
     /// # }
     /// ```
-    fn list<F: FnMut(ServiceDetails<Self>) -> CallbackProgression>(
+    fn list<F: FnMut(Result<ServiceDetails<Self>, ServiceListError>) -> CallbackProgression>(
         config: &config::Config,
         mut callback: F,
     ) -> Result<(), ServiceListError> {
@@ -948,10 +1195,15 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> + Clone {
                 "{} due to a failure while collecting all active services for config: {:?}", msg, config);
 
         for uuid in &service_uuids {
-            if let Ok(Some(service_details)) = details::<Self>(config, uuid) {
-                if callback(service_details) == CallbackProgression::Stop {
-                    break;
-                }
+            let service_details = match details::<Self>(config, uuid) {
+                Ok(Some(service_details)) => Ok(service_details),
+                // the service was removed in the meantime
+                Ok(None) => continue,
+                Err(e) => Err(e.into()),
+            };
+
+            if callback(service_details) == CallbackProgression::Stop {
+                break;
             }
         }
 
@@ -975,12 +1227,12 @@ pub(crate) unsafe fn remove_static_service_config<S: Service>(
     }
 }
 
-fn details<S: Service>(
+fn read_static_config<S: Service>(
     config: &config::Config,
     uuid: &FileName,
-) -> Result<Option<ServiceDetails<S>>, ServiceDetailsError> {
-    let msg = "Unable to acquire service details";
-    let origin = "Service::details()";
+) -> Result<Option<StaticConfig>, ServiceDetailsError> {
+    let msg = "Unable to read the static service config";
+    let origin = "Service::read_static_config()";
     let static_storage_config = config_scheme::static_config_storage_config::<S>(config);
 
     let reader = match <<S::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
@@ -993,6 +1245,11 @@ fn details<S: Service>(
         Ok(reader) => reader,
         Err(StaticStorageOpenError::DoesNotExist)
         | Err(StaticStorageOpenError::InitializationNotYetFinalized) => return Ok(None),
+        Err(StaticStorageOpenError::InsufficientPermissions) => {
+            fail!(from origin, with ServiceDetailsError::InsufficientPermissions,
+                        "{} due to insufficient permissions to open the static service info \"{}\".",
+                        msg, uuid);
+        }
         Err(e) => {
             fail!(from origin, with ServiceDetailsError::FailedToOpenStaticServiceInfo,
                         "{} due to a failure while opening the static service info \"{}\" for reading ({:?})",
@@ -1023,6 +1280,19 @@ fn details<S: Service>(
                 msg, service_config, uuid, config);
     }
 
+    Ok(Some(service_config))
+}
+
+fn details<S: Service>(
+    config: &config::Config,
+    uuid: &FileName,
+) -> Result<Option<ServiceDetails<S>>, ServiceDetailsError> {
+    let origin = "Service::details()";
+    let service_config = match read_static_config::<S>(config, uuid)? {
+        Some(service_config) => service_config,
+        None => return Ok(None),
+    };
+
     let dynamic_config = open_dynamic_config::<S>(config, service_config.service_id())?;
     let dynamic_details = if let Some(d) = dynamic_config {
         let mut nodes = vec![];
@@ -1038,7 +1308,28 @@ fn details<S: Service>(
             };
             CallbackProgression::Continue
         });
-        Some(ServiceDynamicDetails { nodes })
+
+        let mut publishers = vec![];
+        let mut subscribers = vec![];
+        if let static_config::messaging_pattern::MessagingPattern::PublishSubscribe(_) =
+            service_config.messaging_pattern()
+        {
+            let publish_subscribe = d.get().publish_subscribe();
+            publish_subscribe.list_publishers(|details| {
+                publishers.push(*details);
+                CallbackProgression::Continue
+            });
+            publish_subscribe.list_subscribers(|details| {
+                subscribers.push(*details);
+                CallbackProgression::Continue
+            });
+        }
+
+        Some(ServiceDynamicDetails {
+            nodes,
+            publishers,
+            subscribers,
+        })
     } else {
         None
     };
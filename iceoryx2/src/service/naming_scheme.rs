@@ -23,6 +23,14 @@ pub(crate) fn event_concept_name(listener_id: &UniqueListenerId) -> FileName {
                  "{}", msg)
 }
 
+pub(crate) fn subscriber_notification_name(subscriber_port_id: u128) -> FileName {
+    let msg = "The system does not support the required file name length for the subscribers notification event concept name.";
+    let origin = "subscriber_notification_name()";
+    fatal_panic!(from origin,
+                 when FileName::new(subscriber_port_id.to_string().as_bytes()),
+                 "{}", msg)
+}
+
 pub(crate) fn connection_name(sender_port_id: u128, receiver_port_id: u128) -> FileName {
     let mut file = FileName::new(sender_port_id.to_string().as_bytes()).unwrap();
     file.push(b'_').unwrap();
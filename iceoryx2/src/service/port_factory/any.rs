@@ -0,0 +1,144 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::port_factory::any::AnyPortFactory;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let _event = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .event()
+//! #     .open_or_create()?;
+//!
+//! ipc::Service::list(Config::global_config(), |service| {
+//!     let Ok(service) = service else {
+//!         return CallbackProgression::Continue;
+//!     };
+//!
+//!     match ipc::Service::open_any(&node, &service.static_details) {
+//!         Ok(AnyPortFactory::Event(event)) => {
+//!             println!("event service {} with {} listeners", event.name(),
+//!                 event.dynamic_config().number_of_listeners());
+//!         }
+//!         Ok(AnyPortFactory::PublishSubscribe(pubsub)) => {
+//!             println!("publish-subscribe service {} with {} publishers", pubsub.name(),
+//!                 pubsub.dynamic_config().number_of_publishers());
+//!         }
+//!         Err(e) => println!("unable to open {}: {e}", service.static_details.name()),
+//!     }
+//!     CallbackProgression::Continue
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::service::attribute::AttributeSet;
+use crate::service::builder::event::EventOpenError;
+use crate::service::builder::publish_subscribe::PublishSubscribeOpenError;
+use crate::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+
+use super::{event, publish_subscribe, PortFactory};
+
+/// The type-erased [`publish_subscribe::PortFactory`] that is contained in
+/// [`AnyPortFactory::PublishSubscribe`]. The payload and user header are only known by their
+/// [`TypeDetail`](crate::service::static_config::message_type_details::TypeDetail)s.
+pub type AnyPublishSubscribePortFactory<Service> =
+    publish_subscribe::PortFactory<Service, [CustomPayloadMarker], CustomHeaderMarker>;
+
+/// Failures that can occur when a [`Service`](crate::service::Service) of an arbitrary
+/// [`MessagingPattern`] is opened with
+/// [`Service::open_any()`](crate::service::Service::open_any()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnyPortFactoryOpenError {
+    /// The [`MessagingPattern::Event`] [`Service`](crate::service::Service) could not be opened.
+    Event(EventOpenError),
+    /// The [`MessagingPattern::PublishSubscribe`] [`Service`](crate::service::Service) could not
+    /// be opened.
+    PublishSubscribe(PublishSubscribeOpenError),
+    /// The [`MessagingPattern`] of the [`Service`](crate::service::Service) is not covered by
+    /// [`AnyPortFactory`].
+    UnsupportedMessagingPattern,
+}
+
+impl From<EventOpenError> for AnyPortFactoryOpenError {
+    fn from(value: EventOpenError) -> Self {
+        Self::Event(value)
+    }
+}
+
+impl From<PublishSubscribeOpenError> for AnyPortFactoryOpenError {
+    fn from(value: PublishSubscribeOpenError) -> Self {
+        Self::PublishSubscribe(value)
+    }
+}
+
+impl core::fmt::Display for AnyPortFactoryOpenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AnyPortFactoryOpenError::{self:?}")
+    }
+}
+
+impl core::error::Error for AnyPortFactoryOpenError {}
+
+/// Contains the port factory of a [`Service`](crate::service::Service) whose
+/// [`MessagingPattern`] is only known at runtime, for instance when it was acquired via
+/// [`Service::list()`](crate::service::Service::list()). Created with
+/// [`Service::open_any()`](crate::service::Service::open_any()).
+#[derive(Debug)]
+pub enum AnyPortFactory<Service: crate::service::Service> {
+    /// The port factory of a [`MessagingPattern::Event`] based
+    /// [`Service`](crate::service::Service).
+    Event(event::PortFactory<Service>),
+    /// The type-erased port factory of a [`MessagingPattern::PublishSubscribe`] based
+    /// [`Service`](crate::service::Service).
+    PublishSubscribe(AnyPublishSubscribePortFactory<Service>),
+}
+
+impl<Service: crate::service::Service> AnyPortFactory<Service> {
+    /// Returns the [`ServiceName`] of the service
+    pub fn name(&self) -> &ServiceName {
+        match self {
+            AnyPortFactory::Event(v) => v.name(),
+            AnyPortFactory::PublishSubscribe(v) => v.name(),
+        }
+    }
+
+    /// Returns the [`ServiceId`] of the [`Service`](crate::service::Service)
+    pub fn service_id(&self) -> &ServiceId {
+        match self {
+            AnyPortFactory::Event(v) => v.service_id(),
+            AnyPortFactory::PublishSubscribe(v) => v.service_id(),
+        }
+    }
+
+    /// Returns the attributes defined in the [`Service`](crate::service::Service)
+    pub fn attributes(&self) -> &AttributeSet {
+        match self {
+            AnyPortFactory::Event(v) => v.attributes(),
+            AnyPortFactory::PublishSubscribe(v) => v.attributes(),
+        }
+    }
+
+    /// Returns the [`MessagingPattern`] of the [`Service`](crate::service::Service)
+    pub fn messaging_pattern(&self) -> MessagingPattern {
+        match self {
+            AnyPortFactory::Event(v) => v.messaging_pattern(),
+            AnyPortFactory::PublishSubscribe(v) => v.messaging_pattern(),
+        }
+    }
+}
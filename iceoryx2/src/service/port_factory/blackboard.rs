@@ -43,6 +43,7 @@ use super::writer::PortFactoryWriter;
 use crate::node::NodeListFailure;
 use crate::service::attribute::AttributeSet;
 use crate::service::builder::blackboard::BlackboardResources;
+use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config, ServiceState};
@@ -88,6 +89,10 @@ impl<
         self.service.static_config.attributes()
     }
 
+    fn messaging_pattern(&self) -> MessagingPattern {
+        self.service.static_config.messaging_pattern().into()
+    }
+
     fn static_config(&self) -> &static_config::blackboard::StaticConfig {
         self.service.static_config.blackboard()
     }
@@ -96,6 +101,10 @@ impl<
         self.service.dynamic_storage.get().blackboard()
     }
 
+    fn creation_stats(&self) -> crate::service::port_factory::ServiceTiming {
+        self.service.creation_stats
+    }
+
     fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
         &self,
         callback: F,
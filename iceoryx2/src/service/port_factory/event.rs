@@ -35,10 +35,14 @@
 //! # }
 //! ```
 use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_posix::clock::Time;
+use iceoryx2_bb_posix::process::ProcessId;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
-use crate::node::NodeListFailure;
+use crate::node::{NodeId, NodeListFailure};
+use crate::port::port_identifiers::{UniqueListenerId, UniqueNotifierId};
 use crate::service::attribute::AttributeSet;
+use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::service_id::ServiceId;
 use crate::service::{self, static_config, NoResource, ServiceState};
 use crate::service::{dynamic_config, ServiceName};
@@ -50,6 +54,36 @@ use super::notifier::PortFactoryNotifier;
 extern crate alloc;
 use alloc::sync::Arc;
 
+/// Describes a [`crate::port::listener::Listener`] that is currently connected to the
+/// [`Service`](crate::service::Service). Acquired via [`PortFactory::list_listeners()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListenerInfo {
+    /// The [`UniqueListenerId`] of the [`crate::port::listener::Listener`].
+    pub listener_id: UniqueListenerId,
+    /// The [`NodeId`] of the [`Node`](crate::node::Node) that owns the
+    /// [`crate::port::listener::Listener`].
+    pub node_id: NodeId,
+    /// The [`Time`] when the [`crate::port::listener::Listener`] was created.
+    pub creation_time: Time,
+    /// The [`ProcessId`] of the process that created the [`crate::port::listener::Listener`].
+    pub pid: ProcessId,
+}
+
+/// Describes a [`crate::port::notifier::Notifier`] that is currently connected to the
+/// [`Service`](crate::service::Service). Acquired via [`PortFactory::list_notifiers()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotifierInfo {
+    /// The [`UniqueNotifierId`] of the [`crate::port::notifier::Notifier`].
+    pub notifier_id: UniqueNotifierId,
+    /// The [`NodeId`] of the [`Node`](crate::node::Node) that owns the
+    /// [`crate::port::notifier::Notifier`].
+    pub node_id: NodeId,
+    /// The [`Time`] when the [`crate::port::notifier::Notifier`] was created.
+    pub creation_time: Time,
+    /// The [`ProcessId`] of the process that created the [`crate::port::notifier::Notifier`].
+    pub pid: ProcessId,
+}
+
 /// The factory for
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event). It can
 /// acquire dynamic and static service informations and create [`crate::port::notifier::Notifier`]
@@ -79,6 +113,10 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
         self.service.static_config.attributes()
     }
 
+    fn messaging_pattern(&self) -> MessagingPattern {
+        self.service.static_config.messaging_pattern().into()
+    }
+
     fn static_config(&self) -> &static_config::event::StaticConfig {
         self.service.static_config.event()
     }
@@ -87,6 +125,10 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
         self.service.dynamic_storage.get().event()
     }
 
+    fn creation_stats(&self) -> crate::service::port_factory::ServiceTiming {
+        self.service.creation_stats
+    }
+
     fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
         &self,
         callback: F,
@@ -147,4 +189,84 @@ impl<Service: service::Service> PortFactory<Service> {
     pub fn listener_builder(&self) -> PortFactoryListener<Service> {
         PortFactoryListener { factory: self }
     }
+
+    /// Iterates over all [`crate::port::listener::Listener`]s that are currently connected to
+    /// the [`Service`](crate::service::Service) and calls the callback with the corresponding
+    /// [`ListenerInfo`]. The callback shall return [`CallbackProgression::Continue`] when the
+    /// iteration shall continue otherwise [`CallbackProgression::Stop`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let event = node.service_builder(&"MyEventName".try_into()?)
+    ///     .event()
+    ///     .open_or_create()?;
+    ///
+    /// let listener = event.listener_builder().create()?;
+    ///
+    /// event.list_listeners(|info| {
+    ///     println!("listener {:?} created by process {:?}", info.listener_id, info.pid);
+    ///     CallbackProgression::Continue
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_listeners<F: FnMut(ListenerInfo) -> CallbackProgression>(&self, mut callback: F) {
+        self.service
+            .dynamic_storage
+            .get()
+            .event()
+            .list_listeners(|details| {
+                callback(ListenerInfo {
+                    listener_id: details.listener_id,
+                    node_id: details.node_id,
+                    creation_time: details.listener_id.0.creation_time(),
+                    pid: details.listener_id.0.pid(),
+                })
+            });
+    }
+
+    /// Iterates over all [`crate::port::notifier::Notifier`]s that are currently connected to
+    /// the [`Service`](crate::service::Service) and calls the callback with the corresponding
+    /// [`NotifierInfo`]. The callback shall return [`CallbackProgression::Continue`] when the
+    /// iteration shall continue otherwise [`CallbackProgression::Stop`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let event = node.service_builder(&"MyEventName".try_into()?)
+    ///     .event()
+    ///     .open_or_create()?;
+    ///
+    /// let notifier = event.notifier_builder().create()?;
+    ///
+    /// event.list_notifiers(|info| {
+    ///     println!("notifier {:?} created by process {:?}", info.notifier_id, info.pid);
+    ///     CallbackProgression::Continue
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_notifiers<F: FnMut(NotifierInfo) -> CallbackProgression>(&self, mut callback: F) {
+        self.service
+            .dynamic_storage
+            .get()
+            .event()
+            .list_notifiers(|details| {
+                callback(NotifierInfo {
+                    notifier_id: details.notifier_id,
+                    node_id: details.node_id,
+                    creation_time: details.notifier_id.0.creation_time(),
+                    pid: details.notifier_id.0.pid(),
+                })
+            });
+    }
 }
@@ -10,15 +10,23 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::time::Duration;
+
 use iceoryx2_bb_elementary::CallbackProgression;
 
 use crate::config::Config;
 use crate::node::{NodeListFailure, NodeState};
 
 use super::dynamic_config::DynamicConfig;
+use super::messaging_pattern::MessagingPattern;
 use super::service_id::ServiceId;
 use super::{attribute::AttributeSet, service_name::ServiceName};
 
+/// Type-erased port factory for services whose
+/// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern) is only known at
+/// runtime
+pub mod any;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) based
 /// communication and to acquire static and dynamic service information
@@ -54,9 +62,47 @@ pub mod publish_subscribe;
 /// Factory to create a [`Publisher`](crate::port::publisher::Publisher)
 pub mod publisher;
 
+/// Factory to create the endpoints of a
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based communication that are bound to a
+/// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
+/// based notification
+pub mod notifying_publish_subscribe;
+
+/// Factory to create a [`NotifyingPublisher`](crate::port::notifying_publisher::NotifyingPublisher)
+pub mod notifying_publisher;
+
+/// Factory to create a [`NotifyingSubscriber`](crate::port::notifying_subscriber::NotifyingSubscriber)
+pub mod notifying_subscriber;
+
+/// Factory to create the endpoints of
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based communication of serialized types
+pub mod publish_subscribe_serde;
+
+/// Factory to create a [`SerdePublisher`](crate::port::serde_publisher::SerdePublisher)
+pub mod serde_publisher;
+
+/// Factory to create a [`SerdeSubscriber`](crate::port::serde_subscriber::SerdeSubscriber)
+pub mod serde_subscriber;
+
 /// Factory to create a [`Subscriber`](crate::port::subscriber::Subscriber)
 pub mod subscriber;
 
+/// Describes how long it took to open or create a [`crate::service::Service`]. Acquired via
+/// [`PortFactory::creation_stats()`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ServiceTiming {
+    /// The time that passed from the start of the open or create call until the
+    /// [`crate::service::Service`] was available, including the time spent waiting for
+    /// concurrently creating or removing instances.
+    pub waited: Duration,
+    /// The number of attempts that were required to open or create the
+    /// [`crate::service::Service`]. Is `0` when the builder of the messaging pattern does not
+    /// record the timing.
+    pub attempts: usize,
+}
+
 /// The trait that contains the interface of all port factories for any kind of
 /// [`crate::service::messaging_pattern::MessagingPattern`].
 pub trait PortFactory {
@@ -80,6 +126,9 @@ pub trait PortFactory {
     /// Returns the attributes defined in the [`crate::service::Service`]
     fn attributes(&self) -> &AttributeSet;
 
+    /// Returns the [`MessagingPattern`] of the [`crate::service::Service`]
+    fn messaging_pattern(&self) -> MessagingPattern;
+
     /// Returns the StaticConfig of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     fn static_config(&self) -> &Self::StaticConfig;
@@ -88,6 +137,10 @@ pub trait PortFactory {
     /// Contains all dynamic settings, like the current participants etc..
     fn dynamic_config(&self) -> &Self::DynamicConfig;
 
+    /// Returns the [`ServiceTiming`] that was recorded while the [`crate::service::Service`]
+    /// was opened or created.
+    fn creation_stats(&self) -> ServiceTiming;
+
     /// Iterates over all [`Node`](crate::node::Node)s of the [`Service`](crate::service::Service)
     /// and calls for every [`Node`](crate::node::Node) the provided callback. If an error occurs
     /// while acquiring the [`Node`](crate::node::Node)s corresponding [`NodeState`] the error is
@@ -0,0 +1,91 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .notifying_publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! println!("name:                         {:?}", service.publish_subscribe().name());
+//! println!("max publishers:               {:?}", service.publish_subscribe().static_config().max_publishers());
+//! println!("max notifiers:                {:?}", service.event().static_config().max_notifiers());
+//! println!("event id:                     {:?}", service.event_id());
+//!
+//! let publisher = service.publisher_builder().create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+
+use crate::port::event_id::EventId;
+use crate::service;
+
+use super::notifying_publisher::PortFactoryNotifyingPublisher;
+use super::notifying_subscriber::PortFactoryNotifyingSubscriber;
+use super::{event, publish_subscribe};
+
+/// The factory for a publish-subscribe [`Service`](crate::service::Service) that is bound to
+/// an event [`Service`](crate::service::Service) with the same
+/// [`ServiceName`](crate::service::service_name::ServiceName). It creates
+/// [`crate::port::notifying_publisher::NotifyingPublisher`] or
+/// [`crate::port::notifying_subscriber::NotifyingSubscriber`] ports and provides access to
+/// both underlying [`Service`](crate::service::Service)s.
+#[derive(Debug)]
+pub struct PortFactory<Service: service::Service, Payload: Debug + ZeroCopySend + ?Sized> {
+    pub(crate) publish_subscribe: publish_subscribe::PortFactory<Service, Payload, ()>,
+    pub(crate) event: event::PortFactory<Service>,
+    pub(crate) event_id: EventId,
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend + ?Sized>
+    PortFactory<Service, Payload>
+{
+    /// Returns the [`publish_subscribe::PortFactory`] of the underlying publish-subscribe
+    /// [`Service`](crate::service::Service).
+    pub fn publish_subscribe(&self) -> &publish_subscribe::PortFactory<Service, Payload, ()> {
+        &self.publish_subscribe
+    }
+
+    /// Returns the [`event::PortFactory`] of the underlying event
+    /// [`Service`](crate::service::Service).
+    pub fn event(&self) -> &event::PortFactory<Service> {
+        &self.event
+    }
+
+    /// Returns the [`EventId`] that is emitted whenever a
+    /// [`crate::port::notifying_publisher::NotifyingPublisher`] sends data.
+    pub fn event_id(&self) -> EventId {
+        self.event_id
+    }
+
+    /// Returns a [`PortFactoryNotifyingPublisher`] to create a new
+    /// [`crate::port::notifying_publisher::NotifyingPublisher`] port.
+    pub fn publisher_builder(&self) -> PortFactoryNotifyingPublisher<'_, Service, Payload> {
+        PortFactoryNotifyingPublisher::new(self)
+    }
+
+    /// Returns a [`PortFactoryNotifyingSubscriber`] to create a new
+    /// [`crate::port::notifying_subscriber::NotifyingSubscriber`] port.
+    pub fn subscriber_builder(&self) -> PortFactoryNotifyingSubscriber<'_, Service, Payload> {
+        PortFactoryNotifyingSubscriber::new(self)
+    }
+}
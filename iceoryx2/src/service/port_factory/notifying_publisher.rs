@@ -0,0 +1,97 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .notifying_publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder()
+//!                     .max_loaned_samples(6)
+//!                     .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use crate::port::notifying_publisher::{NotifyingPublisher, NotifyingPublisherCreateError};
+use crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy;
+use crate::service;
+
+use super::notifier::PortFactoryNotifier;
+use super::notifying_publish_subscribe::PortFactory;
+use super::publisher::PortFactoryPublisher;
+
+/// Factory to create a new [`NotifyingPublisher`] port/endpoint.
+#[derive(Debug)]
+pub struct PortFactoryNotifyingPublisher<
+    'factory,
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+> {
+    publisher: PortFactoryPublisher<'factory, Service, Payload, ()>,
+    notifier: PortFactoryNotifier<'factory, Service>,
+}
+
+impl<'factory, Service: service::Service, Payload: Debug + ZeroCopySend + ?Sized>
+    PortFactoryNotifyingPublisher<'factory, Service, Payload>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, Payload>) -> Self {
+        Self {
+            publisher: factory.publish_subscribe.publisher_builder(),
+            notifier: factory
+                .event
+                .notifier_builder()
+                .default_event_id(factory.event_id),
+        }
+    }
+
+    /// Defines how many [`crate::sample_mut::SampleMut`] the underlying
+    /// [`Publisher`](crate::port::publisher::Publisher) can loan in parallel.
+    pub fn max_loaned_samples(mut self, value: usize) -> Self {
+        self.publisher = self.publisher.max_loaned_samples(value);
+        self
+    }
+
+    /// Sets the [`UnableToDeliverStrategy`] of the underlying
+    /// [`Publisher`](crate::port::publisher::Publisher).
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.publisher = self.publisher.unable_to_deliver_strategy(value);
+        self
+    }
+
+    /// Creates a new [`NotifyingPublisher`] or returns a [`NotifyingPublisherCreateError`] on
+    /// failure.
+    pub fn create(
+        self,
+    ) -> Result<NotifyingPublisher<Service, Payload>, NotifyingPublisherCreateError> {
+        let origin = format!("{self:?}");
+        let publisher = fail!(from origin, when self.publisher.create(),
+                "Failed to create the publisher of the new NotifyingPublisher port.");
+        let notifier = fail!(from origin, when self.notifier.create(),
+                "Failed to create the notifier of the new NotifyingPublisher port.");
+
+        Ok(NotifyingPublisher::new(publisher, notifier))
+    }
+}
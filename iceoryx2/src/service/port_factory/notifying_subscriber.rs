@@ -0,0 +1,85 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .notifying_publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber_builder()
+//!                     .buffer_size(1)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use crate::port::notifying_subscriber::{NotifyingSubscriber, NotifyingSubscriberCreateError};
+use crate::service;
+
+use super::listener::PortFactoryListener;
+use super::notifying_publish_subscribe::PortFactory;
+use super::subscriber::PortFactorySubscriber;
+
+/// Factory to create a new [`NotifyingSubscriber`] port/endpoint.
+#[derive(Debug)]
+pub struct PortFactoryNotifyingSubscriber<
+    'factory,
+    Service: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+> {
+    subscriber: PortFactorySubscriber<'factory, Service, Payload, ()>,
+    listener: PortFactoryListener<'factory, Service>,
+}
+
+impl<'factory, Service: service::Service, Payload: Debug + ZeroCopySend + ?Sized>
+    PortFactoryNotifyingSubscriber<'factory, Service, Payload>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, Payload>) -> Self {
+        Self {
+            subscriber: factory.publish_subscribe.subscriber_builder(),
+            listener: factory.event.listener_builder(),
+        }
+    }
+
+    /// Defines the buffer size of the underlying
+    /// [`Subscriber`](crate::port::subscriber::Subscriber). Smallest possible value is `1`.
+    pub fn buffer_size(mut self, value: usize) -> Self {
+        self.subscriber = self.subscriber.buffer_size(value);
+        self
+    }
+
+    /// Creates a new [`NotifyingSubscriber`] or returns a [`NotifyingSubscriberCreateError`] on
+    /// failure.
+    pub fn create(
+        self,
+    ) -> Result<NotifyingSubscriber<Service, Payload>, NotifyingSubscriberCreateError> {
+        let origin = format!("{self:?}");
+        let subscriber = fail!(from origin, when self.subscriber.create(),
+                "Failed to create the subscriber of the new NotifyingSubscriber port.");
+        let listener = fail!(from origin, when self.listener.create(),
+                "Failed to create the listener of the new NotifyingSubscriber port.");
+
+        Ok(NotifyingSubscriber::new(subscriber, listener))
+    }
+}
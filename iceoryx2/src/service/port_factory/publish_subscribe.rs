@@ -49,8 +49,10 @@ use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::node::NodeListFailure;
 use crate::service::attribute::AttributeSet;
+use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
+use crate::service::static_config::message_type_details::TypeDetail;
 use crate::service::{self, dynamic_config, static_config, NoResource, ServiceState};
 use alloc::sync::Arc;
 
@@ -110,6 +112,10 @@ impl<
         self.service.static_config.attributes()
     }
 
+    fn messaging_pattern(&self) -> MessagingPattern {
+        self.service.static_config.messaging_pattern().into()
+    }
+
     fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
         self.service.static_config.publish_subscribe()
     }
@@ -118,6 +124,10 @@ impl<
         self.service.dynamic_storage.get().publish_subscribe()
     }
 
+    fn creation_stats(&self) -> crate::service::port_factory::ServiceTiming {
+        self.service.creation_stats
+    }
+
     fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
         &self,
         callback: F,
@@ -192,4 +202,44 @@ impl<
     pub fn publisher_builder(&self) -> PortFactoryPublisher<Service, Payload, UserHeader> {
         PortFactoryPublisher::new(self)
     }
+
+    /// Returns true when the type `T` matches the payload type that is stored in the static
+    /// config of the [`Service`](crate::service::Service). The type name, the size and the
+    /// alignment of `T` are compared. For slice based services, `T` is the element type.
+    ///
+    /// Opening an existing [`Service`](crate::service::Service) already fails with
+    /// [`PublishSubscribeOpenError::IncompatibleTypes`](crate::service::builder::publish_subscribe::PublishSubscribeOpenError::IncompatibleTypes)
+    /// when the payload types differ. This function can be used to verify the payload type
+    /// of a [`Service`](crate::service::Service) with a custom payload type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// assert!(pubsub.is_payload_type_compatible::<u64>());
+    /// assert!(!pubsub.is_payload_type_compatible::<i64>());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_payload_type_compatible<T: ZeroCopySend>(&self) -> bool {
+        let payload = &self
+            .service
+            .static_config
+            .publish_subscribe()
+            .message_type_details
+            .payload;
+        let requested = TypeDetail::new::<T>(payload.variant);
+
+        payload.type_name == requested.type_name
+            && payload.size == requested.size
+            && requested.alignment <= payload.alignment
+    }
 }
@@ -0,0 +1,97 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serde::<String>()
+//!     .open_or_create()?;
+//!
+//! println!("type details: {:?}", service.publish_subscribe().static_config().message_type_details());
+//!
+//! let publisher = service.publisher_builder().create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use crate::service;
+use crate::service::builder::publish_subscribe_serde::SerializationFormat;
+
+use super::publish_subscribe;
+use super::serde_publisher::PortFactorySerdePublisher;
+use super::serde_subscriber::PortFactorySerdeSubscriber;
+
+/// The factory for a publish-subscribe [`Service`](crate::service::Service) of serialized
+/// types. It can create
+/// [`SerdePublisher`](crate::port::serde_publisher::SerdePublisher)s and
+/// [`SerdeSubscriber`](crate::port::serde_subscriber::SerdeSubscriber)s.
+pub struct PortFactory<
+    Service: service::Service,
+    T: serde::Serialize + serde::de::DeserializeOwned,
+    Format: SerializationFormat,
+> {
+    pub(crate) publish_subscribe: publish_subscribe::PortFactory<Service, [u8], ()>,
+    pub(crate) _data: PhantomData<fn() -> (T, Format)>,
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Format: SerializationFormat,
+    > Debug for PortFactory<Service, T, Format>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "publish_subscribe_serde::PortFactory<{}, {}, {}> {{ publish_subscribe: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<T>(),
+            Format::FORMAT_ID,
+            self.publish_subscribe
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Format: SerializationFormat,
+    > PortFactory<Service, T, Format>
+{
+    /// Returns the [`publish_subscribe::PortFactory`] of the underlying publish-subscribe
+    /// [`Service`](crate::service::Service) that transfers the serialized bytes.
+    pub fn publish_subscribe(&self) -> &publish_subscribe::PortFactory<Service, [u8], ()> {
+        &self.publish_subscribe
+    }
+
+    /// Returns a [`PortFactorySerdePublisher`] to create a new
+    /// [`crate::port::serde_publisher::SerdePublisher`] port.
+    pub fn publisher_builder(&self) -> PortFactorySerdePublisher<'_, Service, T, Format> {
+        PortFactorySerdePublisher::new(self)
+    }
+
+    /// Returns a [`PortFactorySerdeSubscriber`] to create a new
+    /// [`crate::port::serde_subscriber::SerdeSubscriber`] port.
+    pub fn subscriber_builder(&self) -> PortFactorySerdeSubscriber<'_, Service, T, Format> {
+        PortFactorySerdeSubscriber::new(self)
+    }
+}
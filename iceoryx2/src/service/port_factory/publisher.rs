@@ -70,6 +70,20 @@ use crate::{
     service,
 };
 
+/// Defines how the memory of the data segment of a [`Publisher`] shall be backed. It is a hint,
+/// when it cannot be applied the [`Publisher`] falls back to the default and
+/// [`Publisher::memory_hint_applied()`] returns false.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum MemoryHint {
+    /// The data segment is backed by regular pages.
+    #[default]
+    Default,
+    /// The data segment is backed by huge pages to reduce the TLB pressure for large samples.
+    /// Only supported on Linux with transparent huge pages enabled for shared memory, see
+    /// `/sys/kernel/mm/transparent_hugepage/shmem_enabled`.
+    HugePages,
+}
+
 #[derive(Debug)]
 pub(crate) struct LocalPublisherConfig {
     pub(crate) max_loaned_samples: usize,
@@ -77,6 +91,9 @@ pub(crate) struct LocalPublisherConfig {
     pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) history: Option<usize>,
+    pub(crate) retention: usize,
+    pub(crate) memory_hint: MemoryHint,
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -119,6 +136,9 @@ impl<
                 degradation_callback: None,
                 initial_max_slice_len: self.config.initial_max_slice_len,
                 allocation_strategy: self.config.allocation_strategy,
+                history: self.config.history,
+                retention: self.config.retention,
+                memory_hint: self.config.memory_hint,
             },
             factory: self.factory,
         }
@@ -138,6 +158,9 @@ impl<
                 allocation_strategy: AllocationStrategy::Static,
                 degradation_callback: None,
                 initial_max_slice_len: 1,
+                history: None,
+                retention: 0,
+                memory_hint: MemoryHint::Default,
                 max_loaned_samples: factory
                     .service
                     .shared_node
@@ -159,12 +182,48 @@ impl<
 
     /// Defines how many [`crate::sample_mut::SampleMut`] the [`Publisher`] can loan with
     /// [`Publisher::loan()`] or
-    /// [`Publisher::loan_uninit()`] in parallel.
+    /// [`Publisher::loan_uninit()`] in parallel. It can exceed the default of the service
+    /// since the data segment of the [`Publisher`] is sized accordingly. When the data segment
+    /// would exceed the maximum supported size, the creation fails with
+    /// [`PublisherCreateError::ExceedsMaxDataSegmentSize`].
     pub fn max_loaned_samples(mut self, value: usize) -> Self {
         self.config.max_loaned_samples = value;
         self
     }
 
+    /// Defines how many of the most recently sent samples the [`Publisher`] retains for late
+    /// joining [`crate::port::subscriber::Subscriber`]s. It must not exceed the history size
+    /// of the service, otherwise the creation fails. By default, the history size of the
+    /// service is used.
+    pub fn history(mut self, value: usize) -> Self {
+        self.config.history = Some(value);
+        self
+    }
+
+    /// Defines how many of the most recently sent samples the [`Publisher`] retains for late
+    /// joining [`crate::port::subscriber::Subscriber`]s, independent of the history size of
+    /// the service. The retained samples are delivered to every newly connected
+    /// [`crate::port::subscriber::Subscriber`] up to its buffer size, even when it requested a
+    /// smaller history, and normal delivery continues afterwards. The history and the
+    /// retained samples share the same storage, therefore a sample is never delivered twice.
+    ///
+    /// The retained samples stay loaned by the [`Publisher`] and do not reduce the
+    /// [`PortFactoryPublisher::max_loaned_samples()`]. The data segment of the [`Publisher`]
+    /// is enlarged by the number of retained samples that exceed the history size of the
+    /// service.
+    pub fn retention(mut self, samples: usize) -> Self {
+        self.config.retention = samples;
+        self
+    }
+
+    /// Defines the [`MemoryHint`] for the data segment of the [`Publisher`]. It is only applied
+    /// to a data segment with [`AllocationStrategy::Static`], a dynamic data segment is always
+    /// backed by regular pages.
+    pub fn memory_hint(mut self, value: MemoryHint) -> Self {
+        self.config.memory_hint = value;
+        self
+    }
+
     /// Sets the [`UnableToDeliverStrategy`].
     pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
         self.config.unable_to_deliver_strategy = value;
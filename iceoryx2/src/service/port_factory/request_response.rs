@@ -48,8 +48,8 @@ use crate::{
     node::NodeListFailure,
     prelude::AttributeSet,
     service::{
-        self, dynamic_config, service_id::ServiceId, service_name::ServiceName, static_config,
-        NoResource, ServiceState,
+        self, dynamic_config, messaging_pattern::MessagingPattern, service_id::ServiceId,
+        service_name::ServiceName, static_config, NoResource, ServiceState,
     },
 };
 
@@ -145,6 +145,10 @@ impl<
         self.service.static_config.attributes()
     }
 
+    fn messaging_pattern(&self) -> MessagingPattern {
+        self.service.static_config.messaging_pattern().into()
+    }
+
     fn static_config(&self) -> &Self::StaticConfig {
         self.service.static_config.request_response()
     }
@@ -153,6 +157,10 @@ impl<
         self.service.dynamic_storage.get().request_response()
     }
 
+    fn creation_stats(&self) -> crate::service::port_factory::ServiceTiming {
+        self.service.creation_stats
+    }
+
     fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
         &self,
         callback: F,
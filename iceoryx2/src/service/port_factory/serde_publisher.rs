@@ -0,0 +1,138 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serde::<String>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder()
+//!                     .max_loaned_samples(6)
+//!                     // serialized values up to 1024 bytes do not require a reallocation
+//!                     .initial_max_slice_len(1024)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::shm_allocator::AllocationStrategy;
+
+use super::{publish_subscribe_serde::PortFactory, publisher::PortFactoryPublisher};
+use crate::{
+    port::{
+        publisher::PublisherCreateError, serde_publisher::SerdePublisher,
+        unable_to_deliver_strategy::UnableToDeliverStrategy,
+    },
+    service,
+    service::builder::publish_subscribe_serde::SerializationFormat,
+};
+
+/// Factory to create a new [`SerdePublisher`] port/endpoint for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
+/// communication of serialized types.
+///
+/// Since the size of a serialized value is not known in advance, the underlying
+/// [`Publisher`](crate::port::publisher::Publisher) uses
+/// [`AllocationStrategy::PowerOfTwo`] by default so that it can grow its memory whenever
+/// a value exceeds [`PortFactorySerdePublisher::initial_max_slice_len()`].
+pub struct PortFactorySerdePublisher<
+    'factory,
+    Service: service::Service,
+    T: serde::Serialize + serde::de::DeserializeOwned,
+    Format: SerializationFormat,
+> {
+    publisher: PortFactoryPublisher<'factory, Service, [u8], ()>,
+    _data: PhantomData<fn() -> (T, Format)>,
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Format: SerializationFormat,
+    > Debug for PortFactorySerdePublisher<'_, Service, T, Format>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "PortFactorySerdePublisher<{}, {}, {}> {{ publisher: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<T>(),
+            Format::FORMAT_ID,
+            self.publisher
+        )
+    }
+}
+
+impl<
+        'factory,
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Format: SerializationFormat,
+    > PortFactorySerdePublisher<'factory, Service, T, Format>
+{
+    const DEFAULT_INITIAL_MAX_SLICE_LEN: usize = 128;
+
+    pub(crate) fn new(factory: &'factory PortFactory<Service, T, Format>) -> Self {
+        Self {
+            publisher: factory
+                .publish_subscribe
+                .publisher_builder()
+                .initial_max_slice_len(Self::DEFAULT_INITIAL_MAX_SLICE_LEN)
+                .allocation_strategy(AllocationStrategy::PowerOfTwo),
+            _data: PhantomData,
+        }
+    }
+
+    /// See [`PortFactoryPublisher::max_loaned_samples()`].
+    pub fn max_loaned_samples(mut self, value: usize) -> Self {
+        self.publisher = self.publisher.max_loaned_samples(value);
+        self
+    }
+
+    /// See [`PortFactoryPublisher::unable_to_deliver_strategy()`].
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.publisher = self.publisher.unable_to_deliver_strategy(value);
+        self
+    }
+
+    /// Defines the number of bytes a serialized value can occupy before the underlying
+    /// [`Publisher`](crate::port::publisher::Publisher) has to reallocate its memory.
+    pub fn initial_max_slice_len(mut self, value: usize) -> Self {
+        self.publisher = self.publisher.initial_max_slice_len(value);
+        self
+    }
+
+    /// See [`PortFactoryPublisher::allocation_strategy()`].
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.publisher = self.publisher.allocation_strategy(value);
+        self
+    }
+
+    /// Creates a new [`SerdePublisher`] or returns a [`PublisherCreateError`] on failure.
+    pub fn create(self) -> Result<SerdePublisher<Service, T, Format>, PublisherCreateError> {
+        let origin = format!("{self:?}");
+        let publisher = fail!(from origin, when self.publisher.create(),
+            "Failed to create new SerdePublisher port.");
+        Ok(SerdePublisher::new(publisher))
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serde::<String>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber_builder()
+//!                     .buffer_size(1)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+
+use super::{publish_subscribe_serde::PortFactory, subscriber::PortFactorySubscriber};
+use crate::{
+    port::{serde_subscriber::SerdeSubscriber, subscriber::SubscriberCreateError},
+    service,
+    service::builder::publish_subscribe_serde::SerializationFormat,
+};
+
+/// Factory to create a new [`SerdeSubscriber`] port/endpoint for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
+/// communication of serialized types.
+pub struct PortFactorySerdeSubscriber<
+    'factory,
+    Service: service::Service,
+    T: serde::Serialize + serde::de::DeserializeOwned,
+    Format: SerializationFormat,
+> {
+    subscriber: PortFactorySubscriber<'factory, Service, [u8], ()>,
+    _data: PhantomData<fn() -> (T, Format)>,
+}
+
+impl<
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Format: SerializationFormat,
+    > Debug for PortFactorySerdeSubscriber<'_, Service, T, Format>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "PortFactorySerdeSubscriber<{}, {}, {}> {{ subscriber: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<T>(),
+            Format::FORMAT_ID,
+            self.subscriber
+        )
+    }
+}
+
+impl<
+        'factory,
+        Service: service::Service,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+        Format: SerializationFormat,
+    > PortFactorySerdeSubscriber<'factory, Service, T, Format>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, T, Format>) -> Self {
+        Self {
+            subscriber: factory.publish_subscribe.subscriber_builder(),
+            _data: PhantomData,
+        }
+    }
+
+    /// See [`PortFactorySubscriber::buffer_size()`].
+    pub fn buffer_size(mut self, value: usize) -> Self {
+        self.subscriber = self.subscriber.buffer_size(value);
+        self
+    }
+
+    /// Creates a new [`SerdeSubscriber`] or returns a [`SubscriberCreateError`] on failure.
+    pub fn create(self) -> Result<SerdeSubscriber<Service, T, Format>, SubscriberCreateError> {
+        let origin = format!("{self:?}");
+        let subscriber = fail!(from origin, when self.subscriber.create(),
+            "Failed to create new SerdeSubscriber port.");
+        Ok(SerdeSubscriber::new(subscriber))
+    }
+}
@@ -29,12 +29,14 @@
 //! ```
 
 use core::fmt::Debug;
+use core::time::Duration;
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
 
 use crate::{
     port::{
+        sample_filter::SampleFilter,
         subscriber::{Subscriber, SubscriberCreateError},
         DegradationAction, DegradationCallback,
     },
@@ -47,6 +49,11 @@ use super::publish_subscribe::PortFactory;
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
     pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
+    pub(crate) filter: Option<SampleFilter>,
+    pub(crate) history_request: Option<usize>,
+    pub(crate) clamp_history_request: bool,
+    pub(crate) keep_latest: bool,
+    pub(crate) max_sample_age: Option<Duration>,
 }
 
 /// Factory to create a new [`Subscriber`] port/endpoint for
@@ -87,6 +94,11 @@ impl<
             config: SubscriberConfig {
                 buffer_size: self.config.buffer_size,
                 degradation_callback: None,
+                filter: self.config.filter,
+                history_request: self.config.history_request,
+                clamp_history_request: self.config.clamp_history_request,
+                keep_latest: self.config.keep_latest,
+                max_sample_age: self.config.max_sample_age,
             },
             factory: self.factory,
         }
@@ -97,6 +109,11 @@ impl<
             config: SubscriberConfig {
                 buffer_size: None,
                 degradation_callback: None,
+                filter: None,
+                history_request: None,
+                clamp_history_request: false,
+                keep_latest: false,
+                max_sample_age: None,
             },
             factory,
         }
@@ -108,6 +125,65 @@ impl<
         self
     }
 
+    /// The [`Subscriber`] keeps only the most recent sample of every
+    /// [`Publisher`](crate::port::publisher::Publisher). Its buffer size is set to `1` and a
+    /// [`Publisher`](crate::port::publisher::Publisher) overwrites the buffered sample with a
+    /// newer one, independent of the overflow setting of the
+    /// [`Service`](crate::service::Service). Therefore, [`Subscriber::receive()`] always
+    /// returns the freshest available sample and the
+    /// [`Publisher`](crate::port::publisher::Publisher) never blocks or discards the new sample.
+    /// It overrides [`PortFactorySubscriber::buffer_size()`].
+    pub fn keep_latest(mut self) -> Self {
+        self.config.keep_latest = true;
+        self
+    }
+
+    /// Defines the maximum age of a received sample. [`Subscriber::receive()`] skips and
+    /// releases every sample that was sent longer ago than the provided duration. The number
+    /// of skipped samples can be acquired with [`Subscriber::expired_samples()`].
+    /// It requires a [`Service`](crate::service::Service) that was created with
+    /// [`Builder::enable_timestamps()`](crate::service::builder::publish_subscribe::Builder::enable_timestamps()),
+    /// otherwise the creation fails with
+    /// [`SubscriberCreateError::TimestampsNotEnabled`].
+    pub fn max_sample_age(mut self, value: Duration) -> Self {
+        self.config.max_sample_age = Some(value);
+        self
+    }
+
+    /// Defines how many of the most recent historical samples the [`Subscriber`] receives
+    /// when it connects to a [`Publisher`](crate::port::publisher::Publisher). If it is not
+    /// set, the full history of the [`Service`](crate::service::Service) is requested.
+    /// The number of delivered samples is also limited by the buffer size of the
+    /// [`Subscriber`]. Whether all historical samples were received can be checked with
+    /// [`Subscriber::has_history_completed()`].
+    ///
+    /// When the request exceeds the history size of the
+    /// [`Service`](crate::service::Service), the creation fails with
+    /// [`SubscriberCreateError::HistoryRequestExceedsHistorySizeOfService`] unless
+    /// [`PortFactorySubscriber::clamp_history_request()`] is enabled.
+    pub fn history_request(mut self, value: usize) -> Self {
+        self.config.history_request = Some(value);
+        self
+    }
+
+    /// When enabled, a [`PortFactorySubscriber::history_request()`] that exceeds the history
+    /// size of the [`Service`](crate::service::Service) is reduced to the history size and
+    /// a warning is emitted instead of failing the creation of the [`Subscriber`].
+    pub fn clamp_history_request(mut self, value: bool) -> Self {
+        self.config.clamp_history_request = value;
+        self
+    }
+
+    /// Registers a [`SampleFilter`] at the [`Subscriber`]. The
+    /// [`Publisher`](crate::port::publisher::Publisher) evaluates it before delivery and
+    /// samples that do not match are never delivered to the [`Subscriber`]. A
+    /// [`SampleFilter`] whose compared range exceeds the user header or, for fixed size
+    /// payloads, the payload causes [`SubscriberCreateError::IncompatibleSampleFilter`].
+    pub fn filter(mut self, value: SampleFilter) -> Self {
+        self.config.filter = Some(value);
+        self
+    }
+
     /// Sets the [`DegradationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegradationAction`] measures will be taken.
@@ -0,0 +1,201 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Service`] trait is statically dispatched and cannot be used as trait object since it
+//! defines associated types. [`ServiceDyn`] is an object-safe subset of it that can be selected
+//! at runtime, for instance depending on whether the application runs in a container.
+//! [`BoxedService`] owns such a runtime selected service type.
+//!
+//! The ports and [`Node`](crate::node::Node)s remain statically dispatched since their types
+//! depend on the associated types of the [`Service`].
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::config::Config;
+//! use iceoryx2::service::service_dyn::{BoxedService, ServiceDyn};
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let use_ipc = true;
+//! let service: BoxedService = if use_ipc {
+//!     BoxedService::new::<ipc::Service>()
+//! } else {
+//!     BoxedService::new::<local::Service>()
+//! };
+//!
+//! let does_exist = service.does_exist(
+//!     &"My/Funk/ServiceName".try_into()?,
+//!     Config::global_config(),
+//!     MessagingPattern::Event,
+//! )?;
+//!
+//! service.list(Config::global_config(), &mut |service| {
+//!     if let Ok(service) = service {
+//!         println!("{}", service.name());
+//!     }
+//!     CallbackProgression::Continue
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+
+use crate::config;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+use crate::service::static_config::StaticConfig;
+use crate::service::{Service, ServiceDetailsError, ServiceInspectError, ServiceListError};
+
+/// Object-safe counterpart of [`Service`] that allows to choose the service type at runtime.
+/// It is implemented for every [`Service`] via [`BoxedService::new()`].
+pub trait ServiceDyn: Debug + Send + Sync {
+    /// Returns the type name of the underlying [`Service`].
+    fn type_name(&self) -> &'static str;
+
+    /// Checks if a service under a given [`config::Config`] does exist, see
+    /// [`Service::does_exist()`].
+    fn does_exist(
+        &self,
+        service_name: &ServiceName,
+        config: &config::Config,
+        messaging_pattern: MessagingPattern,
+    ) -> Result<bool, ServiceDetailsError>;
+
+    /// Acquires the [`StaticConfig`] of a service, see [`Service::details()`].
+    fn details(
+        &self,
+        service_name: &ServiceName,
+        config: &config::Config,
+        messaging_pattern: MessagingPattern,
+    ) -> Result<Option<StaticConfig>, ServiceDetailsError>;
+
+    /// Acquires the [`StaticConfig`] of a service without opening any port, see
+    /// [`Service::static_config()`].
+    fn static_config(
+        &self,
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Result<StaticConfig, ServiceInspectError>;
+
+    /// Acquires the [`StaticConfig`] of the service with the provided [`ServiceId`], see
+    /// [`Service::static_config_by_id()`].
+    fn static_config_by_id(
+        &self,
+        service_id: &ServiceId,
+        config: &config::Config,
+    ) -> Result<StaticConfig, ServiceInspectError>;
+
+    /// Calls the callback with the [`StaticConfig`] of every service created under a given
+    /// [`config::Config`], see [`Service::list()`].
+    fn list(
+        &self,
+        config: &config::Config,
+        callback: &mut dyn FnMut(Result<StaticConfig, ServiceListError>) -> CallbackProgression,
+    ) -> Result<(), ServiceListError>;
+}
+
+#[derive(Debug)]
+struct ServiceDynImpl<S: Service> {
+    _service: PhantomData<fn() -> S>,
+}
+
+impl<S: Service> ServiceDyn for ServiceDynImpl<S> {
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<S>()
+    }
+
+    fn does_exist(
+        &self,
+        service_name: &ServiceName,
+        config: &config::Config,
+        messaging_pattern: MessagingPattern,
+    ) -> Result<bool, ServiceDetailsError> {
+        S::does_exist(service_name, config, messaging_pattern)
+    }
+
+    fn details(
+        &self,
+        service_name: &ServiceName,
+        config: &config::Config,
+        messaging_pattern: MessagingPattern,
+    ) -> Result<Option<StaticConfig>, ServiceDetailsError> {
+        Ok(S::details(service_name, config, messaging_pattern)?.map(|d| d.static_details))
+    }
+
+    fn static_config(
+        &self,
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Result<StaticConfig, ServiceInspectError> {
+        S::static_config(service_name, config)
+    }
+
+    fn static_config_by_id(
+        &self,
+        service_id: &ServiceId,
+        config: &config::Config,
+    ) -> Result<StaticConfig, ServiceInspectError> {
+        S::static_config_by_id(service_id, config)
+    }
+
+    fn list(
+        &self,
+        config: &config::Config,
+        callback: &mut dyn FnMut(Result<StaticConfig, ServiceListError>) -> CallbackProgression,
+    ) -> Result<(), ServiceListError> {
+        S::list(config, |service| {
+            callback(service.map(|d| d.static_details))
+        })
+    }
+}
+
+/// Owns a [`ServiceDyn`] whose [`Service`] type was selected at runtime.
+#[derive(Debug)]
+pub struct BoxedService(Box<dyn ServiceDyn>);
+
+impl BoxedService {
+    /// Creates a new [`BoxedService`] for the [`Service`] type `S`.
+    pub fn new<S: Service + 'static>() -> Self {
+        Self(Box::new(ServiceDynImpl::<S> {
+            _service: PhantomData,
+        }))
+    }
+
+    /// Releases the underlying [`ServiceDyn`] trait object.
+    pub fn into_inner(self) -> Box<dyn ServiceDyn> {
+        self.0
+    }
+}
+
+impl Deref for BoxedService {
+    type Target = dyn ServiceDyn;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+impl From<Box<dyn ServiceDyn>> for BoxedService {
+    fn from(value: Box<dyn ServiceDyn>) -> Self {
+        Self(value)
+    }
+}
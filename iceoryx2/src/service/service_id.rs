@@ -10,6 +10,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::fmt::Display;
+use core::str::FromStr;
+
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -54,3 +57,17 @@ impl ServiceId {
         unsafe { core::str::from_utf8_unchecked(self.0.as_bytes()) }
     }
 }
+
+impl Display for ServiceId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ServiceId {
+    type Err = SemanticStringError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(RestrictedFileName::new(s.as_bytes())?))
+    }
+}
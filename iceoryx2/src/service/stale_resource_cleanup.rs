@@ -10,7 +10,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{debug, fail, warn};
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::event::NamedConceptMgmt;
 use iceoryx2_cal::named_concept::NamedConceptListError;
@@ -18,8 +19,10 @@ use iceoryx2_cal::named_concept::NamedConceptRemoveError;
 use iceoryx2_cal::zero_copy_connection::{ZeroCopyConnection, ZeroCopyPortRemoveError};
 
 use crate::config;
+use crate::node::{NodeId, NodeState, NodeView};
 use crate::service;
 use crate::service::config_scheme::data_segment_config;
+use crate::service::dynamic_config::publish_subscribe::DynamicConfig;
 use crate::service::naming_scheme::data_segment_name;
 
 use super::config_scheme::connection_config;
@@ -175,3 +178,52 @@ pub(crate) unsafe fn remove_receiver_port_from_all_connections<Service: service:
 
     ret_val
 }
+
+/// Checks the [`Node`](crate::node::Node)s of all registered
+/// [`Publisher`](crate::port::publisher::Publisher)s and removes the stale resources of every
+/// dead one. This releases the data segments and connections of its
+/// [`Publisher`](crate::port::publisher::Publisher)s and removes them from the service.
+/// Returns the number of removed [`Publisher`](crate::port::publisher::Publisher)s.
+pub(crate) fn remove_dead_publishers<Service: service::Service>(
+    dynamic_config: &DynamicConfig,
+    config: &config::Config,
+) -> usize {
+    let origin = format!(
+        "remove_dead_publishers::<{}>()",
+        core::any::type_name::<Service>()
+    );
+
+    let mut publishers_of_node: Vec<(NodeId, usize)> = Vec::new();
+    dynamic_config.list_publishers(|details| {
+        match publishers_of_node
+            .iter_mut()
+            .find(|(node_id, _)| *node_id == details.node_id)
+        {
+            Some((_, number_of_publishers)) => *number_of_publishers += 1,
+            None => publishers_of_node.push((details.node_id, 1)),
+        }
+        CallbackProgression::Continue
+    });
+
+    let mut number_of_removed_publishers = 0;
+    for (node_id, number_of_publishers) in publishers_of_node {
+        if let Ok(Some(NodeState::Dead(dead_node))) = NodeState::<Service>::new(&node_id, config) {
+            warn!(from origin,
+                "Detected {} publisher(s) of the dead node {:?}, their resources are reclaimed.",
+                number_of_publishers, dead_node.id());
+            match dead_node.remove_stale_resources() {
+                Ok(true) => number_of_removed_publishers += number_of_publishers,
+                Ok(false) => {
+                    debug!(from origin,
+                        "The dead node {:?} is already cleaned up by another instance.", node_id);
+                }
+                Err(e) => {
+                    debug!(from origin,
+                        "Unable to remove the stale resources of the dead node {:?} ({:?}).", node_id, e);
+                }
+            }
+        }
+    }
+
+    number_of_removed_publishers
+}
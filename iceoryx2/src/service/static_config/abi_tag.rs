@@ -0,0 +1,112 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt::Display;
+
+use iceoryx2_bb_container::byte_string::FixedSizeByteString;
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::MAX_ABI_NAME_LENGTH;
+
+/// A fixed-size string type used to store the name of the ABI, see [`AbiTag::abi_name()`].
+pub type AbiNameString = FixedSizeByteString<MAX_ABI_NAME_LENGTH>;
+
+/// The byte order of the platform that created a [`crate::service::Service`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize)]
+pub enum Endianness {
+    /// The least significant byte is stored first.
+    Little,
+    /// The most significant byte is stored first.
+    Big,
+}
+
+impl Endianness {
+    /// Returns the [`Endianness`] of the current platform.
+    pub const fn native() -> Self {
+        if cfg!(target_endian = "big") {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        }
+    }
+}
+
+/// Describes the binary layout assumptions of the participant that created a
+/// [`crate::service::Service`]: the byte order, the pointer width and optionally a user
+/// defined name of the ABI, like the compiler and its version.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize)]
+#[repr(C)]
+pub struct AbiTag {
+    pub(crate) endianness: Endianness,
+    pub(crate) pointer_width: u32,
+    pub(crate) abi_name: Option<AbiNameString>,
+}
+
+impl Default for AbiTag {
+    fn default() -> Self {
+        Self::native()
+    }
+}
+
+impl Display for AbiTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let endianness = match self.endianness {
+            Endianness::Little => "little-endian",
+            Endianness::Big => "big-endian",
+        };
+        write!(f, "{}, {}-bit", endianness, self.pointer_width)?;
+        if let Some(abi_name) = &self.abi_name {
+            write!(f, ", {abi_name}")?;
+        }
+        Ok(())
+    }
+}
+
+impl AbiTag {
+    /// Returns the [`AbiTag`] of the current platform without an ABI name.
+    pub const fn native() -> Self {
+        Self {
+            endianness: Endianness::native(),
+            pointer_width: usize::BITS,
+            abi_name: None,
+        }
+    }
+
+    /// Returns the [`Endianness`].
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Returns the pointer width in bits.
+    pub fn pointer_width(&self) -> u32 {
+        self.pointer_width
+    }
+
+    /// Returns the user defined name of the ABI, if one was provided.
+    pub fn abi_name(&self) -> Option<&AbiNameString> {
+        self.abi_name.as_ref()
+    }
+
+    /// Two [`AbiTag`]s are compatible when they have the same [`Endianness`] and pointer width.
+    /// The ABI names are only compared when both sides provide one.
+    pub(crate) fn is_compatible_to(&self, rhs: &AbiTag) -> bool {
+        self.endianness == rhs.endianness
+            && self.pointer_width == rhs.pointer_width
+            && match (&self.abi_name, &rhs.abi_name) {
+                (Some(lhs), Some(rhs)) => lhs == rhs,
+                _ => true,
+            }
+    }
+}
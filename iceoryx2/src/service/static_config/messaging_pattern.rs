@@ -62,6 +62,17 @@ impl Display for MessagingPattern {
     }
 }
 
+impl From<&MessagingPattern> for crate::service::messaging_pattern::MessagingPattern {
+    fn from(value: &MessagingPattern) -> Self {
+        match value {
+            MessagingPattern::RequestResponse(_) => Self::RequestResponse,
+            MessagingPattern::PublishSubscribe(_) => Self::PublishSubscribe,
+            MessagingPattern::Event(_) => Self::Event,
+            MessagingPattern::Blackboard(_) => Self::Blackboard,
+        }
+    }
+}
+
 impl MessagingPattern {
     /// checks whether the 2 MessagingPatterns are the same regardless the values inside them.
     pub(crate) fn is_same_pattern(&self, rhs: &MessagingPattern) -> bool {
@@ -24,6 +24,11 @@ pub mod publish_subscribe;
 /// and the type variant
 pub mod message_type_details;
 
+/// Contains the byte order, pointer width and ABI name of the creator of a
+/// [`MessagingPattern::PublishSubscribe`]
+/// based service.
+pub mod abi_tag;
+
 pub mod request_response;
 
 pub mod messaging_pattern;
@@ -28,11 +28,15 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("notifications:                    {:?}", pubsub.static_config().has_notifications());
+//! println!("timestamps:                       {:?}", pubsub.static_config().has_timestamps());
+//! println!("abi tag:                          {}", pubsub.static_config().abi_tag());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use super::abi_tag::AbiTag;
 use super::message_type_details::MessageTypeDetails;
 use crate::config;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
@@ -53,7 +57,11 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) enable_notifications: bool,
+    pub(crate) enable_timestamps: bool,
     pub(crate) message_type_details: MessageTypeDetails,
+    pub(crate) type_hash: Option<[u8; 32]>,
+    pub(crate) abi_tag: AbiTag,
 }
 
 impl StaticConfig {
@@ -72,7 +80,11 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            enable_notifications: false,
+            enable_timestamps: false,
             message_type_details: MessageTypeDetails::default(),
+            type_hash: None,
+            abi_tag: AbiTag::native(),
         }
     }
 
@@ -126,6 +138,32 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns true if the [`crate::port::publisher::Publisher`] notifies the
+    /// [`crate::port::subscriber::Subscriber`] on every delivered [`crate::sample::Sample`] so
+    /// that the [`crate::port::subscriber::Subscriber`] can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet), otherwise false.
+    pub fn has_notifications(&self) -> bool {
+        self.enable_notifications
+    }
+
+    /// Returns true if the [`crate::port::publisher::Publisher`] stores the send time in the
+    /// [`Header`](crate::service::header::publish_subscribe::Header) of every
+    /// [`crate::sample::Sample`], otherwise false.
+    pub fn has_timestamps(&self) -> bool {
+        self.enable_timestamps
+    }
+
+    /// Returns the user-provided hash that describes the layout of the transmitted types. See
+    /// [`Builder::type_hash()`](crate::service::builder::publish_subscribe::Builder::type_hash()).
+    pub fn type_hash(&self) -> Option<&[u8; 32]> {
+        self.type_hash.as_ref()
+    }
+
+    /// Returns the [`AbiTag`] of the participant that created the [`crate::service::Service`].
+    pub fn abi_tag(&self) -> &AbiTag {
+        &self.abi_tag
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
@@ -59,14 +59,16 @@
 //!
 //! let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
 //!     if attachment_id.has_event_from(&guard) {
-//!         while let Ok(Some(event_id)) = listener.try_wait_one() {
+//!         while let Ok(Some(event_id)) = listener.try_wait_one().map(|r| r.event_id()) {
 //!             println!("received notification {:?}", event_id);
 //!         }
 //!     }
 //!     CallbackProgression::Continue
 //! };
 //!
-//! waitset.wait_and_process(on_event)?;
+//! if let WaitSetRunResult::TerminationRequest = waitset.wait_and_process(on_event)? {
+//!     println!("termination requested");
+//! }
 //!
 //! # Ok(())
 //! # }
@@ -91,7 +93,7 @@
 //!
 //! let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
 //!     if attachment_id.has_event_from(&guard) {
-//!         while let Ok(Some(event_id)) = listener.try_wait_one() {
+//!         while let Ok(Some(event_id)) = listener.try_wait_one().map(|r| r.event_id()) {
 //!             println!("received notification {:?}", event_id);
 //!         }
 //!     } else if attachment_id.has_missed_deadline(&guard) {
@@ -172,7 +174,7 @@
 //!
 //! let on_event = |attachment_id| {
 //!     if let Some(listener) = listeners.get(&attachment_id) {
-//!         while let Ok(Some(event_id)) = listener.try_wait_one() {
+//!         while let Ok(Some(event_id)) = listener.try_wait_one().map(|r| r.event_id()) {
 //!             println!("received notification {:?}", event_id);
 //!         }
 //!     }
@@ -214,22 +216,27 @@
 //! # }
 
 use core::{
-    cell::RefCell, fmt::Debug, hash::Hash, marker::PhantomData, sync::atomic::Ordering,
+    cell::Cell, cell::RefCell, fmt::Debug, hash::Hash, marker::PhantomData, sync::atomic::Ordering,
     time::Duration,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use iceoryx2_bb_elementary::CallbackProgression;
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::{debug, fail, warn};
 use iceoryx2_bb_posix::{
+    clock::Time,
     deadline_queue::{DeadlineQueue, DeadlineQueueBuilder, DeadlineQueueGuard, DeadlineQueueIndex},
     file_descriptor::FileDescriptor,
     file_descriptor_set::SynchronousMultiplexing,
-    signal::SignalHandler,
+    signal::{NonFatalFetchableSignal, SignalHandler},
 };
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::event::Event;
 use iceoryx2_cal::reactor::*;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
 
+use crate::node::Node;
+use crate::port::listener::Listener;
 use crate::signal_handling_mode::SignalHandlingMode;
 
 /// States why the [`WaitSet::wait_and_process()`] method returned.
@@ -237,7 +244,8 @@ use crate::signal_handling_mode::SignalHandlingMode;
 pub enum WaitSetRunResult {
     /// A termination signal `SIGTERM` was received.
     TerminationRequest,
-    /// An interrupt signal `SIGINT` was received.
+    /// The wait was interrupted by a signal, for instance `SIGINT`. The signal that caused the
+    /// interrupt can be acquired with [`WaitSet::last_interrupt_signal()`].
     Interrupt,
     /// The users callback returned [`CallbackProgression::Stop`].
     StopRequest,
@@ -253,6 +261,10 @@ pub enum WaitSetAttachmentError {
     InsufficientCapacity,
     /// The attachment is already attached.
     AlreadyAttached,
+    /// The attachment is already attached as notification and shall be attached as deadline
+    /// or vice versa. An object can only be attached once since a deadline attachment already
+    /// reports the events of the object, see [`WaitSet::attach_deadline()`].
+    AlreadyAttachedWithDifferentSemantic,
     /// An internal error has occurred.
     InternalError,
 }
@@ -265,6 +277,23 @@ impl core::fmt::Display for WaitSetAttachmentError {
 
 impl core::error::Error for WaitSetAttachmentError {}
 
+/// Defines the failures that can occur when calling [`WaitSet::reattach_deadline()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetReattachError {
+    /// The provided [`WaitSetGuard`] does not belong to a deadline attachment of the [`WaitSet`].
+    NotADeadlineAttachment,
+    /// An internal error has occurred.
+    InternalError,
+}
+
+impl core::fmt::Display for WaitSetReattachError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "WaitSetReattachError::{self:?}")
+    }
+}
+
+impl core::error::Error for WaitSetReattachError {}
+
 /// Defines the failures that can occur when calling [`WaitSet::wait_and_process()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum WaitSetRunError {
@@ -299,6 +328,73 @@ impl core::fmt::Display for WaitSetCreateError {
 
 impl core::error::Error for WaitSetCreateError {}
 
+/// Accumulated statistics of the event processing of a [`WaitSet`] since its creation, see
+/// [`WaitSet::stats()`]. Useful to diagnose latency spikes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WaitSetStats {
+    /// The number of times the [`WaitSet`] woke up and processed its attachments.
+    pub total_runs: u64,
+    /// The number of callback calls for notification attachments.
+    pub total_notifications: u64,
+    /// The number of callback calls for interval attachments.
+    pub total_ticks: u64,
+    /// The number of callback calls for missed deadlines.
+    pub total_deadline_misses: u64,
+    /// The longest duration of a single callback call in nanoseconds. It is only measured when
+    /// the [`WaitSet`] was created with [`WaitSetBuilder::measure_callback_duration()`],
+    /// otherwise it stays 0.
+    pub max_callback_duration_ns: u64,
+}
+
+#[derive(Debug)]
+struct WaitSetStatsCounters {
+    total_runs: IoxAtomicU64,
+    total_notifications: IoxAtomicU64,
+    total_ticks: IoxAtomicU64,
+    total_deadline_misses: IoxAtomicU64,
+    max_callback_duration_ns: IoxAtomicU64,
+}
+
+impl WaitSetStatsCounters {
+    fn new() -> Self {
+        Self {
+            total_runs: IoxAtomicU64::new(0),
+            total_notifications: IoxAtomicU64::new(0),
+            total_ticks: IoxAtomicU64::new(0),
+            total_deadline_misses: IoxAtomicU64::new(0),
+            max_callback_duration_ns: IoxAtomicU64::new(0),
+        }
+    }
+
+    fn add_run(&self) {
+        self.total_runs.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn add_call(&self, attachment_type: &AttachmentIdType, duration: Option<Duration>) {
+        match attachment_type {
+            AttachmentIdType::Tick(..) => &self.total_ticks,
+            AttachmentIdType::Deadline(..) => &self.total_deadline_misses,
+            AttachmentIdType::Notification(..) => &self.total_notifications,
+        }
+        .fetch_add(1, Ordering::Relaxed);
+
+        if let Some(duration) = duration {
+            self.max_callback_duration_ns
+                .fetch_max(duration.as_nanos() as u64, Ordering::Relaxed);
+        }
+    }
+
+    fn load(&self) -> WaitSetStats {
+        WaitSetStats {
+            total_runs: self.total_runs.load(Ordering::Relaxed),
+            total_notifications: self.total_notifications.load(Ordering::Relaxed),
+            total_ticks: self.total_ticks.load(Ordering::Relaxed),
+            total_deadline_misses: self.total_deadline_misses.load(Ordering::Relaxed),
+            max_callback_duration_ns: self.max_callback_duration_ns.load(Ordering::Relaxed),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
 enum AttachmentIdType {
     Tick(u64, DeadlineQueueIndex),
@@ -306,7 +402,26 @@ enum AttachmentIdType {
     Notification(u64, i32),
 }
 
+/// Defines why the callback of [`WaitSet::wait_and_process_detailed()`] was called for an
+/// attachment. Acquired via [`WaitSetAttachmentId::kind()`].
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum WaitSetAttachmentKind {
+    /// The object attached with [`WaitSet::attach_notification()`] or
+    /// [`WaitSet::attach_deadline()`] has received an event.
+    Notification,
+    /// The object attached with [`WaitSet::attach_deadline()`] did not receive an event
+    /// before its deadline has passed.
+    DeadlineMissed,
+    /// The interval attached with [`WaitSet::attach_interval()`] has passed.
+    Tick,
+}
+
 /// Represents an attachment to the [`WaitSet`]
+///
+/// The [`WaitSetAttachmentId`] contains the address of the [`WaitSet`] it belongs to and is
+/// therefore only meaningful inside the process and the run that created it. Use
+/// [`WaitSetAttachmentId::stable_id()`] to acquire an identifier that can be persisted or
+/// correlated with other processes.
 #[derive(Clone, Copy)]
 pub struct WaitSetAttachmentId<Service: crate::service::Service> {
     attachment_type: AttachmentIdType,
@@ -328,18 +443,14 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
     /// Creates an [`WaitSetAttachmentId`] from a [`WaitSetGuard`] that was returned via
     /// [`WaitSet::attach_interval()`], [`WaitSet::attach_notification()`] or
     /// [`WaitSet::attach_deadline()`].
-    pub fn from_guard(guard: &WaitSetGuard<Service>) -> Self {
+    pub fn from_guard<C>(guard: &WaitSetGuard<Service, C>) -> Self {
         match &guard.guard_type {
             GuardType::Tick(t) => WaitSetAttachmentId::tick(guard.waitset, t.index()),
-            GuardType::Deadline(r, t) => WaitSetAttachmentId::deadline(
-                guard.waitset,
-                unsafe { r.file_descriptor().native_handle() },
-                t.index(),
-            ),
+            GuardType::Deadline(r, t, _) => {
+                WaitSetAttachmentId::deadline(guard.waitset, r.file_descriptor, t.index())
+            }
             GuardType::Notification(r) => {
-                WaitSetAttachmentId::notification(guard.waitset, unsafe {
-                    r.file_descriptor().native_handle()
-                })
+                WaitSetAttachmentId::notification(guard.waitset, r.file_descriptor)
             }
         }
     }
@@ -372,24 +483,24 @@ impl<Service: crate::service::Service> Hash for WaitSetAttachmentId<Service> {
 }
 
 impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
-    fn tick(waitset: &WaitSet<Service>, deadline_queue_idx: DeadlineQueueIndex) -> Self {
+    fn tick<C>(waitset: &WaitSet<Service, C>, deadline_queue_idx: DeadlineQueueIndex) -> Self {
         Self {
             attachment_type: AttachmentIdType::Tick(
-                waitset as *const WaitSet<Service> as u64,
+                waitset as *const WaitSet<Service, C> as u64,
                 deadline_queue_idx,
             ),
             _data: PhantomData,
         }
     }
 
-    fn deadline(
-        waitset: &WaitSet<Service>,
+    fn deadline<C>(
+        waitset: &WaitSet<Service, C>,
         reactor_idx: i32,
         deadline_queue_idx: DeadlineQueueIndex,
     ) -> Self {
         Self {
             attachment_type: AttachmentIdType::Deadline(
-                waitset as *const WaitSet<Service> as u64,
+                waitset as *const WaitSet<Service, C> as u64,
                 reactor_idx,
                 deadline_queue_idx,
             ),
@@ -397,10 +508,10 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
         }
     }
 
-    fn notification(waitset: &WaitSet<Service>, reactor_idx: i32) -> Self {
+    fn notification<C>(waitset: &WaitSet<Service, C>, reactor_idx: i32) -> Self {
         Self {
             attachment_type: AttachmentIdType::Notification(
-                waitset as *const WaitSet<Service> as u64,
+                waitset as *const WaitSet<Service, C> as u64,
                 reactor_idx,
             ),
             _data: PhantomData,
@@ -409,7 +520,7 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
 
     /// Returns true if an event was emitted from a notification or deadline attachment
     /// corresponding to [`WaitSetGuard`].
-    pub fn has_event_from(&self, other: &WaitSetGuard<Service>) -> bool {
+    pub fn has_event_from<C>(&self, other: &WaitSetGuard<Service, C>) -> bool {
         let other_attachment = WaitSetAttachmentId::from_guard(other);
         if let AttachmentIdType::Deadline(other_waitset, other_reactor_idx, _) =
             other_attachment.attachment_type
@@ -425,13 +536,183 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
     }
 
     /// Returns true if the deadline for the attachment corresponding to [`WaitSetGuard`] was missed.
-    pub fn has_missed_deadline(&self, other: &WaitSetGuard<Service>) -> bool {
+    pub fn has_missed_deadline<C>(&self, other: &WaitSetGuard<Service, C>) -> bool {
         if let AttachmentIdType::Deadline(..) = self.attachment_type {
             self.attachment_type == WaitSetAttachmentId::from_guard(other).attachment_type
         } else {
             false
         }
     }
+
+    /// Returns true if an event was emitted from the [`Listener`] owned by the
+    /// [`WaitSetOwnedGuard`].
+    pub fn has_event_from_owned(&self, other: &WaitSetOwnedGuard<Service>) -> bool {
+        matches!(self.attachment_type,
+            AttachmentIdType::Notification(_, reactor_idx) if reactor_idx == other.file_descriptor)
+    }
+
+    /// Returns the [`WaitSetAttachmentKind`] of the attachment, so that the callback can branch
+    /// on it without comparing the [`WaitSetAttachmentId`] with every [`WaitSetGuard`].
+    pub fn kind(&self) -> WaitSetAttachmentKind {
+        match self.attachment_type {
+            AttachmentIdType::Tick(..) => WaitSetAttachmentKind::Tick,
+            AttachmentIdType::Deadline(..) => WaitSetAttachmentKind::DeadlineMissed,
+            AttachmentIdType::Notification(..) => WaitSetAttachmentKind::Notification,
+        }
+    }
+
+    /// Returns the [`WaitSetAttachmentStableId`] of the attachment. In contrast to the
+    /// [`WaitSetAttachmentId`] it does not contain the address of the [`WaitSet`] and
+    /// can therefore be persisted or compared across processes.
+    pub fn stable_id(&self) -> WaitSetAttachmentStableId {
+        match self.attachment_type {
+            AttachmentIdType::Tick(_, deadline_queue_idx) => WaitSetAttachmentStableId::Tick {
+                interval_idx: deadline_queue_idx.value(),
+            },
+            AttachmentIdType::Deadline(_, reactor_idx, deadline_queue_idx) => {
+                WaitSetAttachmentStableId::Deadline {
+                    file_descriptor: reactor_idx,
+                    deadline_idx: deadline_queue_idx.value(),
+                }
+            }
+            AttachmentIdType::Notification(_, reactor_idx) => {
+                WaitSetAttachmentStableId::Notification {
+                    file_descriptor: reactor_idx,
+                }
+            }
+        }
+    }
+}
+
+/// Identifies an attachment of a [`WaitSet`] by its semantics, the file descriptor of the
+/// attached object and/or the index of its interval or deadline, instead of the address of the
+/// [`WaitSet`]. Acquired via [`WaitSetAttachmentId::stable_id()`].
+///
+/// The file descriptors are stable when they are inherited by a child process or when the
+/// objects are attached in the same order after a restart. The indices of intervals and
+/// deadlines are assigned in attachment order and are unique for a [`WaitSet`].
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+/// use iceoryx2::waitset::WaitSetAttachmentStableId;
+/// # use core::time::Duration;
+/// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+/// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+/// let guard = waitset.attach_interval(Duration::from_secs(1))?;
+///
+/// let stable_id = WaitSetAttachmentId::from_guard(&guard).stable_id();
+/// let bytes = stable_id.to_bytes();
+///
+/// assert_eq!(WaitSetAttachmentStableId::from_bytes(&bytes), Some(stable_id));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub enum WaitSetAttachmentStableId {
+    /// An interval attached with [`WaitSet::attach_interval()`].
+    Tick {
+        /// The index of the interval
+        interval_idx: u64,
+    },
+    /// A deadline attached with [`WaitSet::attach_deadline()`].
+    Deadline {
+        /// The native handle of the file descriptor of the attached object
+        file_descriptor: i32,
+        /// The index of the deadline
+        deadline_idx: u64,
+    },
+    /// A notification attached with [`WaitSet::attach_notification()`].
+    Notification {
+        /// The native handle of the file descriptor of the attached object
+        file_descriptor: i32,
+    },
+}
+
+impl WaitSetAttachmentStableId {
+    /// The number of bytes of the serialized [`WaitSetAttachmentStableId`].
+    pub const SERIALIZED_SIZE: usize = 13;
+
+    const TICK_TAG: u8 = 0;
+    const DEADLINE_TAG: u8 = 1;
+    const NOTIFICATION_TAG: u8 = 2;
+
+    /// Serializes the [`WaitSetAttachmentStableId`] into a platform independent little-endian
+    /// byte representation. It can be restored with [`WaitSetAttachmentStableId::from_bytes()`].
+    pub fn to_bytes(&self) -> [u8; Self::SERIALIZED_SIZE] {
+        let (tag, file_descriptor, idx) = match *self {
+            Self::Tick { interval_idx } => (Self::TICK_TAG, 0, interval_idx),
+            Self::Deadline {
+                file_descriptor,
+                deadline_idx,
+            } => (Self::DEADLINE_TAG, file_descriptor, deadline_idx),
+            Self::Notification { file_descriptor } => (Self::NOTIFICATION_TAG, file_descriptor, 0),
+        };
+
+        let mut bytes = [0u8; Self::SERIALIZED_SIZE];
+        bytes[0] = tag;
+        bytes[1..5].copy_from_slice(&file_descriptor.to_le_bytes());
+        bytes[5..13].copy_from_slice(&idx.to_le_bytes());
+        bytes
+    }
+
+    /// Restores a [`WaitSetAttachmentStableId`] that was serialized with
+    /// [`WaitSetAttachmentStableId::to_bytes()`]. Returns [`None`] when the bytes do not
+    /// represent a valid [`WaitSetAttachmentStableId`].
+    pub fn from_bytes(bytes: &[u8; Self::SERIALIZED_SIZE]) -> Option<Self> {
+        let mut file_descriptor = [0u8; 4];
+        file_descriptor.copy_from_slice(&bytes[1..5]);
+        let file_descriptor = i32::from_le_bytes(file_descriptor);
+
+        let mut idx = [0u8; 8];
+        idx.copy_from_slice(&bytes[5..13]);
+        let idx = u64::from_le_bytes(idx);
+
+        match bytes[0] {
+            Self::TICK_TAG => Some(Self::Tick { interval_idx: idx }),
+            Self::DEADLINE_TAG => Some(Self::Deadline {
+                file_descriptor,
+                deadline_idx: idx,
+            }),
+            Self::NOTIFICATION_TAG => Some(Self::Notification { file_descriptor }),
+            _ => None,
+        }
+    }
+}
+
+// The reactor is shared with the WaitSetOwnedGuards, so that they can detach their listener
+// from it when they are dropped, before the listener closes its file descriptor. Besides the
+// reactor it contains the file descriptors of the owned listeners whose guard is alive and of
+// the ones whose guard was already dropped but whose attachment order is still recorded in
+// the WaitSet.
+#[derive(Debug)]
+struct SharedReactor<R: Reactor> {
+    reactor: R,
+    owned_attachments: RefCell<HashSet<i32>>,
+    released_owned_attachments: RefCell<Vec<i32>>,
+}
+
+type SharedReactorPolicy<Service> = <Service as crate::service::Service>::ArcThreadSafetyPolicy<
+    SharedReactor<<Service as crate::service::Service>::Reactor>,
+>;
+
+// Is attached to the shared reactor and detaches the file descriptor from it as soon as it
+// goes out of scope, the reactor guard cannot be used since the reactor is only accessible
+// while it is locked.
+struct ReactorAttachment<'waitset, 'attachment, Service: crate::service::Service> {
+    reactor: &'waitset SharedReactorPolicy<Service>,
+    file_descriptor: i32,
+    _attachment: PhantomData<&'attachment ()>,
+}
+
+impl<Service: crate::service::Service> Drop for ReactorAttachment<'_, '_, Service> {
+    fn drop(&mut self) {
+        self.reactor
+            .lock()
+            .reactor
+            .detach_unguarded(self.file_descriptor);
+    }
 }
 
 enum GuardType<'waitset, 'attachment, Service: crate::service::Service>
@@ -440,36 +721,169 @@ where
 {
     Tick(DeadlineQueueGuard<'waitset>),
     Deadline(
-        <Service::Reactor as Reactor>::Guard<'waitset, 'attachment>,
+        ReactorAttachment<'waitset, 'attachment, Service>,
         DeadlineQueueGuard<'waitset>,
+        Duration,
     ),
-    Notification(<Service::Reactor as Reactor>::Guard<'waitset, 'attachment>),
+    Notification(ReactorAttachment<'waitset, 'attachment, Service>),
 }
 
 /// Is returned when something is attached to the [`WaitSet`]. As soon as it goes out
 /// of scope, the attachment is detached.
-pub struct WaitSetGuard<'waitset, 'attachment, Service: crate::service::Service>
+pub struct WaitSetGuard<'waitset, 'attachment, Service: crate::service::Service, C = ()>
 where
     Service::Reactor: 'waitset,
 {
-    waitset: &'waitset WaitSet<Service>,
+    waitset: &'waitset WaitSet<Service, C>,
     guard_type: GuardType<'waitset, 'attachment, Service>,
 }
 
-impl<Service: crate::service::Service> Drop for WaitSetGuard<'_, '_, Service> {
+impl<Service: crate::service::Service, C> Drop for WaitSetGuard<'_, '_, Service, C> {
     fn drop(&mut self) {
-        if let GuardType::Deadline(r, t) = &self.guard_type {
-            self.waitset
-                .remove_deadline(unsafe { r.file_descriptor().native_handle() }, t.index())
+        match &self.guard_type {
+            GuardType::Deadline(r, t, _) => {
+                self.waitset.remove_deadline(r.file_descriptor, t.index());
+                self.waitset.remove_attachment_order(r.file_descriptor);
+            }
+            GuardType::Notification(r) => {
+                self.waitset.remove_context(r.file_descriptor);
+                self.waitset.remove_attachment_order(r.file_descriptor);
+            }
+            GuardType::Tick(_) => (),
         }
         self.waitset.detach();
     }
 }
 
+impl<Service: crate::service::Service, C> WaitSetGuard<'_, '_, Service, C> {
+    /// Returns the remaining time until the deadline of the attachment is hit. If the deadline
+    /// was already missed it returns [`Duration::ZERO`]. Returns [`None`] when the guard
+    /// does not belong to a deadline attachment or the current time could not be acquired.
+    pub fn deadline_remaining(&self) -> Option<Duration> {
+        match &self.guard_type {
+            GuardType::Deadline(_, deadline_queue_guard, _) => {
+                match deadline_queue_guard.duration_until_deadline() {
+                    Ok(remaining) => remaining,
+                    Err(e) => {
+                        warn!(from self.waitset,
+                            "Unable to acquire the remaining time until the deadline is hit ({:?}).", e);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the deadline that was defined when the attachment was attached with
+    /// [`WaitSet::attach_deadline()`] or [`WaitSet::attach_deadline_at()`], or replaced with
+    /// [`WaitSet::reattach_deadline()`]. Returns [`None`] when the guard does not belong to a
+    /// deadline attachment.
+    pub fn deadline(&self) -> Option<Duration> {
+        match &self.guard_type {
+            GuardType::Deadline(_, _, deadline) => Some(*deadline),
+            _ => None,
+        }
+    }
+}
+
+/// Is returned when a [`Listener`] is attached with [`WaitSet::attach_owned()`]. In contrast
+/// to the [`WaitSetGuard`] it borrows neither the [`WaitSet`] nor the [`Listener`] and can
+/// therefore be stored next to the [`WaitSet`] in the same struct. As soon as it goes out of
+/// scope, the [`Listener`] is detached and dropped.
+///
+/// When the guard is dropped in another thread while the [`WaitSet`] waits, the drop blocks
+/// until the wait returned since the [`Listener`] must not be closed while it is still
+/// attached.
+pub struct WaitSetOwnedGuard<Service: crate::service::Service> {
+    file_descriptor: i32,
+    listener: Listener<Service>,
+    reactor: SharedReactorPolicy<Service>,
+}
+
+impl<Service: crate::service::Service> Debug for WaitSetOwnedGuard<Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "WaitSetOwnedGuard<{}> {{ file_descriptor: {}, listener: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.file_descriptor,
+            self.listener
+        )
+    }
+}
+
+impl<Service: crate::service::Service> WaitSetOwnedGuard<Service> {
+    /// Returns a reference to the [`Listener`] that is owned by the [`WaitSet`].
+    pub fn listener(&self) -> &Listener<Service> {
+        &self.listener
+    }
+}
+
+impl<Service: crate::service::Service> Drop for WaitSetOwnedGuard<Service> {
+    fn drop(&mut self) {
+        // the listener is dropped right after the guard, therefore it is detached from the
+        // reactor before its file descriptor is closed and can be reused
+        let reactor = self.reactor.lock();
+        reactor.reactor.detach_unguarded(self.file_descriptor);
+        reactor
+            .owned_attachments
+            .borrow_mut()
+            .remove(&self.file_descriptor);
+        reactor
+            .released_owned_attachments
+            .borrow_mut()
+            .push(self.file_descriptor);
+    }
+}
+
+/// Iterator over the [`WaitSetAttachmentId`]s that had an event. Acquired with
+/// [`WaitSet::ready_events()`].
+pub struct ReadyEventIter<Service: crate::service::Service> {
+    attachment_ids: alloc::vec::IntoIter<WaitSetAttachmentId<Service>>,
+    run_result: WaitSetRunResult,
+}
+
+impl<Service: crate::service::Service> Debug for ReadyEventIter<Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ReadyEventIter<{}> {{ remaining_attachment_ids: {:?}, run_result: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.attachment_ids.as_slice(),
+            self.run_result
+        )
+    }
+}
+
+impl<Service: crate::service::Service> ReadyEventIter<Service> {
+    /// Returns the [`WaitSetRunResult`] of the wait that acquired the events, for instance
+    /// to detect a [`WaitSetRunResult::TerminationRequest`].
+    pub fn run_result(&self) -> WaitSetRunResult {
+        self.run_result
+    }
+}
+
+impl<Service: crate::service::Service> Iterator for ReadyEventIter<Service> {
+    type Item = WaitSetAttachmentId<Service>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.attachment_ids.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.attachment_ids.size_hint()
+    }
+}
+
+impl<Service: crate::service::Service> ExactSizeIterator for ReadyEventIter<Service> {}
+
 /// The builder for the [`WaitSet`].
 #[derive(Default, Debug, Clone)]
 pub struct WaitSetBuilder {
     signal_handling_mode: SignalHandlingMode,
+    expected_attachments: usize,
+    measure_callback_duration: bool,
 }
 
 impl WaitSetBuilder {
@@ -487,27 +901,88 @@ impl WaitSetBuilder {
         self
     }
 
+    /// Takes over the [`Node::signal_handling_mode()`] of the provided [`Node`], so that the
+    /// [`WaitSet`] reacts to signals like the [`Node`] that created its ports. The
+    /// [`Config`](crate::config::Config) of the [`Node`] is not required since the [`WaitSet`]
+    /// does not acquire any resources from it.
+    pub fn inherit_signal_handling_mode_from<Service: crate::service::Service>(
+        mut self,
+        node: &Node<Service>,
+    ) -> Self {
+        self.signal_handling_mode = node.signal_handling_mode();
+        self
+    }
+
+    /// Defines how many deadline attachments are expected. The internal bookkeeping of the
+    /// deadlines is reserved upfront, so that attaching them does not cause reallocations.
+    /// By default, nothing is reserved.
+    pub fn expected_attachments(mut self, value: usize) -> Self {
+        self.expected_attachments = value;
+        self
+    }
+
+    /// Defines if the duration of every callback call is measured for
+    /// [`WaitSetStats::max_callback_duration_ns`]. The measurement requires two time queries
+    /// per callback call, therefore it is disabled by default.
+    pub fn measure_callback_duration(mut self, value: bool) -> Self {
+        self.measure_callback_duration = value;
+        self
+    }
+
     /// Creates the [`WaitSet`].
     pub fn create<Service: crate::service::Service>(
         self,
     ) -> Result<WaitSet<Service>, WaitSetCreateError> {
+        self.create_with_context()
+    }
+
+    /// Creates the [`WaitSet`] that stores a context of type `C` with every attachment that
+    /// is attached with [`WaitSet::attach_notification_with_context()`].
+    pub fn create_with_context<Service: crate::service::Service, C>(
+        self,
+    ) -> Result<WaitSet<Service, C>, WaitSetCreateError> {
         let msg = "Unable to create WaitSet";
         let deadline_queue = fail!(from self, when DeadlineQueueBuilder::new().create(),
                 with WaitSetCreateError::InternalError,
                 "{msg} since the underlying Timer could not be created.");
 
-        match <Service::Reactor as Reactor>::Builder::new().create() {
+        let reactor = match <Service::Reactor as Reactor>::Builder::new().create() {
+            Ok(reactor) => reactor,
+            Err(ReactorCreateError::UnknownError(e)) => {
+                fail!(from self, with WaitSetCreateError::InternalError,
+                    "{msg} due to an internal error (error code = {})", e);
+            }
+        };
+        let capacity = reactor.capacity();
+
+        match SharedReactorPolicy::<Service>::new(SharedReactor {
+            reactor,
+            owned_attachments: RefCell::new(HashSet::new()),
+            released_owned_attachments: RefCell::new(Vec::new()),
+        }) {
             Ok(reactor) => Ok(WaitSet {
+                triggered_file_descriptors: RefCell::new(Vec::with_capacity(capacity)),
                 reactor,
                 deadline_queue,
-                attachment_to_deadline: RefCell::new(HashMap::new()),
-                deadline_to_attachment: RefCell::new(HashMap::new()),
+                attachment_to_deadline: RefCell::new(HashMap::with_capacity(
+                    self.expected_attachments,
+                )),
+                deadline_to_attachment: RefCell::new(HashMap::with_capacity(
+                    self.expected_attachments,
+                )),
                 attachment_counter: IoxAtomicUsize::new(0),
+                contexts: RefCell::new(HashMap::new()),
+                attachment_order: RefCell::new(HashMap::with_capacity(self.expected_attachments)),
+                attachment_order_counter: Cell::new(0),
+                elapsed_deadlines: RefCell::new(HashMap::with_capacity(self.expected_attachments)),
                 signal_handling_mode: self.signal_handling_mode,
+                last_interrupt_signal: Cell::new(None),
+                stats: WaitSetStatsCounters::new(),
+                measure_callback_duration: self.measure_callback_duration,
             }),
-            Err(ReactorCreateError::UnknownError(e)) => {
+            Err(e) => {
                 fail!(from self, with WaitSetCreateError::InternalError,
-                    "{msg} due to an internal error (error code = {})", e);
+                    "{msg} since the reactor could not be shared ({:?}).", e);
             }
         }
     }
@@ -523,24 +998,66 @@ impl WaitSetBuilder {
 /// [`Listener`](crate::port::listener::Listener) can be attached as well as sockets or anything else that
 /// is [`FileDescriptorBased`](iceoryx2_bb_posix::file_descriptor::FileDescriptorBased).
 ///
-/// Can be created via the [`WaitSetBuilder`].
-#[derive(Debug)]
-pub struct WaitSet<Service: crate::service::Service> {
-    reactor: Service::Reactor,
+/// Can be created via the [`WaitSetBuilder`]. A [`WaitSet`] that is created with
+/// [`WaitSetBuilder::create_with_context()`] stores a context of type `C` with its
+/// attachments, see [`WaitSet::attach_notification_with_context()`].
+pub struct WaitSet<Service: crate::service::Service, C = ()> {
+    reactor: SharedReactorPolicy<Service>,
     deadline_queue: DeadlineQueue,
     attachment_to_deadline: RefCell<HashMap<i32, DeadlineQueueIndex>>,
     deadline_to_attachment: RefCell<HashMap<DeadlineQueueIndex, i32>>,
     attachment_counter: IoxAtomicUsize,
+    contexts: RefCell<HashMap<i32, C>>,
+    attachment_order: RefCell<HashMap<i32, u64>>,
+    attachment_order_counter: Cell<u64>,
+    elapsed_deadlines: RefCell<HashMap<DeadlineQueueIndex, u64>>,
     signal_handling_mode: SignalHandlingMode,
+    triggered_file_descriptors: RefCell<Vec<i32>>,
+    last_interrupt_signal: Cell<Option<NonFatalFetchableSignal>>,
+    stats: WaitSetStatsCounters,
+    measure_callback_duration: bool,
+}
+
+impl<Service: crate::service::Service, C> Debug for WaitSet<Service, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WaitSet")
+            .field("reactor", &self.reactor)
+            .field("deadline_queue", &self.deadline_queue)
+            .field("attachment_counter", &self.attachment_counter)
+            .field("signal_handling_mode", &self.signal_handling_mode)
+            .field("stats", &self.stats)
+            .field("measure_callback_duration", &self.measure_callback_duration)
+            .finish_non_exhaustive()
+    }
 }
 
-impl<Service: crate::service::Service> WaitSet<Service> {
+impl<Service: crate::service::Service, C> WaitSet<Service, C> {
+    // calls the callback and adds the call to the statistics, its duration is only measured
+    // on request since it costs two time queries per call
+    fn call_and_track<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        &self,
+        fn_call: &mut F,
+        id: WaitSetAttachmentId<Service>,
+    ) -> CallbackProgression {
+        let attachment_type = id.attachment_type;
+        if !self.measure_callback_duration {
+            self.stats.add_call(&attachment_type, None);
+            return fn_call(id);
+        }
+
+        let start = Time::now().ok();
+        let progression = fn_call(id);
+        let duration = start.and_then(|start| start.elapsed().ok());
+        self.stats.add_call(&attachment_type, duration);
+        progression
+    }
+
     fn detach(&self) {
         self.attachment_counter.fetch_sub(1, Ordering::Relaxed);
     }
 
     fn attach(&self) -> Result<(), WaitSetAttachmentError> {
-        if self.len() == self.capacity() {
+        if self.is_full() {
             fail!(from self, with WaitSetAttachmentError::InsufficientCapacity,
                     "Unable to add attachment since it would exceed the capacity of {}.", self.capacity());
         }
@@ -549,6 +1066,66 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         Ok(())
     }
 
+    fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    fn optional_attachment<T>(
+        result: Result<T, WaitSetAttachmentError>,
+    ) -> Result<Option<T>, WaitSetAttachmentError> {
+        match result {
+            Ok(guard) => Ok(Some(guard)),
+            Err(WaitSetAttachmentError::InsufficientCapacity) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn record_attachment_order(&self, reactor_idx: i32) {
+        let order = self.attachment_order_counter.get();
+        self.attachment_order_counter.set(order + 1);
+        self.attachment_order
+            .borrow_mut()
+            .insert(reactor_idx, order);
+    }
+
+    fn remove_attachment_order(&self, reactor_idx: i32) {
+        self.attachment_order.borrow_mut().remove(&reactor_idx);
+    }
+
+    fn remove_context(&self, reactor_idx: i32) {
+        let context = self.contexts.borrow_mut().remove(&reactor_idx);
+        // dropped outside of the borrow since the destructor of the context is user code
+        drop(context);
+    }
+
+    // The context is taken out of the WaitSet while the callback uses it, so that the callback
+    // can detach the attachment or attach new ones. It is only restored when the attachment
+    // is still the same afterwards, otherwise it is dropped.
+    fn call_with_context<
+        F: FnMut(WaitSetAttachmentId<Service>, Option<&mut C>) -> CallbackProgression,
+    >(
+        &self,
+        fn_call: &mut F,
+        attachment_id: WaitSetAttachmentId<Service>,
+    ) -> CallbackProgression {
+        let reactor_idx = match attachment_id.attachment_type {
+            AttachmentIdType::Notification(_, reactor_idx) => reactor_idx,
+            _ => return fn_call(attachment_id, None),
+        };
+
+        let mut context = self.contexts.borrow_mut().remove(&reactor_idx);
+        let order = self.attachment_order.borrow().get(&reactor_idx).copied();
+        let progression = fn_call(attachment_id, context.as_mut());
+
+        if let Some(context) = context {
+            if self.attachment_order.borrow().get(&reactor_idx).copied() == order {
+                self.contexts.borrow_mut().insert(reactor_idx, context);
+            }
+        }
+
+        progression
+    }
+
     fn remove_deadline(&self, reactor_idx: i32, deadline_queue_idx: DeadlineQueueIndex) {
         self.attachment_to_deadline
             .borrow_mut()
@@ -558,20 +1135,33 @@ impl<Service: crate::service::Service> WaitSet<Service> {
             .remove(&deadline_queue_idx);
     }
 
-    fn reset_deadline(
-        &self,
-        reactor_idx: i32,
-    ) -> Result<Option<DeadlineQueueIndex>, WaitSetRunError> {
-        let msg = "Unable to reset deadline";
-        if let Some(deadline_queue_idx) = self.attachment_to_deadline.borrow().get(&reactor_idx) {
-            fail!(from self,
-                  when self.deadline_queue.reset(*deadline_queue_idx),
-                  with WaitSetRunError::InternalError,
-                  "{msg} since the deadline_queue guard could not be reset for the attachment {reactor_idx}. Continuing operations will lead to invalid deadline failures.");
-            Ok(Some(*deadline_queue_idx))
-        } else {
-            Ok(None)
+    fn reset_deadlines(&self, triggered_file_descriptors: &[i32]) -> Result<(), WaitSetRunError> {
+        let attachment_to_deadline = self.attachment_to_deadline.borrow();
+        if attachment_to_deadline.is_empty() {
+            return Ok(());
+        }
+
+        let deadline_queue_indices = triggered_file_descriptors
+            .iter()
+            .filter_map(|fd| attachment_to_deadline.get(fd).copied());
+
+        fail!(from self,
+              when self.deadline_queue.reset_multiple(deadline_queue_indices),
+              with WaitSetRunError::InternalError,
+              "Unable to reset deadlines since the deadline_queue guards could not be reset. Continuing operations will lead to invalid deadline failures.");
+
+        Ok(())
+    }
+
+    // Removes the attachment order of the owned listeners whose WaitSetOwnedGuard was dropped
+    // and returns how many were removed.
+    fn release_detached_owned_attachments(&self) -> usize {
+        let released = self.reactor.lock().released_owned_attachments.take();
+        for file_descriptor in &released {
+            self.remove_attachment_order(*file_descriptor);
         }
+
+        released.len()
     }
 
     fn handle_deadlines<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
@@ -581,7 +1171,11 @@ impl<Service: crate::service::Service> WaitSet<Service> {
     ) -> Result<WaitSetRunResult, WaitSetRunError> {
         let deadline_to_attachment = self.deadline_to_attachment.borrow();
         let mut result = WaitSetRunResult::AllEventsHandled;
-        let call = |idx: DeadlineQueueIndex| -> CallbackProgression {
+        self.elapsed_deadlines.borrow_mut().clear();
+        let call = |idx: DeadlineQueueIndex, elapsed_deadlines: u64| -> CallbackProgression {
+            self.elapsed_deadlines
+                .borrow_mut()
+                .insert(idx, elapsed_deadlines);
             let progression = if let Some(reactor_idx) = deadline_to_attachment.get(&idx) {
                 fn_call(WaitSetAttachmentId::deadline(self, *reactor_idx, idx))
             } else {
@@ -596,7 +1190,7 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         };
 
         fail!(from self,
-                  when self.deadline_queue.missed_deadlines(call),
+                  when self.deadline_queue.missed_deadlines_with_count(call),
                   with WaitSetRunError::InternalError,
                   "{error_msg} since the missed deadlines could not be acquired.");
 
@@ -605,17 +1199,13 @@ impl<Service: crate::service::Service> WaitSet<Service> {
 
     fn handle_all_attachments<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
         &self,
-        triggered_file_descriptors: &Vec<i32>,
+        triggered_file_descriptors: &mut [i32],
         fn_call: &mut F,
         error_msg: &str,
     ) -> Result<WaitSetRunResult, WaitSetRunError> {
         // we need to reset the deadlines first, otherwise a long fn_call may extend the
         // deadline unintentionally
-        let mut fd_and_deadline_queue_idx = Vec::with_capacity(triggered_file_descriptors.len());
-
-        for fd in triggered_file_descriptors {
-            fd_and_deadline_queue_idx.push((fd, self.reset_deadline(*fd)?));
-        }
+        self.reset_deadlines(triggered_file_descriptors)?;
 
         // must be called after the deadlines have been reset, in the case that the
         // event has been received shortly before the deadline ended.
@@ -625,7 +1215,15 @@ impl<Service: crate::service::Service> WaitSet<Service> {
             v => return Ok(v),
         };
 
-        for fd in triggered_file_descriptors {
+        // the reactor reports the file descriptors in an implementation specific order, the
+        // callbacks of simultaneous events are called in the order of attachment instead
+        {
+            let attachment_order = self.attachment_order.borrow();
+            triggered_file_descriptors
+                .sort_by_key(|fd| attachment_order.get(fd).copied().unwrap_or(u64::MAX));
+        }
+
+        for fd in triggered_file_descriptors.iter() {
             if let CallbackProgression::Stop = fn_call(WaitSetAttachmentId::notification(self, *fd))
             {
                 return Ok(WaitSetRunResult::StopRequest);
@@ -642,30 +1240,219 @@ impl<Service: crate::service::Service> WaitSet<Service> {
     pub fn attach_notification<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
         &'waitset self,
         attachment: &'attachment T,
-    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
-        let reactor_guard = self.attach_to_reactor(attachment)?;
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service, C>, WaitSetAttachmentError> {
+        let reactor_attachment = self.attach_to_reactor(attachment, false)?;
         self.attach()?;
 
         Ok(WaitSetGuard {
             waitset: self,
-            guard_type: GuardType::Notification(reactor_guard),
+            guard_type: GuardType::Notification(reactor_attachment),
+        })
+    }
+
+    /// Attaches an object as notification to the [`WaitSet`] like
+    /// [`WaitSet::attach_notification()`] and stores the provided context with the attachment.
+    /// The context is owned by the [`WaitSet`] until the attachment is detached and is handed
+    /// to the callback of [`WaitSet::wait_and_process_with_context()`], so that the callback
+    /// can recover the handler of the attachment without an external map. The type of the
+    /// context is defined when the [`WaitSet`] is created with
+    /// [`WaitSetBuilder::create_with_context()`].
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let event_1 = node.service_builder(&"MyEventName_1".try_into()?)
+    /// #     .event()
+    /// #     .open_or_create()?;
+    /// # let event_2 = node.service_builder(&"MyEventName_2".try_into()?)
+    /// #     .event()
+    /// #     .open_or_create()?;
+    ///
+    /// let listener_1 = event_1.listener_builder().create()?;
+    /// let listener_2 = event_2.listener_builder().create()?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create_with_context::<ipc::Service, String>()?;
+    /// let _guard_1 = waitset.attach_notification_with_context(&listener_1, "engine".to_string())?;
+    /// let _guard_2 = waitset.attach_notification_with_context(&listener_2, "brakes".to_string())?;
+    ///
+    /// waitset.wait_and_process_with_context(|_attachment_id, name: Option<&mut String>| {
+    ///     if let Some(name) = name {
+    ///         println!("{name} has an event");
+    ///     }
+    ///     CallbackProgression::Continue
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn attach_notification_with_context<
+        'waitset,
+        'attachment,
+        T: SynchronousMultiplexing + Debug,
+    >(
+        &'waitset self,
+        attachment: &'attachment T,
+        context: C,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service, C>, WaitSetAttachmentError> {
+        let guard = self.attach_notification(attachment)?;
+        self.contexts.borrow_mut().insert(
+            unsafe { attachment.file_descriptor().native_handle() },
+            context,
+        );
+
+        Ok(guard)
+    }
+
+    /// Attaches a [`Listener`] as notification to the [`WaitSet`] like
+    /// [`WaitSet::attach_notification()`] but the [`WaitSet`] takes ownership of the
+    /// [`Listener`]. The returned [`WaitSetOwnedGuard`] does not borrow the [`WaitSet`],
+    /// therefore both can be stored in the same struct. The [`Listener`] is accessible via
+    /// [`WaitSetOwnedGuard::listener()`]. It stays attached until the [`WaitSetOwnedGuard`] is
+    /// dropped which detaches and drops the [`Listener`].
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::waitset::WaitSetOwnedGuard;
+    ///
+    /// struct Dispatcher {
+    ///     waitset: WaitSet<ipc::Service>,
+    ///     guards: Vec<WaitSetOwnedGuard<ipc::Service>>,
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let event = node.service_builder(&"MyEventName".try_into()?)
+    ///     .event()
+    ///     .open_or_create()?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let guard = waitset.attach_owned(event.listener_builder().create()?)?;
+    /// let dispatcher = Dispatcher { waitset, guards: vec![guard] };
+    ///
+    /// dispatcher.waitset.wait_and_process_once(|attachment_id| {
+    ///     for guard in &dispatcher.guards {
+    ///         if attachment_id.has_event_from_owned(guard) {
+    ///             while let Ok(Some(event_id)) = guard.listener().try_wait_one().map(|r| r.event_id()) {
+    ///                 println!("received notification {:?}", event_id);
+    ///             }
+    ///         }
+    ///     }
+    ///     CallbackProgression::Continue
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn attach_owned(
+        &self,
+        listener: Listener<Service>,
+    ) -> Result<WaitSetOwnedGuard<Service>, WaitSetAttachmentError>
+    where
+        <Service::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        if self.is_full() {
+            fail!(from self, with WaitSetAttachmentError::InsufficientCapacity,
+                "Unable to attach owned listener since it would exceed the capacity of {}.", self.capacity());
+        }
+
+        // the listener is owned by the guard and not by the WaitSet, therefore the guard
+        // detaches it from the shared reactor
+        let file_descriptor = self.attach_to_reactor_with(&listener, false)?;
+        self.reactor
+            .lock()
+            .owned_attachments
+            .borrow_mut()
+            .insert(file_descriptor);
+
+        Ok(WaitSetOwnedGuard {
+            file_descriptor,
+            listener,
+            reactor: self.reactor.clone(),
         })
     }
 
+    /// Attaches an object as notification to the [`WaitSet`] like
+    /// [`WaitSet::attach_notification()`] but returns [`None`] instead of
+    /// [`WaitSetAttachmentError::InsufficientCapacity`] when the [`WaitSet`] is full.
+    pub fn try_attach_notification<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+    ) -> Result<Option<WaitSetGuard<'waitset, 'attachment, Service, C>>, WaitSetAttachmentError>
+    {
+        if self.is_full() {
+            return Ok(None);
+        }
+
+        Self::optional_attachment(self.attach_notification(attachment))
+    }
+
     /// Attaches an object as deadline to the [`WaitSet`]. Whenever the event is received or the
     /// deadline is hit, the user is informed in [`WaitSet::wait_and_process()`].
     /// The object cannot be attached twice and the
     /// [`WaitSet::capacity()`] is limited by the underlying implementation.
     /// Whenever the object emits an event the deadline is reset by the [`WaitSet`].
+    /// Deadlines shorter than [`WaitSet::min_timeout_resolution()`] may be reported late.
+    ///
+    /// The events of the object are reported as well and can be identified with
+    /// [`WaitSetAttachmentId::has_event_from()`], missed deadlines with
+    /// [`WaitSetAttachmentId::has_missed_deadline()`]. Therefore, the object cannot be attached
+    /// as notification in addition, it fails with
+    /// [`WaitSetAttachmentError::AlreadyAttachedWithDifferentSemantic`].
     pub fn attach_deadline<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
         &'waitset self,
         attachment: &'attachment T,
         deadline: Duration,
-    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
-        let reactor_guard = self.attach_to_reactor(attachment)?;
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service, C>, WaitSetAttachmentError> {
+        let reactor_attachment = self.attach_to_reactor(attachment, true)?;
         let deadline_queue_guard = self.attach_to_deadline_queue(deadline)?;
+        self.attach_deadline_guards(reactor_attachment, deadline_queue_guard, deadline)
+    }
 
-        let reactor_idx = unsafe { reactor_guard.file_descriptor().native_handle() };
+    /// Attaches an object as deadline to the [`WaitSet`] like [`WaitSet::attach_deadline()`]
+    /// but returns [`None`] instead of [`WaitSetAttachmentError::InsufficientCapacity`] when
+    /// the [`WaitSet`] is full.
+    pub fn try_attach_deadline<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+        deadline: Duration,
+    ) -> Result<Option<WaitSetGuard<'waitset, 'attachment, Service, C>>, WaitSetAttachmentError>
+    {
+        if self.is_full() {
+            return Ok(None);
+        }
+
+        Self::optional_attachment(self.attach_deadline(attachment, deadline))
+    }
+
+    /// Attaches an object as deadline to the [`WaitSet`] whose first deadline is hit at the
+    /// absolute point in time `first_deadline` and every `deadline` afterwards. The
+    /// [`Time`] can be acquired from any [`ClockType`](iceoryx2_bb_posix::clock::ClockType),
+    /// the remaining time is computed when the object is attached. If `first_deadline` has
+    /// already passed, the deadline is reported with the next [`WaitSet::wait_and_process()`]
+    /// call.
+    ///
+    /// When `realign_on_event` is true, an event emitted by the object does not restart the full
+    /// deadline but keeps the deadline aligned to the schedule `first_deadline + n * deadline`,
+    /// otherwise it behaves like [`WaitSet::attach_deadline()`].
+    pub fn attach_deadline_at<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+        first_deadline: Time,
+        deadline: Duration,
+        realign_on_event: bool,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service, C>, WaitSetAttachmentError> {
+        let reactor_attachment = self.attach_to_reactor(attachment, true)?;
+        let deadline_queue_guard =
+            self.attach_to_deadline_queue_at(first_deadline, deadline, realign_on_event)?;
+        self.attach_deadline_guards(reactor_attachment, deadline_queue_guard, deadline)
+    }
+
+    fn attach_deadline_guards<'waitset, 'attachment>(
+        &'waitset self,
+        reactor_attachment: ReactorAttachment<'waitset, 'attachment, Service>,
+        deadline_queue_guard: DeadlineQueueGuard<'waitset>,
+        deadline: Duration,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service, C>, WaitSetAttachmentError> {
+        let reactor_idx = reactor_attachment.file_descriptor;
         let deadline_idx = deadline_queue_guard.index();
 
         self.attachment_to_deadline
@@ -678,16 +1465,52 @@ impl<Service: crate::service::Service> WaitSet<Service> {
 
         Ok(WaitSetGuard {
             waitset: self,
-            guard_type: GuardType::Deadline(reactor_guard, deadline_queue_guard),
+            guard_type: GuardType::Deadline(reactor_attachment, deadline_queue_guard, deadline),
         })
     }
 
+    /// Replaces the deadline of an attachment that was attached with
+    /// [`WaitSet::attach_deadline()`] without detaching it from the underlying reactor, so that
+    /// no event can be missed while the deadline is changed. The deadline starts again with the
+    /// full `new_deadline` and the [`WaitSetGuard`] keeps identifying the same attachment.
+    ///
+    /// If the provided [`WaitSetGuard`] is not a deadline guard of this [`WaitSet`] or the
+    /// deadline could not be updated the call fails and the attachment stays unchanged.
+    pub fn reattach_deadline(
+        &self,
+        guard: &mut WaitSetGuard<'_, '_, Service, C>,
+        new_deadline: Duration,
+    ) -> Result<(), WaitSetReattachError> {
+        let msg = "Unable to reattach deadline";
+
+        if !core::ptr::eq(guard.waitset, self) {
+            fail!(from self, with WaitSetReattachError::NotADeadlineAttachment,
+                "{msg} since the guard belongs to a different WaitSet.");
+        }
+
+        match &mut guard.guard_type {
+            GuardType::Deadline(_, deadline_queue_guard, deadline) => {
+                fail!(from self,
+                      when deadline_queue_guard.update_deadline_interval(new_deadline),
+                      with WaitSetReattachError::InternalError,
+                      "{msg} since the deadline could not be updated in the underlying deadline_queue.");
+                *deadline = new_deadline;
+                Ok(())
+            }
+            _ => {
+                fail!(from self, with WaitSetReattachError::NotADeadlineAttachment,
+                    "{msg} since the guard does not belong to a deadline attachment.");
+            }
+        }
+    }
+
     /// Attaches a tick event to the [`WaitSet`]. Whenever the timeout is reached the [`WaitSet`]
     /// informs the user in [`WaitSet::wait_and_process()`].
+    /// Intervals shorter than [`WaitSet::min_timeout_resolution()`] may be reported late.
     pub fn attach_interval(
         &self,
         interval: Duration,
-    ) -> Result<WaitSetGuard<Service>, WaitSetAttachmentError> {
+    ) -> Result<WaitSetGuard<Service, C>, WaitSetAttachmentError> {
         let deadline_queue_guard = self.attach_to_deadline_queue(interval)?;
         self.attach()?;
 
@@ -697,11 +1520,47 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         })
     }
 
+    /// Attaches a tick event to the [`WaitSet`] like [`WaitSet::attach_interval()`] but returns
+    /// [`None`] instead of [`WaitSetAttachmentError::InsufficientCapacity`] when the
+    /// [`WaitSet`] is full.
+    pub fn try_attach_interval(
+        &self,
+        interval: Duration,
+    ) -> Result<Option<WaitSetGuard<'_, '_, Service, C>>, WaitSetAttachmentError> {
+        if self.is_full() {
+            return Ok(None);
+        }
+
+        Self::optional_attachment(self.attach_interval(interval))
+    }
+
+    /// Attaches a tick event to the [`WaitSet`] that is reported first at the absolute point in
+    /// time `first_tick` and every `interval` afterwards, so that the ticks stay aligned to
+    /// `first_tick + n * interval`. The [`Time`] can be acquired from any
+    /// [`ClockType`](iceoryx2_bb_posix::clock::ClockType), the remaining time is computed when
+    /// the tick is attached. If `first_tick` has already passed, the tick is reported with the
+    /// next [`WaitSet::wait_and_process()`] call.
+    pub fn attach_interval_at(
+        &self,
+        first_tick: Time,
+        interval: Duration,
+    ) -> Result<WaitSetGuard<'_, '_, Service, C>, WaitSetAttachmentError> {
+        let deadline_queue_guard = self.attach_to_deadline_queue_at(first_tick, interval, true)?;
+        self.attach()?;
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            guard_type: GuardType::Tick(deadline_queue_guard),
+        })
+    }
+
     /// Waits until an event arrives on the [`WaitSet`], then collects all events by calling the
     /// provided `fn_call` callback with the corresponding [`WaitSetAttachmentId`]. In contrast
     /// to [`WaitSet::wait_and_process_once()`] it will never return until the user explicitly
     /// requests it by returning [`CallbackProgression::Stop`] or by receiving a signal.
     ///
+    /// The events are handled in the order described in [`WaitSet::wait_and_process_once()`].
+    ///
     /// The provided callback must return [`CallbackProgression::Continue`] to continue the event
     /// processing and handle the next event or [`CallbackProgression::Stop`] to return from this
     /// call immediately. All unhandled events will be lost forever and the call will return
@@ -759,11 +1618,142 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         }
     }
 
+    /// Works like [`WaitSet::wait_and_process()`] but the callback receives additionally the
+    /// [`WaitSetAttachmentKind`] of the attachment, for instance to handle a missed deadline
+    /// differently than a received event.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::waitset::WaitSetAttachmentKind;
+    /// # use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let event = node.service_builder(&"MyEventName_1".try_into()?)
+    /// #     .event()
+    /// #     .open_or_create()?;
+    ///
+    /// # let mut listener = event.listener_builder().create()?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let guard = waitset.attach_deadline(&listener, Duration::from_secs(1))?;
+    ///
+    /// waitset.wait_and_process_detailed(|_attachment_id, kind| {
+    ///     match kind {
+    ///         WaitSetAttachmentKind::DeadlineMissed => println!("listener missed its deadline"),
+    ///         WaitSetAttachmentKind::Notification => {
+    ///             while let Ok(Some(event_id)) = listener.try_wait_one().map(|r| r.event_id()) {
+    ///                 println!("received event {event_id:?}");
+    ///             }
+    ///         }
+    ///         WaitSetAttachmentKind::Tick => (),
+    ///     }
+    ///     CallbackProgression::Continue
+    /// })?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_and_process_detailed<
+        F: FnMut(WaitSetAttachmentId<Service>, WaitSetAttachmentKind) -> CallbackProgression,
+    >(
+        &self,
+        mut fn_call: F,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        self.wait_and_process(|attachment_id| {
+            let kind = attachment_id.kind();
+            fn_call(attachment_id, kind)
+        })
+    }
+
+    /// Works like [`WaitSet::wait_and_process()`] but the callback receives additionally the
+    /// context that was stored with [`WaitSet::attach_notification_with_context()`]. When the
+    /// attachment has no context [`None`] is provided.
+    pub fn wait_and_process_with_context<
+        F: FnMut(WaitSetAttachmentId<Service>, Option<&mut C>) -> CallbackProgression,
+    >(
+        &self,
+        mut fn_call: F,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        self.wait_and_process(|attachment_id| self.call_with_context(&mut fn_call, attachment_id))
+    }
+
+    /// Works like [`WaitSet::wait_and_process()`] but does not return when a [`WaitSetRunError`]
+    /// occurs. Instead, the error is handed to `err_handler` which decides if the event
+    /// processing shall continue with [`CallbackProgression::Continue`] or return the error
+    /// with [`CallbackProgression::Stop`]. This is intended for always-on loops that shall log
+    /// and retry failures instead of terminating the application.
+    ///
+    /// If an interrupt- (`SIGINT`) or a termination-signal (`SIGTERM`) was received, it will still
+    /// exit the loop and inform the user with [`WaitSetRunResult::Interrupt`] or
+    /// [`WaitSetRunResult::TerminationRequest`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let event = node.service_builder(&"MyEventName_1".try_into()?)
+    /// #     .event()
+    /// #     .open_or_create()?;
+    ///
+    /// # let mut listener = event.listener_builder().create()?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// # let guard = waitset.attach_notification(&listener)?;
+    ///
+    /// let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
+    ///     CallbackProgression::Continue
+    /// };
+    ///
+    /// let on_error = |error: WaitSetRunError| {
+    ///     println!("failed to wait for events ({error:?}), retrying");
+    ///     CallbackProgression::Continue
+    /// };
+    ///
+    /// waitset.wait_and_process_with_error_handler(on_event, on_error)?;
+    /// println!("goodbye");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_and_process_with_error_handler<
+        F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression,
+        E: FnMut(WaitSetRunError) -> CallbackProgression,
+    >(
+        &self,
+        mut fn_call: F,
+        mut err_handler: E,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        loop {
+            match self.wait_and_process_once(&mut fn_call) {
+                Ok(WaitSetRunResult::AllEventsHandled) => (),
+                Ok(v) => return Ok(v),
+                Err(e) => match err_handler(e) {
+                    CallbackProgression::Continue => {
+                        debug!(from self,
+                            "Continue the WaitSet::wait_and_process_with_error_handler() loop after ({:?}) has occurred.", e);
+                    }
+                    CallbackProgression::Stop => {
+                        fail!(from self, with e,
+                            "Unable to run in WaitSet::wait_and_process_with_error_handler() loop since ({:?}) has occurred.", e);
+                    }
+                },
+            }
+        }
+    }
+
     /// Waits until an event arrives on the [`WaitSet`], then
     /// collects all events by calling the provided `fn_call` callback with the corresponding
     /// [`WaitSetAttachmentId`] and then returns. This makes it ideal to be called in some kind of
     /// event-loop.
     ///
+    /// Missed deadlines and ticks are handled first. Afterwards, the callback is called for every
+    /// attachment with an event in the order in which the attachments were attached, independent
+    /// of the order in which the underlying reactor reports them.
+    ///
     /// The provided callback must return [`CallbackProgression::Continue`] to continue the event
     /// processing and handle the next event or [`CallbackProgression::Stop`] to return from this
     /// call immediately. All unhandled events will be lost forever and the call will return
@@ -888,7 +1878,12 @@ impl<Service: crate::service::Service> WaitSet<Service> {
                                  "{msg} since the next timeout could not be acquired.");
         let next_timeout = next_timeout.min(timeout);
 
-        let mut triggered_file_descriptors = vec![];
+        self.release_detached_owned_attachments();
+
+        // The buffer is taken out of the WaitSet while it is in use, so that the callback can
+        // call the WaitSet again without a conflicting borrow.
+        let mut triggered_file_descriptors = self.triggered_file_descriptors.take();
+        triggered_file_descriptors.clear();
         let collect_triggered_fds = |fd: &FileDescriptor| {
             let fd = unsafe { fd.native_handle() };
             triggered_file_descriptors.push(fd);
@@ -898,15 +1893,181 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         // the deadline and then call the callback, otherwise a long callback may destroy the
         // deadline contract.
         let reactor_wait_result = if next_timeout == Duration::MAX {
-            self.reactor.blocking_wait(collect_triggered_fds)
+            self.reactor
+                .lock()
+                .reactor
+                .blocking_wait(collect_triggered_fds)
         } else {
-            self.reactor.timed_wait(collect_triggered_fds, next_timeout)
+            self.reactor
+                .lock()
+                .reactor
+                .timed_wait(collect_triggered_fds, next_timeout)
+        };
+
+        // Another thread may have dropped a WaitSetOwnedGuard right after the wait, the events
+        // of its listener are discarded.
+        if self.release_detached_owned_attachments() != 0 {
+            let attachment_order = self.attachment_order.borrow();
+            triggered_file_descriptors.retain(|fd| attachment_order.contains_key(fd));
+        }
+
+        let mut fn_call = |id| self.call_and_track(&mut fn_call, id);
+        self.stats.add_run();
+        self.last_interrupt_signal.set(None);
+        let run_result = match reactor_wait_result {
+            Ok(0) => Ok(self.handle_deadlines(&mut fn_call, msg)),
+            Ok(_) => {
+                Ok(self.handle_all_attachments(&mut triggered_file_descriptors, &mut fn_call, msg))
+            }
+            Err(e) => Err(e),
+        };
+        self.triggered_file_descriptors
+            .replace(triggered_file_descriptors);
+
+        match run_result {
+            Ok(result) => result,
+            Err(ReactorWaitError::Interrupt) => {
+                self.last_interrupt_signal
+                    .set(SignalHandler::peek_last_signal());
+                Ok(WaitSetRunResult::Interrupt)
+            }
+            Err(ReactorWaitError::InsufficientPermissions) => {
+                fail!(from self, with WaitSetRunError::InsufficientPermissions,
+                    "{msg} due to insufficient permissions.");
+            }
+            Err(ReactorWaitError::UnknownError) => {
+                fail!(from self, with WaitSetRunError::InternalError,
+                    "{msg} due to an internal error.");
+            }
+        }
+    }
+
+    /// Waits until an event arrives on the [`WaitSet`] or the provided timeout has passed and
+    /// returns a [`ReadyEventIter`] over the [`WaitSetAttachmentId`]s that had an event. It
+    /// allows to process the events with a `for` loop or iterator combinators. The
+    /// [`WaitSetRunResult`] of the wait can be acquired with [`ReadyEventIter::run_result()`].
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// # use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let event = node.service_builder(&"MyEventName_1".try_into()?)
+    /// #     .event()
+    /// #     .open_or_create()?;
+    /// # let listener = event.listener_builder().create()?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let guard = waitset.attach_notification(&listener)?;
+    ///
+    /// for attachment_id in waitset.ready_events(Duration::from_millis(10))? {
+    ///     if attachment_id.has_event_from(&guard) {
+    ///         listener.try_wait_all(|event_id| {
+    ///             println!("received notification {:?}", event_id);
+    ///         })?;
+    ///     }
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ready_events(
+        &self,
+        timeout: Duration,
+    ) -> Result<ReadyEventIter<Service>, WaitSetRunError> {
+        let mut attachment_ids = alloc::vec::Vec::new();
+        let run_result = self.wait_and_process_once_with_timeout(
+            |attachment_id| {
+                attachment_ids.push(attachment_id);
+                CallbackProgression::Continue
+            },
+            timeout,
+        )?;
+
+        Ok(ReadyEventIter {
+            attachment_ids: attachment_ids.into_iter(),
+            run_result,
+        })
+    }
+
+    /// Handles all events that are already pending without waiting for new ones and returns
+    /// the [`WaitSetRunResult`] together with the number of calls of the provided callback.
+    /// In contrast to [`WaitSet::wait_and_process_once_with_timeout()`] it does not acquire the
+    /// next timeout and does not block. The processing stops early when the callback returns
+    /// [`CallbackProgression::Stop`] and [`WaitSetRunResult::StopRequest`] is returned.
+    ///
+    /// If an interrupt- (`SIGINT`) or a termination-signal (`SIGTERM`) was received, it
+    /// informs the user with [`WaitSetRunResult::Interrupt`] or
+    /// [`WaitSetRunResult::TerminationRequest`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let event = node.service_builder(&"MyEventName_1".try_into()?)
+    /// #     .event()
+    /// #     .open_or_create()?;
+    /// # let listener = event.listener_builder().create()?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let _guard = waitset.attach_notification(&listener)?;
+    ///
+    /// let (run_result, number_of_handled_events) = waitset.drain(|attachment_id| {
+    ///     // do some event processing
+    ///     CallbackProgression::Continue
+    /// })?;
+    /// println!("handled {number_of_handled_events} events ({run_result:?})");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn drain<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        &self,
+        mut fn_call: F,
+    ) -> Result<(WaitSetRunResult, usize), WaitSetRunError> {
+        let msg = "Unable to call WaitSet::drain()";
+
+        if self.signal_handling_mode == SignalHandlingMode::HandleTerminationRequests
+            && SignalHandler::termination_requested()
+        {
+            return Ok((WaitSetRunResult::TerminationRequest, 0));
+        }
+
+        let mut number_of_calls: usize = 0;
+        let mut fn_call = |id| {
+            number_of_calls += 1;
+            self.call_and_track(&mut fn_call, id)
         };
 
-        match reactor_wait_result {
-            Ok(0) => self.handle_deadlines(&mut fn_call, msg),
-            Ok(_) => self.handle_all_attachments(&triggered_file_descriptors, &mut fn_call, msg),
-            Err(ReactorWaitError::Interrupt) => Ok(WaitSetRunResult::Interrupt),
+        self.release_detached_owned_attachments();
+
+        self.stats.add_run();
+        self.last_interrupt_signal.set(None);
+        let mut triggered_file_descriptors = self.triggered_file_descriptors.take();
+        triggered_file_descriptors.clear();
+        let reactor_wait_result = self.reactor.lock().reactor.try_wait(|fd: &FileDescriptor| {
+            triggered_file_descriptors.push(unsafe { fd.native_handle() })
+        });
+
+        let result = match reactor_wait_result {
+            Ok(0) => Ok(self.handle_deadlines(&mut fn_call, msg)),
+            Ok(_) => {
+                Ok(self.handle_all_attachments(&mut triggered_file_descriptors, &mut fn_call, msg))
+            }
+            Err(e) => Err(e),
+        };
+        self.triggered_file_descriptors
+            .replace(triggered_file_descriptors);
+
+        let run_result = match result {
+            Ok(result) => result?,
+            Err(ReactorWaitError::Interrupt) => {
+                self.last_interrupt_signal
+                    .set(SignalHandler::peek_last_signal());
+                WaitSetRunResult::Interrupt
+            }
             Err(ReactorWaitError::InsufficientPermissions) => {
                 fail!(from self, with WaitSetRunError::InsufficientPermissions,
                     "{msg} due to insufficient permissions.");
@@ -915,17 +2076,105 @@ impl<Service: crate::service::Service> WaitSet<Service> {
                 fail!(from self, with WaitSetRunError::InternalError,
                     "{msg} due to an internal error.");
             }
+        };
+
+        Ok((run_result, number_of_calls))
+    }
+
+    /// Returns the accumulated [`WaitSetStats`] of all runs of the [`WaitSet`] since it was
+    /// created.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// # use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let waitset = WaitSetBuilder::new()
+    ///     .measure_callback_duration(true)
+    ///     .create::<ipc::Service>()?;
+    /// let _guard = waitset.attach_interval(Duration::from_millis(10))?;
+    ///
+    /// waitset.wait_and_process_once(|_| CallbackProgression::Continue)?;
+    ///
+    /// let stats = waitset.stats();
+    /// println!("longest callback took {} ns", stats.max_callback_duration_ns);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stats(&self) -> WaitSetStats {
+        self.stats.load()
+    }
+
+    /// Returns how many ticks of the interval attachment were missed when the callback was
+    /// called with the provided [`WaitSetAttachmentId`] in the current or most recent
+    /// iteration. A value of 0 means that the interval is processed on schedule, a value of 3
+    /// that 3 ticks elapsed in addition without a callback call, for instance because a
+    /// previous callback ran longer than the interval. For a deadline attachment it returns the
+    /// number of missed deadlines. Returns 0 for notifications and for attachments that were
+    /// not handled in the most recent iteration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let guard = waitset.attach_interval(Duration::from_millis(10))?;
+    ///
+    /// waitset.wait_and_process_once(|attachment_id| {
+    ///     if attachment_id.has_event_from(&guard) && waitset.missed_ticks(&attachment_id) > 0 {
+    ///         println!("the periodic task fell behind");
+    ///     }
+    ///     CallbackProgression::Continue
+    /// })?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn missed_ticks(&self, attachment_id: &WaitSetAttachmentId<Service>) -> u64 {
+        let elapsed_deadlines = |waitset: u64, deadline_queue_idx: &DeadlineQueueIndex| {
+            if waitset != self as *const WaitSet<Service, C> as u64 {
+                return 0;
+            }
+
+            self.elapsed_deadlines
+                .borrow()
+                .get(deadline_queue_idx)
+                .copied()
+                .unwrap_or(0)
+        };
+
+        match &attachment_id.attachment_type {
+            AttachmentIdType::Tick(waitset, deadline_queue_idx) => {
+                elapsed_deadlines(*waitset, deadline_queue_idx).saturating_sub(1)
+            }
+            AttachmentIdType::Deadline(waitset, _, deadline_queue_idx) => {
+                elapsed_deadlines(*waitset, deadline_queue_idx)
+            }
+            AttachmentIdType::Notification(..) => 0,
         }
     }
 
+    /// Every subsequent time query of the underlying [`DeadlineQueue`] fails with the provided
+    /// [`TimeError`](iceoryx2_bb_posix::clock::TimeError) until it is called with [`None`].
+    #[doc(hidden)]
+    #[cfg(feature = "testing")]
+    pub fn __internal_inject_time_error(&self, value: Option<iceoryx2_bb_posix::clock::TimeError>) {
+        self.deadline_queue.__internal_inject_time_error(value);
+    }
+
     /// Returns the capacity of the [`WaitSet`]
     pub fn capacity(&self) -> usize {
-        self.reactor.capacity()
+        self.reactor.lock().reactor.capacity()
     }
 
     /// Returns the number of attachments.
     pub fn len(&self) -> usize {
         self.attachment_counter.load(Ordering::Relaxed)
+            + self.reactor.lock().owned_attachments.borrow().len()
     }
 
     /// Returns true if the [`WaitSet`] has no attachments, otherwise false.
@@ -933,23 +2182,94 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         self.len() == 0
     }
 
+    /// Returns the number of attachments that can still be attached to the [`WaitSet`].
+    pub fn capacity_remaining(&self) -> usize {
+        self.capacity().saturating_sub(self.len())
+    }
+
     /// Returns the [`SignalHandlingMode`] with which the [`WaitSet`] was created.
     pub fn signal_handling_mode(&self) -> SignalHandlingMode {
         self.signal_handling_mode
     }
 
+    /// Returns the signal that interrupted the last wait of the [`WaitSet`] when it returned
+    /// [`WaitSetRunResult::Interrupt`], for instance
+    /// [`NonFatalFetchableSignal::Interrupt`] for `SIGINT`. Returns [`None`] when the last
+    /// wait was not interrupted or the signal was not captured by the
+    /// [`SignalHandler`].
+    pub fn last_interrupt_signal(&self) -> Option<NonFatalFetchableSignal> {
+        self.last_interrupt_signal.get()
+    }
+
+    /// Returns the finest timeout resolution the underlying reactor of the [`WaitSet`] can
+    /// express on the current platform. Deadlines and intervals that are shorter than the
+    /// resolution, or are not a multiple of it, may fire late. It is only a lower bound and
+    /// not the effective granularity, the operating system may round the timeout up, for
+    /// instance by its timer slack (about 50µs by default on Linux) or its tick rate, and the
+    /// scheduler can add further latency on top.
+    pub fn min_timeout_resolution(&self) -> Duration {
+        self.reactor.lock().reactor.min_timeout_resolution()
+    }
+
     fn attach_to_reactor<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
         &'waitset self,
         attachment: &'attachment T,
-    ) -> Result<<Service::Reactor as Reactor>::Guard<'waitset, 'attachment>, WaitSetAttachmentError>
-    {
+        as_deadline: bool,
+    ) -> Result<ReactorAttachment<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        Ok(ReactorAttachment {
+            reactor: &self.reactor,
+            file_descriptor: self.attach_to_reactor_with(attachment, as_deadline)?,
+            _attachment: PhantomData,
+        })
+    }
+
+    // Attaches the object without a guard to the reactor and returns its file descriptor, the
+    // caller is responsible to detach it again.
+    fn attach_to_reactor_with<T: SynchronousMultiplexing + Debug>(
+        &self,
+        attachment: &T,
+        as_deadline: bool,
+    ) -> Result<i32, WaitSetAttachmentError> {
         let msg = "Unable to attach object to internal reactor";
+        let file_descriptor = unsafe { attachment.file_descriptor().native_handle() };
+        let already_attached_error = |attached_as_deadline: bool| {
+            if attached_as_deadline == as_deadline {
+                WaitSetAttachmentError::AlreadyAttached
+            } else {
+                WaitSetAttachmentError::AlreadyAttachedWithDifferentSemantic
+            }
+        };
 
-        match self.reactor.attach(attachment) {
-            Ok(guard) => Ok(guard),
+        self.release_detached_owned_attachments();
+        if self
+            .reactor
+            .lock()
+            .owned_attachments
+            .borrow()
+            .contains(&file_descriptor)
+        {
+            fail!(from self, with already_attached_error(false),
+                "{msg} {:?} since it is already attached as owned listener.", attachment);
+        }
+
+        let attach_result = self
+            .reactor
+            .lock()
+            .reactor
+            .attach_unguarded(attachment.file_descriptor());
+        match attach_result {
+            Ok(()) => {
+                self.record_attachment_order(file_descriptor);
+                Ok(file_descriptor)
+            }
             Err(ReactorAttachError::AlreadyAttached) => {
-                fail!(from self, with WaitSetAttachmentError::AlreadyAttached,
-                    "{msg} {:?} since it is already attached.", attachment);
+                let attached_as_deadline = self
+                    .attachment_to_deadline
+                    .borrow()
+                    .contains_key(&file_descriptor);
+                fail!(from self, with already_attached_error(attached_as_deadline),
+                    "{msg} {:?} since it is already attached as {}.", attachment,
+                    if attached_as_deadline { "deadline" } else { "notification" });
             }
             Err(ReactorAttachError::CapacityExceeded) => {
                 fail!(from self, with WaitSetAttachmentError::AlreadyAttached,
@@ -977,4 +2297,31 @@ impl<Service: crate::service::Service> WaitSet<Service> {
             }
         }
     }
+    fn attach_to_deadline_queue_at(
+        &self,
+        first_deadline: Time,
+        timeout: Duration,
+        realign_on_reset: bool,
+    ) -> Result<DeadlineQueueGuard<'_>, WaitSetAttachmentError> {
+        let msg = "Unable to attach timeout to underlying Timer";
+
+        let now = fail!(from self, when Time::now_with_clock(first_deadline.clock_type()),
+                with WaitSetAttachmentError::InternalError,
+                "{msg} since the current time of the clock {:?} could not be acquired.",
+                first_deadline.clock_type());
+        let first_timeout = first_deadline
+            .as_duration()
+            .saturating_sub(now.as_duration());
+
+        match self
+            .deadline_queue
+            .add_deadline_interval_at(first_timeout, timeout, realign_on_reset)
+        {
+            Ok(guard) => Ok(guard),
+            Err(e) => {
+                fail!(from self, with WaitSetAttachmentError::InternalError,
+                    "{msg} since the timeout could not be attached to the underlying deadline_queue due to ({:?}).", e);
+            }
+        }
+    }
 }
@@ -12,8 +12,9 @@
 
 #[generic_tests::define]
 mod listener {
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
 
+    use iceoryx2::port::port_identifiers::UniqueListenerId;
     use iceoryx2::testing::*;
     use iceoryx2::{node::NodeBuilder, port::listener::ListenerCreateError, service::Service};
     use iceoryx2_bb_testing::assert_that;
@@ -50,6 +51,85 @@ mod listener {
         }
     }
 
+    #[test]
+    fn id_can_be_used_as_map_key_and_displayed<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        let id: UniqueListenerId = listener.id();
+        let mut listener_names = HashMap::new();
+        listener_names.insert(id, "my listener");
+        assert_that!(listener_names.get(&listener.id()), eq Some(&"my listener"));
+
+        assert_that!(id.to_string(), eq id.value().to_string());
+    }
+
+    #[test]
+    fn missed_notification_count_reports_notifications_dropped_by_full_buffer<Sut: Service>() {
+        const MAX_NOTIFICATIONS: usize = 1_000_000;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+        assert_that!(sut.missed_notification_count(), eq 0);
+
+        let mut delivered_notifications = 0;
+        for _ in 0..MAX_NOTIFICATIONS {
+            if notifier.notify().unwrap() == 0 {
+                break;
+            }
+            delivered_notifications += 1;
+        }
+        assert_that!(delivered_notifications, lt MAX_NOTIFICATIONS);
+        assert_that!(sut.missed_notification_count(), eq 1);
+
+        assert_that!(notifier.notify().unwrap(), eq 0);
+        assert_that!(sut.missed_notification_count(), eq 2);
+
+        sut.try_wait_all(|_| {}).unwrap();
+        assert_that!(notifier.notify().unwrap(), eq 1);
+        assert_that!(sut.missed_notification_count(), eq 2);
+    }
+
+    #[test]
+    fn missed_notification_count_starts_at_zero_for_reused_listener_slot<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .max_listeners(1)
+            .create()
+            .unwrap();
+
+        let sut = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+        while notifier.notify().unwrap() != 0 {}
+        assert_that!(sut.missed_notification_count(), eq 1);
+        drop(sut);
+
+        let sut = service.listener_builder().create().unwrap();
+        assert_that!(sut.missed_notification_count(), eq 0);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -17,6 +17,7 @@ mod node_death_tests {
     use iceoryx2::config::Config;
     use iceoryx2::node::testing::__internal_node_staged_death;
     use iceoryx2::node::{CleanupState, NodeState};
+    use iceoryx2::port::ReceiveError;
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
@@ -536,6 +537,7 @@ mod node_death_tests {
 
         assert_that!(
             S::Service::list(&config, |service_details| {
+                let service_details = service_details.unwrap();
                 assert_that!(*service_details.static_details.name(), eq service_name);
                 CallbackProgression::Continue
             }),
@@ -569,6 +571,7 @@ mod node_death_tests {
 
         assert_that!(
             S::Service::list(&config, |service_details| {
+                let service_details = service_details.unwrap();
                 assert_that!(*service_details.static_details.name(), eq service_name);
                 CallbackProgression::Continue
             }),
@@ -602,6 +605,7 @@ mod node_death_tests {
 
         assert_that!(
             S::Service::list(&config, |service_details| {
+                let service_details = service_details.unwrap();
                 assert_that!(*service_details.static_details.name(), eq service_name);
                 CallbackProgression::Continue
             }),
@@ -636,6 +640,7 @@ mod node_death_tests {
 
         assert_that!(
             S::Service::list(&config, |service_details| {
+                let service_details = service_details.unwrap();
                 assert_that!(*service_details.static_details.name(), eq service_name);
                 CallbackProgression::Continue
             }),
@@ -714,6 +719,7 @@ mod node_death_tests {
 
         assert_that!(
             S::Service::list(&config, |service_details| {
+                let service_details = service_details.unwrap();
                 assert_that!(*service_details.static_details.name(), eq service_name);
                 CallbackProgression::Continue
             }),
@@ -761,6 +767,7 @@ mod node_death_tests {
 
         assert_that!(
             S::Service::list(&config, |service_details| {
+                let service_details = service_details.unwrap();
                 assert_that!(*service_details.static_details.name(), eq service_name);
                 CallbackProgression::Continue
             }),
@@ -872,6 +879,125 @@ mod node_death_tests {
         assert_that!(number_of_nodes(), eq 0);
     }
 
+    #[test]
+    fn new_publisher_reclaims_slot_of_publisher_from_dead_node<S: Test>() {
+        const MAX_LOANED_SAMPLES: usize = 4;
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut bad_node = S::create_test_node(&config).node;
+        let good_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+
+        let bad_service = bad_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(1)
+            .open_or_create()
+            .unwrap();
+        let bad_publisher = bad_service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .create()
+            .unwrap();
+        let mut loaned_samples = vec![];
+        for _ in 0..MAX_LOANED_SAMPLES {
+            loaned_samples.push(bad_publisher.loan_uninit().unwrap());
+        }
+
+        let service = good_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        S::staged_death(&mut bad_node);
+        core::mem::forget(loaned_samples);
+        core::mem::forget(bad_publisher);
+        core::mem::forget(bad_service);
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .create()
+            .unwrap();
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 1);
+
+        let mut samples = vec![];
+        for _ in 0..MAX_LOANED_SAMPLES {
+            samples.push(sut.loan_uninit().unwrap());
+        }
+    }
+
+    #[test]
+    fn subscriber_removes_publisher_from_dead_node<S: Test>() {
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut bad_node = S::create_test_node(&config).node;
+        let good_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+
+        let bad_service = bad_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let bad_publisher = bad_service.publisher_builder().create().unwrap();
+
+        let service = good_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let good_publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        S::staged_death(&mut bad_node);
+        core::mem::forget(bad_publisher);
+        core::mem::forget(bad_service);
+
+        assert_that!(sut.remove_dead_publishers(), eq Ok(1));
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 1);
+        service.dynamic_config().list_publishers(|details| {
+            assert_that!(details.publisher_id, eq good_publisher.id());
+            CallbackProgression::Continue
+        });
+
+        good_publisher.send_copy(1234).unwrap();
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1234);
+    }
+
+    #[test]
+    fn subscriber_of_removed_service_fails_to_receive<S: Test>() {
+        let service_name = generate_service_name();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut bad_node = S::create_test_node(&config).node;
+        let service = bad_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        S::staged_death(&mut bad_node);
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(&config), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+
+        assert_that!(sut.receive().err(), eq Some(ReceiveError::ServiceNoLongerExists));
+        assert_that!(sut.receive_batch(1).err(), eq Some(ReceiveError::ServiceNoLongerExists));
+
+        core::mem::forget(sut);
+        core::mem::forget(service);
+    }
+
     #[instantiate_tests(<ZeroCopy>)]
     mod ipc {}
 }
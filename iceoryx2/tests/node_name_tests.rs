@@ -12,6 +12,7 @@
 
 mod node_name {
     use iceoryx2::prelude::*;
+    use iceoryx2_bb_container::semantic_string::SemanticStringError;
     use iceoryx2_bb_testing::assert_that;
 
     #[test]
@@ -39,4 +40,20 @@ mod node_name {
         assert_that!(sut, eq value);
         assert_that!(&sut, eq value);
     }
+
+    #[test]
+    fn creating_with_too_long_name_fails() {
+        let value = "a".repeat(NodeName::max_len() + 1);
+        let sut = NodeName::new(&value);
+
+        assert_that!(sut.err(), eq Some(SemanticStringError::ExceedsMaximumLength));
+    }
+
+    #[test]
+    fn creating_with_max_len_name_works() {
+        let value = "a".repeat(NodeName::max_len());
+        let sut = NodeName::new(&value).unwrap();
+
+        assert_that!(sut, eq value.as_str());
+    }
 }
@@ -394,6 +394,91 @@ mod node {
         assert_that!(node.signal_handling_mode(), eq SignalHandlingMode::HandleTerminationRequests);
     }
 
+    #[test]
+    fn node_without_heartbeat_interval_has_no_heartbeat<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(node.last_heartbeat(), is_none);
+
+        let mut heartbeats = vec![];
+        Node::<S>::list(&config, |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                heartbeats.push(view.last_heartbeat());
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(heartbeats, len 1);
+        assert_that!(heartbeats[0], is_none);
+    }
+
+    #[test]
+    fn node_with_heartbeat_interval_updates_heartbeat<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .heartbeat_interval(Duration::from_millis(1))
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        let first_heartbeat = node.last_heartbeat().unwrap().as_duration();
+        loop {
+            let heartbeat = node.last_heartbeat().unwrap().as_duration();
+            if heartbeat > first_heartbeat {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn heartbeat_is_visible_while_listing_nodes<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .heartbeat_interval(Duration::from_millis(10))
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        let mut heartbeats = vec![];
+        Node::<S>::list(&config, |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                heartbeats.push(view.last_heartbeat());
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(heartbeats, len 1);
+        assert_that!(heartbeats[0], is_some);
+        assert_that!(heartbeats[0].unwrap().as_duration(), le node.last_heartbeat().unwrap().as_duration());
+    }
+
+    #[test]
+    fn heartbeat_is_removed_when_node_goes_out_of_scope<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .heartbeat_interval(Duration::from_millis(10))
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        drop(node);
+
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        assert_that!(node.last_heartbeat(), is_none);
+
+        let mut number_of_nodes = 0;
+        Node::<S>::list(&config, |_| {
+            number_of_nodes += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_nodes, eq 1);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
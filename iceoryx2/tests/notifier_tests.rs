@@ -14,6 +14,8 @@
 mod notifier {
     use std::collections::HashSet;
 
+    use iceoryx2::port::event_id::{EventId, EventIdConversionError, IntoEventId};
+    use iceoryx2::prelude::{CallbackProgression, PortFactory};
     use iceoryx2::testing::*;
     use iceoryx2::{
         node::NodeBuilder,
@@ -22,6 +24,13 @@ mod notifier {
     };
     use iceoryx2_bb_testing::assert_that;
 
+    #[derive(Debug, Clone, Copy, PartialEq, IntoEventId)]
+    enum TestEvent {
+        Start = 2,
+        Stop = 5,
+        Abort = 12,
+    }
+
     #[test]
     fn create_error_display_works<S: Service>() {
         assert_that!(
@@ -58,6 +67,132 @@ mod notifier {
         }
     }
 
+    #[test]
+    fn notifier_id_is_unique_and_listed_in_dynamic_config<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const MAX_NOTIFIERS: usize = 8;
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(MAX_NOTIFIERS)
+            .create()
+            .unwrap();
+
+        let mut notifiers = vec![];
+        let mut notifier_id_set = HashSet::new();
+
+        for _ in 0..MAX_NOTIFIERS {
+            let notifier = sut.notifier_builder().create().unwrap();
+            assert_that!(notifier_id_set.insert(notifier.id()), eq true);
+            notifiers.push(notifier);
+        }
+
+        let mut listed_notifier_ids = HashSet::new();
+        sut.dynamic_config().list_notifiers(|details| {
+            listed_notifier_ids.insert(details.notifier_id);
+            CallbackProgression::Continue
+        });
+        assert_that!(listed_notifier_ids, eq notifier_id_set);
+    }
+
+    #[test]
+    fn try_notify_reports_whether_listeners_were_notified<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        assert_that!(notifier.try_notify(), eq Ok(false));
+
+        let listener = sut.listener_builder().create().unwrap();
+        assert_that!(notifier.try_notify(), eq Ok(true));
+        assert_that!(listener.try_wait_one().unwrap().event_id(), is_some);
+
+        drop(listener);
+        assert_that!(notifier.try_notify(), eq Ok(false));
+    }
+
+    #[test]
+    fn notify_with_derived_event_id_works<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(8)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        for event in [TestEvent::Start, TestEvent::Stop] {
+            assert_that!(notifier.notify_with_custom_event_id(event.into()), eq Ok(1));
+            let event_id = listener.try_wait_one().unwrap().event_id().unwrap();
+            assert_that!(TestEvent::try_from(event_id), eq Ok(event));
+        }
+
+        assert_that!(
+            notifier.notify_with_custom_event_id(TestEvent::Abort.into()),
+            eq Err(NotifierNotifyError::EventIdOutOfBounds)
+        );
+
+        assert_that!(
+            TestEvent::try_from(EventId::new(3)),
+            eq Err(EventIdConversionError::UnknownEventId)
+        );
+    }
+
+    #[test]
+    fn notify_with_event_id_converted_from_integer_works<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(300)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        let event_ids = [
+            EventId::from(7u8),
+            EventId::from(260u16),
+            EventId::from(42u32),
+            EventId::try_from(299u64).unwrap(),
+        ];
+        for event_id in event_ids {
+            assert_that!(notifier.notify_with_custom_event_id(event_id), eq Ok(1));
+            let received = listener.try_wait_one().unwrap().event_id().unwrap();
+            assert_that!(received, eq event_id);
+        }
+        assert_that!(event_ids.map(|id| id.value()), eq [7, 260, 42, 299]);
+
+        let too_large = EventId::try_from(301u64).unwrap();
+        assert_that!(
+            notifier.notify_with_custom_event_id(too_large),
+            eq Err(NotifierNotifyError::EventIdOutOfBounds)
+        );
+
+        assert_that!(EventId::try_from(EventId::MAX.value() as u64), eq Ok(EventId::MAX));
+        assert_that!(EventId::default().value(), eq 0);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -17,9 +17,13 @@ mod publisher {
     use std::sync::Mutex;
     use std::time::Instant;
 
-    use iceoryx2::port::{publisher::PublisherCreateError, LoanError};
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::port::{
+        publisher::PublisherCreateError, BlockingLoanError, DeliveryState, LoanError,
+    };
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::CustomPayloadMarker;
+    use iceoryx2::service::port_factory::publisher::MemoryHint;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::service::{service_name::ServiceName, Service};
     use iceoryx2::testing;
@@ -287,6 +291,255 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_reports_max_and_currently_loaned_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let max_loaned_samples = config
+            .defaults
+            .publish_subscribe
+            .publisher_max_loaned_samples
+            + 8;
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(max_loaned_samples)
+            .create()?;
+
+        assert_that!(sut.max_loaned_samples(), eq max_loaned_samples);
+        assert_that!(sut.currently_loaned_samples(), eq 0);
+
+        let mut samples = vec![];
+        for n in 0..max_loaned_samples {
+            samples.push(sut.loan_uninit()?);
+            assert_that!(sut.currently_loaned_samples(), eq n + 1);
+        }
+
+        assert_that!(sut.loan_uninit().err(), eq Some(LoanError::ExceedsMaxLoans));
+
+        samples.pop();
+        assert_that!(sut.currently_loaned_samples(), eq max_loaned_samples - 1);
+        samples.pop().unwrap().write_payload(0).send()?;
+        assert_that!(sut.currently_loaned_samples(), eq max_loaned_samples - 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_creation_fails_when_data_segment_would_exceed_max_size<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(usize::MAX)
+            .create();
+        assert_that!(sut.err(), eq Some(PublisherCreateError::ExceedsMaxDataSegmentSize));
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(isize::MAX as usize / 8)
+            .create();
+        assert_that!(sut.err(), eq Some(PublisherCreateError::ExceedsMaxDataSegmentSize));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_history_depth_is_history_size_of_service_by_default<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(5)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        assert_that!(sut.history_depth(), eq 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_history_limits_the_samples_delivered_to_late_subscribers<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(5)
+            .subscriber_max_buffer_size(5)
+            .create()?;
+
+        let sut = service.publisher_builder().history(2).create()?;
+        assert_that!(sut.history_depth(), eq 2);
+
+        for n in 0..5 {
+            sut.send_copy(n)?;
+        }
+
+        let subscriber = service.subscriber_builder().create()?;
+        sut.update_connections()?;
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 3);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 4);
+        assert_that!(subscriber.receive()?, is_none);
+
+        let sut = service.publisher_builder().history(6).create();
+        assert_that!(sut.err(), eq Some(PublisherCreateError::HistoryExceedsHistorySizeOfService));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_retention_delivers_samples_to_late_subscribers_beyond_service_history<
+        Sut: Service,
+    >() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(1)
+            .subscriber_max_buffer_size(5)
+            .create()?;
+
+        let sut = service.publisher_builder().retention(3).create()?;
+        assert_that!(sut.history_depth(), eq 3);
+
+        for n in 0..5 {
+            sut.send_copy(n)?;
+        }
+
+        // requests a smaller history, the retained samples are delivered nevertheless and
+        // the sample that is part of the history is not delivered twice
+        let subscriber = service.subscriber_builder().history_request(1).create()?;
+        sut.update_connections()?;
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 2);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 3);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 4);
+        assert_that!(subscriber.receive()?, is_none);
+
+        sut.send_copy(5)?;
+        assert_that!(*subscriber.receive()?.unwrap(), eq 5);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_retention_does_not_reduce_max_loaned_samples<Sut: Service>() -> TestResult<()> {
+        const MAX_LOANED_SAMPLES: usize = 2;
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(1)
+            .subscriber_max_borrowed_samples(1)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .retention(4)
+            .create()?;
+
+        for n in 0..4 {
+            sut.send_copy(n)?;
+        }
+
+        let mut samples = vec![];
+        for _ in 0..MAX_LOANED_SAMPLES {
+            samples.push(sut.loan()?);
+        }
+        assert_that!(sut.loan().err(), eq Some(LoanError::ExceedsMaxLoans));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_without_memory_hint_does_not_apply_it<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        assert_that!(sut.memory_hint_applied(), eq false);
+
+        let service = node
+            .service_builder(&generate_name()?)
+            .publish_subscribe::<[u8]>()
+            .create()?;
+        let sut = service
+            .publisher_builder()
+            .memory_hint(MemoryHint::HugePages)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?;
+        assert_that!(sut.memory_hint_applied(), eq false);
+
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn publisher_with_huge_pages_memory_hint_works<Sut: Service>() -> TestResult<()> {
+        let huge_pages_are_available =
+            std::fs::read_to_string("/sys/kernel/mm/transparent_hugepage/enabled")
+                .map(|v| !v.contains("[never]"))
+                .unwrap_or(false);
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(1024 * 1024)
+            .memory_hint(MemoryHint::HugePages)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        if huge_pages_are_available {
+            assert_that!(sut.memory_hint_applied(), eq true);
+        }
+
+        // the memory hint must not affect the communication
+        let sample = sut.loan_slice_uninit(4)?;
+        sample.write_from_slice(&[1, 2, 3, 4]).send()?;
+        assert_that!(*subscriber.receive()?.unwrap(), eq [1, 2, 3, 4]);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_sending_sample_reduces_loan_counter<Sut: Service>() -> TestResult<()> {
         let service_name = generate_name()?;
@@ -399,6 +652,366 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_block_when_unable_to_deliver_send_timeout_discards_sample_after_timeout<
+        Sut: Service,
+    >() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_copy(1), eq Ok(1));
+
+        let now = Instant::now();
+        let sample = sut.loan_uninit()?.write_payload(2);
+        assert_that!(sample.send_timeout(TIMEOUT), eq Ok(0));
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_detailed_reports_delivery_state_of_every_subscriber<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()?;
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
+
+        let report = sut.loan_uninit()?.write_payload(1).send_detailed()?;
+        assert_that!(report.deliveries(), len 2);
+        assert_that!(report.number_of_recipients(), eq 2);
+        assert_that!(report.state_of(&subscriber_1.id()), eq Some(DeliveryState::Delivered));
+        assert_that!(report.state_of(&subscriber_2.id()), eq Some(DeliveryState::Delivered));
+
+        assert_that!(*subscriber_2.receive()?.unwrap(), eq 1);
+
+        let report = sut.loan_uninit()?.write_payload(2).send_detailed()?;
+        assert_that!(report.deliveries(), len 2);
+        assert_that!(report.number_of_recipients(), eq 1);
+        assert_that!(report.state_of(&subscriber_1.id()), eq Some(DeliveryState::DroppedDueToFullBuffer));
+        assert_that!(report.state_of(&subscriber_2.id()), eq Some(DeliveryState::Delivered));
+
+        assert_that!(*subscriber_1.receive()?.unwrap(), eq 1);
+        assert_that!(subscriber_1.receive()?, is_none);
+        assert_that!(*subscriber_2.receive()?.unwrap(), eq 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_detailed_without_subscribers_returns_empty_report<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        let report = sut.loan_uninit()?.write_payload(1).send_detailed()?;
+        assert_that!(report.deliveries(), is_empty);
+        assert_that!(report.number_of_recipients(), eq 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dropped_samples_are_counted_consistently_on_both_sides<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_SENDS: u64 = 10;
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()?;
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
+
+        assert_that!(sut.dropped_samples().total(), eq 0);
+
+        for n in 0..NUMBER_OF_SENDS {
+            sut.send_copy(n)?;
+        }
+        assert_that!(*subscriber_2.receive()?.unwrap(), eq 0);
+        sut.send_copy(NUMBER_OF_SENDS)?;
+
+        let dropped_samples = sut.dropped_samples();
+        assert_that!(dropped_samples.per_subscriber(), len 2);
+        assert_that!(dropped_samples.of(&subscriber_1.id()), eq Some(NUMBER_OF_SENDS));
+        assert_that!(dropped_samples.of(&subscriber_2.id()), eq Some(NUMBER_OF_SENDS - 1));
+        assert_that!(dropped_samples.total(), eq 2 * NUMBER_OF_SENDS - 1);
+        assert_that!(subscriber_1.missed_due_to_overflow(), eq NUMBER_OF_SENDS);
+        assert_that!(subscriber_2.missed_due_to_overflow(), eq NUMBER_OF_SENDS - 1);
+
+        sut.reset_dropped_samples();
+        assert_that!(sut.dropped_samples().total(), eq 0);
+        assert_that!(subscriber_1.missed_due_to_overflow(), eq NUMBER_OF_SENDS);
+
+        subscriber_1.reset_missed_due_to_overflow();
+        assert_that!(subscriber_1.missed_due_to_overflow(), eq 0);
+        assert_that!(subscriber_2.missed_due_to_overflow(), eq NUMBER_OF_SENDS - 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn samples_replaced_on_overflow_are_counted_as_dropped<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_SENDS: u64 = 10;
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for n in 0..NUMBER_OF_SENDS {
+            sut.send_copy(n)?;
+        }
+
+        assert_that!(sut.dropped_samples().of(&subscriber.id()), eq Some(NUMBER_OF_SENDS - 1));
+        assert_that!(subscriber.missed_due_to_overflow(), eq NUMBER_OF_SENDS - 1);
+        assert_that!(*subscriber.receive()?.unwrap(), eq NUMBER_OF_SENDS - 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_block_when_unable_to_deliver_send_timeout_delivers_when_buffer_has_space<
+        Sut: Service,
+    >() -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+        let service = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+
+        let handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .subscriber_max_buffer_size(1)
+                    .open()
+                    .unwrap();
+
+                let subscriber = service.subscriber_builder().create().unwrap();
+
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                let sample = subscriber.receive().unwrap();
+                assert_that!(sample, is_some);
+                assert_that!(*sample.unwrap(), eq 8192);
+            });
+
+            barrier.wait();
+            let now = Instant::now();
+            assert_that!(sut.send_copy(8192), eq Ok(1));
+            let sample = sut.loan_uninit().unwrap().write_payload(2);
+            assert_that!(sample.send_timeout(TIMEOUT * 100), eq Ok(1));
+            assert_that!(now.elapsed(), time_at_least TIMEOUT);
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn wait_for_subscribers_returns_when_subscriber_connects<Sut: Service>() -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+        let service = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        let start_handle = BarrierHandle::new();
+        let start_barrier = BarrierBuilder::new(2).create(&start_handle).unwrap();
+        let end_handle = BarrierHandle::new();
+        let end_barrier = BarrierBuilder::new(2).create(&end_handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap();
+
+                start_barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                let _subscriber = service.subscriber_builder().create().unwrap();
+                end_barrier.wait();
+            });
+
+            start_barrier.wait();
+            let now = Instant::now();
+            assert_that!(sut.wait_for_subscribers(1, TIMEOUT * 100), eq Ok(1));
+            assert_that!(now.elapsed(), time_at_least TIMEOUT);
+            end_barrier.wait();
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn wait_for_subscribers_returns_connected_subscribers_after_timeout<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        assert_that!(sut.wait_for_subscribers(0, TIMEOUT), eq Ok(0));
+
+        let now = Instant::now();
+        assert_that!(sut.wait_for_subscribers(1, TIMEOUT), eq Ok(0));
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+
+        let _subscriber = service.subscriber_builder().create()?;
+        let now = Instant::now();
+        assert_that!(sut.wait_for_subscribers(2, TIMEOUT), eq Ok(1));
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+
+        Ok(())
+    }
+
+    #[test]
+    fn flush_after_send_succeeds_and_sample_is_received<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+        assert_that!(sut.flush(), is_ok);
+
+        sut.send_copy(8912)?;
+        assert_that!(sut.flush(), is_ok);
+
+        let sample = subscriber.receive()?;
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 8912);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unable_to_deliver_strategy_is_visible_in_dynamic_config<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()?;
+
+        let blocking_publisher = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+        let discarding_publisher = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()?;
+
+        let mut strategies = vec![];
+        service.dynamic_config().list_publishers(|details| {
+            strategies.push((details.publisher_id, details.unable_to_deliver_strategy));
+            CallbackProgression::Continue
+        });
+
+        assert_that!(strategies, len 2);
+        assert_that!(
+            strategies,
+            contains(blocking_publisher.id(), UnableToDeliverStrategy::Block)
+        );
+        assert_that!(
+            strategies,
+            contains(
+                discarding_publisher.id(),
+                UnableToDeliverStrategy::DiscardSample
+            )
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn create_error_display_works<S: Service>() {
         assert_that!(
@@ -419,6 +1032,214 @@ mod publisher {
             format!("{}", LoanError::InternalFailure), eq "LoanError::InternalFailure");
     }
 
+    #[test]
+    fn loan_fails_with_retryable_error_when_all_samples_are_loaned<Sut: Service>() -> TestResult<()>
+    {
+        const MAX_LOANED_SAMPLES: usize = 3;
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .create()?;
+
+        let mut samples = vec![];
+        for _ in 0..MAX_LOANED_SAMPLES {
+            samples.push(sut.loan()?);
+        }
+
+        let error = sut.loan().err().unwrap();
+        assert_that!(error.is_retryable(), eq true);
+
+        samples.pop();
+        assert_that!(sut.loan(), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loan_timeout_fails_with_timeout_when_no_sample_is_released<Sut: Service>() -> TestResult<()>
+    {
+        const MAX_LOANED_SAMPLES: usize = 2;
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .create()?;
+
+        let mut samples = vec![];
+        for _ in 0..MAX_LOANED_SAMPLES {
+            samples.push(sut.loan_timeout(TIMEOUT)?);
+        }
+
+        let now = Instant::now();
+        assert_that!(sut.loan_timeout(TIMEOUT).err(), eq Some(BlockingLoanError::Timeout));
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+
+        samples.pop();
+        assert_that!(sut.loan_timeout(TIMEOUT), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loan_blocking_returns_sample_when_one_is_available<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(1).create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for i in 0..3 {
+            let mut sample = sut.loan_blocking()?;
+            *sample.payload_mut() = i;
+            sample.send()?;
+
+            assert_that!(*subscriber.receive()?.unwrap(), eq i);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn loan_from_copies_payload_and_user_header_of_received_sample<Sut: Service>() -> TestResult<()>
+    {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let input = node
+            .service_builder(&generate_name()?)
+            .publish_subscribe::<[u64; 4]>()
+            .user_header::<u64>()
+            .create()?;
+        let output = node
+            .service_builder(&generate_name()?)
+            .publish_subscribe::<[u64; 4]>()
+            .user_header::<u64>()
+            .create()?;
+
+        let input_publisher = input.publisher_builder().create()?;
+        let input_subscriber = input.subscriber_builder().create()?;
+        let sut = output.publisher_builder().max_loaned_samples(1).create()?;
+        let output_subscriber = output.subscriber_builder().create()?;
+
+        let mut sample = input_publisher.loan_uninit()?;
+        *sample.user_header_mut() = 981;
+        sample.write_payload([1, 2, 3, 4]).send()?;
+
+        let received = input_subscriber.receive()?.unwrap();
+        let mut sample = sut.loan_from(&received)?;
+        assert_that!(*sample.payload(), eq [1, 2, 3, 4]);
+        assert_that!(*sample.user_header(), eq 981);
+        assert_that!(sut.loan_from(&received).err(), eq Some(LoanError::ExceedsMaxLoans));
+
+        // the original sample can be released before the copy is sent
+        drop(received);
+        sample.payload_mut()[0] = 42;
+        sample.send()?;
+
+        let republished = output_subscriber.receive()?.unwrap();
+        assert_that!(*republished.payload(), eq [42, 2, 3, 4]);
+        assert_that!(*republished.user_header(), eq 981);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loan_slice_from_copies_payload_of_received_sample<Sut: Service>() -> TestResult<()> {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let input = node
+            .service_builder(&generate_name()?)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+        let output = node
+            .service_builder(&generate_name()?)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let input_publisher = input
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()?;
+        let input_subscriber = input.subscriber_builder().create()?;
+        let sut = output
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()?;
+        let output_subscriber = output.subscriber_builder().create()?;
+
+        input_publisher
+            .loan_slice_uninit(3)?
+            .write_from_slice(&[5, 6, 7])
+            .send()?;
+
+        let received = input_subscriber.receive()?.unwrap();
+        let sample = sut.loan_slice_from(&received)?;
+
+        // the received sample stays valid while the copy is sent
+        sample.send()?;
+        assert_that!(received.payload(), eq [5, 6, 7]);
+        drop(received);
+
+        let republished = output_subscriber.receive()?.unwrap();
+        assert_that!(republished.payload(), eq [5, 6, 7]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loan_slice_from_fails_when_received_sample_exceeds_max_slice_len<Sut: Service>(
+    ) -> TestResult<()> {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let input = node
+            .service_builder(&generate_name()?)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+        let output = node
+            .service_builder(&generate_name()?)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let input_publisher = input
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()?;
+        let input_subscriber = input.subscriber_builder().create()?;
+        let sut = output
+            .publisher_builder()
+            .initial_max_slice_len(2)
+            .create()?;
+
+        input_publisher
+            .loan_slice_uninit(3)?
+            .write_from_slice(&[5, 6, 7])
+            .send()?;
+
+        let received = input_subscriber.receive()?.unwrap();
+        assert_that!(sut.loan_slice_from(&received).err(), eq Some(LoanError::ExceedsMaxLoanSize));
+
+        Ok(())
+    }
+
     #[test]
     fn id_is_unique<Sut: Service>() {
         let service_name = generate_name().unwrap();
@@ -571,3 +1392,16 @@ mod publisher {
     #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
     mod local_threadsafe {}
 }
+
+mod loan_error {
+    use iceoryx2::port::LoanError;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn loan_error_distinguishes_retryable_failures() {
+        assert_that!(LoanError::OutOfMemory.is_retryable(), eq true);
+        assert_that!(LoanError::ExceedsMaxLoans.is_retryable(), eq true);
+        assert_that!(LoanError::ExceedsMaxLoanSize.is_retryable(), eq false);
+        assert_that!(LoanError::InternalFailure.is_retryable(), eq false);
+    }
+}
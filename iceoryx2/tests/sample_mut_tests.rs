@@ -102,6 +102,19 @@ mod sample_mut {
         assert_that!(sample.header().publisher_id(), eq test_context.publisher.id());
     }
 
+    #[test]
+    fn debug_output_contains_header_but_not_payload<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+        let mut sample = test_context.publisher.loan().unwrap();
+        *sample.payload_mut() = 98127634;
+        let debug_output = format!("{sample:?}");
+
+        assert_that!(debug_output.contains(&format!("publisher_id: {:?}", test_context.publisher.id())), eq true);
+        assert_that!(debug_output.contains("payload_size: 8"), eq true);
+        assert_that!(debug_output.contains("98127634"), eq false);
+    }
+
     #[test]
     fn write_payload_works<Sut: Service>() {
         const PAYLOAD_1: u64 = 891283689123555;
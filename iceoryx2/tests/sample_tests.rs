@@ -82,6 +82,70 @@ mod sample {
         assert_that!(sample.origin(), eq test_context.publisher_2.id());
     }
 
+    #[test]
+    fn sequence_number_is_incremented_per_publisher<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+
+        for n in 0..3 {
+            assert_that!(test_context.publisher_1.send_copy(123), eq Ok(1));
+            let sample = test_context.subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.sequence_number(), eq n);
+        }
+
+        assert_that!(test_context.publisher_2.send_copy(456), eq Ok(1));
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.sequence_number(), eq 0);
+    }
+
+    #[test]
+    fn sequence_numbers_of_interleaving_publishers_are_monotonic<Sut: Service>() {
+        const NUMBER_OF_ITERATIONS: u64 = 8;
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+
+        let mut next_sequence_number_1 = 0;
+        let mut next_sequence_number_2 = 0;
+        for n in 0..NUMBER_OF_ITERATIONS {
+            assert_that!(test_context.publisher_1.send_copy(n), eq Ok(1));
+            if n % 2 == 0 {
+                assert_that!(test_context.publisher_2.send_copy(n), eq Ok(1));
+            }
+
+            while let Some(sample) = test_context.subscriber.receive().unwrap() {
+                if sample.origin() == test_context.publisher_1.id() {
+                    assert_that!(sample.sequence_number(), eq next_sequence_number_1);
+                    next_sequence_number_1 += 1;
+                } else {
+                    assert_that!(sample.origin(), eq test_context.publisher_2.id());
+                    assert_that!(sample.sequence_number(), eq next_sequence_number_2);
+                    next_sequence_number_2 += 1;
+                }
+            }
+        }
+
+        assert_that!(next_sequence_number_1, eq NUMBER_OF_ITERATIONS);
+        assert_that!(next_sequence_number_2, eq NUMBER_OF_ITERATIONS / 2);
+    }
+
+    #[test]
+    fn debug_output_contains_header_but_not_payload<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+
+        for _ in 0..2 {
+            assert_that!(test_context.publisher_1.send_copy(98127634), eq Ok(1));
+        }
+        let _first_sample = test_context.subscriber.receive().unwrap().unwrap();
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        let debug_output = format!("{sample:?}");
+
+        assert_that!(debug_output.contains(&format!("publisher_id: {:?}", test_context.publisher_1.id())), eq true);
+        assert_that!(debug_output.contains("sequence_number: 1"), eq true);
+        assert_that!(debug_output.contains("payload_size: 8"), eq true);
+        assert_that!(debug_output.contains("98127634"), eq false);
+    }
+
     #[test]
     fn sample_of_dropped_service_does_block_new_service_creation<Sut: Service>() {
         let config = generate_isolated_config();
@@ -959,7 +959,7 @@ mod service_blackboard {
 
             let mut service_list = vec![];
             Sut::list(&config, |s| {
-                service_list.push(s);
+                service_list.push(s.unwrap());
                 CallbackProgression::Continue
             })
             .unwrap();
@@ -974,7 +974,7 @@ mod service_blackboard {
 
             let mut service_list = vec![];
             Sut::list(&config, |s| {
-                service_list.push(s);
+                service_list.push(s.unwrap());
                 CallbackProgression::Continue
             })
             .unwrap();
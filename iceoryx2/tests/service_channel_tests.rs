@@ -0,0 +1,143 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_channel {
+    use core::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::channel::ChannelCreateError;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::event::Event;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_channel_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn initiator_and_responder_can_communicate<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let builder = node.service_builder(&generate_name()).channel::<u64, i32>();
+
+        let initiator = builder.clone().open_or_create_initiator().unwrap();
+        let responder = builder.open_or_create_responder().unwrap();
+
+        assert_that!(responder.recv().unwrap(), eq None);
+        initiator.send(8192).unwrap();
+
+        assert_that!(responder.timed_wait(TIMEOUT).unwrap(), eq true);
+        assert_that!(responder.recv().unwrap(), eq Some(8192));
+        assert_that!(responder.recv().unwrap(), eq None);
+
+        responder.send(-17).unwrap();
+
+        assert_that!(initiator.timed_wait(TIMEOUT).unwrap(), eq true);
+        assert_that!(initiator.recv().unwrap(), eq Some(-17));
+        assert_that!(initiator.recv().unwrap(), eq None);
+    }
+
+    #[test]
+    fn channel_settings_are_applied_to_both_directions<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .channel::<u64, u64>()
+            .history_size(3)
+            .subscriber_max_buffer_size(5)
+            .open_or_create_initiator()
+            .unwrap();
+
+        for suffix in ["forward", "backward"] {
+            let name = ServiceName::new(&format!("{service_name}/{suffix}")).unwrap();
+            let service = node
+                .service_builder(&name)
+                .publish_subscribe::<u64>()
+                .open()
+                .unwrap();
+            assert_that!(service.static_config().history_size(), eq 3);
+            assert_that!(service.static_config().subscriber_max_buffer_size(), eq 5);
+        }
+    }
+
+    #[test]
+    fn channel_with_too_long_name_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = ServiceName::new(&"a".repeat(ServiceName::max_len())).unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .channel::<u64, u64>()
+            .open_or_create_initiator();
+
+        assert_that!(sut.err(), eq Some(ChannelCreateError::InvalidServiceName));
+    }
+
+    #[test]
+    fn channel_can_be_attached_to_waitset<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let builder = node.service_builder(&generate_name()).channel::<u64, u64>();
+
+        let initiator = builder.clone().open_or_create_initiator().unwrap();
+        let responder = builder.open_or_create_responder().unwrap();
+
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset.attach_notification(&responder).unwrap();
+
+        initiator.send(99).unwrap();
+
+        let mut received = vec![];
+        waitset
+            .wait_and_process_once(|attachment_id| {
+                if attachment_id.has_event_from(&guard) {
+                    responder.try_wait().unwrap();
+                    while let Some(value) = responder.recv().unwrap() {
+                        received.push(value);
+                    }
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        assert_that!(received, eq vec![99]);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
+    mod ipc_threadsafe {}
+
+    #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
+    mod local_threadsafe {}
+}
@@ -18,11 +18,14 @@ mod service_event {
     use std::sync::Barrier;
     use std::time::Instant;
 
-    use iceoryx2::port::listener::{Listener, ListenerCreateError};
+    use iceoryx2::port::listener::{Listener, ListenerCreateError, ListenerReceiveResult};
     use iceoryx2::port::notifier::{NotifierCreateError, NotifierNotifyError};
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
+    use iceoryx2::service::builder::BackoffPolicy;
     use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::clock::Time;
+    use iceoryx2_bb_posix::process::Process;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
@@ -49,6 +52,36 @@ mod service_event {
         assert_that!(*sut.name(), eq service_name);
     }
 
+    #[test]
+    fn creation_stats_are_populated<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let start = Instant::now();
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let stats = sut.creation_stats();
+        assert_that!(stats.attempts, eq 1);
+        assert_that!(stats.waited, le start.elapsed());
+
+        let start = Instant::now();
+        let sut2 = node.service_builder(&service_name).event().open().unwrap();
+        let stats = sut2.creation_stats();
+        assert_that!(stats.attempts, eq 1);
+        assert_that!(stats.waited, le start.elapsed());
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        assert_that!(sut3.creation_stats().attempts, ge 1);
+    }
+
     #[test]
     fn creating_same_service_twice_fails<Sut: Service>() {
         let service_name = generate_name();
@@ -101,6 +134,76 @@ mod service_event {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn open_or_create_with_backoff_policy_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let policy = BackoffPolicy::new(Duration::from_millis(1), Duration::from_millis(10), 2);
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .backoff_policy(policy)
+            .open_or_create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .backoff_policy(policy)
+            .open_or_create();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_listener_only_ignores_notifier_requirements<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(1)
+            .max_listeners(2)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(config.defaults.event.max_notifiers + 1)
+            .open_listener_only();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().listener_builder().create(), is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .event()
+            .max_listeners(3)
+            .open_listener_only();
+        assert_that!(sut3.err(), eq Some(EventOpenError::DoesNotSupportRequestedAmountOfListeners));
+    }
+
+    #[test]
+    fn open_listener_only_fails_when_messaging_pattern_does_not_match<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .open_listener_only();
+        // the messaging pattern is part of the service id, therefore no event service exists
+        assert_that!(sut2.err(), eq Some(EventOpenError::DoesNotExist));
+    }
+
     #[test]
     fn open_fails_when_service_does_not_satisfy_opener_notifier_requirements<Sut: Service>() {
         let service_name = generate_name();
@@ -357,7 +460,7 @@ mod service_event {
         assert_that!(notifier.notify(), is_ok);
 
         let mut received_events = 0;
-        for event in listener.try_wait_one().unwrap().iter() {
+        for event in listener.try_wait_one().unwrap().event_id().iter() {
             assert_that!(*event, eq event_id);
             received_events += 1;
         }
@@ -389,7 +492,7 @@ mod service_event {
         assert_that!(notifier.notify(), is_ok);
 
         let mut received_events = 0;
-        for event in listener.try_wait_one().unwrap().iter() {
+        for event in listener.try_wait_one().unwrap().event_id().iter() {
             assert_that!(*event, eq event_id);
             received_events += 1;
         }
@@ -416,7 +519,7 @@ mod service_event {
         let notifier = sut2.notifier_builder().create().unwrap();
 
         let mut received_events = 0;
-        for _ in listener.try_wait_one().unwrap().iter() {
+        for _ in listener.try_wait_one().unwrap().event_id().iter() {
             received_events += 1;
         }
         assert_that!(received_events, eq 0);
@@ -424,7 +527,7 @@ mod service_event {
         drop(notifier);
 
         let mut received_events = 0;
-        for _ in listener.try_wait_one().unwrap().iter() {
+        for _ in listener.try_wait_one().unwrap().event_id().iter() {
             received_events += 1;
         }
         assert_that!(received_events, eq 0);
@@ -452,7 +555,7 @@ mod service_event {
         let notifier = sut2.notifier_builder().create().unwrap();
 
         let mut received_events = 0;
-        for event in listener.try_wait_one().unwrap().iter() {
+        for event in listener.try_wait_one().unwrap().event_id().iter() {
             assert_that!(*event, eq notifier_created);
             received_events += 1;
         }
@@ -461,7 +564,7 @@ mod service_event {
         drop(notifier);
 
         let mut received_events = 0;
-        for event in listener.try_wait_one().unwrap().iter() {
+        for event in listener.try_wait_one().unwrap().event_id().iter() {
             assert_that!(*event, eq notifier_dropped);
             received_events += 1;
         }
@@ -507,7 +610,7 @@ mod service_event {
 
                 for listener in &mut listeners {
                     let mut received_events = 0;
-                    for event in listener.try_wait_one().unwrap().iter() {
+                    for event in listener.try_wait_one().unwrap().event_id().iter() {
                         assert_that!(*event, eq EventId::new(i + 3));
                         received_events += 1;
                     }
@@ -559,7 +662,7 @@ mod service_event {
                 let mut received_events = 0;
 
                 let mut received_event_ids = [false; MAX_NOTIFIERS];
-                while let Some(event) = listener.try_wait_one().unwrap() {
+                while let Some(event) = listener.try_wait_one().unwrap().event_id() {
                     assert_that!(received_event_ids[event.as_value()], eq false);
                     received_event_ids[event.as_value()] = true;
                     received_events += 1;
@@ -716,7 +819,7 @@ mod service_event {
             assert_that!(notifier
                 .notify_with_custom_event_id(EventId::new(i))
                 .unwrap(), eq 1);
-            assert_that!(listener.try_wait_one().unwrap(), eq Some(EventId::new(i)));
+            assert_that!(listener.try_wait_one().unwrap().event_id(), eq Some(EventId::new(i)));
         }
 
         let result = notifier.notify_with_custom_event_id(EventId::new(EVENT_ID_MAX_VALUE + 1));
@@ -874,7 +977,7 @@ mod service_event {
         assert_that!(notifier.notify(), eq Ok(1));
 
         let mut received_events = 0;
-        for event in listener.try_wait_one().unwrap().iter() {
+        for event in listener.try_wait_one().unwrap().event_id().iter() {
             assert_that!(*event, eq event_id);
             received_events += 1;
         }
@@ -939,7 +1042,7 @@ mod service_event {
         let sut = node.service_builder(&service_name).event().open().unwrap();
         let listener = sut.listener_builder().create().unwrap();
         notifier.notify_with_custom_event_id(event_id).unwrap();
-        let notification = listener.try_wait_one().unwrap();
+        let notification = listener.try_wait_one().unwrap().event_id();
         assert_that!(notification, eq Some(event_id));
 
         drop(listener);
@@ -977,7 +1080,7 @@ mod service_event {
         let sut = node.service_builder(&service_name).event().open().unwrap();
         let notifier = sut.notifier_builder().create().unwrap();
         notifier.notify_with_custom_event_id(event_id).unwrap();
-        let notification = listener.try_wait_one().unwrap();
+        let notification = listener.try_wait_one().unwrap().event_id();
         assert_that!(notification, eq Some(event_id));
 
         drop(notifier);
@@ -1082,7 +1185,7 @@ mod service_event {
     fn try_wait_collects_all_notifications<Sut: Service>() {
         const NUMBER_OF_NOTIFICATIONS: usize = 8;
         wait_collects_all_notifications(NUMBER_OF_NOTIFICATIONS, |l: &Listener<Sut>, ids| {
-            while let Some(id) = l.try_wait_one().unwrap() {
+            while let Some(id) = l.try_wait_one().unwrap().event_id() {
                 assert_that!(ids.insert(id), eq true);
             }
         });
@@ -1364,12 +1467,12 @@ mod service_event {
         std::thread::sleep(TIMEOUT);
         let result = notifier_create.notify();
         assert_that!(result.err(), eq Some(NotifierNotifyError::MissedDeadline));
-        assert_that!(listener.try_wait_one().unwrap(), is_some);
+        assert_that!(listener.try_wait_one().unwrap().event_id(), is_some);
 
         std::thread::sleep(TIMEOUT);
         let result = notifier_open.notify();
         assert_that!(result.err(), eq Some(NotifierNotifyError::MissedDeadline));
-        assert_that!(listener.try_wait_one().unwrap(), is_some);
+        assert_that!(listener.try_wait_one().unwrap().event_id(), is_some);
     }
 
     #[test]
@@ -1395,11 +1498,81 @@ mod service_event {
 
         std::thread::sleep(TIMEOUT);
         assert_that!(notifier_create.notify(), is_ok);
-        assert_that!(listener.try_wait_one().unwrap(), is_some);
+        assert_that!(listener.try_wait_one().unwrap().event_id(), is_some);
 
         std::thread::sleep(TIMEOUT);
         assert_that!(notifier_open.notify(), is_ok);
-        assert_that!(listener.try_wait_one().unwrap(), is_some);
+        assert_that!(listener.try_wait_one().unwrap().event_id(), is_some);
+    }
+
+    #[test]
+    fn try_wait_one_distinguishes_no_events_from_all_notifiers_gone<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), eq ListenerReceiveResult::NoEvents);
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(3))
+            .unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), eq ListenerReceiveResult::Event(EventId::new(3)));
+        assert_that!(listener.try_wait_one().unwrap(), eq ListenerReceiveResult::NoEvents);
+
+        drop(notifier);
+        assert_that!(listener.try_wait_one().unwrap(), eq ListenerReceiveResult::AllNotifiersGone);
+    }
+
+    #[test]
+    fn try_wait_one_of_new_listener_without_notifier_returns_no_events<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), eq ListenerReceiveResult::NoEvents);
+        assert_that!(listener.try_wait_one().unwrap(), eq ListenerReceiveResult::NoEvents);
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        assert_that!(listener.try_wait_one().unwrap(), eq ListenerReceiveResult::NoEvents);
+
+        drop(notifier);
+        assert_that!(listener.try_wait_one().unwrap(), eq ListenerReceiveResult::AllNotifiersGone);
+    }
+
+    #[test]
+    fn try_wait_one_delivers_pending_events_before_all_notifiers_gone<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(7))
+            .unwrap();
+        drop(notifier);
+
+        assert_that!(listener.try_wait_one().unwrap(), eq ListenerReceiveResult::Event(EventId::new(7)));
+        assert_that!(listener.try_wait_one().unwrap(), eq ListenerReceiveResult::AllNotifiersGone);
     }
 
     #[test]
@@ -1524,6 +1697,116 @@ mod service_event {
         assert_that!(counter, eq 1);
     }
 
+    #[test]
+    fn port_factory_lists_info_of_all_listeners<S: Service>() {
+        const NUMBER_OF_LISTENERS: usize = 5;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_listeners(NUMBER_OF_LISTENERS)
+            .create()
+            .unwrap();
+
+        let mut listeners = vec![];
+        for _ in 0..NUMBER_OF_LISTENERS {
+            listeners.push(sut.listener_builder().create().unwrap());
+        }
+
+        let now = Time::now().unwrap();
+        let mut listener_infos = vec![];
+        sut.list_listeners(|info| {
+            listener_infos.push(info);
+            CallbackProgression::Continue
+        });
+
+        assert_that!(listener_infos, len NUMBER_OF_LISTENERS);
+        for info in &listener_infos {
+            assert_that!(info.node_id, eq * node.id());
+            assert_that!(info.pid, eq Process::from_self().id());
+            assert_that!(info.creation_time.as_duration(), le now.as_duration());
+        }
+        for listener in listeners {
+            assert_that!(listener_infos.iter().any(|info| info.listener_id == listener.id()), eq true);
+        }
+    }
+
+    #[test]
+    fn port_factory_lists_info_of_all_notifiers<S: Service>() {
+        const NUMBER_OF_NOTIFIERS: usize = 5;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(NUMBER_OF_NOTIFIERS)
+            .create()
+            .unwrap();
+
+        let mut notifiers = vec![];
+        for _ in 0..NUMBER_OF_NOTIFIERS {
+            notifiers.push(sut.notifier_builder().create().unwrap());
+        }
+
+        let now = Time::now().unwrap();
+        let mut notifier_infos = vec![];
+        sut.list_notifiers(|info| {
+            notifier_infos.push(info);
+            CallbackProgression::Continue
+        });
+
+        assert_that!(notifier_infos, len NUMBER_OF_NOTIFIERS);
+        for info in &notifier_infos {
+            assert_that!(info.node_id, eq * node.id());
+            assert_that!(info.pid, eq Process::from_self().id());
+            assert_that!(info.creation_time.as_duration(), le now.as_duration());
+        }
+        for notifier in notifiers {
+            assert_that!(notifier_infos.iter().any(|info| info.notifier_id == notifier.id()), eq true);
+        }
+    }
+
+    #[test]
+    fn port_factory_listing_of_listeners_and_notifiers_stops_on_request<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_listeners(2)
+            .max_notifiers(2)
+            .create()
+            .unwrap();
+
+        let _listeners = [
+            sut.listener_builder().create().unwrap(),
+            sut.listener_builder().create().unwrap(),
+        ];
+        let _notifiers = [
+            sut.notifier_builder().create().unwrap(),
+            sut.notifier_builder().create().unwrap(),
+        ];
+
+        let mut counter = 0;
+        sut.list_listeners(|_| {
+            counter += 1;
+            CallbackProgression::Stop
+        });
+        sut.list_notifiers(|_| {
+            counter += 1;
+            CallbackProgression::Stop
+        });
+
+        assert_that!(counter, eq 2);
+    }
+
     #[test]
     fn notifier_does_not_notify_listener_from_same_node_id_when_requested<Sut: Service>() {
         let service_name = generate_name();
@@ -1555,13 +1838,13 @@ mod service_event {
         assert_that!(notifier.__internal_notify(event_id, true), is_ok);
 
         let mut received_events = 0;
-        for _ in listener_1.try_wait_one().unwrap().iter() {
+        for _ in listener_1.try_wait_one().unwrap().event_id().iter() {
             received_events += 1;
         }
         assert_that!(received_events, eq 0);
 
         let mut received_events = 0;
-        for event in listener_2.try_wait_one().unwrap().iter() {
+        for event in listener_2.try_wait_one().unwrap().event_id().iter() {
             assert_that!(*event, eq event_id);
             received_events += 1;
         }
@@ -1580,3 +1863,41 @@ mod service_event {
     #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
     mod local_threadsafe {}
 }
+
+mod backoff_policy {
+    use core::time::Duration;
+
+    use iceoryx2::service::builder::BackoffPolicy;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn backoff_policy_wait_time_grows_up_to_max() {
+        let sut = BackoffPolicy::new(Duration::from_millis(1), Duration::from_millis(10), 3);
+
+        assert_that!(sut.wait_time(0), eq Duration::from_millis(1));
+        assert_that!(sut.wait_time(1), eq Duration::from_millis(3));
+        assert_that!(sut.wait_time(2), eq Duration::from_millis(9));
+        assert_that!(sut.wait_time(3), eq Duration::from_millis(10));
+        assert_that!(sut.wait_time(u32::MAX), eq Duration::from_millis(10));
+    }
+}
+
+mod event_open_or_create_error {
+    use iceoryx2::service::builder::event::{
+        EventCreateError, EventOpenError, EventOpenOrCreateError,
+    };
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn open_or_create_error_source_is_the_wrapped_error() {
+        use core::error::Error;
+
+        let sut = EventOpenOrCreateError::EventOpenError(EventOpenError::DoesNotExist);
+        assert_that!(sut.source().map(|e| e.to_string()), eq Some(EventOpenError::DoesNotExist.to_string()));
+
+        let sut = EventOpenOrCreateError::EventCreateError(EventCreateError::AlreadyExists);
+        assert_that!(sut.source().map(|e| e.to_string()), eq Some(EventCreateError::AlreadyExists.to_string()));
+
+        assert_that!(EventOpenOrCreateError::SystemInFlux.source(), is_none);
+    }
+}
@@ -66,7 +66,7 @@ fn notifying_events_concurrently_works() {
         let mut total_received_events = 0;
         let mut received_events = [0; NUMBER_OF_ITERATIONS];
         while total_received_events < number_of_notifier_threads * NUMBER_OF_ITERATIONS {
-            if let Ok(Some(event)) = listener.try_wait_one() {
+            if let Ok(Some(event)) = listener.try_wait_one().map(|r| r.event_id()) {
                 received_events[event.as_value()] += 1;
                 total_received_events += 1;
             } else if number_of_finished_notifier_threads.load(Ordering::Relaxed)
@@ -77,7 +77,7 @@ fn notifying_events_concurrently_works() {
         }
 
         // ensure all events are read
-        while let Ok(Some(event)) = listener.try_wait_one() {
+        while let Ok(Some(event)) = listener.try_wait_one().map(|r| r.event_id()) {
             received_events[event.as_value()] += 1;
             total_received_events += 1;
         }
@@ -122,7 +122,7 @@ fn listening_on_events_concurrently_works() {
                 let mut received_events = [0; NUMBER_OF_ITERATIONS];
                 barrier.wait();
                 loop {
-                    if let Ok(Some(event)) = listener.try_wait_one() {
+                    if let Ok(Some(event)) = listener.try_wait_one().map(|r| r.event_id()) {
                         received_events[event.as_value()] += 1;
                     } else if notification_finished.load(Ordering::Relaxed) {
                         break;
@@ -130,7 +130,7 @@ fn listening_on_events_concurrently_works() {
                 }
 
                 // ensure all events are received
-                while let Ok(Some(event)) = listener.try_wait_one() {
+                while let Ok(Some(event)) = listener.try_wait_one().map(|r| r.event_id()) {
                     received_events[event.as_value()] += 1;
                 }
 
@@ -0,0 +1,205 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_notifying_publish_subscribe {
+    use core::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::event::EventOpenError;
+    use iceoryx2::service::builder::notifying_publish_subscribe::{
+        NotifyingPublishSubscribeCreateError, NotifyingPublishSubscribeOpenError,
+    };
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
+    use iceoryx2::service::port_factory::PortFactory;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::event::Event;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_notifying_publish_subscribe_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn port_limits_are_applied_to_both_services<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&generate_name())
+            .notifying_publish_subscribe::<u64>()
+            .max_publishers(3)
+            .max_subscribers(5)
+            .max_nodes(7)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.publish_subscribe().static_config().max_publishers(), eq 3);
+        assert_that!(sut.publish_subscribe().static_config().max_subscribers(), eq 5);
+        assert_that!(sut.publish_subscribe().static_config().max_nodes(), eq 7);
+        assert_that!(sut.event().static_config().max_notifiers(), eq 3);
+        assert_that!(sut.event().static_config().max_listeners(), eq 5);
+        assert_that!(sut.event().static_config().max_nodes(), eq 7);
+    }
+
+    #[test]
+    fn create_fails_when_service_already_exists<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .notifying_publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let result = node
+            .service_builder(&service_name)
+            .notifying_publish_subscribe::<u64>()
+            .create();
+
+        assert_that!(result.err(), eq Some(NotifyingPublishSubscribeCreateError::PublishSubscribeCreateError(PublishSubscribeCreateError::AlreadyExists)));
+    }
+
+    #[test]
+    fn open_fails_when_event_service_is_missing<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+
+        let _pubsub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let result = node
+            .service_builder(&service_name)
+            .notifying_publish_subscribe::<u64>()
+            .open();
+
+        assert_that!(result.err(), eq Some(NotifyingPublishSubscribeOpenError::EventOpenError(EventOpenError::DoesNotExist)));
+    }
+
+    #[test]
+    fn send_notifies_subscriber<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&generate_name())
+            .notifying_publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(subscriber.try_wait().unwrap(), eq false);
+
+        let sample = publisher.publisher().loan_uninit().unwrap();
+        assert_that!(publisher.send(sample.write_payload(42)), eq Ok(1));
+        assert_that!(publisher.send_copy(43), eq Ok(1));
+
+        assert_that!(subscriber.timed_wait(TIMEOUT).unwrap(), eq true);
+        assert_that!(subscriber.try_wait().unwrap(), eq false);
+
+        let mut received = vec![];
+        while let Some(sample) = subscriber.receive().unwrap() {
+            received.push(*sample);
+        }
+        assert_that!(received, eq vec![42, 43]);
+    }
+
+    #[test]
+    fn custom_event_id_is_emitted<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let event_id = EventId::new(3);
+
+        let sut = node
+            .service_builder(&generate_name())
+            .notifying_publish_subscribe::<u64>()
+            .event_id(event_id)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+
+        assert_that!(sut.event_id(), eq event_id);
+        assert_that!(subscriber.listener().try_wait_one().unwrap().event_id(), eq Some(event_id));
+    }
+
+    #[test]
+    fn notifying_subscriber_can_be_attached_to_waitset<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&generate_name())
+            .notifying_publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset.attach_notification(&subscriber).unwrap();
+
+        publisher.send_copy(99).unwrap();
+
+        let mut received = vec![];
+        waitset
+            .wait_and_process_once(|attachment_id| {
+                if attachment_id.has_event_from(&guard) {
+                    subscriber.try_wait().unwrap();
+                    while let Some(sample) = subscriber.receive().unwrap() {
+                        received.push(*sample);
+                    }
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        assert_that!(received, eq vec![99]);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
+    mod ipc_threadsafe {}
+
+    #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
+    mod local_threadsafe {}
+}
@@ -0,0 +1,141 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_publish_subscribe_serde {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::serialize::cdr::Cdr;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+    struct Message {
+        text: String,
+        values: Vec<u64>,
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_publish_subscribe_serde_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn send_serialized_value_can_be_received<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe_serde::<Message>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let message = Message {
+            text: "all glory to the hypnotoad".to_string(),
+            values: vec![1, 2, 3, 4, 5],
+        };
+        assert_that!(publisher.send_serialized(&message), eq Ok(1));
+
+        assert_that!(subscriber.receive_deserialized().unwrap(), eq Some(message));
+        assert_that!(subscriber.receive_deserialized().unwrap(), eq None);
+    }
+
+    #[test]
+    fn open_with_different_format_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serde::<Message>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe_serde::<Message>()
+            .format::<Cdr>()
+            .open();
+
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn open_with_different_type_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serde::<Message>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe_serde::<String>()
+            .open();
+
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn values_larger_than_initial_max_slice_len_can_be_sent<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe_serde::<Message>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for n in [1, 100, 10000] {
+            let message = Message {
+                text: "x".repeat(n),
+                values: (0..n as u64).collect(),
+            };
+            assert_that!(publisher.send_serialized(&message), eq Ok(1));
+            assert_that!(subscriber.receive_deserialized().unwrap(), eq Some(message));
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
+    mod ipc_threadsafe {}
+
+    #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
+    mod local_threadsafe {}
+}
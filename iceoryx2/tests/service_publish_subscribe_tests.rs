@@ -22,10 +22,13 @@ mod service_publish_subscribe {
     use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::port::LoanError;
     use iceoryx2::prelude::{AllocationStrategy, *};
+    use iceoryx2::service::builder::publish_subscribe::EventIdScheme;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
     use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
     use iceoryx2::service::messaging_pattern::MessagingPattern;
+    use iceoryx2::service::port_factory::any::AnyPortFactory;
+    use iceoryx2::service::static_config::abi_tag::{AbiNameString, Endianness};
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::service::{Service, ServiceDetails};
     use iceoryx2::testing;
@@ -43,6 +46,12 @@ mod service_publish_subscribe {
         value: [u64; 1024],
     }
 
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    struct OtherUserHeader {
+        value: [u64; 1024],
+    }
+
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
             "service_tests_{}",
@@ -242,6 +251,37 @@ mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[test]
+    fn payload_type_compatibility_can_be_checked<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.is_payload_type_compatible::<u64>(), eq true);
+        assert_that!(sut.is_payload_type_compatible::<i64>(), eq false);
+        assert_that!(sut.is_payload_type_compatible::<u32>(), eq false);
+    }
+
+    #[test]
+    fn payload_type_compatibility_of_slice_checks_element_type<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.is_payload_type_compatible::<u64>(), eq true);
+        assert_that!(sut.is_payload_type_compatible::<i64>(), eq false);
+    }
+
     #[test]
     fn open_fails_when_service_has_wrong_slice_base_type<Sut: Service>() {
         let service_name = generate_name();
@@ -299,6 +339,199 @@ mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[test]
+    fn open_with_same_type_hash_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .type_hash([7; 32])
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().type_hash(), eq Some(&[7; 32]));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .type_hash([7; 32])
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_type_hash_differs<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .type_hash([7; 32])
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .type_hash([8; 32])
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut3.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn open_with_skipped_type_hash_check_ignores_type_hash<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .type_hash([7; 32])
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .skip_type_hash_check()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .type_hash([8; 32])
+            .skip_type_hash_check()
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn type_erased_service_opened_with_open_any_receives_from_typed_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let typed_service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<u32>()
+            .create()
+            .unwrap();
+        let publisher = typed_service.publisher_builder().create().unwrap();
+
+        let static_config = Sut::static_config(&service_name, &config).unwrap();
+        let any_service = Sut::open_any(&node, &static_config).unwrap();
+        let AnyPortFactory::PublishSubscribe(any_service) = any_service else {
+            panic!("open_any must return a publish-subscribe port factory");
+        };
+        let subscriber = any_service.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        *sample.user_header_mut() = 42;
+        *sample.payload_mut() = 0x0123456789abcdef;
+        sample.send().unwrap();
+
+        let sample = unsafe { subscriber.receive_custom_payload() }.unwrap();
+        assert_that!(sample, is_some);
+        let sample = sample.unwrap();
+        let payload = unsafe {
+            core::slice::from_raw_parts(
+                sample.payload().as_ptr().cast::<u8>(),
+                core::mem::size_of::<u64>(),
+            )
+        };
+        assert_that!(payload, eq 0x0123456789abcdef_u64.to_ne_bytes());
+    }
+
+    #[test]
+    fn created_service_stores_abi_tag_of_platform<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let abi_tag = sut.static_config().abi_tag();
+        assert_that!(abi_tag.endianness(), eq Endianness::native());
+        assert_that!(abi_tag.pointer_width(), eq usize::BITS);
+        assert_that!(abi_tag.abi_name(), is_none);
+
+        let abi_name = AbiNameString::try_from("rustc-1.85").unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .abi_name(abi_name)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().abi_tag().abi_name(), eq Some(&abi_name));
+        assert_that!(format!("{}", sut.static_config().abi_tag()).ends_with(", rustc-1.85"), eq true);
+    }
+
+    #[test]
+    fn open_fails_when_abi_name_differs<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .abi_name(AbiNameString::try_from("abi-a").unwrap())
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .abi_name(AbiNameString::try_from("abi-b").unwrap())
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleAbi));
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .abi_name(AbiNameString::try_from("abi-a").unwrap())
+            .open();
+        assert_that!(sut3, is_ok);
+
+        let sut4 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut4, is_ok);
+    }
+
+    #[test]
+    fn open_with_allowed_abi_mismatch_ignores_abi_tag<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .abi_name(AbiNameString::try_from("abi-a").unwrap())
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .abi_name(AbiNameString::try_from("abi-b").unwrap())
+            .allow_abi_mismatch()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
     #[test]
     fn open_fails_when_service_does_not_satisfy_max_nodes_requirement<Sut: Service>() {
         let service_name = generate_name();
@@ -1098,6 +1331,36 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn uninit_samples_are_aligned_to_page_size<Sut: Service>() {
+        const BUFFER_SIZE: usize = 4;
+        const ALIGNMENT: usize = 4096;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8; 64]>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let mut samples = vec![];
+        for _ in 0..BUFFER_SIZE {
+            let sample = publisher.loan_uninit().unwrap();
+            assert_that!((sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
+            samples.push(sample);
+        }
+    }
+
     #[test]
     fn publisher_reclaims_all_samples_after_disconnect<Sut: Service>() {
         let service_name = generate_name();
@@ -2176,7 +2439,7 @@ mod service_publish_subscribe {
 
             let mut service_list = vec![];
             Sut::list(&config, |s| {
-                service_list.push(s);
+                service_list.push(s.unwrap());
                 CallbackProgression::Continue
             })
             .unwrap();
@@ -2191,7 +2454,7 @@ mod service_publish_subscribe {
 
             let mut service_list = vec![];
             Sut::list(&config, |s| {
-                service_list.push(s);
+                service_list.push(s.unwrap());
                 CallbackProgression::Continue
             })
             .unwrap();
@@ -2451,6 +2714,48 @@ mod service_publish_subscribe {
         assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService);
     }
 
+    #[test]
+    fn subscribers_with_different_buffer_sizes_receive_independently<Sut: Service>() {
+        const SMALL_BUFFER_SIZE: usize = 1;
+        const LARGE_BUFFER_SIZE: usize = 8;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(LARGE_BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let small_subscriber = sut
+            .subscriber_builder()
+            .buffer_size(SMALL_BUFFER_SIZE)
+            .create()
+            .unwrap();
+        let large_subscriber = sut
+            .subscriber_builder()
+            .buffer_size(LARGE_BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        for n in 0..LARGE_BUFFER_SIZE {
+            assert_that!(publisher.send_copy(n), eq Ok(2));
+        }
+
+        let sample = small_subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq LARGE_BUFFER_SIZE - 1);
+        assert_that!(small_subscriber.receive().unwrap(), is_none);
+
+        for n in 0..LARGE_BUFFER_SIZE {
+            let sample = large_subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+        }
+        assert_that!(large_subscriber.receive().unwrap(), is_none);
+    }
+
     #[test]
     fn subscriber_buffer_size_is_at_least_one<Sut: Service>() {
         let service_name = generate_name();
@@ -2608,6 +2913,129 @@ mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[test]
+    fn opening_service_without_user_header_with_user_header_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<SomeUserHeader>()
+            .open();
+
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<()>()
+            .open();
+
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn user_headers_with_same_layout_but_different_type_are_incompatible<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<SomeUserHeader>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<OtherUserHeader>()
+            .open();
+
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn connection_changes_are_notified_on_event_service<Sut: Service>() {
+        let service_name = generate_name();
+        let event_service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let event_ids = EventIdScheme::default();
+
+        let event_service = node
+            .service_builder(&event_service_name)
+            .event()
+            .create()
+            .unwrap();
+        let listener = event_service.listener_builder().create().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .notify_connection_changes(&event_service_name, event_ids)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let event_id = listener.try_wait_one().unwrap().event_id();
+        assert_that!(event_id, eq Some(event_ids.publisher_connected));
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let event_id = listener.try_wait_one().unwrap().event_id();
+        assert_that!(event_id, eq Some(event_ids.subscriber_connected));
+
+        drop(subscriber);
+        let event_id = listener.try_wait_one().unwrap().event_id();
+        assert_that!(event_id, eq Some(event_ids.subscriber_disconnected));
+
+        drop(publisher);
+        let event_id = listener.try_wait_one().unwrap().event_id();
+        assert_that!(event_id, eq Some(event_ids.publisher_disconnected));
+
+        let event_id = listener.try_wait_one().unwrap().event_id();
+        assert_that!(event_id, is_none);
+    }
+
+    #[test]
+    fn connection_change_event_service_is_released_with_last_port<Sut: Service>() {
+        let service_name = generate_name();
+        let event_service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .notify_connection_changes(&event_service_name, EventIdScheme::default())
+            .create()
+            .unwrap();
+
+        let does_event_service_exist =
+            || Sut::does_exist(&event_service_name, &config, MessagingPattern::Event).unwrap();
+
+        assert_that!(does_event_service_exist(), eq false);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(does_event_service_exist(), eq true);
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        drop(publisher);
+        assert_that!(does_event_service_exist(), eq true);
+
+        drop(subscriber);
+        assert_that!(does_event_service_exist(), eq false);
+    }
+
     #[test]
     fn create_with_custom_payload_type_works<Sut: Service>() {
         let service_name = generate_name();
@@ -2739,6 +3167,8 @@ mod service_publish_subscribe {
                                   "PublishSubscribeOpenError::InternalFailure");
         assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleTypes), eq
                                   "PublishSubscribeOpenError::IncompatibleTypes");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleAbi), eq
+                                  "PublishSubscribeOpenError::IncompatibleAbi");
         assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleMessagingPattern), eq
                                   "PublishSubscribeOpenError::IncompatibleMessagingPattern");
         assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleAttributes), eq
@@ -3409,6 +3839,83 @@ mod service_publish_subscribe {
         assert_that!(counter, eq 1);
     }
 
+    #[test]
+    fn port_details_contain_configuration_of_ports<S: Service>() {
+        const MAX_LOANED_SAMPLES: usize = 7;
+        const BUFFER_SIZE: usize = 5;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .create()
+            .unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let mut publisher_details = vec![];
+        sut.dynamic_config().list_publishers(|details| {
+            publisher_details.push(*details);
+            CallbackProgression::Continue
+        });
+
+        assert_that!(publisher_details, len 1);
+        assert_that!(publisher_details[0].publisher_id, eq publisher.id());
+        assert_that!(publisher_details[0].node_id, eq * node.id());
+        assert_that!(publisher_details[0].node_id.pid(), eq node.id().pid());
+        assert_that!(publisher_details[0].max_loaned_samples, eq MAX_LOANED_SAMPLES);
+
+        let mut subscriber_details = vec![];
+        sut.dynamic_config().list_subscribers(|details| {
+            subscriber_details.push(*details);
+            CallbackProgression::Continue
+        });
+
+        assert_that!(subscriber_details, len 1);
+        assert_that!(subscriber_details[0].subscriber_id, eq subscriber.id());
+        assert_that!(subscriber_details[0].node_id, eq * node.id());
+        assert_that!(subscriber_details[0].buffer_size, eq BUFFER_SIZE);
+    }
+
+    #[test]
+    fn service_details_contain_publishers_and_subscribers<S: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let details = S::details(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap()
+            .dynamic_details
+            .unwrap();
+
+        assert_that!(details.publishers, len 1);
+        assert_that!(details.publishers[0].publisher_id, eq publisher.id());
+        assert_that!(details.subscribers, len 1);
+        assert_that!(details.subscribers[0].subscriber_id, eq subscriber.id());
+    }
+
     #[test]
     fn receive_does_not_return_error_when_pub_goes_out_of_scope_after_reallocation<S: Service>() {
         const SLICE_MAX_LEN: usize = 1;
@@ -3461,3 +3968,30 @@ mod service_publish_subscribe {
     #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
     mod local_threadsafe {}
 }
+
+mod publish_subscribe_open_or_create_error {
+    use iceoryx2::service::builder::publish_subscribe::{
+        PublishSubscribeCreateError, PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
+    };
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn open_or_create_error_source_is_the_wrapped_error() {
+        use core::error::Error;
+
+        let sut = PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(
+            PublishSubscribeOpenError::DoesNotExist,
+        );
+        assert_that!(sut.source().map(|e| e.to_string()), eq Some(PublishSubscribeOpenError::DoesNotExist.to_string()));
+
+        let sut = PublishSubscribeOpenOrCreateError::PublishSubscribeCreateError(
+            PublishSubscribeCreateError::AlreadyExists,
+        );
+        assert_that!(sut.source().map(|e| e.to_string()), eq Some(PublishSubscribeCreateError::AlreadyExists.to_string()));
+
+        assert_that!(
+            PublishSubscribeOpenOrCreateError::SystemInFlux.source(),
+            is_none
+        );
+    }
+}
@@ -874,6 +874,7 @@ mod service_request_response {
         }
 
         Sut::list(&config, |service| {
+            let service = service.unwrap();
             assert_that!(service_names, contains * service.static_details.name());
             service_names.retain(|v| v != service.static_details.name());
             CallbackProgression::Continue
@@ -126,6 +126,41 @@ mod service_request_response {
         }
     }
 
+    #[test]
+    fn settings_can_be_modified_via_custom_config<Sut: Service>() {
+        let service_name = testing::generate_service_name();
+        let mut config = testing::generate_isolated_config();
+        config.defaults.request_response.max_nodes = 13;
+        config.defaults.request_response.max_servers = 9;
+        config.defaults.request_response.max_clients = 10;
+        config
+            .defaults
+            .request_response
+            .max_active_requests_per_client = 3;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(4)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().max_nodes(), eq 13);
+        assert_that!(sut.static_config().max_servers(), eq 9);
+        assert_that!(sut.static_config().max_clients(), eq 4);
+        assert_that!(sut.static_config().max_active_requests_per_client(), eq 3);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open()
+            .unwrap();
+        assert_that!(sut2.static_config().max_nodes(), eq 13);
+        assert_that!(sut2.static_config().max_servers(), eq 9);
+        assert_that!(sut2.static_config().max_clients(), eq 4);
+        assert_that!(sut2.static_config().max_active_requests_per_client(), eq 3);
+    }
+
     #[test]
     fn response_buffer_size_of_client_is_set_correctly<Sut: Service>() {
         let test_args = Args {
@@ -13,6 +13,7 @@
 #[generic_tests::define]
 mod service {
     use core::marker::PhantomData;
+    use core::str::FromStr;
     use core::sync::atomic::{AtomicU64, Ordering};
     use core::time::Duration;
     use std::sync::Barrier;
@@ -28,14 +29,22 @@ mod service {
         RequestResponseCreateError, RequestResponseOpenError,
     };
     use iceoryx2::service::messaging_pattern::MessagingPattern;
+    use iceoryx2::service::port_factory::any::{AnyPortFactory, AnyPortFactoryOpenError};
     use iceoryx2::service::port_factory::{blackboard, event, publish_subscribe, request_response};
-    use iceoryx2::service::{ServiceDetailsError, ServiceListError};
+    use iceoryx2::service::service_dyn::BoxedService;
+    use iceoryx2::service::service_id::ServiceId;
+    use iceoryx2::service::{ServiceDetailsError, ServiceInspectError, ServiceListError};
     use iceoryx2::testing::*;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::named_concept::{
+        NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+    };
+    use iceoryx2_cal::serialize::Serialize;
+    use iceoryx2_cal::static_storage::{StaticStorage, StaticStorageBuilder};
 
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
@@ -395,10 +404,178 @@ mod service {
 
         const EVENT_ID: EventId = EventId::new(31);
         sut_notifier.notify_with_custom_event_id(EVENT_ID).unwrap();
-        let received_event = sut_listener.try_wait_one().unwrap();
+        let received_event = sut_listener.try_wait_one().unwrap().event_id();
         assert_that!(received_event, eq Some(EVENT_ID));
     }
 
+    #[test]
+    fn port_factory_returns_messaging_pattern_of_service<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        assert_that!(sut.messaging_pattern(), eq Factory::messaging_pattern());
+
+        let sut = test
+            .open(&node, &service_name, &AttributeVerifier::new())
+            .unwrap();
+        assert_that!(sut.messaging_pattern(), eq Factory::messaging_pattern());
+    }
+
+    #[test]
+    fn open_any_opens_services_of_supported_messaging_patterns<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        let static_config = Sut::static_config(&service_name, &config).unwrap();
+
+        let result = Sut::open_any(&node, &static_config);
+        match Factory::messaging_pattern() {
+            MessagingPattern::Event | MessagingPattern::PublishSubscribe => {
+                assert_that!(result, is_ok);
+                let any = result.unwrap();
+                assert_that!(any.name(), eq & service_name);
+                assert_that!(any.service_id(), eq sut.service_id());
+                assert_that!(any.messaging_pattern(), eq Factory::messaging_pattern());
+                match any {
+                    AnyPortFactory::Event(_) => {
+                        assert_that!(Factory::messaging_pattern(), eq MessagingPattern::Event)
+                    }
+                    AnyPortFactory::PublishSubscribe(_) => {
+                        assert_that!(
+                            Factory::messaging_pattern(),
+                            eq MessagingPattern::PublishSubscribe
+                        )
+                    }
+                }
+            }
+            _ => {
+                assert_that!(
+                    result.err(),
+                    eq Some(AnyPortFactoryOpenError::UnsupportedMessagingPattern)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn open_any_fails_when_service_was_removed<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        let static_config = Sut::static_config(&service_name, &config).unwrap();
+        drop(sut);
+
+        assert_that!(Sut::open_any(&node, &static_config), is_err);
+    }
+
+    #[test]
+    fn service_id_can_be_parsed_from_its_string_representation<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        let id = sut.service_id();
+
+        let parsed_id = ServiceId::from_str(&id.to_string());
+        assert_that!(parsed_id, is_ok);
+        assert_that!(&parsed_id.unwrap(), eq id);
+
+        assert_that!(ServiceId::from_str(""), is_err);
+    }
+
+    #[test]
+    fn static_config_can_be_acquired_by_service_id<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        let static_config = Sut::static_config_by_id(sut.service_id(), &config);
+        assert_that!(static_config, is_ok);
+        let static_config = static_config.unwrap();
+        assert_that!(static_config.name(), eq & service_name);
+        assert_that!(static_config.service_id(), eq sut.service_id());
+        assert_that!(MessagingPattern::from(static_config.messaging_pattern()), eq Factory::messaging_pattern());
+    }
+
+    #[test]
+    fn service_builder_by_id_succeeds_for_existing_service<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        let id = ServiceId::from_str(&sut.service_id().to_string()).unwrap();
+
+        assert_that!(node.service_builder_by_id(&id), is_ok);
+
+        let name = Sut::static_config_by_id(&id, &config)
+            .unwrap()
+            .name()
+            .clone();
+        let sut2 = test.open(&node, &name, &AttributeVerifier::new()).unwrap();
+        assert_that!(sut2.service_id(), eq & id);
+    }
+
+    #[test]
+    fn service_builder_by_id_fails_when_service_does_not_exist<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+        let id = sut.service_id().clone();
+        drop(sut);
+
+        let result = node.service_builder_by_id(&id);
+        assert_that!(result.err(), eq Some(ServiceInspectError::DoesNotExist));
+        assert_that!(
+            Sut::static_config_by_id(&id, &config).err(),
+            eq Some(ServiceInspectError::DoesNotExist)
+        );
+    }
+
     #[test]
     fn concurrent_creating_services_with_unique_names_is_successful<
         Sut: Service,
@@ -831,6 +1008,9 @@ mod service {
 
         assert_that!(format!("{}", ServiceDetailsError::FailedToAcquireNodeState), eq
     "ServiceDetailsError::FailedToAcquireNodeState");
+
+        assert_that!(format!("{}", ServiceDetailsError::InsufficientPermissions), eq
+    "ServiceDetailsError::InsufficientPermissions");
     }
 
     #[test]
@@ -840,6 +1020,60 @@ mod service {
 
         assert_that!(format!("{}", ServiceListError::InternalError), eq
     "ServiceListError::InternalError");
+
+        assert_that!(format!("{}", ServiceListError::CorruptedService), eq
+    "ServiceListError::CorruptedService");
+
+        assert_that!(format!("{}", ServiceListError::VersionMismatch), eq
+    "ServiceListError::VersionMismatch");
+    }
+
+    #[test]
+    fn inspect_error_display_works<Sut: Service, Factory: SutFactory<Sut>>() {
+        assert_that!(format!("{}", ServiceInspectError::DoesNotExist), eq
+    "ServiceInspectError::DoesNotExist");
+
+        assert_that!(format!("{}", ServiceInspectError::InsufficientPermissions), eq
+    "ServiceInspectError::InsufficientPermissions");
+
+        assert_that!(format!("{}", ServiceInspectError::CorruptedService), eq
+    "ServiceInspectError::CorruptedService");
+
+        assert_that!(format!("{}", ServiceInspectError::VersionMismatch), eq
+    "ServiceInspectError::VersionMismatch");
+
+        assert_that!(format!("{}", ServiceInspectError::InternalError), eq
+    "ServiceInspectError::InternalError");
+    }
+
+    #[test]
+    fn static_config_of_non_existing_service_fails<Sut: Service, Factory: SutFactory<Sut>>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+
+        let sut = Sut::static_config(&service_name, &config);
+        assert_that!(sut.err(), eq Some(ServiceInspectError::DoesNotExist));
+    }
+
+    #[test]
+    fn static_config_can_be_acquired_without_opening_ports<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        let sut = Sut::static_config(&service_name, &config).unwrap();
+        assert_that!(sut.service_id(), eq service.service_id());
+        assert_that!(sut.name(), eq & service_name);
+
+        drop(service);
+        assert_that!(Sut::static_config(&service_name, &config).err(), eq Some(ServiceInspectError::DoesNotExist));
     }
 
     #[test]
@@ -865,7 +1099,7 @@ mod service {
 
         let mut listed_services = vec![];
         let result = Sut::list(&config, |service| {
-            listed_services.push(service.static_details.service_id().clone());
+            listed_services.push(service.unwrap().static_details.service_id().clone());
             CallbackProgression::Continue
         });
         assert_that!(result, is_ok);
@@ -875,6 +1109,141 @@ mod service {
         }
     }
 
+    #[test]
+    fn boxed_service_dispatches_to_the_service_type<
+        Sut: Service + 'static,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let sut = BoxedService::new::<Sut>();
+
+        assert_that!(sut.type_name(), eq core::any::type_name::<Sut>());
+        assert_that!(
+            sut.does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(false));
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        assert_that!(
+            sut.does_exist(&service_name, &config, Factory::messaging_pattern()), eq Ok(true));
+
+        let details = sut
+            .details(&service_name, &config, Factory::messaging_pattern())
+            .unwrap();
+        assert_that!(details, is_some);
+        let details = details.unwrap();
+        assert_that!(details.service_id(), eq service.service_id());
+
+        let mut listed_services = vec![];
+        let result = sut.list(&config, &mut |service| {
+            listed_services.push(service.unwrap().service_id().clone());
+            CallbackProgression::Continue
+        });
+        assert_that!(result, is_ok);
+        assert_that!(listed_services, contains service.service_id().clone());
+    }
+
+    #[test]
+    fn list_services_reports_corrupted_services<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        let mut path_hint = config.global.root_path().clone();
+        path_hint
+            .add_path_entry(&config.global.service.directory)
+            .unwrap();
+        let static_storage_config =
+            <Sut::StaticStorage as NamedConceptMgmt>::Configuration::default()
+                .prefix(&config.global.prefix)
+                .suffix(&config.global.service.static_config_storage_suffix)
+                .path_hint(&path_hint);
+        let _corrupted_service =
+            <<Sut::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+                Sut::StaticStorage,
+            >>::new(&FileName::new(b"corrupted_service").unwrap())
+            .config(&static_storage_config)
+            .create(b"this is not a static service config")
+            .unwrap();
+
+        let mut listed_services = vec![];
+        let mut listed_errors = vec![];
+        let result = Sut::list(&config, |service| {
+            match service {
+                Ok(service) => listed_services.push(service.static_details.service_id().clone()),
+                Err(e) => listed_errors.push(e),
+            }
+            CallbackProgression::Continue
+        });
+
+        assert_that!(result, is_ok);
+        assert_that!(listed_services, eq vec![sut.service_id().clone()]);
+        assert_that!(listed_errors, eq vec![ServiceListError::CorruptedService]);
+    }
+
+    #[test]
+    fn list_services_reports_truncated_static_configs_as_corrupted<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_name();
+        let sut = test
+            .create(&node, &service_name, &AttributeSpecifier::new())
+            .unwrap();
+
+        let mut static_details = None;
+        Sut::list(&config, |service| {
+            static_details = Some(service.unwrap().static_details);
+            CallbackProgression::Stop
+        })
+        .unwrap();
+        let mut serialized_config =
+            Sut::ConfigSerializer::serialize(&static_details.unwrap()).unwrap();
+        serialized_config.truncate(serialized_config.len() / 2);
+
+        let mut path_hint = config.global.root_path().clone();
+        path_hint
+            .add_path_entry(&config.global.service.directory)
+            .unwrap();
+        let static_storage_config =
+            <Sut::StaticStorage as NamedConceptMgmt>::Configuration::default()
+                .prefix(&config.global.prefix)
+                .suffix(&config.global.service.static_config_storage_suffix)
+                .path_hint(&path_hint);
+        let _truncated_service =
+            <<Sut::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+                Sut::StaticStorage,
+            >>::new(&FileName::new(b"truncated_service").unwrap())
+            .config(&static_storage_config)
+            .create(&serialized_config)
+            .unwrap();
+
+        let mut listed_services = vec![];
+        let mut listed_errors = vec![];
+        let result = Sut::list(&config, |service| {
+            match service {
+                Ok(service) => listed_services.push(service.static_details.service_id().clone()),
+                Err(e) => listed_errors.push(e),
+            }
+            CallbackProgression::Continue
+        });
+
+        assert_that!(result, is_ok);
+        assert_that!(listed_services, eq vec![sut.service_id().clone()]);
+        assert_that!(listed_errors, eq vec![ServiceListError::CorruptedService]);
+    }
+
     #[test]
     fn list_services_stops_when_callback_progression_states_stop<
         Sut: Service,
@@ -928,7 +1297,7 @@ mod service {
 
                         let mut found_me = false;
                         let result = Sut::list(&config, |s| {
-                            if sut.service_id() == s.static_details.service_id() {
+                            if sut.service_id() == s.unwrap().static_details.service_id() {
                                 found_me = true;
                             }
                             CallbackProgression::Continue
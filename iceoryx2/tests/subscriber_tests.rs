@@ -12,10 +12,18 @@
 
 #[generic_tests::define]
 mod subscriber {
+    use core::sync::atomic::Ordering;
+    use core::time::Duration;
+    use iceoryx2::port::sample_filter::{SampleFilter, SampleFilterCreateError};
+    use iceoryx2::port::subscriber::Subscriber;
+    use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::port::ReceiveError;
+    use iceoryx2::prelude::{CallbackProgression, PortFactory};
     use iceoryx2::service::builder::CustomPayloadMarker;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use std::collections::HashSet;
+    use std::sync::Mutex;
+    use std::time::Instant;
 
     use iceoryx2::{
         node::NodeBuilder,
@@ -23,8 +31,14 @@ mod subscriber {
         service::{service_name::ServiceName, Service},
         testing,
     };
+    use iceoryx2_bb_posix::barrier::*;
+    use iceoryx2_bb_posix::clock::Time;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
-    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_bb_testing::{assert_err, assert_ok, assert_that};
+    use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
 
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
@@ -73,30 +87,1269 @@ mod subscriber {
     }
 
     #[test]
-    #[should_panic]
-    #[cfg(debug_assertions)]
-    fn subscriber_with_custom_payload_details_panics_when_calling_non_custom_receive<
-        Sut: Service,
-    >() {
-        const TYPE_SIZE_OVERRIDE: usize = 128;
+    fn lost_samples_are_zero_without_overflow<Sut: Service>() {
         let service_name = generate_name();
         let config = testing::generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let mut type_detail = TypeDetail::new::<u8>(TypeVariant::FixedSize);
-        testing::type_detail_set_size(&mut type_detail, TYPE_SIZE_OVERRIDE);
 
-        let service = unsafe {
-            node.service_builder(&service_name)
-                .publish_subscribe::<[CustomPayloadMarker]>()
-                .__internal_set_payload_type_details(&type_detail)
-                .create()
-                .unwrap()
-        };
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
 
+        let publisher = service.publisher_builder().create().unwrap();
         let sut = service.subscriber_builder().create().unwrap();
 
-        // panics here
-        let _sample = sut.receive();
+        for n in 0..5 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        while sut.receive().unwrap().is_some() {}
+
+        assert_that!(sut.lost_samples(), eq 0);
+    }
+
+    #[test]
+    fn lost_samples_are_counted_on_buffer_overflow<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        const NUMBER_OF_SAMPLES: u64 = 5;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(0).unwrap();
+        assert_that!(sut.receive().unwrap(), is_some);
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(n).unwrap();
+        }
+
+        while sut.receive().unwrap().is_some() {}
+
+        assert_that!(sut.lost_samples(), eq NUMBER_OF_SAMPLES - BUFFER_SIZE as u64);
+    }
+
+    #[test]
+    fn lost_samples_are_tracked_per_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        for n in 0..3 {
+            publisher_1.send_copy(n).unwrap();
+            publisher_2.send_copy(n).unwrap();
+        }
+
+        while sut.receive().unwrap().is_some() {}
+
+        assert_that!(sut.lost_samples(), eq 0);
+    }
+
+    #[test]
+    fn timed_receive_returns_none_on_timeout<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let _publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let start = Instant::now();
+        let sample = sut.timed_receive(TIMEOUT).unwrap();
+
+        assert_that!(sample, is_none);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+    }
+
+    #[test]
+    fn timed_receive_returns_available_sample_immediately<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+
+        let start = Instant::now();
+        let sample = sut.timed_receive(TIMEOUT * 100).unwrap();
+
+        assert_that!(start.elapsed(), lt TIMEOUT * 100);
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 1234);
+    }
+
+    #[test]
+    fn peek_does_not_remove_sample<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.peek().unwrap(), is_none);
+
+        publisher.send_copy(12).unwrap();
+        publisher.send_copy(34).unwrap();
+
+        let sample = sut.peek().unwrap().unwrap();
+        assert_that!(*sample, eq 12);
+        assert_that!(sample.origin(), eq publisher.id());
+        assert_that!(*sut.peek().unwrap().unwrap(), eq 12);
+        assert_that!(sut.has_samples().unwrap(), eq true);
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 12);
+        assert_that!(*sut.peek().unwrap().unwrap(), eq 34);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 34);
+        assert_that!(sut.peek().unwrap(), is_none);
+        assert_that!(sut.receive().unwrap(), is_none);
+        assert_that!(sut.has_samples().unwrap(), eq false);
+    }
+
+    #[test]
+    fn len_counts_buffered_samples_of_all_publishers<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let mut sut = service
+            .subscriber_builder()
+            .buffer_size(4)
+            .create()
+            .unwrap();
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+
+        assert_that!(sut.len(), eq 0);
+        assert_that!(sut.is_empty(), eq true);
+
+        publisher_1.send_copy(1).unwrap();
+        publisher_1.send_copy(2).unwrap();
+        publisher_2.send_copy(3).unwrap();
+
+        assert_that!(sut.len(), eq 3);
+        assert_that!(sut.is_empty(), eq false);
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sut.len(), eq 2);
+        drop(sample);
+
+        assert_that!(sut.peek().unwrap(), is_some);
+        assert_that!(sut.len(), eq 2);
+
+        while sut.receive().unwrap().is_some() {}
+        assert_that!(sut.len(), eq 0);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn receive_batch_visits_publishers_in_round_robin_order<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(4)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(4)
+            .create()
+            .unwrap();
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+
+        assert_that!(sut.receive_batch(4).unwrap(), len 0);
+
+        for n in 0..3 {
+            publisher_1.send_copy(n).unwrap();
+            publisher_2.send_copy(10 + n).unwrap();
+        }
+
+        let samples = sut.receive_batch(4).unwrap();
+        let payloads: Vec<u64> = samples.iter().map(|s| **s).collect();
+        assert_that!(payloads, len 4);
+        assert_that!(payloads, contains 0);
+        assert_that!(payloads, contains 1);
+        assert_that!(payloads, contains 10);
+        assert_that!(payloads, contains 11);
+        drop(samples);
+
+        let samples = sut.receive_batch(4).unwrap();
+        assert_that!(samples, len 2);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn receive_batch_fails_when_batch_exceeds_max_borrowed_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(3)
+            .subscriber_max_borrowed_samples(2)
+            .create()
+            .unwrap();
+
+        let mut sut = service
+            .subscriber_builder()
+            .buffer_size(3)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+        publisher.send_copy(3).unwrap();
+
+        assert_err!(sut.receive_batch(3), eq ReceiveError::ExceedsMaxBorrows);
+
+        assert_that!(*sut.peek().unwrap().unwrap(), eq 1);
+        let samples = assert_ok!(sut.receive_batch(2));
+        assert_that!(*samples[0], eq 1);
+        assert_that!(*samples[1], eq 2);
+
+        assert_err!(sut.receive_batch(1), eq ReceiveError::ExceedsMaxBorrows);
+        drop(samples);
+
+        let samples = assert_ok!(sut.receive_batch(2));
+        assert_that!(samples, len 1);
+        assert_that!(*samples[0], eq 3);
+    }
+
+    #[test]
+    fn iter_receives_samples_until_subscriber_is_empty<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(5)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        for i in 0..5 {
+            publisher.send_copy(i).unwrap();
+        }
+
+        let mut received = vec![];
+        for sample in &sut {
+            received.push(*sample);
+        }
+
+        assert_that!(received, eq vec![0, 1, 2, 3, 4]);
+        assert_that!(sut.iter().next(), is_none);
+        assert_that!(sut.try_iter().next(), is_none);
+    }
+
+    #[test]
+    fn iter_stops_when_max_borrowed_samples_are_exceeded<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(3)
+            .subscriber_max_borrowed_samples(2)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(3)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+        publisher.send_copy(3).unwrap();
+
+        let samples: Vec<_> = sut.iter().collect();
+        assert_that!(samples, len 2);
+        assert_that!(*samples[0], eq 1);
+        assert_that!(*samples[1], eq 2);
+        assert_that!(sut.try_iter().next(), is_none);
+        drop(samples);
+
+        let samples = sut.try_iter().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_that!(samples, len 1);
+        assert_that!(*samples[0], eq 3);
+    }
+
+    #[test]
+    fn iter_works_with_slices<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .create()
+            .unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        for len in 1..=2 {
+            let sample = publisher.loan_slice_uninit(len).unwrap();
+            sample.write_from_fn(|i| i as u64).send().unwrap();
+        }
+
+        let received: Vec<Vec<u64>> = sut.iter().map(|s| s.payload().to_vec()).collect();
+        assert_that!(received, eq vec![vec![0], vec![0, 1]]);
+    }
+
+    #[test]
+    fn peek_works_with_slices<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .create()
+            .unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(3).unwrap();
+        sample.write_from_slice(&[1, 2, 3]).send().unwrap();
+
+        let peeked_sample = sut.peek().unwrap().unwrap();
+        assert_that!(peeked_sample.payload(), eq [1, 2, 3]);
+
+        let received_sample = sut.receive().unwrap().unwrap();
+        assert_that!(received_sample.payload(), eq [1, 2, 3]);
+        assert_that!(sut.peek().unwrap(), is_none);
+    }
+
+    #[test]
+    fn timed_receive_returns_peeked_sample<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(5678).unwrap();
+        assert_that!(*sut.peek().unwrap().unwrap(), eq 5678);
+
+        let sample = sut.timed_receive(TIMEOUT).unwrap();
+        assert_that!(*sample.unwrap(), eq 5678);
+    }
+
+    fn blocking_receive_wakes_up_when_sample_is_sent<Sut: Service>(enable_notifications: bool) {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+
+        let service = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notifications(enable_notifications)
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap();
+
+                let publisher = service.publisher_builder().create().unwrap();
+
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                publisher.send_copy(5678).unwrap();
+            });
+
+            barrier.wait();
+            let start = Instant::now();
+            let sample = sut.blocking_receive().unwrap();
+
+            assert_that!(start.elapsed(), ge TIMEOUT);
+            assert_that!(*sample, eq 5678);
+        });
+    }
+
+    #[test]
+    fn blocking_receive_wakes_up_when_sample_is_sent_without_notifications<Sut: Service>() {
+        blocking_receive_wakes_up_when_sample_is_sent::<Sut>(false);
+    }
+
+    #[test]
+    fn blocking_receive_wakes_up_when_sample_is_sent_with_notifications<Sut: Service>() {
+        blocking_receive_wakes_up_when_sample_is_sent::<Sut>(true);
+    }
+
+    #[test]
+    fn keep_latest_subscriber_receives_only_the_most_recent_sample<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(false)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(4)
+            .keep_latest()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        for value in 0..10 {
+            assert_that!(publisher.send_copy(value), eq Ok(1));
+        }
+
+        let mut details = vec![];
+        service.dynamic_config().list_subscribers(|s| {
+            details.push((s.buffer_size, s.keep_latest));
+            CallbackProgression::Continue
+        });
+        assert_that!(details, eq vec![(1, true)]);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 9);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn max_sample_age_requires_service_with_timestamps<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .max_sample_age(TIMEOUT)
+            .create();
+        assert_that!(sut.err(), eq Some(SubscriberCreateError::TimestampsNotEnabled));
+    }
+
+    #[test]
+    fn samples_contain_timestamp_only_when_enabled<Sut: Service>() {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        for enable_timestamps in [false, true] {
+            let service = node
+                .service_builder(&generate_name())
+                .publish_subscribe::<u64>()
+                .enable_timestamps(enable_timestamps)
+                .create()
+                .unwrap();
+            assert_that!(service.static_config().has_timestamps(), eq enable_timestamps);
+
+            let sut = service.subscriber_builder().create().unwrap();
+            let publisher = service.publisher_builder().create().unwrap();
+            assert_that!(publisher.send_copy(1), eq Ok(1));
+
+            let sample = sut.receive().unwrap().unwrap();
+            assert_that!(sample.header().timestamp().is_some(), eq enable_timestamps);
+        }
+    }
+
+    #[test]
+    fn publish_timestamp_is_earlier_than_receive_time<Sut: Service>() {
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_timestamps(true)
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().create().unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let before_send = Time::now().unwrap().as_duration();
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+        let sample = sut.receive().unwrap().unwrap();
+        let receive_time = Time::now().unwrap().as_duration();
+
+        let publish_timestamp = sample.publish_timestamp().unwrap();
+        assert_that!(publish_timestamp, ge before_send);
+        assert_that!(publish_timestamp, le receive_time);
+        assert_that!(publish_timestamp, eq sample.header().timestamp().unwrap());
+    }
+
+    #[test]
+    fn subscriber_skips_samples_older_than_max_sample_age<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .enable_timestamps(true)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .max_sample_age(TIMEOUT)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+        assert_that!(publisher.send_copy(2), eq Ok(1));
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(publisher.send_copy(3), eq Ok(1));
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 3);
+        assert_that!(sut.expired_samples(), eq 2);
+        assert_that!(sut.receive().unwrap(), is_none);
+        assert_that!(sut.lost_samples(), eq 0);
+    }
+
+    #[test]
+    fn subscriber_receives_nothing_when_all_samples_are_expired<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(4)
+            .enable_timestamps(true)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .max_sample_age(TIMEOUT)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        for value in 0..4 {
+            assert_that!(publisher.send_copy(value), eq Ok(1));
+        }
+        std::thread::sleep(TIMEOUT * 2);
+
+        assert_that!(sut.receive().unwrap(), is_none);
+        assert_that!(sut.expired_samples(), eq 4);
+
+        // the expired samples were released and the publisher can deliver new ones
+        for value in 4..8 {
+            assert_that!(publisher.send_copy(value), eq Ok(1));
+        }
+        let received: Vec<u64> = sut
+            .receive_batch(4)
+            .unwrap()
+            .iter()
+            .map(|sample| **sample)
+            .collect();
+        assert_that!(received, eq vec![4, 5, 6, 7]);
+        assert_that!(sut.expired_samples(), eq 4);
+    }
+
+    #[test]
+    fn keep_latest_subscriber_always_receives_fresh_samples_from_fast_publisher<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 100000;
+        let _watchdog = Watchdog::new_with_timeout(Duration::from_secs(60));
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+
+        let service = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().keep_latest().create().unwrap();
+        let publisher_done = IoxAtomicBool::new(false);
+
+        let handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap();
+
+                let publisher = service.publisher_builder().create().unwrap();
+
+                barrier.wait();
+                for value in 0..NUMBER_OF_SAMPLES {
+                    publisher.send_copy(value).unwrap();
+                }
+                publisher_done.store(true, Ordering::Relaxed);
+            });
+
+            barrier.wait();
+            let mut last_value = None;
+            let mut number_of_received_samples = 0;
+            loop {
+                let is_done = publisher_done.load(Ordering::Relaxed);
+                while let Some(sample) = sut.receive().unwrap() {
+                    // a keep latest subscriber never receives an outdated sample
+                    assert_that!(last_value < Some(*sample), eq true);
+                    last_value = Some(*sample);
+                    number_of_received_samples += 1;
+                }
+
+                if is_done {
+                    break;
+                }
+                std::thread::sleep(Duration::from_micros(100));
+            }
+
+            assert_that!(last_value, eq Some(NUMBER_OF_SAMPLES - 1));
+            assert_that!(number_of_received_samples, lt NUMBER_OF_SAMPLES);
+        });
+    }
+
+    #[test]
+    fn history_request_exceeding_history_size_of_service_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(3)
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().history_request(4).create();
+        assert_that!(sut.err(), eq Some(SubscriberCreateError::HistoryRequestExceedsHistorySizeOfService));
+
+        let sut = service.subscriber_builder().history_request(3).create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn history_request_exceeding_history_size_of_service_can_be_clamped<Sut: Service>() {
+        const HISTORY_SIZE: usize = 3;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(2 * HISTORY_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        for n in 0..2 * HISTORY_SIZE as u64 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sut = service
+            .subscriber_builder()
+            .history_request(2 * HISTORY_SIZE)
+            .clamp_history_request(true)
+            .create()
+            .unwrap();
+        publisher.update_connections().unwrap();
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+        assert_that!(received, eq vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn subscriber_receives_requested_number_of_historical_samples<Sut: Service>() {
+        const HISTORY_SIZE: usize = 5;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
+            .max_subscribers(HISTORY_SIZE + 1)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        for n in 0..HISTORY_SIZE as u64 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        for history_request in 0..=HISTORY_SIZE {
+            let sut = service
+                .subscriber_builder()
+                .history_request(history_request)
+                .create()
+                .unwrap();
+            publisher.update_connections().unwrap();
+
+            let mut received = vec![];
+            while let Some(sample) = sut.receive().unwrap() {
+                received.push(*sample);
+            }
+            assert_that!(
+                received,
+                eq(HISTORY_SIZE - history_request..HISTORY_SIZE)
+                    .map(|n| n as u64)
+                    .collect::<Vec<_>>()
+            );
+            assert_that!(sut.has_history_completed().unwrap(), eq true);
+        }
+    }
+
+    #[test]
+    fn history_is_completed_when_all_historical_samples_are_received<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(4)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        for n in 0..4 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sut = service
+            .subscriber_builder()
+            .history_request(2)
+            .create()
+            .unwrap();
+        assert_that!(sut.has_history_completed().unwrap(), eq false);
+
+        publisher.update_connections().unwrap();
+        assert_that!(sut.has_history_completed().unwrap(), eq false);
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 2);
+        assert_that!(sut.has_history_completed().unwrap(), eq false);
+
+        publisher.send_copy(4).unwrap();
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 3);
+        assert_that!(sut.has_history_completed().unwrap(), eq true);
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 4);
+        assert_that!(sut.has_history_completed().unwrap(), eq true);
+    }
+
+    #[test]
+    fn history_is_completed_without_history<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let _publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.has_history_completed().unwrap(), eq true);
+    }
+
+    #[test]
+    fn history_is_completed_when_publisher_has_no_history<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(4)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().history(0).create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .history_request(2)
+            .create()
+            .unwrap();
+
+        publisher.update_connections().unwrap();
+        assert_that!(sut.has_history_completed().unwrap(), eq true);
+    }
+
+    #[test]
+    fn invalid_sample_filters_are_rejected<Sut: Service>() {
+        assert_that!(SampleFilter::offset_equals(0, &[]).err(), eq Some(SampleFilterCreateError::InvalidLength));
+        assert_that!(SampleFilter::offset_equals(0, &[0; SampleFilter::MAX_LENGTH + 1]).err(), eq Some(SampleFilterCreateError::InvalidLength));
+        assert_that!(SampleFilter::masked_offset_equals(0, &[1, 2], &[1]).err(), eq Some(SampleFilterCreateError::MaskLengthMismatch));
+        assert_that!(
+            SampleFilter::offset_equals(0, &[0; SampleFilter::MAX_LENGTH]),
+            is_ok
+        );
+
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<u32>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::offset_equals(4, &[0; 5]).unwrap())
+            .create();
+        assert_that!(sut.err(), eq Some(SubscriberCreateError::IncompatibleSampleFilter));
+
+        let sut = service
+            .subscriber_builder()
+            .filter(
+                SampleFilter::offset_equals(2, &[0; 4])
+                    .unwrap()
+                    .on_user_header(),
+            )
+            .create();
+        assert_that!(sut.err(), eq Some(SubscriberCreateError::IncompatibleSampleFilter));
+
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::offset_equals(4, &[0; 4]).unwrap())
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut = service
+            .subscriber_builder()
+            .filter(
+                SampleFilter::offset_equals(0, &[0; 4])
+                    .unwrap()
+                    .on_user_header(),
+            )
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn subscribers_with_different_payload_filters_receive_only_matching_samples<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 8;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .max_subscribers(3)
+            .create()
+            .unwrap();
+
+        let sut_equals = service
+            .subscriber_builder()
+            .filter(SampleFilter::offset_equals(0, &5u64.to_ne_bytes()).unwrap())
+            .create()
+            .unwrap();
+        let lowest_byte = if cfg!(target_endian = "little") { 0 } else { 7 };
+        let sut_even = service
+            .subscriber_builder()
+            .filter(SampleFilter::masked_offset_equals(lowest_byte, &[0], &[1]).unwrap())
+            .create()
+            .unwrap();
+        let sut_unfiltered = service.subscriber_builder().create().unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let mut number_of_recipients = 0;
+        for n in 0..NUMBER_OF_SAMPLES {
+            number_of_recipients += publisher.send_copy(n).unwrap();
+        }
+        assert_that!(number_of_recipients, eq 1 + NUMBER_OF_SAMPLES as usize / 2 + NUMBER_OF_SAMPLES as usize);
+
+        let receive_all = |subscriber: &Subscriber<Sut, u64, ()>| {
+            let mut received = vec![];
+            while let Some(sample) = subscriber.receive().unwrap() {
+                received.push(*sample);
+            }
+            received
+        };
+
+        assert_that!(receive_all(&sut_equals), eq vec![5]);
+        assert_that!(receive_all(&sut_even), eq vec![0, 2, 4, 6]);
+        assert_that!(
+            receive_all(&sut_unfiltered),
+            eq(0..NUMBER_OF_SAMPLES).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn subscribers_with_user_header_and_payload_filters_receive_only_matching_samples<
+        Sut: Service,
+    >() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<u64>()
+            .max_subscribers(2)
+            .create()
+            .unwrap();
+
+        let sut_header = service
+            .subscriber_builder()
+            .filter(
+                SampleFilter::offset_equals(0, &3u64.to_ne_bytes())
+                    .unwrap()
+                    .on_user_header(),
+            )
+            .create()
+            .unwrap();
+        let sut_payload = service
+            .subscriber_builder()
+            .filter(SampleFilter::offset_equals(0, &3u64.to_ne_bytes()).unwrap())
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        for (header, payload) in [(3, 1), (1, 3), (2, 2)] {
+            let mut sample = publisher.loan().unwrap();
+            *sample.user_header_mut() = header;
+            *sample.payload_mut() = payload;
+            sample.send().unwrap();
+        }
+
+        let sample = sut_header.receive().unwrap().unwrap();
+        assert_that!(*sample.user_header(), eq 3);
+        assert_that!(*sample.payload(), eq 1);
+        assert_that!(sut_header.receive().unwrap(), is_none);
+
+        let sample = sut_payload.receive().unwrap().unwrap();
+        assert_that!(*sample.user_header(), eq 1);
+        assert_that!(*sample.payload(), eq 3);
+        assert_that!(sut_payload.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn sample_filter_on_slice_does_not_match_too_short_slices<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::offset_equals(2, &[7]).unwrap())
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .create()
+            .unwrap();
+
+        for payload in [&[7, 7][..], &[0, 0, 8, 0], &[0, 0, 7]] {
+            let sample = publisher.loan_slice_uninit(payload.len()).unwrap();
+            sample.write_from_slice(payload).send().unwrap();
+        }
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq [0, 0, 7]);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn sample_filter_is_applied_to_history<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u8>()
+            .history_size(4)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        for n in 0..4 {
+            publisher.send_copy(n % 2).unwrap();
+        }
+
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::offset_equals(0, &[1]).unwrap())
+            .create()
+            .unwrap();
+        publisher.update_connections().unwrap();
+
+        let mut number_of_samples = 0;
+        while let Some(sample) = sut.receive().unwrap() {
+            assert_that!(*sample, eq 1);
+            number_of_samples += 1;
+        }
+        assert_that!(number_of_samples, eq 2);
+    }
+
+    #[test]
+    fn filtered_subscriber_does_not_count_lost_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u8>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::offset_equals(0, &[1]).unwrap())
+            .create()
+            .unwrap();
+
+        for n in 0..6 {
+            publisher.send_copy(n % 2).unwrap();
+        }
+
+        while sut.receive().unwrap().is_some() {}
+
+        assert_that!(sut.lost_samples(), eq 0);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn subscriber_with_custom_payload_details_panics_when_calling_non_custom_receive<
+        Sut: Service,
+    >() {
+        const TYPE_SIZE_OVERRIDE: usize = 128;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let mut type_detail = TypeDetail::new::<u8>(TypeVariant::FixedSize);
+        testing::type_detail_set_size(&mut type_detail, TYPE_SIZE_OVERRIDE);
+
+        let service = unsafe {
+            node.service_builder(&service_name)
+                .publish_subscribe::<[CustomPayloadMarker]>()
+                .__internal_set_payload_type_details(&type_detail)
+                .create()
+                .unwrap()
+        };
+
+        let sut = service.subscriber_builder().create().unwrap();
+
+        // panics here
+        let _sample = sut.receive();
+    }
+
+    #[test]
+    fn wait_for_publishers_returns_when_publisher_connects<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = Mutex::new(NodeBuilder::new().config(&config).create::<Sut>().unwrap());
+        let service = node
+            .lock()
+            .unwrap()
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let start_handle = BarrierHandle::new();
+        let start_barrier = BarrierBuilder::new(2).create(&start_handle).unwrap();
+        let end_handle = BarrierHandle::new();
+        let end_barrier = BarrierBuilder::new(2).create(&end_handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = node
+                    .lock()
+                    .unwrap()
+                    .service_builder(&service_name)
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap();
+
+                start_barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                let _publisher = service.publisher_builder().create().unwrap();
+                end_barrier.wait();
+            });
+
+            start_barrier.wait();
+            let now = Instant::now();
+            assert_that!(sut.wait_for_publishers(1, TIMEOUT * 100), eq Ok(1));
+            assert_that!(now.elapsed(), time_at_least TIMEOUT);
+            end_barrier.wait();
+        });
+    }
+
+    #[test]
+    fn wait_for_publishers_returns_connected_publishers_after_timeout<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let now = Instant::now();
+        assert_that!(sut.wait_for_publishers(1, TIMEOUT), eq Ok(0));
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+
+        let _publisher = service.publisher_builder().create().unwrap();
+        assert_that!(sut.wait_for_publishers(1, TIMEOUT), eq Ok(1));
     }
 
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
@@ -13,13 +13,18 @@
 #[generic_tests::define]
 mod waitset {
     use core::time::Duration;
+    use std::sync::Arc;
     use std::time::Instant;
 
     use iceoryx2::port::listener::Listener;
     use iceoryx2::port::notifier::Notifier;
     use iceoryx2::prelude::{WaitSetBuilder, *};
     use iceoryx2::testing::*;
-    use iceoryx2::waitset::{WaitSetAttachmentError, WaitSetRunError};
+    use iceoryx2::waitset::{
+        WaitSetAttachmentError, WaitSetAttachmentKind, WaitSetAttachmentStableId,
+        WaitSetOwnedGuard, WaitSetReattachError, WaitSetRunError, WaitSetRunResult, WaitSetStats,
+    };
+    use iceoryx2_bb_posix::clock::{ClockType, Time, TimeBuilder, TimeError};
     use iceoryx2_bb_posix::config::test_directory;
     use iceoryx2_bb_posix::directory::Directory;
     use iceoryx2_bb_posix::file::Permission;
@@ -70,6 +75,15 @@ mod waitset {
         )
     }
 
+    fn time_from_now(clock_type: ClockType, offset: Duration) -> Time {
+        let time = Time::now_with_clock(clock_type).unwrap().as_duration() + offset;
+        TimeBuilder::new()
+            .clock_type(clock_type)
+            .seconds(time.as_secs())
+            .nanoseconds(time.subsec_nanos())
+            .create()
+    }
+
     fn create_socket() -> (UnixDatagramReceiver, UnixDatagramSender) {
         let uds_name = generate_uds_name();
 
@@ -90,6 +104,136 @@ mod waitset {
         assert_that!(result.err(), eq Some(WaitSetRunError::NoAttachments));
     }
 
+    #[test]
+    fn error_handler_decides_if_wait_and_process_continues_after_failure<S: Service>() {
+        const NUMBER_OF_RETRIES: usize = 3;
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let mut errors = vec![];
+        let result = sut.wait_and_process_with_error_handler(
+            |_| CallbackProgression::Continue,
+            |e| {
+                errors.push(e);
+                if errors.len() < NUMBER_OF_RETRIES {
+                    CallbackProgression::Continue
+                } else {
+                    CallbackProgression::Stop
+                }
+            },
+        );
+
+        assert_that!(result.err(), eq Some(WaitSetRunError::NoAttachments));
+        assert_that!(errors, len NUMBER_OF_RETRIES);
+        for e in errors {
+            assert_that!(e, eq WaitSetRunError::NoAttachments);
+        }
+    }
+
+    #[test]
+    fn wait_and_process_with_error_handler_returns_on_stop_request<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let _tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        let mut number_of_errors = 0;
+        let result = sut.wait_and_process_with_error_handler(
+            |_| CallbackProgression::Stop,
+            |_| {
+                number_of_errors += 1;
+                CallbackProgression::Stop
+            },
+        );
+
+        assert_that!(result, eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(number_of_errors, eq 0);
+    }
+
+    #[test]
+    fn min_timeout_resolution_is_not_zero<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        assert_that!(sut.min_timeout_resolution(), gt Duration::ZERO);
+    }
+
+    #[test]
+    fn stable_id_of_notification_is_independent_of_the_waitset<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut_1 = WaitSetBuilder::new().create::<S>().unwrap();
+        let sut_2 = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _) = create_event::<S>(&node);
+
+        let guard_1 = sut_1.attach_notification(&listener).unwrap();
+        let guard_2 = sut_2.attach_notification(&listener).unwrap();
+
+        let id_1 = WaitSetAttachmentId::from_guard(&guard_1);
+        let id_2 = WaitSetAttachmentId::from_guard(&guard_2);
+
+        assert_that!(id_1, ne id_2);
+        assert_that!(id_1.stable_id(), eq id_2.stable_id());
+        assert_that!(id_1.stable_id(), eq WaitSetAttachmentStableId::Notification {
+            file_descriptor: unsafe { listener.file_descriptor().native_handle() }
+        });
+    }
+
+    #[test]
+    fn stable_id_distinguishes_attachment_types<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, _) = create_event::<S>(&node);
+        let (listener_2, _) = create_event::<S>(&node);
+
+        let notification_guard = sut.attach_notification(&listener_1).unwrap();
+        let deadline_guard = sut.attach_deadline(&listener_2, TIMEOUT).unwrap();
+        let tick_guard_1 = sut.attach_interval(TIMEOUT).unwrap();
+        let tick_guard_2 = sut.attach_interval(TIMEOUT).unwrap();
+
+        let notification_id = WaitSetAttachmentId::from_guard(&notification_guard).stable_id();
+        let deadline_id = WaitSetAttachmentId::from_guard(&deadline_guard).stable_id();
+        let tick_id_1 = WaitSetAttachmentId::from_guard(&tick_guard_1).stable_id();
+        let tick_id_2 = WaitSetAttachmentId::from_guard(&tick_guard_2).stable_id();
+
+        assert_that!(notification_id, ne deadline_id);
+        assert_that!(tick_id_1, ne tick_id_2);
+        assert_that!(deadline_id, ne tick_id_1);
+    }
+
+    #[test]
+    fn stable_id_can_be_serialized_and_deserialized<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, _) = create_event::<S>(&node);
+        let (listener_2, _) = create_event::<S>(&node);
+
+        let notification_guard = sut.attach_notification(&listener_1).unwrap();
+        let deadline_guard = sut.attach_deadline(&listener_2, TIMEOUT).unwrap();
+        let tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        for guard in [&notification_guard, &deadline_guard, &tick_guard] {
+            let stable_id = WaitSetAttachmentId::from_guard(guard).stable_id();
+            let bytes = stable_id.to_bytes();
+            assert_that!(WaitSetAttachmentStableId::from_bytes(&bytes), eq Some(stable_id));
+        }
+
+        let mut invalid_bytes = [0u8; WaitSetAttachmentStableId::SERIALIZED_SIZE];
+        invalid_bytes[0] = 255;
+        assert_that!(WaitSetAttachmentStableId::from_bytes(&invalid_bytes), eq None);
+    }
+
     #[test]
     fn attach_multiple_notifications_works<S: Service>()
     where
@@ -154,6 +298,296 @@ mod waitset {
         assert_that!(sut.attach_notification(&receiver).err(), eq Some(WaitSetAttachmentError::AlreadyAttached));
     }
 
+    #[test]
+    fn try_attach_returns_none_when_capacity_is_exceeded<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (listener, _) = create_event::<S>(&node);
+
+        assert_that!(sut.capacity_remaining(), eq sut.capacity());
+
+        let mut guards = vec![];
+        for n in 0..sut.capacity() {
+            guards.push(sut.try_attach_interval(TIMEOUT).unwrap().unwrap());
+            assert_that!(sut.capacity_remaining(), eq sut.capacity() - n - 1);
+        }
+
+        assert_that!(sut.try_attach_interval(TIMEOUT).unwrap(), is_none);
+        assert_that!(sut.try_attach_notification(&listener).unwrap(), is_none);
+        assert_that!(
+            sut.try_attach_deadline(&listener, TIMEOUT).unwrap(),
+            is_none
+        );
+        assert_that!(sut.len(), eq sut.capacity());
+
+        guards.pop();
+        assert_that!(sut.capacity_remaining(), eq 1);
+        let guard = sut.try_attach_notification(&listener).unwrap();
+        assert_that!(guard, is_some);
+        assert_that!(sut.len(), eq sut.capacity());
+    }
+
+    #[test]
+    fn try_attach_fails_when_attaching_same_notification_twice<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (listener, _) = create_event::<S>(&node);
+
+        let _guard = sut.try_attach_notification(&listener).unwrap();
+        assert_that!(sut.try_attach_notification(&listener).err(), eq Some(WaitSetAttachmentError::AlreadyAttached));
+        assert_that!(sut.try_attach_deadline(&listener, TIMEOUT).err(), eq Some(WaitSetAttachmentError::AlreadyAttachedWithDifferentSemantic));
+    }
+
+    #[test]
+    fn owned_attachment_can_be_stored_together_with_waitset<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        struct Dispatcher<S: Service> {
+            waitset: WaitSet<S>,
+            guards: Vec<WaitSetOwnedGuard<S>>,
+        }
+
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, _notifier_2) = create_event::<S>(&node);
+        let guards = vec![
+            waitset.attach_owned(listener_1).unwrap(),
+            waitset.attach_owned(listener_2).unwrap(),
+        ];
+        let sut = Dispatcher { waitset, guards };
+
+        assert_that!(sut.waitset.len(), eq 2);
+
+        notifier_1.notify().unwrap();
+
+        let mut received_from = vec![];
+        let result = sut
+            .waitset
+            .wait_and_process_once(|id| {
+                for (n, guard) in sut.guards.iter().enumerate() {
+                    if id.has_event_from_owned(guard) {
+                        while guard
+                            .listener()
+                            .try_wait_one()
+                            .unwrap()
+                            .event_id()
+                            .is_some()
+                        {}
+                        received_from.push(n);
+                    }
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        assert_that!(result, eq WaitSetRunResult::AllEventsHandled);
+        assert_that!(received_from, eq vec![0]);
+    }
+
+    #[test]
+    fn dropping_owned_guard_detaches_listener<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (listener, _notifier) = create_event::<S>(&node);
+
+        let guard = sut.attach_owned(listener).unwrap();
+        assert_that!(sut.len(), eq 1);
+
+        drop(guard);
+        assert_that!(sut.len(), eq 0);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn dropping_owned_guard_drops_listener_immediately<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (listener, notifier) = create_event::<S>(&node);
+
+        let guard = sut.attach_owned(listener).unwrap();
+        assert_that!(notifier.notify().unwrap(), eq 1);
+
+        drop(guard);
+        assert_that!(notifier.notify().unwrap(), eq 0);
+    }
+
+    #[test]
+    fn listener_can_be_attached_after_owned_guard_with_same_file_descriptor_was_dropped<
+        S: Service,
+    >()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, _notifier_1) = create_event::<S>(&node);
+        drop(sut.attach_owned(listener_1).unwrap());
+
+        // the new listener may reuse the file descriptor of the dropped one
+        let (listener_2, notifier_2) = create_event::<S>(&node);
+        let guard = sut.attach_notification(&listener_2).unwrap();
+        notifier_2.notify().unwrap();
+
+        let mut received_event = false;
+        sut.wait_and_process_once(|id| {
+            received_event |= id.has_event_from(&guard);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(received_event, eq true);
+    }
+
+    #[test]
+    fn owned_attachment_stays_attached_until_guard_is_dropped<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, notifier_2) = create_event::<S>(&node);
+        let mut guards = vec![
+            waitset.attach_owned(listener_1).unwrap(),
+            waitset.attach_owned(listener_2).unwrap(),
+        ];
+        // the owned attachments must stay valid when the WaitSet is moved
+        let sut = Box::new(waitset);
+
+        let wait_for_events = |guards: &[WaitSetOwnedGuard<S>]| {
+            let mut received_from = vec![];
+            sut.wait_and_process_once(|id| {
+                for (n, guard) in guards.iter().enumerate() {
+                    if id.has_event_from_owned(guard) {
+                        while guard
+                            .listener()
+                            .try_wait_one()
+                            .unwrap()
+                            .event_id()
+                            .is_some()
+                        {}
+                        received_from.push(n);
+                    }
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+            received_from
+        };
+
+        for _ in 0..3 {
+            notifier_1.notify().unwrap();
+            assert_that!(wait_for_events(&guards), eq vec![0]);
+        }
+
+        guards.remove(0);
+        assert_that!(sut.len(), eq 1);
+
+        notifier_1.notify().unwrap();
+        notifier_2.notify().unwrap();
+        assert_that!(wait_for_events(&guards), eq vec![0]);
+
+        drop(sut);
+        drop(guards);
+    }
+
+    #[test]
+    fn attaching_same_object_as_notification_and_deadline_fails<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _) = create_event::<S>(&node);
+        let (receiver, _) = create_socket();
+
+        let _guard = sut.attach_notification(&listener).unwrap();
+        assert_that!(sut.attach_deadline(&listener, TIMEOUT).err(), eq Some(WaitSetAttachmentError::AlreadyAttachedWithDifferentSemantic));
+
+        let _guard = sut.attach_deadline(&receiver, TIMEOUT).unwrap();
+        assert_that!(sut.attach_notification(&receiver).err(), eq Some(WaitSetAttachmentError::AlreadyAttachedWithDifferentSemantic));
+        assert_that!(sut.len(), eq 2);
+    }
+
+    #[test]
+    fn owned_listener_cannot_be_attached_as_deadline<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (listener, _notifier) = create_event::<S>(&node);
+
+        let guard = sut.attach_owned(listener).unwrap();
+
+        assert_that!(sut.attach_deadline(guard.listener(), TIMEOUT).err(), eq Some(WaitSetAttachmentError::AlreadyAttachedWithDifferentSemantic));
+        assert_that!(sut.len(), eq 1);
+    }
+
+    #[test]
+    fn owned_listener_cannot_be_attached_twice<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (listener, _notifier) = create_event::<S>(&node);
+
+        let guard = sut.attach_owned(listener).unwrap();
+
+        assert_that!(sut.attach_notification(guard.listener()).err(), eq Some(WaitSetAttachmentError::AlreadyAttached));
+        assert_that!(sut.len(), eq 1);
+    }
+
+    #[test]
+    fn attach_owned_fails_when_capacity_is_exceeded<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (listener, _notifier) = create_event::<S>(&node);
+
+        let mut guards = vec![];
+        for _ in 0..sut.capacity() {
+            guards.push(sut.attach_interval(TIMEOUT).unwrap());
+        }
+
+        assert_that!(sut.attach_owned(listener).err(), eq Some(WaitSetAttachmentError::InsufficientCapacity));
+    }
+
     #[test]
     fn attaching_same_deadline_twice_fails<S: Service>()
     where
@@ -274,30 +708,317 @@ mod waitset {
     }
 
     #[test]
-    fn wait_and_process_once_does_not_block_longer_than_provided_timeout<S: Service>()
+    fn simultaneous_events_are_handled_in_attachment_order<S: Service>()
     where
         <S::Event as Event>::Listener: SynchronousMultiplexing,
     {
+        const NUMBER_OF_ATTACHMENTS: usize = 8;
         let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
         let sut = WaitSetBuilder::new().create::<S>().unwrap();
 
-        let _tick_guard = sut.attach_interval(Duration::MAX).unwrap();
+        let mut listeners = vec![];
+        let mut notifiers = vec![];
+        for _ in 0..NUMBER_OF_ATTACHMENTS {
+            let (listener, notifier) = create_event::<S>(&node);
+            listeners.push(listener);
+            notifiers.push(notifier);
+        }
 
-        let mut callback_called = false;
-        let start = Instant::now();
-        sut.wait_and_process_once_with_timeout(
-            |_| {
-                callback_called = true;
+        // attached in reverse order of creation so that the attachment order differs from the
+        // order of the file descriptors
+        let mut guards = vec![];
+        for listener in listeners.iter().rev() {
+            guards.push(sut.attach_notification(listener).unwrap());
+        }
+
+        let handled_attachments = |guards: &[WaitSetGuard<S>]| {
+            let mut handled = vec![];
+            sut.wait_and_process_once(|id| {
+                let idx = guards.iter().position(|g| id.has_event_from(g)).unwrap();
+                handled.push(idx);
                 CallbackProgression::Continue
-            },
-            TIMEOUT,
-        )
-        .unwrap();
+            })
+            .unwrap();
+            handled
+        };
 
-        assert_that!(callback_called, eq false);
+        for notifier in &notifiers {
+            notifier.notify().unwrap();
+        }
+        let handled = handled_attachments(&guards);
+        assert_that!(handled, eq(0..NUMBER_OF_ATTACHMENTS).collect::<Vec<_>>());
+
+        // a reattached object is handled after all other attachments
+        let first_guard = guards.remove(0);
+        drop(first_guard);
+        guards.push(
+            sut.attach_notification(&listeners[NUMBER_OF_ATTACHMENTS - 1])
+                .unwrap(),
+        );
+
+        let handled = handled_attachments(&guards);
+        assert_that!(handled, eq(0..NUMBER_OF_ATTACHMENTS).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn wait_and_process_with_context_provides_context_of_attachment<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new()
+            .create_with_context::<S, u64>()
+            .unwrap();
+
+        let (listener_1, _notifier_1) = create_event::<S>(&node);
+        let (listener_2, notifier_2) = create_event::<S>(&node);
+        let (listener_3, notifier_3) = create_event::<S>(&node);
+        let _guard_1 = sut
+            .attach_notification_with_context(&listener_1, 1u64)
+            .unwrap();
+        let guard_2 = sut
+            .attach_notification_with_context(&listener_2, 2u64)
+            .unwrap();
+        let guard_3 = sut.attach_notification(&listener_3).unwrap();
+
+        notifier_2.notify().unwrap();
+        notifier_3.notify().unwrap();
+
+        let mut received_context = false;
+        let mut received_no_context = false;
+        sut.wait_and_process_with_context(|id, context: Option<&mut u64>| {
+            if id.has_event_from(&guard_2) {
+                assert_that!(context, eq Some(&mut 2));
+                *context.unwrap() += 10;
+                while listener_2.try_wait_one().unwrap().event_id().is_some() {}
+                received_context = true;
+            } else if id.has_event_from(&guard_3) {
+                assert_that!(context, is_none);
+                while listener_3.try_wait_one().unwrap().event_id().is_some() {}
+                received_no_context = true;
+            } else {
+                test_fail!("only listener 2 and 3 have received an event");
+            }
+
+            if received_context && received_no_context {
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        })
+        .unwrap();
+
+        notifier_2.notify().unwrap();
+        sut.wait_and_process_with_context(|id, context: Option<&mut u64>| {
+            assert_that!(id.has_event_from(&guard_2), eq true);
+            assert_that!(context, eq Some(&mut 12));
+            CallbackProgression::Stop
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn context_is_dropped_when_attachment_is_detached_in_callback<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new()
+            .create_with_context::<S, Arc<u64>>()
+            .unwrap();
+
+        let (listener, notifier) = create_event::<S>(&node);
+        let context = Arc::new(0u64);
+        let mut guard = Some(
+            sut.attach_notification_with_context(&listener, context.clone())
+                .unwrap(),
+        );
+
+        notifier.notify().unwrap();
+        sut.wait_and_process_with_context(|_, attachment_context: Option<&mut Arc<u64>>| {
+            assert_that!(attachment_context, is_some);
+            guard.take();
+            CallbackProgression::Stop
+        })
+        .unwrap();
+
+        assert_that!(sut.is_empty(), eq true);
+        assert_that!(Arc::strong_count(&context), eq 1);
+    }
+
+    #[test]
+    fn context_is_dropped_when_attachment_is_detached<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new()
+            .create_with_context::<S, Arc<u64>>()
+            .unwrap();
+
+        let (listener, notifier) = create_event::<S>(&node);
+        let context = Arc::new(0u64);
+        let guard = sut
+            .attach_notification_with_context(&listener, context.clone())
+            .unwrap();
+        assert_that!(Arc::strong_count(&context), eq 2);
+
+        drop(guard);
+        assert_that!(Arc::strong_count(&context), eq 1);
+
+        let _guard = sut.attach_notification(&listener).unwrap();
+        notifier.notify().unwrap();
+        sut.wait_and_process_with_context(|_, context: Option<&mut Arc<u64>>| {
+            assert_that!(context, is_none);
+            CallbackProgression::Stop
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn wait_and_process_detailed_reports_missed_deadline<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _notifier) = create_event::<S>(&node);
+        let guard = sut.attach_deadline(&listener, TIMEOUT).unwrap();
+
+        let mut reported_kinds = vec![];
+        let start = Instant::now();
+        let result = sut.wait_and_process_detailed(|id, kind| {
+            assert_that!(id.has_missed_deadline(&guard), eq true);
+            assert_that!(id.kind(), eq kind);
+            reported_kinds.push(kind);
+            CallbackProgression::Stop
+        });
+
+        assert_that!(result, eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(reported_kinds, eq vec![WaitSetAttachmentKind::DeadlineMissed]);
+    }
+
+    #[test]
+    fn wait_and_process_detailed_reports_notification_and_tick<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, notifier) = create_event::<S>(&node);
+        let notification_guard = sut.attach_deadline(&listener, TIMEOUT * 1000).unwrap();
+        let tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        notifier.notify().unwrap();
+
+        let mut received_notification = false;
+        let mut received_tick = false;
+        sut.wait_and_process_detailed(|id, kind| {
+            match kind {
+                WaitSetAttachmentKind::Notification => {
+                    assert_that!(id.has_event_from(&notification_guard), eq true);
+                    while listener.try_wait_one().unwrap().event_id().is_some() {}
+                    received_notification = true;
+                }
+                WaitSetAttachmentKind::Tick => {
+                    assert_that!(id.has_event_from(&tick_guard), eq true);
+                    received_tick = true;
+                }
+                WaitSetAttachmentKind::DeadlineMissed => {
+                    test_fail!("the deadline must not be missed");
+                }
+            }
+
+            if received_notification && received_tick {
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn wait_and_process_once_does_not_block_longer_than_provided_timeout<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let _tick_guard = sut.attach_interval(Duration::MAX).unwrap();
+
+        let mut callback_called = false;
+        let start = Instant::now();
+        sut.wait_and_process_once_with_timeout(
+            |_| {
+                callback_called = true;
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+
+        assert_that!(callback_called, eq false);
         assert_that!(start.elapsed(), time_at_least TIMEOUT);
     }
 
+    #[test]
+    fn wait_and_process_once_reports_all_events_handled_when_timeout_passed<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, notifier) = create_event::<S>(&node);
+        let _listener_guard = sut.attach_notification(&listener).unwrap();
+
+        let mut number_of_calls = 0;
+        let result = sut
+            .wait_and_process_once_with_timeout(
+                |_| {
+                    number_of_calls += 1;
+                    CallbackProgression::Continue
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+
+        assert_that!(result, eq WaitSetRunResult::AllEventsHandled);
+        assert_that!(number_of_calls, eq 0);
+
+        notifier.notify().unwrap();
+        let result = sut
+            .wait_and_process_once_with_timeout(
+                |_| {
+                    number_of_calls += 1;
+                    CallbackProgression::Continue
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+
+        assert_that!(result, eq WaitSetRunResult::AllEventsHandled);
+        assert_that!(number_of_calls, eq 1);
+    }
+
     #[test]
     fn wait_and_process_once_with_timeout_blocks_at_each_invocation<S: Service>()
     where
@@ -350,6 +1071,25 @@ mod waitset {
         assert_that!(start.elapsed(), time_at_least TIMEOUT);
     }
 
+    #[test]
+    fn wait_and_process_once_returns_error_when_time_cannot_be_acquired<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let _tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        sut.__internal_inject_time_error(Some(TimeError::UnknownError(0)));
+        let result = sut.wait_and_process_once(|_| CallbackProgression::Continue);
+        assert_that!(result.err(), eq Some(WaitSetRunError::InternalError));
+
+        sut.__internal_inject_time_error(None);
+        let result = sut.wait_and_process_once(|_| CallbackProgression::Continue);
+        assert_that!(result, is_ok);
+    }
+
     #[test]
     fn wait_and_process_once_lists_all_deadlines<S: Service>()
     where
@@ -449,93 +1189,435 @@ mod waitset {
     }
 
     #[test]
-    fn wait_and_process_stops_when_requested<S: Service>()
+    fn wait_and_process_once_with_tick_at_blocks_until_first_tick<S: Service>()
     where
         <S::Event as Event>::Listener: SynchronousMultiplexing,
     {
+        let _watchdog = Watchdog::new();
         let sut = WaitSetBuilder::new().create::<S>().unwrap();
 
-        let _tick_1_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
-        let _tick_2_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
-        let _tick_3_guard = sut.attach_interval(TIMEOUT * 1000).unwrap();
-        let _tick_4_guard = sut.attach_interval(TIMEOUT * 1000).unwrap();
+        for clock_type in ClockType::all_supported_clocks() {
+            let first_tick = time_from_now(*clock_type, TIMEOUT);
+            let tick_guard = sut.attach_interval_at(first_tick, TIMEOUT * 1000).unwrap();
 
-        std::thread::sleep(TIMEOUT);
+            let mut callback_called = false;
+            let start = Instant::now();
+            sut.wait_and_process_once(|id| {
+                callback_called = true;
+                assert_that!(id.has_event_from(&tick_guard), eq true);
+                CallbackProgression::Continue
+            })
+            .unwrap();
 
-        let mut counter = 0;
+            assert_that!(callback_called, eq true);
+            assert_that!(start.elapsed(), time_at_least TIMEOUT);
+            assert_that!(start.elapsed(), lt TIMEOUT * 1000);
+        }
+    }
 
-        sut.wait_and_process(|_| {
-            counter += 1;
-            CallbackProgression::Stop
+    #[test]
+    fn tick_at_in_the_past_is_reported_with_next_wait<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let first_tick = Time::now().unwrap();
+        std::thread::sleep(Duration::from_millis(1));
+        let tick_guard = sut.attach_interval_at(first_tick, TIMEOUT * 1000).unwrap();
+
+        let mut callback_called = false;
+        let start = Instant::now();
+        sut.wait_and_process_once(|id| {
+            callback_called = true;
+            assert_that!(id.has_event_from(&tick_guard), eq true);
+            CallbackProgression::Continue
         })
         .unwrap();
 
-        assert_that!(counter, eq 1);
+        assert_that!(callback_called, eq true);
+        assert_that!(start.elapsed(), lt TIMEOUT * 1000);
     }
 
     #[test]
-    fn wait_and_process_once_lists_mixed<S: Service>()
+    fn missed_ticks_reports_ticks_that_elapsed_during_a_long_callback<S: Service>()
     where
         <S::Event as Event>::Listener: SynchronousMultiplexing,
     {
+        const INTERVAL: Duration = Duration::from_millis(20);
+        let _watchdog = Watchdog::new();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
         let sut = WaitSetBuilder::new().create::<S>().unwrap();
 
-        let (listener_1, notifier_1) = create_event::<S>(&node);
-        let (listener_2, _notifier_2) = create_event::<S>(&node);
-        let (listener_3, notifier_3) = create_event::<S>(&node);
-        let (listener_4, _notifier_4) = create_event::<S>(&node);
-
-        let tick_1_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
-        let tick_2_guard = sut.attach_interval(TIMEOUT * 1000).unwrap();
-        let notification_1_guard = sut.attach_notification(&listener_1).unwrap();
-        let notification_2_guard = sut.attach_notification(&listener_2).unwrap();
-        let deadline_1_guard = sut.attach_deadline(&listener_3, TIMEOUT * 1000).unwrap();
-        let deadline_2_guard = sut
-            .attach_deadline(&listener_4, Duration::from_nanos(1))
-            .unwrap();
-
-        std::thread::sleep(TIMEOUT);
-
-        notifier_1.notify().unwrap();
-        notifier_3.notify().unwrap();
-
-        let mut tick_1_triggered = false;
-        let mut tick_2_triggered = false;
-        let mut notification_1_triggered = false;
-        let mut notification_2_triggered = false;
-        let mut deadline_1_triggered = false;
-        let mut deadline_2_triggered = false;
-        let mut deadline_1_missed = false;
-        let mut deadline_2_missed = false;
+        let (listener, notifier) = create_event::<S>(&node);
+        let notification_guard = sut.attach_notification(&listener).unwrap();
+        let tick_guard = sut.attach_interval(INTERVAL).unwrap();
 
-        sut.wait_and_process_once(|attachment_id| {
-            if attachment_id.has_event_from(&tick_1_guard) {
-                tick_1_triggered = true;
-            } else if attachment_id.has_event_from(&tick_2_guard) {
-                tick_2_triggered = true;
-            } else if attachment_id.has_event_from(&notification_1_guard) {
-                notification_1_triggered = true;
-            } else if attachment_id.has_event_from(&notification_2_guard) {
-                notification_2_triggered = true;
-            } else if attachment_id.has_event_from(&deadline_1_guard) {
-                deadline_1_triggered = true;
-            } else if attachment_id.has_event_from(&deadline_2_guard) {
-                deadline_2_triggered = true;
-            } else if attachment_id.has_missed_deadline(&deadline_1_guard) {
-                deadline_1_missed = true;
-            } else if attachment_id.has_missed_deadline(&deadline_2_guard) {
-                deadline_2_missed = true;
-            } else {
-                test_fail!("only attachments shall trigger");
+        sut.wait_and_process_once(|id| {
+            if id.has_event_from(&tick_guard) {
+                std::thread::sleep(INTERVAL * 3 + INTERVAL / 2);
             }
-
             CallbackProgression::Continue
         })
         .unwrap();
 
-        assert_that!(tick_1_triggered, eq true);
+        notifier.notify().unwrap();
+        let mut tick_handled = false;
+        let mut notification_handled = false;
+        sut.wait_and_process_once(|id| {
+            if id.has_event_from(&tick_guard) {
+                tick_handled = true;
+                assert_that!(sut.missed_ticks(&id), ge 2);
+            } else if id.has_event_from(&notification_guard) {
+                notification_handled = true;
+                assert_that!(sut.missed_ticks(&id), eq 0);
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(tick_handled, eq true);
+        assert_that!(notification_handled, eq true);
+    }
+
+    #[test]
+    fn deadline_at_is_reported_at_first_deadline<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _) = create_event::<S>(&node);
+        let first_deadline = time_from_now(ClockType::default(), TIMEOUT);
+        let guard = sut
+            .attach_deadline_at(&listener, first_deadline, TIMEOUT * 1000, true)
+            .unwrap();
+
+        let mut callback_called = false;
+        let start = Instant::now();
+        sut.wait_and_process_once(|id| {
+            callback_called = true;
+            assert_that!(id.has_missed_deadline(&guard), eq true);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(callback_called, eq true);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(start.elapsed(), lt TIMEOUT * 1000);
+    }
+
+    #[test]
+    fn wait_and_process_once_can_be_called_from_within_the_callback<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, notifier) = create_event::<S>(&node);
+        let guard = sut.attach_notification(&listener).unwrap();
+        let _tick_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
+
+        notifier.notify().unwrap();
+
+        let mut number_of_inner_calls = 0;
+        for _ in 0..2 {
+            sut.wait_and_process_once(|id| {
+                if id.has_event_from(&guard) {
+                    while listener.try_wait_one().unwrap().event_id().is_some() {}
+                    sut.wait_and_process_once(|_| {
+                        number_of_inner_calls += 1;
+                        CallbackProgression::Continue
+                    })
+                    .unwrap();
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+            notifier.notify().unwrap();
+        }
+
+        assert_that!(number_of_inner_calls, ge 2);
+    }
+
+    #[test]
+    fn drain_handles_all_pending_events_without_blocking<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, notifier_2) = create_event::<S>(&node);
+        let (listener_3, _notifier_3) = create_event::<S>(&node);
+        let guard_1 = sut.attach_notification(&listener_1).unwrap();
+        let guard_2 = sut.attach_notification(&listener_2).unwrap();
+        let _guard_3 = sut.attach_notification(&listener_3).unwrap();
+        let _tick_guard = sut.attach_interval(TIMEOUT * 1000).unwrap();
+
+        assert_that!(
+            sut.drain(|_| CallbackProgression::Continue).unwrap(),
+            eq(WaitSetRunResult::AllEventsHandled, 0)
+        );
+
+        notifier_1.notify().unwrap();
+        notifier_2.notify().unwrap();
+
+        let mut received = vec![];
+        let (run_result, number_of_calls) = sut
+            .drain(|id| {
+                if id.has_event_from(&guard_1) {
+                    while listener_1.try_wait_one().unwrap().event_id().is_some() {}
+                    received.push(1);
+                } else if id.has_event_from(&guard_2) {
+                    while listener_2.try_wait_one().unwrap().event_id().is_some() {}
+                    received.push(2);
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        received.sort();
+        assert_that!(run_result, eq WaitSetRunResult::AllEventsHandled);
+        assert_that!(number_of_calls, eq 2);
+        assert_that!(received, eq vec![1, 2]);
+        assert_that!(
+            sut.drain(|_| CallbackProgression::Continue).unwrap(),
+            eq(WaitSetRunResult::AllEventsHandled, 0)
+        );
+    }
+
+    #[test]
+    fn drain_stops_when_requested<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let _tick_1_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
+        let _tick_2_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
+
+        std::thread::sleep(TIMEOUT);
+
+        assert_that!(
+            sut.drain(|_| CallbackProgression::Stop).unwrap(),
+            eq(WaitSetRunResult::StopRequest, 1)
+        );
+    }
+
+    #[test]
+    fn drain_with_no_attachments_does_nothing<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        assert_that!(
+            sut.drain(|_| CallbackProgression::Continue).unwrap(),
+            eq(WaitSetRunResult::AllEventsHandled, 0)
+        );
+    }
+
+    #[test]
+    fn deadline_remaining_returns_time_until_deadline<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, _notifier_1) = create_event::<S>(&node);
+        let (listener_2, _notifier_2) = create_event::<S>(&node);
+        let deadline_guard = sut
+            .attach_deadline(&listener_1, Duration::from_secs(1))
+            .unwrap();
+        let notification_guard = sut.attach_notification(&listener_2).unwrap();
+        let tick_guard = sut.attach_interval(Duration::from_secs(1)).unwrap();
+
+        let remaining = deadline_guard.deadline_remaining().unwrap();
+        assert_that!(remaining, gt Duration::ZERO);
+        assert_that!(remaining, le Duration::from_secs(1));
+        assert_that!(notification_guard.deadline_remaining(), is_none);
+        assert_that!(tick_guard.deadline_remaining(), is_none);
+    }
+
+    #[test]
+    fn deadline_returns_the_configured_deadline<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, _notifier_1) = create_event::<S>(&node);
+        let (listener_2, _notifier_2) = create_event::<S>(&node);
+        let mut deadline_guard = sut
+            .attach_deadline(&listener_1, Duration::from_secs(1))
+            .unwrap();
+        let notification_guard = sut.attach_notification(&listener_2).unwrap();
+        let tick_guard = sut.attach_interval(Duration::from_secs(1)).unwrap();
+
+        assert_that!(deadline_guard.deadline(), eq Some(Duration::from_secs(1)));
+        assert_that!(notification_guard.deadline(), is_none);
+        assert_that!(tick_guard.deadline(), is_none);
+
+        sut.reattach_deadline(&mut deadline_guard, Duration::from_secs(2))
+            .unwrap();
+        assert_that!(deadline_guard.deadline(), eq Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn ready_events_returns_attachments_with_events<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, _notifier_2) = create_event::<S>(&node);
+        let (listener_3, notifier_3) = create_event::<S>(&node);
+        let guard_1 = sut.attach_notification(&listener_1).unwrap();
+        let guard_2 = sut.attach_notification(&listener_2).unwrap();
+        let guard_3 = sut.attach_notification(&listener_3).unwrap();
+
+        notifier_1.notify().unwrap();
+        notifier_3.notify().unwrap();
+
+        let ready_events = sut.ready_events(TIMEOUT).unwrap();
+        assert_that!(ready_events.run_result(), eq WaitSetRunResult::AllEventsHandled);
+        assert_that!(ready_events.len(), eq 2);
+
+        let ids: Vec<_> = ready_events.collect();
+        assert_that!(ids.iter().any(|id| id.has_event_from(&guard_1)), eq true);
+        assert_that!(ids.iter().any(|id| id.has_event_from(&guard_2)), eq false);
+        assert_that!(ids.iter().any(|id| id.has_event_from(&guard_3)), eq true);
+    }
+
+    #[test]
+    fn ready_events_is_empty_when_timeout_passed<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _notifier) = create_event::<S>(&node);
+        let _guard = sut.attach_notification(&listener).unwrap();
+
+        let start = Instant::now();
+        let mut ready_events = sut.ready_events(TIMEOUT).unwrap();
+
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(ready_events.run_result(), eq WaitSetRunResult::AllEventsHandled);
+        assert_that!(ready_events.next(), is_none);
+    }
+
+    #[test]
+    fn wait_and_process_stops_when_requested<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let _tick_1_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
+        let _tick_2_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
+        let _tick_3_guard = sut.attach_interval(TIMEOUT * 1000).unwrap();
+        let _tick_4_guard = sut.attach_interval(TIMEOUT * 1000).unwrap();
+
+        std::thread::sleep(TIMEOUT);
+
+        let mut counter = 0;
+
+        sut.wait_and_process(|_| {
+            counter += 1;
+            CallbackProgression::Stop
+        })
+        .unwrap();
+
+        assert_that!(counter, eq 1);
+    }
+
+    #[test]
+    fn wait_and_process_once_lists_mixed<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, _notifier_2) = create_event::<S>(&node);
+        let (listener_3, notifier_3) = create_event::<S>(&node);
+        let (listener_4, _notifier_4) = create_event::<S>(&node);
+
+        let tick_1_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
+        let tick_2_guard = sut.attach_interval(TIMEOUT * 1000).unwrap();
+        let notification_1_guard = sut.attach_notification(&listener_1).unwrap();
+        let notification_2_guard = sut.attach_notification(&listener_2).unwrap();
+        let deadline_1_guard = sut.attach_deadline(&listener_3, TIMEOUT * 1000).unwrap();
+        let deadline_2_guard = sut
+            .attach_deadline(&listener_4, Duration::from_nanos(1))
+            .unwrap();
+
+        std::thread::sleep(TIMEOUT);
+
+        notifier_1.notify().unwrap();
+        notifier_3.notify().unwrap();
+
+        let mut tick_1_triggered = false;
+        let mut tick_2_triggered = false;
+        let mut notification_1_triggered = false;
+        let mut notification_2_triggered = false;
+        let mut deadline_1_triggered = false;
+        let mut deadline_2_triggered = false;
+        let mut deadline_1_missed = false;
+        let mut deadline_2_missed = false;
+
+        sut.wait_and_process_once(|attachment_id| {
+            if attachment_id.has_event_from(&tick_1_guard) {
+                tick_1_triggered = true;
+            } else if attachment_id.has_event_from(&tick_2_guard) {
+                tick_2_triggered = true;
+            } else if attachment_id.has_event_from(&notification_1_guard) {
+                notification_1_triggered = true;
+            } else if attachment_id.has_event_from(&notification_2_guard) {
+                notification_2_triggered = true;
+            } else if attachment_id.has_event_from(&deadline_1_guard) {
+                deadline_1_triggered = true;
+            } else if attachment_id.has_event_from(&deadline_2_guard) {
+                deadline_2_triggered = true;
+            } else if attachment_id.has_missed_deadline(&deadline_1_guard) {
+                deadline_1_missed = true;
+            } else if attachment_id.has_missed_deadline(&deadline_2_guard) {
+                deadline_2_missed = true;
+            } else {
+                test_fail!("only attachments shall trigger");
+            }
+
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(tick_1_triggered, eq true);
         assert_that!(tick_2_triggered, eq false);
         assert_that!(notification_1_triggered, eq true);
         assert_that!(notification_2_triggered, eq false);
@@ -604,6 +1686,233 @@ mod waitset {
         assert_that!(now.elapsed(), time_at_least TIMEOUT / 2);
     }
 
+    #[test]
+    fn reattach_deadline_changes_deadline_in_place<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _) = create_event::<S>(&node);
+        let mut guard = sut.attach_deadline(&listener, TIMEOUT * 1000).unwrap();
+        let attachment_id = WaitSetAttachmentId::from_guard(&guard);
+
+        sut.reattach_deadline(&mut guard, TIMEOUT).unwrap();
+        assert_that!(sut.len(), eq 1);
+        assert_that!(WaitSetAttachmentId::from_guard(&guard), eq attachment_id);
+
+        let mut missed_deadline = false;
+        let start = Instant::now();
+        sut.wait_and_process_once(|id| {
+            missed_deadline = id.has_missed_deadline(&guard);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadline, eq true);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(start.elapsed(), lt TIMEOUT * 1000);
+    }
+
+    #[test]
+    fn reattach_deadline_keeps_notifications_attached<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, notifier) = create_event::<S>(&node);
+        let mut guard = sut.attach_deadline(&listener, TIMEOUT).unwrap();
+
+        notifier.notify().unwrap();
+        sut.reattach_deadline(&mut guard, TIMEOUT * 1000).unwrap();
+
+        let mut received_event = false;
+        sut.wait_and_process_once(|id| {
+            if id.has_event_from(&guard) {
+                received_event = true;
+            } else {
+                test_fail!("only the deadline attachment shall trigger");
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(received_event, eq true);
+    }
+
+    #[test]
+    fn reattach_deadline_with_non_deadline_guard_fails<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let other_waitset = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, _) = create_event::<S>(&node);
+        let (listener_2, _) = create_event::<S>(&node);
+
+        let mut tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+        let mut notification_guard = sut.attach_notification(&listener_1).unwrap();
+        let mut foreign_guard = other_waitset.attach_deadline(&listener_2, TIMEOUT).unwrap();
+
+        assert_that!(sut.reattach_deadline(&mut tick_guard, TIMEOUT).err(), eq Some(WaitSetReattachError::NotADeadlineAttachment));
+        assert_that!(sut.reattach_deadline(&mut notification_guard, TIMEOUT).err(), eq Some(WaitSetReattachError::NotADeadlineAttachment));
+        assert_that!(sut.reattach_deadline(&mut foreign_guard, TIMEOUT).err(), eq Some(WaitSetReattachError::NotADeadlineAttachment));
+
+        // a failed reattach leaves the attachments intact
+        assert_that!(sut.len(), eq 2);
+        assert_that!(other_waitset.len(), eq 1);
+        assert_that!(foreign_guard.deadline(), eq Some(TIMEOUT));
+    }
+
+    #[test]
+    fn attached_subscriber_wakes_up_waitset_on_new_sample<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_notifications(true)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let notification = subscriber.notification().unwrap();
+        let subscriber_guard = sut.attach_notification(&notification).unwrap();
+
+        publisher.send_copy(1234).unwrap();
+
+        let mut received_samples = vec![];
+        sut.wait_and_process_once(|attachment_id| {
+            if attachment_id.has_event_from(&subscriber_guard) {
+                while let Some(sample) = subscriber.receive().unwrap() {
+                    received_samples.push(*sample);
+                }
+            } else {
+                test_fail!("only the subscriber shall trigger");
+            }
+
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(received_samples, eq vec![1234]);
+    }
+
+    #[test]
+    fn attached_subscriber_does_not_wake_up_waitset_when_samples_are_received<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_notifications(true)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let notification = subscriber.notification().unwrap();
+        let _subscriber_guard = sut.attach_notification(&notification).unwrap();
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+        while subscriber.receive().unwrap().is_some() {}
+
+        let mut callback_called = false;
+        sut.wait_and_process_once_with_timeout(
+            |_| {
+                callback_called = true;
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+
+        assert_that!(callback_called, eq false);
+    }
+
+    #[test]
+    fn attached_subscriber_wakes_up_waitset_while_samples_are_pending<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_notifications(true)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let notification = subscriber.notification().unwrap();
+        let subscriber_guard = sut.attach_notification(&notification).unwrap();
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        let mut received_samples = vec![];
+        for _ in 0..2 {
+            sut.wait_and_process_once_with_timeout(
+                |attachment_id| {
+                    if attachment_id.has_event_from(&subscriber_guard) {
+                        received_samples.push(*subscriber.receive().unwrap().unwrap());
+                    }
+                    CallbackProgression::Continue
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+        }
+
+        assert_that!(received_samples, eq vec![1, 2]);
+    }
+
+    #[test]
+    fn subscriber_without_notifications_enabled_has_no_notification<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        assert_that!(subscriber.notification(), is_none);
+    }
+
     #[test]
     fn signal_handling_mechanism_can_be_configured<S: Service>() {
         let sut_1 = WaitSetBuilder::new()
@@ -620,6 +1929,136 @@ mod waitset {
         assert_that!(sut_2.signal_handling_mode(), eq SignalHandlingMode::HandleTerminationRequests);
     }
 
+    #[test]
+    fn signal_handling_mechanism_can_be_inherited_from_node<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .signal_handling_mode(SignalHandlingMode::Disabled)
+            .create::<S>()
+            .unwrap();
+
+        let sut = WaitSetBuilder::new()
+            .inherit_signal_handling_mode_from(&node)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(sut.signal_handling_mode(), eq SignalHandlingMode::Disabled);
+    }
+
+    #[test]
+    fn last_interrupt_signal_is_none_when_wait_was_not_interrupted<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let _tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        assert_that!(sut.last_interrupt_signal(), eq None);
+
+        let result = sut
+            .wait_and_process_once(|_| CallbackProgression::Continue)
+            .unwrap();
+
+        assert_that!(result, eq WaitSetRunResult::AllEventsHandled);
+        assert_that!(sut.last_interrupt_signal(), eq None);
+    }
+
+    #[test]
+    fn attaching_more_deadlines_than_expected_attachments_works<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const EXPECTED_ATTACHMENTS: usize = 2;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new()
+            .expected_attachments(EXPECTED_ATTACHMENTS)
+            .create::<S>()
+            .unwrap();
+
+        let mut listeners = vec![];
+        for _ in 0..EXPECTED_ATTACHMENTS * 2 {
+            listeners.push(create_event::<S>(&node).0);
+        }
+
+        let mut guards = vec![];
+        for listener in &listeners {
+            guards.push(sut.attach_deadline(listener, TIMEOUT * 1000).unwrap());
+        }
+
+        assert_that!(sut.len(), eq EXPECTED_ATTACHMENTS * 2);
+        drop(guards);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn stats_are_empty_after_creation<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        assert_that!(sut.stats(), eq WaitSetStats::default());
+    }
+
+    #[test]
+    fn stats_accumulate_all_runs<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const CALLBACK_DURATION: Duration = Duration::from_millis(10);
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, _) = create_event::<S>(&node);
+        let sut = WaitSetBuilder::new()
+            .measure_callback_duration(true)
+            .create::<S>()
+            .unwrap();
+
+        let notification_guard = sut.attach_notification(&listener_1).unwrap();
+        notifier_1.notify().unwrap();
+        sut.wait_and_process_once(|_| {
+            std::thread::sleep(CALLBACK_DURATION);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        listener_1.try_wait_all(|_| {}).unwrap();
+        drop(notification_guard);
+
+        let deadline_guard = sut.attach_deadline(&listener_2, TIMEOUT).unwrap();
+        sut.wait_and_process_once(|_| CallbackProgression::Continue)
+            .unwrap();
+        drop(deadline_guard);
+
+        let _tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+        sut.wait_and_process_once(|_| CallbackProgression::Continue)
+            .unwrap();
+
+        let stats = sut.stats();
+        assert_that!(stats.total_runs, eq 3);
+        assert_that!(stats.total_notifications, eq 1);
+        assert_that!(stats.total_deadline_misses, eq 1);
+        assert_that!(stats.total_ticks, eq 1);
+        assert_that!(stats.max_callback_duration_ns, ge CALLBACK_DURATION.as_nanos() as u64);
+    }
+
+    #[test]
+    fn callback_duration_is_not_measured_by_default<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let _tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+        sut.wait_and_process_once(|_| {
+            std::thread::sleep(TIMEOUT);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        let stats = sut.stats();
+        assert_that!(stats.total_ticks, eq 1);
+        assert_that!(stats.max_callback_duration_ns, eq 0);
+    }
+
     #[test]
     fn by_default_termination_signals_are_handled<S: Service>() {
         let sut = WaitSetBuilder::new().create::<S>().unwrap();